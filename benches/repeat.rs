@@ -0,0 +1,26 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ratatui_core::text::{Line, Span};
+use ratatui_macros::line;
+
+/// Compares the `span; n` syntax of `line!` with its previous expansion, `vec![span.into(); n]`,
+/// which clones the converted span for every element.
+fn repeat(c: &mut Criterion) {
+    let mut group = c.benchmark_group("line repeat");
+    for n in [10, 1_000, 100_000] {
+        group.bench_with_input(BenchmarkId::new("line!", n), &n, |b, &n| {
+            b.iter(|| black_box(line![black_box("fox"); n]))
+        });
+        group.bench_with_input(BenchmarkId::new("vec!", n), &n, |b, &n| {
+            b.iter(|| {
+                let span: Span = black_box("fox").into();
+                black_box(Line::from(vec![span; n]))
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, repeat);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ratatui_core::{style::Color, text::Span};
+use ratatui_macros::span;
+
+const CONTENT: &str = "the quick brown fox jumps over the lazy dog";
+
+/// Compares creating spans from string literals with `span!` against the previous expansion,
+/// which formatted the literal into a new `String` for every span.
+fn literal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("span literal");
+    group.bench_function("span!", |b| {
+        b.iter(|| black_box(span!("the quick brown fox jumps over the lazy dog")))
+    });
+    group.bench_function("styled span!", |b| {
+        b.iter(|| black_box(span!(Color::Red; "the quick brown fox jumps over the lazy dog")))
+    });
+    group.bench_function("Span::raw(String)", |b| {
+        b.iter(|| black_box(Span::raw(String::from(black_box(CONTENT)))))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, literal);
+criterion_main!(benches);
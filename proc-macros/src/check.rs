@@ -0,0 +1,38 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::quote;
+
+/// Validates the arguments of a `macro_rules!` macro and passes them on to it.
+///
+/// The input is `[callback!] [args] tokens`, where `tokens` are the arguments written by the user.
+/// When `check` accepts them, the expansion is `callback!(args tokens)`. Otherwise it is a compile
+/// error that points at the offending tokens, instead of the error reported by the `macro_rules!`
+/// macro when none of its rules match.
+pub(crate) fn expand(
+    input: TokenStream,
+    check: fn(TokenStream) -> syn::Result<()>,
+) -> syn::Result<TokenStream> {
+    let mut input = input.into_iter();
+    let callback = bracketed(input.next())?;
+    let args = bracketed(input.next())?;
+    let tokens = input.collect::<TokenStream>();
+    check(tokens.clone())?;
+    let group = Group::new(Delimiter::Parenthesis, quote!(#args #tokens));
+    Ok(quote!(#callback #group))
+}
+
+fn bracketed(token: Option<TokenTree>) -> syn::Result<TokenStream> {
+    match token {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+            Ok(group.stream())
+        }
+        token => Err(syn::Error::new_spanned(
+            token.map(TokenStream::from).unwrap_or_default(),
+            "expected a bracketed group",
+        )),
+    }
+}
+
+/// Renders tokens for an error message, e.g. `== 50 %` as `==50%`.
+pub(crate) fn render(tokens: &TokenStream) -> String {
+    tokens.to_string().replace(' ', "")
+}
@@ -0,0 +1,166 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::key_names::{self, Code, KeyError};
+
+/// A single key press, e.g. `ctrl-shift-p`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Chord {
+    /// Whether `shift`, `ctrl`, `alt`, `super`, `hyper` and `meta` are pressed, in that order
+    modifiers: [bool; 6],
+    code: Code,
+}
+
+/// Parses a sequence of key presses separated by whitespace, e.g. `g g` or `ctrl-x ctrl-s`.
+pub(crate) fn parse_sequence(input: &str) -> Result<Vec<Chord>, String> {
+    let sequence = input
+        .split_whitespace()
+        .map(parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if sequence.is_empty() {
+        return Err("expected a key like `ctrl-q`, found an empty string".to_string());
+    }
+    Ok(sequence)
+}
+
+/// Parses a single key press, e.g. `ctrl-q`, `shift-F5` or `esc`.
+pub(crate) fn parse(input: &str) -> Result<Chord, String> {
+    match key_names::parse_key(input) {
+        Ok((modifiers, code)) => Ok(Chord { modifiers, code }),
+        Err(KeyError::DuplicateModifier(modifier)) => Err(format!(
+            "duplicate modifier `{}` in `{input}`",
+            modifier.to_lowercase()
+        )),
+        Err(KeyError::UnknownKey(key)) => Err(format!(
+            "unknown key `{key}` in `{input}`, expected a character, a function key like `f5` \
+             or a key name like `esc`, optionally preceded by modifiers like `ctrl-`"
+        )),
+    }
+}
+
+impl std::fmt::Display for Chord {
+    /// Writes the chord with lowercase names and the modifiers in the order `ctrl`, `alt`,
+    /// `shift`, `super`, `hyper` and `meta`, e.g. `ctrl-shift-p`. A letter with `shift` is written
+    /// in lowercase, like it is usually written in a key binding.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, index) in [
+            ("ctrl", 1),
+            ("alt", 2),
+            ("shift", 0),
+            ("super", 3),
+            ("hyper", 4),
+            ("meta", 5),
+        ] {
+            if self.modifiers[index] {
+                write!(f, "{name}-")?;
+            }
+        }
+        match &self.code {
+            Code::Char(' ') => f.write_str("space"),
+            Code::Char(c) if self.modifiers[0] => write!(f, "{}", c.to_ascii_lowercase()),
+            Code::Char(c) => write!(f, "{c}"),
+            Code::F(number) => write!(f, "f{number}"),
+            Code::Named(variant) => f.write_str(&variant.to_lowercase()),
+        }
+    }
+}
+
+impl Chord {
+    /// Expands the chord to a `ratatui_macros::KeyChord` struct literal.
+    pub(crate) fn to_tokens(&self, krate: &TokenStream) -> TokenStream {
+        let code = match &self.code {
+            Code::Char(c) => quote!(Char(#c)),
+            Code::F(number) => quote!(F(#number)),
+            Code::Named(name) => {
+                let name = format_ident!("{name}");
+                quote!(#name)
+            }
+        };
+        let modifiers = self.modifiers;
+        quote! {
+            #krate::KeyChord {
+                code: #krate::crossterm::event::KeyCode::#code,
+                modifiers: #krate::__KeyModifiers::<#(#modifiers),*>::MODIFIERS,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(modifiers: [bool; 6], code: Code) -> Chord {
+        Chord { modifiers, code }
+    }
+
+    #[test]
+    fn keys() {
+        const NONE: [bool; 6] = [false; 6];
+        assert_eq!(parse("q"), Ok(chord(NONE, Code::Char('q'))));
+        assert_eq!(parse("Q"), Ok(chord(NONE, Code::Char('Q'))));
+        assert_eq!(parse("-"), Ok(chord(NONE, Code::Char('-'))));
+        assert_eq!(parse("space"), Ok(chord(NONE, Code::Char(' '))));
+        assert_eq!(parse("F5"), Ok(chord(NONE, Code::F(5))));
+        assert_eq!(parse("esc"), Ok(chord(NONE, Code::Named("Esc"))));
+        assert_eq!(parse("PageDown"), Ok(chord(NONE, Code::Named("PageDown"))));
+        assert!(parse("f25").is_err());
+        assert!(parse("escc").is_err());
+    }
+
+    #[test]
+    fn modifiers() {
+        let ctrl = [false, true, false, false, false, false];
+        let ctrl_shift = [true, true, false, false, false, false];
+        assert_eq!(parse("ctrl-q"), Ok(chord(ctrl, Code::Char('q'))));
+        assert_eq!(
+            parse("shift-a"),
+            Ok(chord(
+                [true, false, false, false, false, false],
+                Code::Char('A')
+            ))
+        );
+        assert_eq!(parse("Ctrl--"), Ok(chord(ctrl, Code::Char('-'))));
+        assert_eq!(
+            parse("ctrl-shift-up"),
+            Ok(chord(ctrl_shift, Code::Named("Up")))
+        );
+        assert_eq!(
+            parse("shift-control-up"),
+            Ok(chord(ctrl_shift, Code::Named("Up")))
+        );
+        assert!(parse("ctrl-ctrl-q").is_err());
+        assert!(parse("cmd-q").is_err());
+    }
+
+    #[test]
+    fn display() {
+        let display = |input| parse(input).unwrap().to_string();
+        assert_eq!(display("Shift-Ctrl-p"), "ctrl-shift-p");
+        assert_eq!(display("shift-P"), "shift-p");
+        assert_eq!(display("alt-return"), "alt-enter");
+        assert_eq!(display("meta-space"), "meta-space");
+        assert_eq!(display("F12"), "f12");
+        assert_eq!(display("ctrl--"), "ctrl--");
+        assert_eq!(display("PageDown"), "pagedown");
+    }
+
+    #[test]
+    fn shared_names() {
+        // `KeyChord::from_str` and `parse_key_sequence` parse the same table of names at runtime
+        for (modifier, _) in key_names::MODIFIERS {
+            for (name, variant) in key_names::NAMED_KEYS {
+                let chord = parse(&format!("{modifier}-{name}")).unwrap();
+                assert_eq!(chord.code, Code::Named(variant));
+            }
+        }
+    }
+
+    #[test]
+    fn sequences() {
+        let g = chord([false; 6], Code::Char('g'));
+        assert_eq!(parse_sequence("g g"), Ok(vec![g.clone(), g]));
+        assert!(parse_sequence("").is_err());
+        assert!(parse_sequence("g foo").is_err());
+    }
+}
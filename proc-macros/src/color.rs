@@ -0,0 +1,296 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitInt, LitStr, Token,
+};
+
+/// A color parsed from a string literal.
+#[derive(Debug, PartialEq, Eq)]
+enum Color {
+    /// The name of a `Color` variant, e.g. `LightRed`
+    Named(&'static str),
+    Rgb(u8, u8, u8),
+    Indexed(u8),
+    /// A color of the tailwind palette of ratatui, e.g. `SLATE` and `c500` for `slate.500`
+    Tailwind(&'static str, &'static str),
+}
+
+/// The named colors, keyed by their lowercase name without separators.
+const NAMED_COLORS: &[(&str, &str)] = &[
+    ("reset", "Reset"),
+    ("black", "Black"),
+    ("red", "Red"),
+    ("green", "Green"),
+    ("yellow", "Yellow"),
+    ("blue", "Blue"),
+    ("magenta", "Magenta"),
+    ("cyan", "Cyan"),
+    ("gray", "Gray"),
+    ("grey", "Gray"),
+    ("darkgray", "DarkGray"),
+    ("darkgrey", "DarkGray"),
+    ("lightred", "LightRed"),
+    ("lightgreen", "LightGreen"),
+    ("lightyellow", "LightYellow"),
+    ("lightblue", "LightBlue"),
+    ("lightmagenta", "LightMagenta"),
+    ("lightcyan", "LightCyan"),
+    ("white", "White"),
+];
+
+/// The palettes in `ratatui::style::palette::tailwind` and the names of their constants.
+const TAILWIND_PALETTES: &[(&str, &str)] = &[
+    ("slate", "SLATE"),
+    ("gray", "GRAY"),
+    ("zinc", "ZINC"),
+    ("neutral", "NEUTRAL"),
+    ("stone", "STONE"),
+    ("red", "RED"),
+    ("orange", "ORANGE"),
+    ("amber", "AMBER"),
+    ("yellow", "YELLOW"),
+    ("lime", "LIME"),
+    ("green", "GREEN"),
+    ("emerald", "EMERALD"),
+    ("teal", "TEAL"),
+    ("cyan", "CYAN"),
+    ("sky", "SKY"),
+    ("blue", "BLUE"),
+    ("indigo", "INDIGO"),
+    ("violet", "VIOLET"),
+    ("purple", "PURPLE"),
+    ("fuchsia", "FUCHSIA"),
+    ("pink", "PINK"),
+    ("rose", "ROSE"),
+];
+
+/// The shades of each tailwind palette, and the field of `Palette` that holds it.
+const TAILWIND_SHADES: &[(&str, &str)] = &[
+    ("50", "c50"),
+    ("100", "c100"),
+    ("200", "c200"),
+    ("300", "c300"),
+    ("400", "c400"),
+    ("500", "c500"),
+    ("600", "c600"),
+    ("700", "c700"),
+    ("800", "c800"),
+    ("900", "c900"),
+    ("950", "c950"),
+];
+
+/// A color literal, an indexed color, e.g. `@208`, or a tailwind color written as a palette and a
+/// shade, e.g. `slate.500`.
+pub(crate) struct Input(LitStr);
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return input.parse().map(Self);
+        }
+        if input.peek(Token![@]) {
+            input.parse::<Token![@]>()?;
+            let index = input.parse::<LitInt>()?;
+            return Ok(Self(LitStr::new(&format!("@{index}"), index.span())));
+        }
+        let lookahead = input.lookahead1();
+        if !lookahead.peek(Ident) {
+            return Err(lookahead.error());
+        }
+        let palette = input.parse::<Ident>()?;
+        input.parse::<Token![.]>()?;
+        let shade = input.parse::<LitInt>()?;
+        Ok(Self(LitStr::new(
+            &format!("{palette}.{shade}"),
+            palette.span(),
+        )))
+    }
+}
+
+pub(crate) fn expand(krate: &TokenStream, input: &Input) -> syn::Result<TokenStream> {
+    let literal = &input.0;
+    to_tokens(krate, &literal.value()).map_err(|err| syn::Error::new(literal.span(), err))
+}
+
+/// Parses a color and expands it to the matching `Color`.
+pub(crate) fn to_tokens(krate: &TokenStream, input: &str) -> Result<TokenStream, String> {
+    let color = match parse(input)? {
+        Color::Named(name) => {
+            let name = format_ident!("{name}");
+            quote!(#krate::ratatui_core::style::Color::#name)
+        }
+        Color::Rgb(r, g, b) => quote!(#krate::ratatui_core::style::Color::Rgb(#r, #g, #b)),
+        Color::Indexed(index) => quote!(#krate::ratatui_core::style::Color::Indexed(#index)),
+        Color::Tailwind(palette, shade) => {
+            let palette = format_ident!("{palette}");
+            let shade = format_ident!("{shade}");
+            quote!(#krate::ratatui_core::style::palette::tailwind::#palette.#shade)
+        }
+    };
+    Ok(color)
+}
+
+/// Parses a hex color (`#1e293b` or `#fff`), an `rgb(r, g, b)` color, an indexed color (`@208`),
+/// a tailwind color (`slate.500`) or a named color.
+fn parse(input: &str) -> Result<Color, String> {
+    let input = input.trim();
+    if let Some(hex) = input.strip_prefix('#') {
+        return parse_hex(hex).ok_or_else(|| {
+            format!("invalid hex color `{input}`, expected a color like `#1e293b` or `#fff`")
+        });
+    }
+    if let Some(index) = input.strip_prefix('@') {
+        return index.parse().map(Color::Indexed).map_err(|_| {
+            format!("invalid indexed color `{input}`, expected an index from `@0` to `@255`")
+        });
+    }
+    if let Some(args) = input
+        .strip_prefix("rgb(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return parse_rgb(args).ok_or_else(|| {
+            format!("invalid rgb color `{input}`, expected a color like `rgb(30, 41, 59)`")
+        });
+    }
+    if let Some(color) = parse_tailwind(input) {
+        return color;
+    }
+    let name = input
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .collect::<String>()
+        .to_lowercase();
+    NAMED_COLORS
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, variant)| Color::Named(variant))
+        .ok_or_else(|| {
+            format!(
+                "unknown color `{input}`, expected a hex color like `#1e293b`, an rgb color like \
+                 `rgb(30, 41, 59)`, a color name like `light-red` or a tailwind color like \
+                 `slate.500`"
+            )
+        })
+}
+
+/// Parses a tailwind color, which is a palette and a shade separated by `.` or `-`, e.g.
+/// `slate.500` or `amber-200`. Returns `None` if the input does not end with a shade.
+fn parse_tailwind(input: &str) -> Option<Result<Color, String>> {
+    let (palette, shade) = input.rsplit_once(['.', '-'])?;
+    if palette.is_empty() || shade.is_empty() || !shade.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let lowercase = palette.to_lowercase();
+    let Some((_, constant)) = TAILWIND_PALETTES
+        .iter()
+        .find(|(name, _)| *name == lowercase)
+    else {
+        let palettes = TAILWIND_PALETTES.iter().map(|(name, _)| *name);
+        return Some(Err(format!(
+            "unknown tailwind color `{palette}` in `{input}`, expected one of {}",
+            palettes.collect::<Vec<_>>().join(", ")
+        )));
+    };
+    let Some((_, field)) = TAILWIND_SHADES.iter().find(|(name, _)| *name == shade) else {
+        let shades = TAILWIND_SHADES.iter().map(|(name, _)| *name);
+        return Some(Err(format!(
+            "unknown tailwind shade `{shade}` in `{input}`, expected one of {}",
+            shades.collect::<Vec<_>>().join(", ")
+        )));
+    };
+    Some(Ok(Color::Tailwind(constant, field)))
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let component = |index: usize, len: usize| {
+        let value = u8::from_str_radix(&hex[index * len..(index + 1) * len], 16).ok()?;
+        // `#fff` is a shorthand for `#ffffff`
+        Some(if len == 1 { value * 0x11 } else { value })
+    };
+    let len = match hex.len() {
+        3 => 1,
+        6 => 2,
+        _ => return None,
+    };
+    Some(Color::Rgb(
+        component(0, len)?,
+        component(1, len)?,
+        component(2, len)?,
+    ))
+}
+
+fn parse_rgb(args: &str) -> Option<Color> {
+    let mut components = args.split(',').map(|value| value.trim().parse::<u8>());
+    let color = Color::Rgb(
+        components.next()?.ok()?,
+        components.next()?.ok()?,
+        components.next()?.ok()?,
+    );
+    components.next().is_none().then_some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse("#1e293b"), Ok(Color::Rgb(0x1e, 0x29, 0x3b)));
+        assert_eq!(parse("#FFAA00"), Ok(Color::Rgb(0xff, 0xaa, 0x00)));
+        assert_eq!(parse("#fa0"), Ok(Color::Rgb(0xff, 0xaa, 0x00)));
+        assert!(parse("#1e293").is_err());
+        assert!(parse("#1e293g").is_err());
+        assert!(parse("#").is_err());
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(parse("rgb(30, 41, 59)"), Ok(Color::Rgb(30, 41, 59)));
+        assert_eq!(parse("rgb(0,0,255)"), Ok(Color::Rgb(0, 0, 255)));
+        assert!(parse("rgb(30, 41)").is_err());
+        assert!(parse("rgb(30, 41, 59, 1)").is_err());
+        assert!(parse("rgb(256, 0, 0)").is_err());
+    }
+
+    #[test]
+    fn named() {
+        assert_eq!(parse("red"), Ok(Color::Named("Red")));
+        assert_eq!(parse("LightBlue"), Ok(Color::Named("LightBlue")));
+        assert_eq!(parse("light-blue"), Ok(Color::Named("LightBlue")));
+        assert_eq!(parse("dark_grey"), Ok(Color::Named("DarkGray")));
+        assert!(parse("redish").is_err());
+    }
+
+    #[test]
+    fn indexed() {
+        assert_eq!(parse("@208"), Ok(Color::Indexed(208)));
+        assert_eq!(parse("@0"), Ok(Color::Indexed(0)));
+        assert_eq!(
+            parse("@256"),
+            Err("invalid indexed color `@256`, expected an index from `@0` to `@255`".to_string())
+        );
+        assert!(parse("@").is_err());
+    }
+
+    #[test]
+    fn tailwind() {
+        assert_eq!(parse("slate.500"), Ok(Color::Tailwind("SLATE", "c500")));
+        assert_eq!(parse("Amber-200"), Ok(Color::Tailwind("AMBER", "c200")));
+        assert_eq!(parse("rose.950"), Ok(Color::Tailwind("ROSE", "c950")));
+        assert_eq!(
+            parse("slate.550"),
+            Err(
+                "unknown tailwind shade `550` in `slate.550`, expected one of 50, 100, 200, 300, \
+                 400, 500, 600, 700, 800, 900, 950"
+                    .to_string()
+            )
+        );
+        assert!(parse("slaty.500")
+            .unwrap_err()
+            .starts_with("unknown tailwind color `slaty`"));
+    }
+}
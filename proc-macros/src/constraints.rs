@@ -0,0 +1,238 @@
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use syn::{Expr, LitInt};
+
+use crate::check::render;
+
+/// The operators that start a constraint, e.g. `==` in `==50%`.
+const OPERATORS: &[&str] = &["==", ">=", "<=", "*="];
+
+/// Checks a comma separated list of constraints, optionally followed by `; count`.
+pub(crate) fn check(tokens: TokenStream) -> syn::Result<()> {
+    // each constraint and the comma that ends it
+    let mut elements = vec![(TokenStream::new(), None)];
+    let mut count = None;
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                elements.last_mut().unwrap().1 = Some(token.clone());
+                elements.push((TokenStream::new(), None));
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                count = Some((token.clone(), tokens.by_ref().collect::<TokenStream>()));
+            }
+            _ => elements.last_mut().unwrap().0.extend([token]),
+        }
+    }
+    // a single trailing comma is allowed
+    if elements.len() > 1 && elements.last().unwrap().0.is_empty() && count.is_none() {
+        elements.pop();
+    }
+    for (element, comma) in &elements {
+        if element.is_empty() {
+            let message = "expected a constraint like `==50%`, found an empty constraint";
+            return Err(match comma {
+                Some(comma) => syn::Error::new_spanned(comma, message),
+                None => syn::Error::new(proc_macro2::Span::call_site(), message),
+            });
+        }
+        check_constraint(element)?;
+    }
+    let mut repeat = 1;
+    if let Some((semicolon, count)) = count {
+        if elements.len() > 1 {
+            return Err(syn::Error::new_spanned(
+                semicolon,
+                "repetition with `;` is only supported for a single constraint, e.g. `==1; 3`",
+            ));
+        }
+        let count = strip_trailing_comma(count);
+        if count.is_empty() {
+            return Err(syn::Error::new_spanned(
+                semicolon,
+                "expected the number of constraints after `;`, e.g. `==1; 3`",
+            ));
+        }
+        syn::parse2::<Expr>(count.clone())?;
+        match syn::parse2::<LitInt>(count).and_then(|count| count.base10_parse::<u64>()) {
+            Ok(count) => repeat = count,
+            // the number of constraints is only known at runtime
+            Err(_) => return Ok(()),
+        }
+    }
+    check_percentages(&elements, repeat)
+}
+
+/// Checks that literal percentages add up to at most 100%, when all the constraints are literal
+/// percentages like `==60%`. The error points at the constraint that exceeds 100%.
+fn check_percentages(
+    elements: &[(TokenStream, Option<TokenTree>)],
+    repeat: u64,
+) -> syn::Result<()> {
+    let mut total = 0;
+    let mut exceeded = None;
+    for (element, _) in elements {
+        let tokens = element.clone().into_iter().collect::<Vec<_>>();
+        let percentage = match tokens.as_slice() {
+            [TokenTree::Punct(_), TokenTree::Punct(_), TokenTree::Literal(value), TokenTree::Punct(percent)]
+                if percent.as_char() == '%' =>
+            {
+                syn::parse2::<LitInt>(TokenTree::Literal(value.clone()).into())
+                    .and_then(|value| value.base10_parse::<u64>())
+                    .ok()
+            }
+            _ => None,
+        };
+        // `check_constraint` only accepts `%` after `==`
+        let Some(percentage) = percentage else {
+            return Ok(());
+        };
+        total += percentage.saturating_mul(repeat);
+        if total > 100 && exceeded.is_none() {
+            exceeded = Some(element);
+        }
+    }
+    match exceeded {
+        Some(element) => Err(syn::Error::new_spanned(
+            element,
+            format!("the percentages add up to {total}%, which is more than 100%"),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_constraint(element: &TokenStream) -> syn::Result<()> {
+    let tokens = element.clone().into_iter().collect::<Vec<_>>();
+    let operator = match tokens.as_slice() {
+        [TokenTree::Punct(first), TokenTree::Punct(second), ..]
+            if first.spacing() == Spacing::Joint =>
+        {
+            format!("{}{}", first.as_char(), second.as_char())
+        }
+        _ => String::new(),
+    };
+    if !OPERATORS.contains(&operator.as_str()) {
+        return Err(syn::Error::new_spanned(
+            element,
+            format!(
+                "expected a constraint like `==50%`, `>=10`, `<=20`, `==1/3` or `*=1`, found `{}`",
+                render(element)
+            ),
+        ));
+    }
+    let value = tokens[2..].iter().cloned().collect::<TokenStream>();
+    if value.is_empty() {
+        return Err(syn::Error::new_spanned(
+            element,
+            format!("expected a value after `{operator}`, e.g. `{operator}10`"),
+        ));
+    }
+    if operator == "==" {
+        // literal percentages and denominators are checked like in `parse_constraint`
+        let literal = |token: &TokenTree| {
+            syn::parse2::<LitInt>(token.clone().into())
+                .and_then(|value| value.base10_parse::<u64>())
+                .ok()
+        };
+        match &tokens[2..] {
+            [value, TokenTree::Punct(percent)] if percent.as_char() == '%' => {
+                if literal(value).is_some_and(|value| value > 100) {
+                    return Err(syn::Error::new_spanned(
+                        element,
+                        format!(
+                            "expected a percentage from 0 to 100 in `{}`",
+                            render(element)
+                        ),
+                    ));
+                }
+                return Ok(());
+            }
+            [_, TokenTree::Punct(slash), denominator] if slash.as_char() == '/' => {
+                if literal(denominator) == Some(0) {
+                    return Err(syn::Error::new_spanned(
+                        element,
+                        format!(
+                            "expected a ratio with a denominator that is not 0 in `{}`",
+                            render(element)
+                        ),
+                    ));
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+    syn::parse2::<Expr>(value).map(|_| ()).map_err(|err| {
+        syn::Error::new(
+            err.span(),
+            format!("invalid constraint `{}`: {err}", render(element)),
+        )
+    })
+}
+
+fn strip_trailing_comma(tokens: TokenStream) -> TokenStream {
+    let mut tokens = tokens.into_iter().collect::<Vec<_>>();
+    if matches!(tokens.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == ',') {
+        tokens.pop();
+    }
+    tokens.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn valid_constraints() {
+        assert!(check(quote!(==50%, >=3, <=1, ==1/2, *=1, ==a + b)).is_ok());
+        assert!(check(quote!(==5,)).is_ok());
+        assert!(check(quote!(==5; 5)).is_ok());
+        assert!(check(quote!(==60%, ==40%)).is_ok());
+        assert!(check(quote!(==60%, ==60%, ==a)).is_ok());
+        assert!(check(quote!(==60%, >=60)).is_ok());
+        assert!(check(quote!(==60%; count)).is_ok());
+    }
+
+    #[test]
+    fn invalid_constraints() {
+        let err = check(quote!(=50%)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a constraint like `==50%`, `>=10`, `<=20`, `==1/3` or `*=1`, found `=50%`"
+        );
+        let err = check(quote!(==1, ==)).unwrap_err();
+        assert_eq!(err.to_string(), "expected a value after `==`, e.g. `==10`");
+        let err = check(quote!(==1,, ==2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a constraint like `==50%`, found an empty constraint"
+        );
+        let err = check(quote!(==1, ==2; 3)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "repetition with `;` is only supported for a single constraint, e.g. `==1; 3`"
+        );
+        let err = check(quote!(==60%, ==30%, ==20%)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the percentages add up to 110%, which is more than 100%"
+        );
+        let err = check(quote!(==101%)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a percentage from 0 to 100 in `==101%`"
+        );
+        let err = check(quote!(==1, ==1/0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a ratio with a denominator that is not 0 in `==1/0`"
+        );
+        let err = check(quote!(==30%; 4)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the percentages add up to 120%, which is more than 100%"
+        );
+    }
+}
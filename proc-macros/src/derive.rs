@@ -0,0 +1,408 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{meta::ParseNestedMeta, Data, DeriveInput, Fields, Ident, Lit, LitStr, Member, Token};
+
+use crate::{color, markup::MODIFIERS, rich::Style};
+
+/// The path of the `ratatui-macros` crate in the expansion. Derive macros do not receive a path
+/// like the function-like macros, so the crate is `::ratatui_macros` unless the struct sets another
+/// path with `crate = "..."`, e.g. when `ratatui-macros` is re-exported by another crate.
+fn krate(attrs: &[syn::Attribute], target: Target) -> syn::Result<TokenStream> {
+    let mut krate = quote!(::ratatui_macros);
+    for attr in attrs {
+        if !attr.path().is_ident(target.attribute()) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let path = meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?;
+                krate = quote!(#path);
+            } else if meta.input.peek(Token![=]) {
+                // the other attributes are parsed by `parse_attributes`
+                meta.value()?.parse::<Lit>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(krate)
+}
+
+/// The type that a derive converts a struct into.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Target {
+    /// `#[derive(ToLine)]`, which turns each field into a span of a `Line`
+    Line,
+    /// `#[derive(ToText)]`, which turns each field into a line of a `Text`
+    Text,
+    /// `#[derive(ToRow)]`, which turns each field into a cell of a `Row`
+    Row,
+}
+
+impl Target {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Line => "ToLine",
+            Self::Text => "ToText",
+            Self::Row => "ToRow",
+        }
+    }
+
+    fn attribute(self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Text => "text",
+            Self::Row => "row",
+        }
+    }
+}
+
+/// The attributes of the struct or of a field, e.g. `#[line(style = "bold", format = "({})")]`.
+#[derive(Default)]
+struct Attributes {
+    style: Style,
+    format: Option<LitStr>,
+    separator: Option<LitStr>,
+    skip: bool,
+    /// The alignment of a cell of a `Row`, e.g. `Right`
+    alignment: Option<Ident>,
+    /// The title of the header cell of a field of a `Row`
+    header: Option<LitStr>,
+    /// The style of the header `Row`
+    header_style: Style,
+}
+
+pub(crate) fn expand(input: &DeriveInput, target: Target) -> syn::Result<TokenStream> {
+    let krate = krate(&input.attrs, target)?;
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("`{}` can only be derived for structs", target.name()),
+        ));
+    };
+    let container = parse_attributes(&krate, &input.attrs, target, true)?;
+    let mut parts = Vec::new();
+    let mut headers = Vec::new();
+    let fields = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+    for (index, field) in fields.into_iter().enumerate() {
+        let attributes = parse_attributes(&krate, &field.attrs, target, false)?;
+        if attributes.skip {
+            continue;
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        };
+        let content = match &attributes.format {
+            Some(format) => quote!(::std::format!(#format, value.#member)),
+            None => quote!(::std::string::ToString::to_string(&value.#member)),
+        };
+        let style = (!attributes.style.is_empty()).then(|| attributes.style.to_tokens(&krate));
+        let part = match target {
+            Target::Line | Target::Text => {
+                let span = match style {
+                    Some(style) => {
+                        quote!(#krate::ratatui_core::text::Span::styled(#content, #style))
+                    }
+                    None => quote!(#krate::ratatui_core::text::Span::raw(#content)),
+                };
+                match target {
+                    Target::Line => span,
+                    _ => quote!(#krate::ratatui_core::text::Line::from(#span)),
+                }
+            }
+            Target::Row => {
+                let alignment = attributes.alignment.as_ref().map(|alignment| {
+                    quote!(.alignment(#krate::ratatui_core::layout::Alignment::#alignment))
+                });
+                let style = style.map(|style| quote!(.style(#style)));
+                let header = attributes.header.clone().unwrap_or_else(|| {
+                    let title = match &member {
+                        Member::Named(ident) => ident.to_string(),
+                        Member::Unnamed(index) => index.index.to_string(),
+                    };
+                    LitStr::new(&title, proc_macro2::Span::call_site())
+                });
+                headers.push(quote!(#krate::ratatui_widgets::table::Cell::from(
+                    #krate::ratatui_core::text::Text::from(#header) #alignment
+                )));
+                quote!(#krate::ratatui_widgets::table::Cell::from(
+                    #krate::ratatui_core::text::Text::from(#content) #alignment
+                ) #style)
+            }
+        };
+        parts.push(part);
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let style = (!container.style.is_empty()).then(|| {
+        let style = container.style.to_tokens(&krate);
+        quote!(.style(#style))
+    });
+    let (ty, body) = match target {
+        Target::Line => {
+            let separator = container
+                .separator
+                .map_or_else(|| quote!(" "), |separator| quote!(#separator));
+            let mut spans = Vec::new();
+            for (index, part) in parts.into_iter().enumerate() {
+                if index > 0 {
+                    spans.push(quote!(#krate::ratatui_core::text::Span::raw(#separator)));
+                }
+                spans.push(part);
+            }
+            (
+                quote!(#krate::ratatui_core::text::Line<'static>),
+                quote!(#krate::ratatui_core::text::Line::from(::std::vec![#(#spans),*]) #style),
+            )
+        }
+        Target::Text => (
+            quote!(#krate::ratatui_core::text::Text<'static>),
+            quote!(#krate::ratatui_core::text::Text::from(::std::vec![#(#parts),*]) #style),
+        ),
+        Target::Row => (
+            quote!(#krate::ratatui_widgets::table::Row<'static>),
+            quote!(#krate::ratatui_widgets::table::Row::new([#(#parts),*]) #style),
+        ),
+    };
+    let header = (target == Target::Row).then(|| {
+        let style = (!container.header_style.is_empty()).then(|| {
+            let style = container.header_style.to_tokens(&krate);
+            quote!(.style(#style))
+        });
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Returns the header row of a table of this type, with a title for each field.
+                #[allow(dead_code)]
+                pub fn header() -> #krate::ratatui_widgets::table::Row<'static> {
+                    #krate::ratatui_widgets::table::Row::new([#(#headers),*]) #style
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<&#name #ty_generics> for #ty #where_clause {
+            fn from(value: &#name #ty_generics) -> Self {
+                #body
+            }
+        }
+        #header
+    })
+}
+
+fn parse_attributes(
+    krate: &TokenStream,
+    attrs: &[syn::Attribute],
+    target: Target,
+    container: bool,
+) -> syn::Result<Attributes> {
+    let mut attributes = Attributes::default();
+    for attr in attrs {
+        if !attr.path().is_ident(target.attribute()) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("style") {
+                let value = meta.value()?.parse::<LitStr>()?;
+                let style = parse_style(krate, &value.value())
+                    .map_err(|err| syn::Error::new(value.span(), err))?;
+                attributes.style.patch(&style);
+            } else if meta.path.is_ident("fg") {
+                attributes.style.fg = Some(parse_color(krate, &meta)?);
+            } else if meta.path.is_ident("bg") {
+                attributes.style.bg = Some(parse_color(krate, &meta)?);
+            } else if meta.path.is_ident("crate") && container {
+                // parsed by `krate`
+                meta.value()?.parse::<LitStr>()?;
+            } else if meta.path.is_ident("format") && !container {
+                attributes.format = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("separator") && container && target == Target::Line {
+                attributes.separator = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("skip") && !container {
+                attributes.skip = true;
+            } else if meta.path.is_ident("align") && !container && target == Target::Row {
+                let value = meta.value()?.parse::<LitStr>()?;
+                let alignment = match value.value().as_str() {
+                    "left" => "Left",
+                    "center" => "Center",
+                    "right" => "Right",
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "expected `left`, `center` or `right`",
+                        ))
+                    }
+                };
+                attributes.alignment = Some(Ident::new(alignment, value.span()));
+            } else if meta.path.is_ident("header") && !container && target == Target::Row {
+                attributes.header = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("header_style") && container && target == Target::Row {
+                let value = meta.value()?.parse::<LitStr>()?;
+                attributes.header_style = parse_style(krate, &value.value())
+                    .map_err(|err| syn::Error::new(value.span(), err))?;
+            } else {
+                let expected = match (container, target) {
+                    (true, Target::Line) => "`style`, `fg`, `bg`, `separator` or `crate`",
+                    (true, Target::Text) => "`style`, `fg`, `bg` or `crate`",
+                    (true, Target::Row) => "`style`, `fg`, `bg`, `header_style` or `crate`",
+                    (false, Target::Row) => {
+                        "`style`, `fg`, `bg`, `format`, `align`, `header` or `skip`"
+                    }
+                    (false, _) => "`style`, `fg`, `bg`, `format` or `skip`",
+                };
+                return Err(meta.error(format!("unknown attribute, expected {expected}")));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attributes)
+}
+
+fn parse_color(krate: &TokenStream, meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let value = meta.value()?.parse::<LitStr>()?;
+    color::to_tokens(krate, &value.value()).map_err(|err| syn::Error::new(value.span(), err))
+}
+
+/// Parses the words of a `style` attribute, e.g. `bold red on black`, which are the same as the
+/// words of a `markup!` tag.
+pub(crate) fn parse_style(krate: &TokenStream, style: &str) -> Result<Style, String> {
+    let mut parsed = Style::default();
+    let mut words = style.split_whitespace();
+    while let Some(word) = words.next() {
+        if let Some((_, modifier)) = MODIFIERS.iter().find(|(name, _)| *name == word) {
+            parsed.add_modifier(modifier);
+        } else if word == "on" {
+            let color = words
+                .next()
+                .ok_or_else(|| format!("expected a color after `on` in `{style}`"))?;
+            parsed.bg = Some(color::to_tokens(krate, color)?);
+        } else {
+            parsed.fg = Some(color::to_tokens(krate, word).map_err(|_| {
+                format!(
+                    "unknown style `{word}`, expected a modifier like `bold`, a color like `red` \
+                     or `on` followed by a background color"
+                )
+            })?);
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(input: TokenStream, target: Target) -> String {
+        let input = syn::parse2::<DeriveInput>(input).unwrap();
+        match expand(&input, target) {
+            Ok(tokens) => tokens.to_string().replace(' ', ""),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn line() {
+        let output = render(
+            quote! {
+                #[line(separator = "|")]
+                struct Entry {
+                    #[line(style = "bold")]
+                    name: String,
+                    #[line(fg = "gray", format = "({})")]
+                    count: usize,
+                    #[line(skip)]
+                    id: u64,
+                }
+            },
+            Target::Line,
+        );
+        assert_eq!(
+            output,
+            "impl::core::convert::From<&Entry>for::ratatui_macros::ratatui_core::text::Line<'static>\
+             {fnfrom(value:&Entry)->Self{::ratatui_macros::ratatui_core::text::Line::from(\
+             ::std::vec![::ratatui_macros::ratatui_core::text::Span::styled(\
+             ::std::string::ToString::to_string(&value.name),\
+             ::ratatui_macros::ratatui_core::style::Style::new().add_modifier(\
+             ::ratatui_macros::ratatui_core::style::Modifier::BOLD)),\
+             ::ratatui_macros::ratatui_core::text::Span::raw(\"|\"),\
+             ::ratatui_macros::ratatui_core::text::Span::styled(::std::format!(\"({})\",value.count),\
+             ::ratatui_macros::ratatui_core::style::Style::new().fg(\
+             ::ratatui_macros::ratatui_core::style::Color::Gray))])}}"
+        );
+    }
+
+    #[test]
+    fn crate_path() {
+        let output = render(
+            quote! {
+                #[line(style = "bold", crate = "framework::ratatui_macros")]
+                struct Entry(u8);
+            },
+            Target::Line,
+        );
+        assert!(output.starts_with(
+            "impl::core::convert::From<&Entry>forframework::ratatui_macros::ratatui_core::text::Line"
+        ));
+        assert!(!output.contains("(::ratatui_macros"));
+    }
+
+    #[test]
+    fn row() {
+        let output = render(
+            quote! {
+                struct Entry(#[row(align = "right", header = "Size")] u64);
+            },
+            Target::Row,
+        );
+        assert!(output.contains(
+            "::ratatui_macros::ratatui_widgets::table::Cell::from(\
+             ::ratatui_macros::ratatui_core::text::Text::from(\"Size\").alignment(\
+             ::ratatui_macros::ratatui_core::layout::Alignment::Right))"
+        ));
+        assert!(output.contains("pubfnheader()"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            render(
+                quote!(
+                    enum Entry {}
+                ),
+                Target::Line
+            ),
+            "`ToLine` can only be derived for structs"
+        );
+        assert_eq!(
+            render(
+                quote!(
+                    struct Entry(#[text(separator = ",")] u8);
+                ),
+                Target::Text
+            ),
+            "unknown attribute, expected `style`, `fg`, `bg`, `format` or `skip`"
+        );
+        assert_eq!(
+            render(
+                quote!(
+                    struct Entry(#[row(align = "middle")] u8);
+                ),
+                Target::Row
+            ),
+            "expected `left`, `center` or `right`"
+        );
+        assert_eq!(
+            render(
+                quote!(
+                    struct Entry(#[line(style = "blod")] u8);
+                ),
+                Target::Line
+            ),
+            "unknown style `blod`, expected a modifier like `bold`, a color like `red` or `on` \
+             followed by a background color"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    color,
+    rich::{self, placeholder_len, Input, Line, Style},
+};
+
+pub(crate) fn expand(krate: &TokenStream, input: &Input) -> syn::Result<TokenStream> {
+    let lines = parse(krate, &input.format.value())
+        .map_err(|err| syn::Error::new(input.format.span(), err))?;
+    rich::expand(krate, input, lines)
+}
+
+/// The tags without attributes and the modifier they add.
+const TAGS: &[(&str, &str)] = &[
+    ("b", "BOLD"),
+    ("strong", "BOLD"),
+    ("i", "ITALIC"),
+    ("em", "ITALIC"),
+    ("u", "UNDERLINED"),
+    ("s", "CROSSED_OUT"),
+    ("del", "CROSSED_OUT"),
+];
+
+/// The character entities and the text they stand for.
+const ENTITIES: &[(&str, &str)] = &[
+    ("&lt;", "<"),
+    ("&gt;", ">"),
+    ("&amp;", "&"),
+    ("&quot;", "\""),
+    ("&apos;", "'"),
+    ("&nbsp;", "\u{a0}"),
+];
+
+/// Parses HTML-like tags, e.g. `<b>Save</b> <span fg="red">changes</span>`, into lines of styled
+/// text. Both `\n` and `<br>` start a new line.
+fn parse(krate: &TokenStream, html: &str) -> Result<Vec<Line>, String> {
+    let mut lines = vec![Line::default()];
+    // the open tags and their styles, innermost last
+    let mut open: Vec<(&str, Style)> = Vec::new();
+    let mut rest = html;
+    while let Some(c) = rest.chars().next() {
+        let line = lines.last_mut().expect("there is always a line");
+        if c == '<' {
+            let end = rest.find('>').ok_or_else(|| {
+                format!("unclosed tag `{rest}`, use `&lt;` to write a literal `<`")
+            })?;
+            let tag = rest[1..end].trim();
+            rest = &rest[end + 1..];
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                match open.pop() {
+                    Some((last, _)) if last == name => {}
+                    Some((last, _)) => {
+                        return Err(format!("expected `</{last}>`, found `</{name}>`"));
+                    }
+                    None => return Err(format!("`</{name}>` does not close any tag")),
+                }
+            } else if matches!(tag.trim_end_matches('/').trim(), "br") {
+                lines.push(Line::default());
+            } else {
+                let (name, style) = parse_tag(krate, tag)?;
+                open.push((name, style));
+            }
+        } else if c == '&' {
+            let (entity, text) = ENTITIES
+                .iter()
+                .find(|(entity, _)| rest.starts_with(entity))
+                .ok_or_else(|| {
+                    let entity = &rest[..rest.find(';').map_or(1, |end| end + 1)];
+                    format!("unknown entity `{entity}`, use `&amp;` to write a literal `&`")
+                })?;
+            line.push(text, &style(&open));
+            rest = &rest[entity.len()..];
+        } else if c == '{' || c == '}' {
+            let len = placeholder_len(rest)?;
+            line.push(&rest[..len], &style(&open));
+            rest = &rest[len..];
+        } else if c == '\n' {
+            lines.push(Line::default());
+            rest = &rest[1..];
+        } else {
+            line.push(&rest[..c.len_utf8()], &style(&open));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if let Some((tag, _)) = open.last() {
+        return Err(format!("unclosed tag `<{tag}>`, close it with `</{tag}>`"));
+    }
+    Ok(lines)
+}
+
+/// Parses an opening tag, e.g. `b` or `span fg="red" bg="black"`, into its name and style.
+fn parse_tag<'a>(krate: &TokenStream, tag: &'a str) -> Result<(&'a str, Style), String> {
+    let (name, mut attributes) = tag.split_at(tag.find(char::is_whitespace).unwrap_or(tag.len()));
+    let mut style = Style::default();
+    if let Some((_, modifier)) = TAGS.iter().find(|(tag, _)| *tag == name) {
+        if !attributes.trim().is_empty() {
+            return Err(format!("`<{name}>` does not accept attributes"));
+        }
+        style.add_modifier(modifier);
+        return Ok((name, style));
+    }
+    if name != "span" {
+        return Err(format!(
+            "unknown tag `<{name}>`, expected one of `<b>`, `<strong>`, `<i>`, `<em>`, `<u>`, \
+             `<s>`, `<del>`, `<span>` or `<br>`"
+        ));
+    }
+    loop {
+        attributes = attributes.trim_start();
+        if attributes.is_empty() {
+            break;
+        }
+        let (key, value, remaining) = parse_attribute(attributes)
+            .ok_or_else(|| format!("expected an attribute like `fg=\"red\"` in `<{tag}>`"))?;
+        attributes = remaining;
+        let color = color::to_tokens(krate, value)?;
+        match key {
+            "fg" => style.fg = Some(color),
+            "bg" => style.bg = Some(color),
+            _ => {
+                return Err(format!(
+                    "unknown attribute `{key}` in `<{tag}>`, expected `fg` or `bg`"
+                ));
+            }
+        }
+    }
+    Ok((name, style))
+}
+
+/// Splits `key="value" rest` into the key, the value and the rest. The value may also be quoted
+/// with `'`.
+fn parse_attribute(input: &str) -> Option<(&str, &str, &str)> {
+    let (key, rest) = input.split_once('=')?;
+    let rest = rest.trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let (value, rest) = rest[1..].split_once(quote)?;
+    Some((key.trim(), value, rest))
+}
+
+fn style(open: &[(&str, Style)]) -> Style {
+    let mut style = Style::default();
+    for (_, tag) in open {
+        style.patch(tag);
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(lines: &[Line]) -> Vec<Vec<(String, String)>> {
+        lines
+            .iter()
+            .map(|line| {
+                line.segments
+                    .iter()
+                    .map(|segment| {
+                        let style = format!(
+                            "{:?} {:?} {:?}",
+                            segment.style.fg.as_ref().map(ToString::to_string),
+                            segment.style.bg.as_ref().map(ToString::to_string),
+                            segment.style.modifiers
+                        );
+                        (segment.text.clone(), style.replace(' ', ""))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tags() {
+        let krate = TokenStream::new();
+        let lines = parse(&krate, "<b>Save</b> <i><u>now</u></i>").unwrap();
+        assert_eq!(
+            render(&lines),
+            [[
+                ("Save".to_string(), r#"NoneNone["BOLD"]"#.to_string()),
+                (" ".to_string(), "NoneNone[]".to_string()),
+                (
+                    "now".to_string(),
+                    r#"NoneNone["ITALIC","UNDERLINED"]"#.to_string()
+                ),
+            ]]
+        );
+    }
+
+    #[test]
+    fn span_attributes() {
+        let krate = TokenStream::new();
+        let lines = parse(&krate, "<span fg=\"red\" bg='#000'>{}</span>").unwrap();
+        assert_eq!(
+            render(&lines),
+            [[(
+                "{}".to_string(),
+                r#"Some("::ratatui_core::style::Color::Red")Some("::ratatui_core::style::Color::Rgb(0u8,0u8,0u8)")[]"#
+                    .to_string()
+            )]]
+        );
+    }
+
+    #[test]
+    fn line_breaks_and_entities() {
+        let krate = TokenStream::new();
+        let lines = parse(&krate, "a &lt;b&gt;<br/>c &amp; d\ne").unwrap();
+        let texts = lines
+            .iter()
+            .map(|line| line.segments.iter().map(|s| s.text.as_str()).collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(texts, [vec!["a <b>"], vec!["c & d"], vec!["e"]]);
+    }
+
+    #[test]
+    fn errors() {
+        let krate = TokenStream::new();
+        assert_eq!(
+            parse(&krate, "<b>bold").err(),
+            Some("unclosed tag `<b>`, close it with `</b>`".to_string())
+        );
+        assert_eq!(
+            parse(&krate, "<b>bold</i>").err(),
+            Some("expected `</b>`, found `</i>`".to_string())
+        );
+        assert!(parse(&krate, "<blink>x</blink>").is_err());
+        assert!(parse(&krate, "<b fg=\"red\">x</b>").is_err());
+        assert!(parse(&krate, "<span color=\"red\">x</span>").is_err());
+        assert!(parse(&krate, "<span fg=\"rde\">x</span>").is_err());
+        assert!(parse(&krate, "a < b").is_err());
+        assert!(parse(&krate, "&copy;").is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{punctuated::Punctuated, Data, DeriveInput, Fields, LitStr, Token};
+
+use crate::chord::{self, Chord};
+
+/// Expands `#[derive(KeyChord)]`, which binds each unit variant of an enum to the key chords in
+/// its `#[chord("ctrl-q", ...)]` attribute.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let krate = krate(&input.attrs)?;
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`KeyChord` can only be derived for enums",
+        ));
+    };
+    let mut bound: Vec<(Chord, String)> = Vec::new();
+    let mut matches = Vec::new();
+    let mut chords = Vec::new();
+    let mut names = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "the variants of a `KeyChord` enum can not have fields",
+            ));
+        }
+        let keys = variant_chords(variant)?;
+        let mut variant_chords = Vec::new();
+        let mut name = None;
+        for keys in &keys {
+            let value = keys.value();
+            if value.split_whitespace().count() > 1 {
+                return Err(syn::Error::new(
+                    keys.span(),
+                    format!("`{value}` is a key sequence, use `keymap!` to bind key sequences"),
+                ));
+            }
+            let chord =
+                chord::parse(value.trim()).map_err(|err| syn::Error::new(keys.span(), err))?;
+            if let Some((_, other)) = bound.iter().find(|(other, _)| *other == chord) {
+                return Err(syn::Error::new(
+                    keys.span(),
+                    format!("`{value}` is bound more than once, it is the same as `{other}`"),
+                ));
+            }
+            name.get_or_insert_with(|| chord.to_string());
+            variant_chords.push(chord.to_tokens(&krate));
+            bound.push((chord, value));
+        }
+        let ident = &variant.ident;
+        matches.push(quote! {
+            if Self::#ident.key_chords().iter().any(|chord| chord.matches(event)) {
+                return ::core::option::Option::Some(Self::#ident);
+            }
+        });
+        chords.push(quote! {
+            Self::#ident => {
+                const CHORDS: &[#krate::KeyChord] = &[#(#variant_chords),*];
+                CHORDS
+            }
+        });
+        names.push(quote!(Self::#ident => #name));
+    }
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            /// Returns the variant that is bound to the key event, if any.
+            pub fn from_key_event(
+                event: &#krate::crossterm::event::KeyEvent,
+            ) -> ::core::option::Option<Self> {
+                #(#matches)*
+                ::core::option::Option::None
+            }
+
+            /// Returns the key chords that are bound to the variant.
+            pub fn key_chords(&self) -> &'static [#krate::KeyChord] {
+                match self {
+                    #(#chords)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match self {
+                    #(#names,)*
+                })
+            }
+        }
+    })
+}
+
+/// The path of the `ratatui-macros` crate, which is `::ratatui_macros` unless the enum sets
+/// another path with `#[chord(crate = "...")]`.
+fn krate(attrs: &[syn::Attribute]) -> syn::Result<TokenStream> {
+    let mut krate = quote!(::ratatui_macros);
+    for attr in attrs {
+        if !attr.path().is_ident("chord") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let path = meta.value()?.parse::<LitStr>()?.parse::<syn::Path>()?;
+                krate = quote!(#path);
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(krate)
+}
+
+/// Returns the key chords in the `#[chord(...)]` attributes of a variant.
+fn variant_chords(variant: &syn::Variant) -> syn::Result<Vec<LitStr>> {
+    let mut keys = Vec::new();
+    for attr in &variant.attrs {
+        if attr.path().is_ident("chord") {
+            let chords = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+            keys.extend(chords);
+        }
+    }
+    if keys.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            "expected a key chord like `#[chord(\"ctrl-q\")]`",
+        ));
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: &str) -> Result<String, String> {
+        let input = syn::parse_str(input).unwrap();
+        expand(&input)
+            .map(|output| output.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn display_names() {
+        let output = expand_str(
+            r#"enum Action { #[chord("Shift-Ctrl-p")] Palette, #[chord("esc", "q")] Quit }"#,
+        )
+        .unwrap();
+        assert!(output.contains(r#"Self :: Palette => "ctrl-shift-p""#));
+        assert!(output.contains(r#"Self :: Quit => "esc""#));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            expand_str("struct Action;").unwrap_err(),
+            "`KeyChord` can only be derived for enums"
+        );
+        assert_eq!(
+            expand_str("enum Action { Quit }").unwrap_err(),
+            "expected a key chord like `#[chord(\"ctrl-q\")]`"
+        );
+        assert_eq!(
+            expand_str(r#"enum Action { #[chord("q")] Quit(u8) }"#).unwrap_err(),
+            "the variants of a `KeyChord` enum can not have fields"
+        );
+        assert_eq!(
+            expand_str(r#"enum Action { #[chord("g g")] Top }"#).unwrap_err(),
+            "`g g` is a key sequence, use `keymap!` to bind key sequences"
+        );
+        assert_eq!(
+            expand_str(r#"enum Action { #[chord("ctrl-q")] Quit, #[chord("control-q")] Exit }"#)
+                .unwrap_err(),
+            "`control-q` is bound more than once, it is the same as `ctrl-q`"
+        );
+        assert!(expand_str(r#"enum Action { #[chord("ctrl-")] Quit }"#).is_err());
+        assert!(expand_str(r#"#[chord(crate = "x")] enum Action { #[chord("q")] Quit }"#).is_ok());
+    }
+}
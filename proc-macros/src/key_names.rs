@@ -0,0 +1,110 @@
+// The syntax of a key press like `ctrl-shift-p`, which is shared by `KeyChord`,
+// `parse_key_sequence`, `keymap!` and `#[derive(KeyChord)]` so that they accept the same names.
+//
+// This file is the `key_names` module of the proc-macro crate, and `src/key_names.rs` of the main
+// crate includes it with `include!`, so it cannot have inner attributes or doc comments.
+
+/// The names of the modifiers and their index in the modifiers of a key press, which are `shift`,
+/// `ctrl`, `alt`, `super`, `hyper` and `meta`, in that order.
+pub(crate) const MODIFIERS: &[(&str, usize)] = &[
+    ("shift", 0),
+    ("ctrl", 1),
+    ("control", 1),
+    ("alt", 2),
+    ("super", 3),
+    ("hyper", 4),
+    ("meta", 5),
+];
+
+/// The lowercase names of the keys and the names of their crossterm `KeyCode` variants.
+pub(crate) const NAMED_KEYS: &[(&str, &str)] = &[
+    ("backspace", "Backspace"),
+    ("enter", "Enter"),
+    ("return", "Enter"),
+    ("left", "Left"),
+    ("right", "Right"),
+    ("up", "Up"),
+    ("down", "Down"),
+    ("home", "Home"),
+    ("end", "End"),
+    ("pageup", "PageUp"),
+    ("pagedown", "PageDown"),
+    ("tab", "Tab"),
+    ("backtab", "BackTab"),
+    ("delete", "Delete"),
+    ("del", "Delete"),
+    ("insert", "Insert"),
+    ("ins", "Insert"),
+    ("esc", "Esc"),
+    ("escape", "Esc"),
+];
+
+/// The key of a key press, without its modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Code {
+    Char(char),
+    F(u8),
+    /// The name of a `KeyCode` variant, e.g. `Esc`
+    Named(&'static str),
+}
+
+/// The reason that a key press could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyError<'a> {
+    /// The modifier is given twice
+    DuplicateModifier(&'a str),
+    /// The key is not a character, a function key or a key name
+    UnknownKey(&'a str),
+}
+
+/// Parses a key press, e.g. `ctrl-q`, `shift-F5` or `esc`, into whether each modifier is held and
+/// the key. The names are matched case insensitively, and a letter with `shift` is uppercased, as
+/// terminals report `shift-a` as `A`.
+pub(crate) fn parse_key(input: &str) -> Result<([bool; 6], Code), KeyError<'_>> {
+    let mut modifiers = [false; 6];
+    let mut key = input;
+    while let Some((modifier, rest)) = key.split_once('-') {
+        // a trailing `-` is the minus key, e.g. `ctrl--`
+        if rest.is_empty() {
+            break;
+        }
+        let Some((_, index)) = MODIFIERS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(modifier))
+        else {
+            break;
+        };
+        if modifiers[*index] {
+            return Err(KeyError::DuplicateModifier(modifier));
+        }
+        modifiers[*index] = true;
+        key = rest;
+    }
+    let code = match parse_code(key).ok_or(KeyError::UnknownKey(key))? {
+        Code::Char(c) if modifiers[0] => Code::Char(c.to_ascii_uppercase()),
+        code => code,
+    };
+    Ok((modifiers, code))
+}
+
+fn parse_code(key: &str) -> Option<Code> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Code::Char(c));
+    }
+    let key = key.to_lowercase();
+    if key == "space" {
+        return Some(Code::Char(' '));
+    }
+    if let Some(number) = key.strip_prefix('f') {
+        return number
+            .parse()
+            .ok()
+            .filter(|number| (1..=24).contains(number))
+            .map(Code::F);
+    }
+    NAMED_KEYS
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, variant)| Code::Named(variant))
+}
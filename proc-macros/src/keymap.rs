@@ -0,0 +1,60 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, LitStr, Token,
+};
+
+use crate::chord::{self, Chord};
+
+/// The bindings of a keymap, e.g. `"ctrl-q" => Action::Quit, "g g" => Action::Top`.
+pub(crate) struct Bindings(Punctuated<Binding, Token![,]>);
+
+struct Binding {
+    keys: LitStr,
+    action: Expr,
+}
+
+impl Parse for Bindings {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Punctuated::parse_terminated(input).map(Self)
+    }
+}
+
+impl Parse for Binding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keys = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let action = input.parse()?;
+        Ok(Self { keys, action })
+    }
+}
+
+pub(crate) fn expand(krate: &TokenStream, bindings: &Bindings) -> syn::Result<TokenStream> {
+    let mut sequences: Vec<(Vec<Chord>, &LitStr)> = Vec::new();
+    let mut entries = Vec::new();
+    for Binding { keys, action } in &bindings.0 {
+        let sequence = chord::parse_sequence(&keys.value())
+            .map_err(|err| syn::Error::new(keys.span(), err))?;
+        for (other, other_keys) in &sequences {
+            if sequence.starts_with(other) || other.starts_with(&sequence) {
+                let message = if sequence == *other {
+                    format!("`{}` is bound more than once", keys.value())
+                } else {
+                    format!(
+                        "`{}` conflicts with `{}`, a key sequence can not be a prefix of \
+                         another one",
+                        keys.value(),
+                        other_keys.value()
+                    )
+                };
+                return Err(syn::Error::new(keys.span(), message));
+            }
+        }
+        let chords = sequence.iter().map(|chord| chord.to_tokens(krate));
+        entries.push(quote!((&[#(#chords),*], #action)));
+        sequences.push((sequence, keys));
+    }
+    Ok(quote!(#krate::Keymap::new(&[#(#entries),*])))
+}
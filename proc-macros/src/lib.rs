@@ -0,0 +1,320 @@
+//! Procedural macros for [Ratatui], used by the [`ratatui-macros`] crate.
+//!
+//! The macros in this crate are not meant to be used directly. Enable the `proc-macros` feature of
+//! `ratatui-macros` and use the macros it re-exports instead. Each macro takes the path of the
+//! `ratatui-macros` crate as its first argument so that the expansion can refer to the re-exported
+//! `ratatui_core` and `ratatui_widgets` crates.
+//!
+//! [Ratatui]: https://ratatui.rs
+//! [`ratatui-macros`]: https://docs.rs/ratatui-macros
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, DeriveInput, Token,
+};
+
+mod check;
+mod chord;
+mod color;
+mod constraints;
+mod derive;
+mod html;
+mod key_chord;
+mod key_names;
+mod keymap;
+mod line;
+mod markdown;
+mod markup;
+mod rich;
+mod styled;
+mod styled_format;
+#[cfg(feature = "include-theme")]
+mod theme;
+mod width;
+
+/// Parses a color string literal at compile time. See `ratatui_macros::color!`.
+#[proc_macro]
+pub fn color(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<color::Input>);
+    color::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parses a list of key bindings at compile time. See `ratatui_macros::keymap!`.
+#[proc_macro]
+pub fn keymap(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<keymap::Bindings>);
+    keymap::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Reads a theme file at compile time and expands it to a theme struct. See
+/// `ratatui_macros::include_theme!`.
+#[cfg(feature = "include-theme")]
+#[proc_macro]
+pub fn include_theme(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<theme::Input>);
+    theme::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parses HTML-like tags into a `Text` at compile time. See `ratatui_macros::html!`.
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<rich::Input>);
+    html::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parses inline markdown into a `Text` at compile time. See `ratatui_macros::markdown!`.
+#[proc_macro]
+pub fn markdown(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<rich::Input>);
+    markdown::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parses BBCode-style markup into a `Line` at compile time. See `ratatui_macros::markup!`.
+#[proc_macro]
+pub fn markup(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<rich::Input>);
+    markup::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parses a format string with styled placeholders into a `Line` at compile time. See
+/// `ratatui_macros::styled_format!`.
+#[proc_macro]
+pub fn styled_format(input: TokenStream) -> TokenStream {
+    let WithCrate { krate, input } = parse_macro_input!(input as WithCrate<rich::Input>);
+    styled_format::expand(&krate, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Measures the display width of a string literal at compile time. See
+/// `ratatui_macros::display_width!`.
+#[proc_macro]
+pub fn literal_width(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::LitStr);
+    width::expand(&input).into()
+}
+
+/// Checks the arguments of `ratatui_macros::constraints!` and reports spanned errors.
+#[proc_macro]
+pub fn check_constraints(input: TokenStream) -> TokenStream {
+    check::expand(input.into(), constraints::check)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Checks the arguments of `ratatui_macros::line!` and reports spanned errors.
+#[proc_macro]
+pub fn check_line(input: TokenStream) -> TokenStream {
+    check::expand(input.into(), line::check)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Checks the arguments of `ratatui_macros::styled!` and reports spanned errors.
+#[proc_macro]
+pub fn check_styled(input: TokenStream) -> TokenStream {
+    check::expand(input.into(), styled::check)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The input of a macro, preceded by the path of the `ratatui-macros` crate and a comma.
+/// Derives `From<&T> for Line<'static>` for a struct, turning each field into a span.
+///
+/// Each field is converted with its `Display` implementation, and the spans are separated by a
+/// space. The fields accept `#[line(...)]` attributes:
+///
+/// - `style = "bold red on black"` sets the modifiers and colors of the span, using the words of
+///   a `markup!` tag
+/// - `fg = "gray"` and `bg = "#1e293b"` set the colors of the span, using the colors of `color!`
+/// - `format = "({})"` formats the field with a format string instead of `Display`
+/// - `skip` leaves out the field
+///
+/// The struct accepts `style`, `fg` and `bg`, which set the style of the line, and
+/// `separator = ", "`, which replaces the space between the spans. This derive requires the `derive`
+/// feature of `ratatui-macros`.
+///
+/// The generated code refers to the crate as `::ratatui_macros`. When `ratatui-macros` is only
+/// available through another crate, e.g. a framework that re-exports it, the struct accepts
+/// `crate = "framework::ratatui_macros"` to set the path of the crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::Line;
+/// use ratatui_macros::ToLine;
+///
+/// #[derive(ToLine)]
+/// struct Entry {
+///     #[line(style = "bold")]
+///     name: String,
+///     #[line(fg = "gray", format = "({})")]
+///     count: usize,
+/// }
+///
+/// let entry = Entry { name: "src".to_string(), count: 3 };
+/// let line = Line::from(&entry);
+/// ```
+#[proc_macro_derive(ToLine, attributes(line))]
+pub fn derive_to_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(&input, derive::Target::Line)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `From<&T> for Text<'static>` for a struct, turning each field into a line.
+///
+/// The fields accept the same `#[text(...)]` attributes as the `#[line(...)]` attributes of
+/// [`ToLine`](derive@ToLine), and the style attributes of the struct set the style of the text.
+/// Like `ToLine`, the struct accepts `crate = "..."` to set the path of `ratatui-macros`. This
+/// derive requires the `derive` feature of `ratatui-macros`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::Text;
+/// use ratatui_macros::ToText;
+///
+/// #[derive(ToText)]
+/// struct Commit {
+///     #[text(style = "yellow", format = "commit {}")]
+///     hash: String,
+///     #[text(format = "Author: {}")]
+///     author: String,
+///     #[text(skip)]
+///     timestamp: u64,
+/// }
+///
+/// # let commit = Commit {
+/// #     hash: "3f2a9c1".to_string(),
+/// #     author: "Ferris".to_string(),
+/// #     timestamp: 0,
+/// # };
+/// let text = Text::from(&commit);
+/// ```
+#[proc_macro_derive(ToText, attributes(text))]
+pub fn derive_to_text(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(&input, derive::Target::Text)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `From<&T> for Row<'static>` for a struct, turning each field into a cell, and an
+/// associated `header()` function that returns the header row.
+///
+/// The fields accept the `style`, `fg`, `bg`, `format` and `skip` attributes of
+/// [`ToLine`](derive@ToLine) in `#[row(...)]`, and:
+///
+/// - `align = "right"` aligns the cell and its header, which is `left`, `center` or `right`
+/// - `header = "Size"` sets the title of the header cell, which defaults to the name of the field
+///
+/// The struct accepts `style`, `fg` and `bg`, which set the style of the row, and
+/// `header_style = "bold"`, which sets the style of the header row. Like `ToLine`, the struct also
+/// accepts `crate = "..."` to set the path of `ratatui-macros`. This derive requires the `derive`
+/// feature of `ratatui-macros`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::ToRow;
+/// use ratatui_widgets::table::{Row, Table};
+///
+/// #[derive(ToRow)]
+/// #[row(header_style = "bold")]
+/// struct Process {
+///     #[row(header = "PID", align = "right")]
+///     pid: u32,
+///     name: String,
+///     #[row(header = "CPU", format = "{:.1}%", align = "right")]
+///     cpu: f32,
+/// }
+///
+/// # let processes = [Process { pid: 1, name: "init".to_string(), cpu: 0.5 }];
+/// # let widths = [5, 10, 6];
+/// let table = Table::new(processes.iter().map(Row::from), widths).header(Process::header());
+/// ```
+#[proc_macro_derive(ToRow, attributes(row))]
+pub fn derive_to_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(&input, derive::Target::Row)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `from_key_event`, `key_chords` and `Display` for an enum of actions from the key chord
+/// of each variant.
+///
+/// Each variant is a unit variant with a `#[chord("...")]` attribute that lists one or more key
+/// chords, e.g. `#[chord("ctrl-q")]` or `#[chord("esc", "q")]`, with the syntax of a single key of
+/// `keymap!`. The chords are parsed at compile time, so a malformed chord, a chord that is bound
+/// to more than one variant, or a variant without a chord is a compile error.
+///
+/// The derive generates:
+///
+/// - `fn from_key_event(event: &KeyEvent) -> Option<Self>`, which returns the variant that is
+///   bound to a crossterm key event, matching it like a `KeyChord`
+/// - `fn key_chords(&self) -> &'static [KeyChord]`, which returns the chords of the variant
+/// - `Display`, which writes the first chord of the variant, e.g. `ctrl-shift-p` for
+///   `"Shift-Ctrl-p"`, so `to_string()` can be shown in a help screen
+///
+/// Like `ToLine`, the enum accepts `#[chord(crate = "...")]` to set the path of `ratatui-macros`.
+/// This derive requires the `crossterm` and `derive` features of `ratatui-macros`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui_macros::KeyChord;
+///
+/// #[derive(KeyChord)]
+/// enum Action {
+///     #[chord("ctrl-q", "esc")]
+///     Quit,
+///     #[chord("ctrl-shift-p")]
+///     Palette,
+/// }
+///
+/// # let event = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+/// let action = Action::from_key_event(&event);
+/// let help = format!("{} to quit", Action::Quit);
+/// ```
+#[proc_macro_derive(KeyChord, attributes(chord))]
+pub fn derive_key_chord(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    key_chord::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct WithCrate<T> {
+    krate: TokenStream2,
+    input: T,
+}
+
+impl<T: Parse> Parse for WithCrate<T> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut krate = TokenStream2::new();
+        while !input.peek(Token![,]) {
+            krate.extend([input.parse::<proc_macro2::TokenTree>()?]);
+        }
+        input.parse::<Token![,]>()?;
+        let input = input.parse()?;
+        Ok(Self { krate, input })
+    }
+}
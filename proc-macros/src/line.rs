@@ -0,0 +1,155 @@
+use proc_macro2::TokenStream;
+use syn::{
+    parse::{ParseStream, Parser},
+    token, Expr, Lit, Token,
+};
+
+/// Checks a comma separated list of spans, each of which is one of `expr`, `{"format", args}`,
+/// `if condition => span`, `?option` or `..iter`.
+pub(crate) fn check(tokens: TokenStream) -> syn::Result<()> {
+    Parser::parse2(check_spans, tokens)
+}
+
+fn check_spans(input: ParseStream) -> syn::Result<()> {
+    let mut first = true;
+    while !input.is_empty() {
+        check_element(input)?;
+        if input.is_empty() {
+            break;
+        }
+        // a single `if` expression can be repeated, e.g. `line![if a { "-" } else { "=" }; 10]`
+        if first && input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+            check_expr(input, "expected the number of repetitions after `;`")?;
+            if !input.is_empty() {
+                return Err(input.error("unexpected tokens after the number of repetitions"));
+            }
+            break;
+        }
+        first = false;
+        if input.peek(Token![;]) {
+            return Err(input.error(
+                "repetition with `;` is only supported for a single span, e.g. `line![\"-\"; 10]`",
+            ));
+        }
+        if !input.peek(Token![,]) {
+            return Err(input.error("expected `,` between the spans of a line"));
+        }
+        input.parse::<Token![,]>()?;
+    }
+    Ok(())
+}
+
+fn check_element(input: ParseStream) -> syn::Result<()> {
+    if input.peek(Token![if]) {
+        let condition = input.fork();
+        condition.parse::<Token![if]>()?;
+        Expr::parse_without_eager_brace(&condition)?;
+        if condition.peek(Token![=>]) {
+            input.parse::<Token![if]>()?;
+            Expr::parse_without_eager_brace(input)?;
+            input.parse::<Token![=>]>()?;
+            return check_span(input);
+        }
+        // without `=>`, the element is an `if` expression, e.g. `if flag { "x" } else { "y" }`
+        if input.fork().parse::<Expr>().is_ok() {
+            input.parse::<Expr>()?;
+            return Ok(());
+        }
+        return Err(condition.error(
+            "expected `=>` followed by a span after the condition, e.g. \
+             `if modified => \" [+]\"`",
+        ));
+    }
+    if input.peek(Token![?]) {
+        input.parse::<Token![?]>()?;
+        return check_expr(input, "expected an `Option` of a span after `?`");
+    }
+    if input.peek(Token![..]) {
+        input.parse::<Token![..]>()?;
+        return check_expr(input, "expected an iterator of spans after `..`");
+    }
+    check_span(input)
+}
+
+/// Checks a span expression or a `{"format", args}` element.
+fn check_span(input: ParseStream) -> syn::Result<()> {
+    if input.peek(token::Brace) {
+        let fork = input.fork();
+        let content;
+        syn::braced!(content in fork);
+        // a block that starts with a literal, e.g. `{ "a".to_string() }`, is an expression
+        if content.peek(Lit) && (content.peek2(Token![,]) || is_single_token(&content)) {
+            let format = content.parse::<Lit>()?;
+            if !matches!(format, Lit::Str(_)) {
+                return Err(syn::Error::new_spanned(
+                    format,
+                    "expected a format string, e.g. `{\"{}: \", key}`",
+                ));
+            }
+            content.parse::<TokenStream>()?;
+            input.parse::<proc_macro2::TokenTree>()?;
+            return Ok(());
+        }
+    }
+    check_expr(
+        input,
+        "expected a span, e.g. `\"text\"`, `\"text\".bold()` or `{\"{}\", value}`",
+    )
+}
+
+fn is_single_token(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<proc_macro2::TokenTree>().is_ok() && fork.is_empty()
+}
+
+fn check_expr(input: ParseStream, message: &str) -> syn::Result<()> {
+    if input.is_empty() || input.peek(Token![,]) {
+        return Err(input.error(message));
+    }
+    input
+        .parse::<Expr>()
+        .map(|_| ())
+        .map_err(|err| syn::Error::new(err.span(), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn valid_spans() {
+        assert!(check(quote!("a", "b".bold(), {"{}", 1}, {"x"}, { a + b })).is_ok());
+        assert!(check(quote!(if a => "b", if a.is_empty() => {"{}", 1}, ?c, ..d.iter(),)).is_ok());
+        assert!(check(quote!(if a { "x" } else { "y" }, if a => "b")).is_ok());
+        assert!(check(quote!(if a { "x" } else { "y" }; 3)).is_ok());
+    }
+
+    #[test]
+    fn invalid_spans() {
+        let err = check(quote!("a", if modified " [+]")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected `=>` followed by a span after the condition, e.g. `if modified => \" [+]\"`"
+        );
+        let err = check(quote!("a" "b")).unwrap_err();
+        assert_eq!(err.to_string(), "expected `,` between the spans of a line");
+        let err = check(quote!("a", {1, 2})).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a format string, e.g. `{\"{}: \", key}`"
+        );
+        let err = check(quote!("a",, "b")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a span, e.g. `\"text\"`, `\"text\".bold()` or `{\"{}\", value}`"
+        );
+        let err = check(quote!("a", "b"; 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "repetition with `;` is only supported for a single span, e.g. `line![\"-\"; 10]`"
+        );
+    }
+}
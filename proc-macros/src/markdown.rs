@@ -0,0 +1,244 @@
+use proc_macro2::TokenStream;
+
+use crate::rich::{self, placeholder_len, Input, Line, Style};
+
+pub(crate) fn expand(krate: &TokenStream, input: &Input) -> syn::Result<TokenStream> {
+    let lines =
+        parse(&input.format.value()).map_err(|err| syn::Error::new(input.format.span(), err))?;
+    rich::expand(krate, input, lines)
+}
+
+/// The inline markers and the modifier they toggle, longest first.
+const MARKERS: &[(&str, &str)] = &[
+    ("**", "BOLD"),
+    ("__", "BOLD"),
+    ("~~", "CROSSED_OUT"),
+    ("*", "ITALIC"),
+    ("_", "ITALIC"),
+    ("`", "REVERSED"),
+];
+
+/// Parses inline markdown into lines of styled text. Supports `# headings`, `**bold**`,
+/// `*italic*`, `~~strikethrough~~` and `` `code` ``.
+fn parse(markdown: &str) -> Result<Vec<Line>, String> {
+    markdown.split('\n').map(parse_line).collect()
+}
+
+fn parse_line(text: &str) -> Result<Line, String> {
+    let mut line = Line::default();
+    let level = text.chars().take_while(|c| *c == '#').count();
+    let mut rest = text;
+    if level > 0 && text[level..].starts_with(' ') {
+        rest = &text[level + 1..];
+        line.style.add_modifier("BOLD");
+        if level == 1 {
+            line.style.add_modifier("UNDERLINED");
+        }
+    }
+    // the markers that are currently open, innermost last
+    let mut open: Vec<&str> = Vec::new();
+    let mut previous = None;
+    while let Some(c) = rest.chars().next() {
+        let in_code = open.last() == Some(&"`");
+        if c == '\\' && !in_code {
+            let escaped = rest[1..].chars().next();
+            if let Some(escaped) = escaped.filter(|c| "\\*_~`#".contains(*c)) {
+                line.push(&escaped.to_string(), &style(&open));
+                rest = &rest[1 + escaped.len_utf8()..];
+                previous = Some(escaped);
+                continue;
+            }
+        }
+        if c == '{' || c == '}' {
+            let len = placeholder_len(rest)?;
+            line.push(&rest[..len], &style(&open));
+            rest = &rest[len..];
+            previous = Some('}');
+            continue;
+        }
+        if c == '`' {
+            if in_code {
+                open.pop();
+            } else {
+                open.push("`");
+            }
+            rest = &rest[1..];
+            previous = Some(c);
+            continue;
+        }
+        if !in_code && "*_~".contains(c) {
+            let run = rest.len() - rest.trim_start_matches(c).len();
+            let next = rest[run..].chars().next();
+            let (can_open, can_close) = flanking(c, previous, next);
+            let mut remaining = run;
+            while remaining > 0 {
+                let markers = &rest[run - remaining..run];
+                // a run like `***` closes the innermost markers first, e.g. in `**a *b***`
+                let innermost = open.last().filter(|marker| markers.starts_with(**marker));
+                if let Some(marker) = innermost.filter(|_| can_close) {
+                    remaining -= marker.len();
+                    open.pop();
+                    continue;
+                }
+                let marker = MARKERS
+                    .iter()
+                    .map(|(marker, _)| *marker)
+                    .find(|marker| markers.starts_with(marker));
+                match marker {
+                    Some(marker) if can_open => {
+                        open.push(marker);
+                        remaining -= marker.len();
+                    }
+                    Some(marker) if can_close && open.contains(&marker) => {
+                        return Err(format!("`{marker}` is closed before the markers inside it"));
+                    }
+                    _ => {
+                        line.push(markers, &style(&open));
+                        remaining = 0;
+                    }
+                }
+            }
+            rest = &rest[run..];
+            previous = Some(c);
+            continue;
+        }
+        line.push(&rest[..c.len_utf8()], &style(&open));
+        rest = &rest[c.len_utf8()..];
+        previous = Some(c);
+    }
+    if let Some(marker) = open.last() {
+        return Err(format!("unclosed `{marker}` in markdown"));
+    }
+    Ok(line)
+}
+
+/// Returns whether a run of `c` between `previous` and `next` can open and close emphasis, with
+/// the flanking rules of CommonMark: `2 * 3` has no emphasis, as the `*` is surrounded by
+/// whitespace, and `_` inside a word, e.g. in `snake_case`, is not a marker.
+fn flanking(c: char, previous: Option<char>, next: Option<char>) -> (bool, bool) {
+    let is_whitespace = |c: Option<char>| c.is_none_or(char::is_whitespace);
+    let is_punctuation = |c: Option<char>| c.is_some_and(|c| c.is_ascii_punctuation());
+    let left = !is_whitespace(next)
+        && (!is_punctuation(next) || is_whitespace(previous) || is_punctuation(previous));
+    let right = !is_whitespace(previous)
+        && (!is_punctuation(previous) || is_whitespace(next) || is_punctuation(next));
+    if c == '_' {
+        (
+            left && (!right || is_punctuation(previous)),
+            right && (!left || is_punctuation(next)),
+        )
+    } else {
+        (left, right)
+    }
+}
+
+fn style(open: &[&str]) -> Style {
+    let mut style = Style::default();
+    for marker in open {
+        let (_, modifier) = MARKERS.iter().find(|(m, _)| m == marker).unwrap();
+        style.add_modifier(modifier);
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(lines: &[Line]) -> Vec<Vec<(String, Vec<&'static str>)>> {
+        lines
+            .iter()
+            .map(|line| {
+                line.segments
+                    .iter()
+                    .map(|segment| {
+                        let mut modifiers = line.style.modifiers.clone();
+                        modifiers.extend(&segment.style.modifiers);
+                        (segment.text.clone(), modifiers)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn inline_styles() {
+        let lines = parse("**Error:** could not open `{}`").unwrap();
+        assert_eq!(
+            render(&lines),
+            [vec![
+                ("Error:".to_string(), vec!["BOLD"]),
+                (" could not open ".to_string(), vec![]),
+                ("{}".to_string(), vec!["REVERSED"]),
+            ]]
+        );
+        let lines = parse("*a **b** ~~c~~* snake_case \\*").unwrap();
+        assert_eq!(
+            render(&lines),
+            [vec![
+                ("a ".to_string(), vec!["ITALIC"]),
+                ("b".to_string(), vec!["ITALIC", "BOLD"]),
+                (" ".to_string(), vec!["ITALIC"]),
+                ("c".to_string(), vec!["ITALIC", "CROSSED_OUT"]),
+                (" snake_case *".to_string(), vec![]),
+            ]]
+        );
+    }
+
+    #[test]
+    fn flanking() {
+        let lines = parse("2 * 3 * 4 and a_b_c").unwrap();
+        assert_eq!(
+            render(&lines),
+            [vec![("2 * 3 * 4 and a_b_c".to_string(), vec![])]]
+        );
+        let lines = parse("**bold *both***").unwrap();
+        assert_eq!(
+            render(&lines),
+            [vec![
+                ("bold ".to_string(), vec!["BOLD"]),
+                ("both".to_string(), vec!["BOLD", "ITALIC"]),
+            ]]
+        );
+        let lines = parse("***both*** a*b*c").unwrap();
+        assert_eq!(
+            render(&lines),
+            [vec![
+                ("both".to_string(), vec!["BOLD", "ITALIC"]),
+                (" a".to_string(), vec![]),
+                ("b".to_string(), vec!["ITALIC"]),
+                ("c".to_string(), vec![]),
+            ]]
+        );
+    }
+
+    #[test]
+    fn headings_and_lines() {
+        let lines = parse("# Help\n## Keys\nq: quit").unwrap();
+        assert_eq!(
+            render(&lines),
+            [
+                vec![("Help".to_string(), vec!["BOLD", "UNDERLINED"])],
+                vec![("Keys".to_string(), vec!["BOLD"])],
+                vec![("q: quit".to_string(), vec![])],
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholders_are_not_markup() {
+        let lines = parse("{:*>5} {{}}").unwrap();
+        assert_eq!(render(&lines), [vec![("{:*>5} {{}}".to_string(), vec![])]]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("**bold").err(),
+            Some("unclosed `**` in markdown".to_string())
+        );
+        assert!(parse("**a *b** c*").is_err());
+        assert!(parse("*a ~~b* c~~").is_err());
+        assert!(parse("{").is_err());
+    }
+}
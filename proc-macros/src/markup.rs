@@ -0,0 +1,199 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    color,
+    rich::{self, placeholder_len, Input, Line, Style},
+};
+
+pub(crate) fn expand(krate: &TokenStream, input: &Input) -> syn::Result<TokenStream> {
+    let line = parse(krate, &input.format.value())
+        .map_err(|err| syn::Error::new(input.format.span(), err))?;
+    rich::expand_line(krate, input, line)
+}
+
+/// The modifier tags and the modifier they add.
+pub(crate) const MODIFIERS: &[(&str, &str)] = &[
+    ("b", "BOLD"),
+    ("bold", "BOLD"),
+    ("d", "DIM"),
+    ("dim", "DIM"),
+    ("i", "ITALIC"),
+    ("italic", "ITALIC"),
+    ("u", "UNDERLINED"),
+    ("underline", "UNDERLINED"),
+    ("underlined", "UNDERLINED"),
+    ("blink", "SLOW_BLINK"),
+    ("reverse", "REVERSED"),
+    ("reversed", "REVERSED"),
+    ("hidden", "HIDDEN"),
+    ("s", "CROSSED_OUT"),
+    ("strike", "CROSSED_OUT"),
+    ("crossed_out", "CROSSED_OUT"),
+];
+
+/// Parses BBCode-style markup, e.g. `[b]Save[/b] [red on black]changes[/]`, into a line of
+/// styled text.
+fn parse(krate: &TokenStream, markup: &str) -> Result<Line, String> {
+    let mut line = Line::default();
+    // the open tags and their styles, innermost last
+    let mut open: Vec<(&str, Style)> = Vec::new();
+    let mut rest = markup;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("[[") {
+            line.push("[", &style(&open));
+            rest = &rest[2..];
+        } else if c == '[' {
+            let end = rest
+                .find(']')
+                .ok_or_else(|| format!("unclosed tag `{rest}`, use `[[` to write a literal `[`"))?;
+            let tag = rest[1..end].trim();
+            rest = &rest[end + 1..];
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                match open.last() {
+                    Some((last, _)) if name.is_empty() || *last == name => {
+                        open.pop();
+                    }
+                    Some((last, _)) => {
+                        return Err(format!("expected `[/{last}]` or `[/]`, found `[/{name}]`"));
+                    }
+                    None => return Err(format!("`[/{name}]` does not close any tag")),
+                }
+            } else {
+                open.push((tag, parse_tag(krate, tag)?));
+            }
+        } else if c == '{' || c == '}' {
+            let len = placeholder_len(rest)?;
+            line.push(&rest[..len], &style(&open));
+            rest = &rest[len..];
+        } else if c == '\n' {
+            return Err(
+                "`markup!` creates a single line, use `text!` to combine lines".to_string(),
+            );
+        } else {
+            line.push(&rest[..c.len_utf8()], &style(&open));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if let Some((tag, _)) = open.last() {
+        return Err(format!(
+            "unclosed tag `[{tag}]`, close it with `[/{tag}]` or `[/]`"
+        ));
+    }
+    Ok(line)
+}
+
+/// Parses the words of an opening tag, e.g. `bold red on black`.
+fn parse_tag(krate: &TokenStream, tag: &str) -> Result<Style, String> {
+    let mut style = Style::default();
+    let mut words = tag.split_whitespace();
+    if tag.is_empty() {
+        return Err("empty tag `[]`".to_string());
+    }
+    while let Some(word) = words.next() {
+        if let Some((_, modifier)) = MODIFIERS.iter().find(|(name, _)| *name == word) {
+            style.add_modifier(modifier);
+        } else if word == "on" {
+            let color = words
+                .next()
+                .ok_or_else(|| format!("expected a color after `on` in `[{tag}]`"))?;
+            style.bg = Some(parse_color(krate, tag, color)?);
+        } else if let Some(color) = word.strip_prefix("fg=") {
+            style.fg = Some(parse_color(krate, tag, color)?);
+        } else if let Some(color) = word.strip_prefix("bg=") {
+            style.bg = Some(parse_color(krate, tag, color)?);
+        } else {
+            style.fg = Some(parse_color(krate, tag, word)?);
+        }
+    }
+    Ok(style)
+}
+
+fn parse_color(krate: &TokenStream, tag: &str, color: &str) -> Result<TokenStream, String> {
+    color::to_tokens(krate, color).map_err(|_| {
+        format!(
+            "unknown markup tag `[{tag}]`, expected a modifier like `b` or `italic`, a color like \
+             `red` or `#1e293b`, or `on` followed by a background color"
+        )
+    })
+}
+
+fn style(open: &[(&str, Style)]) -> Style {
+    let mut style = Style::default();
+    for (_, tag) in open {
+        style.patch(tag);
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(line: &Line) -> Vec<(String, String)> {
+        line.segments
+            .iter()
+            .map(|segment| {
+                let style = format!(
+                    "{:?} {:?} {:?}",
+                    segment.style.fg.as_ref().map(ToString::to_string),
+                    segment.style.bg.as_ref().map(ToString::to_string),
+                    segment.style.modifiers
+                );
+                (segment.text.clone(), style.replace(' ', ""))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tags() {
+        let krate = TokenStream::new();
+        let line = parse(&krate, "[b]Save[/b] [red]unsaved [i]changes[/][/red] [[x]").unwrap();
+        assert_eq!(
+            render(&line),
+            [
+                ("Save".to_string(), r#"NoneNone["BOLD"]"#.to_string()),
+                (" ".to_string(), "NoneNone[]".to_string()),
+                (
+                    "unsaved ".to_string(),
+                    r#"Some("::ratatui_core::style::Color::Red")None[]"#.to_string()
+                ),
+                (
+                    "changes".to_string(),
+                    r#"Some("::ratatui_core::style::Color::Red")None["ITALIC"]"#.to_string()
+                ),
+                (" [x]".to_string(), "NoneNone[]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn combined_tags() {
+        let krate = TokenStream::new();
+        let line = parse(&krate, "[bold #fff on blue]{}[/]").unwrap();
+        assert_eq!(
+            render(&line),
+            [(
+                "{}".to_string(),
+                r#"Some("::ratatui_core::style::Color::Rgb(255u8,255u8,255u8)")Some("::ratatui_core::style::Color::Blue")["BOLD"]"#
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let krate = TokenStream::new();
+        assert_eq!(
+            parse(&krate, "[b]bold").err(),
+            Some("unclosed tag `[b]`, close it with `[/b]` or `[/]`".to_string())
+        );
+        assert_eq!(
+            parse(&krate, "[b]bold[/i]").err(),
+            Some("expected `[/b]` or `[/]`, found `[/i]`".to_string())
+        );
+        assert!(parse(&krate, "[blod]x[/]").is_err());
+        assert!(parse(&krate, "[/]").is_err());
+        assert!(parse(&krate, "[b").is_err());
+    }
+}
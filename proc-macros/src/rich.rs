@@ -0,0 +1,308 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, Ident, LitStr, Token,
+};
+
+/// A format string with styled markup and its arguments, e.g. `"**Error:** {}", message`.
+pub(crate) struct Input {
+    pub(crate) format: LitStr,
+    args: Vec<Arg>,
+}
+
+/// A format argument, either positional (`value`) or named (`name = value`).
+struct Arg {
+    name: Option<Ident>,
+    value: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format = input.parse()?;
+        let mut args = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            args = Punctuated::<Arg, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect();
+        }
+        Ok(Self { format, args })
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Some(name)
+        } else {
+            None
+        };
+        let value = input.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+/// The style of a piece of text.
+#[derive(Clone, Default)]
+pub(crate) struct Style {
+    pub(crate) fg: Option<TokenStream>,
+    pub(crate) bg: Option<TokenStream>,
+    /// The names of the [`Modifier`] flags, e.g. `BOLD`
+    pub(crate) modifiers: Vec<&'static str>,
+}
+
+impl Style {
+    pub(crate) fn add_modifier(&mut self, modifier: &'static str) {
+        if !self.modifiers.contains(&modifier) {
+            self.modifiers.push(modifier);
+        }
+    }
+
+    /// Applies the colors and modifiers of `other` on top of this style.
+    pub(crate) fn patch(&mut self, other: &Self) {
+        if other.fg.is_some() {
+            self.fg.clone_from(&other.fg);
+        }
+        if other.bg.is_some() {
+            self.bg.clone_from(&other.bg);
+        }
+        for modifier in &other.modifiers {
+            self.add_modifier(modifier);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.fg.is_none() && self.bg.is_none() && self.modifiers.is_empty()
+    }
+
+    pub(crate) fn to_tokens(&self, krate: &TokenStream) -> TokenStream {
+        let fg = self.fg.iter().map(|fg| quote!(.fg(#fg)));
+        let bg = self.bg.iter().map(|bg| quote!(.bg(#bg)));
+        let modifiers = self.modifiers.iter().map(|modifier| {
+            let modifier = format_ident!("{modifier}");
+            quote!(.add_modifier(#krate::ratatui_core::style::Modifier::#modifier))
+        });
+        quote!(#krate::ratatui_core::style::Style::new() #(#fg)* #(#bg)* #(#modifiers)*)
+    }
+}
+
+/// A piece of text with a single style. The text is a format string, so it may contain
+/// placeholders such as `{}` and escaped braces such as `{{`.
+pub(crate) struct Segment {
+    pub(crate) text: String,
+    pub(crate) style: Style,
+}
+
+/// A line of styled text.
+#[derive(Default)]
+pub(crate) struct Line {
+    pub(crate) segments: Vec<Segment>,
+    pub(crate) style: Style,
+}
+
+impl Line {
+    /// Appends text with the given style, merging it into the previous segment if the style is the
+    /// same.
+    pub(crate) fn push(&mut self, text: &str, style: &Style) {
+        if text.is_empty() {
+            return;
+        }
+        if let Some(last) = self.segments.last_mut() {
+            if last.style.modifiers == style.modifiers
+                && tokens_eq(&last.style.fg, &style.fg)
+                && tokens_eq(&last.style.bg, &style.bg)
+            {
+                last.text.push_str(text);
+                return;
+            }
+        }
+        self.segments.push(Segment {
+            text: text.to_string(),
+            style: style.clone(),
+        });
+    }
+}
+
+fn tokens_eq(a: &Option<TokenStream>, b: &Option<TokenStream>) -> bool {
+    a.as_ref().map(ToString::to_string) == b.as_ref().map(ToString::to_string)
+}
+
+/// Returns the length of the placeholder or escaped brace at the start of `text`, which starts
+/// with `{` or `}`, so that markup parsers can copy it unchanged.
+pub(crate) fn placeholder_len(text: &str) -> Result<usize, String> {
+    if text.starts_with("{{") || text.starts_with("}}") {
+        return Ok(2);
+    }
+    if text.starts_with('}') {
+        return Err("unmatched `}` in format string, use `}}` to write a literal `}`".to_string());
+    }
+    text.find('}')
+        .map(|end| end + 1)
+        .ok_or_else(|| "unclosed `{` in format string, use `{{` to write a literal `{`".to_string())
+}
+
+/// Expands a single line into a `Line`, formatting each segment with the arguments of the input.
+pub(crate) fn expand_line(
+    krate: &TokenStream,
+    input: &Input,
+    line: Line,
+) -> syn::Result<TokenStream> {
+    let (bindings, mut lines) = expand_lines(krate, input, vec![line])?;
+    let line = lines.pop();
+    Ok(quote!({
+        #bindings
+        #line
+    }))
+}
+
+/// Expands the lines into a `Text`, formatting each segment with the arguments of the input.
+pub(crate) fn expand(
+    krate: &TokenStream,
+    input: &Input,
+    lines: Vec<Line>,
+) -> syn::Result<TokenStream> {
+    let (bindings, lines) = expand_lines(krate, input, lines)?;
+    Ok(quote!({
+        #bindings
+        #krate::ratatui_core::text::Text::from(::std::vec![#(#lines),*])
+    }))
+}
+
+/// Returns the bindings of the arguments and an expression for each line.
+fn expand_lines(
+    krate: &TokenStream,
+    input: &Input,
+    lines: Vec<Line>,
+) -> syn::Result<(TokenStream, Vec<TokenStream>)> {
+    let span = input.format.span();
+    let positional = input.args.iter().filter(|arg| arg.name.is_none()).count();
+    let mut used = vec![false; positional];
+    let mut next = 0;
+    let mut rendered_lines = Vec::new();
+    for line in lines {
+        let mut spans = Vec::new();
+        for segment in line.segments {
+            let text = rewrite_placeholders(&segment.text, span, &mut next, &mut used)
+                .map_err(|err| syn::Error::new(span, err))?;
+            let content = if text.contains(['{', '}']) {
+                let text = LitStr::new(&text, span);
+                quote!(format!(#text))
+            } else {
+                let text = LitStr::new(&text, span);
+                quote!(#text)
+            };
+            let mut style = line.style.clone();
+            style.patch(&segment.style);
+            spans.push(if style.is_empty() {
+                quote!(#krate::ratatui_core::text::Span::raw(#content))
+            } else {
+                let style = style.to_tokens(krate);
+                quote!(#krate::ratatui_core::text::Span::styled(#content, #style))
+            });
+        }
+        rendered_lines.push(quote!(#krate::ratatui_core::text::Line::from(
+            ::std::vec![#(#spans),*]
+        )));
+    }
+    if let Some(index) = used.iter().position(|used| !used) {
+        let arg = input
+            .args
+            .iter()
+            .filter(|arg| arg.name.is_none())
+            .nth(index);
+        return Err(syn::Error::new_spanned(
+            &arg.unwrap().value,
+            "argument never used",
+        ));
+    }
+    // the arguments are evaluated once, before the segments that refer to them are formatted
+    let mut index = 0;
+    let bindings = input.args.iter().map(|arg| {
+        let name = match &arg.name {
+            Some(name) => name.clone(),
+            None => {
+                index += 1;
+                positional_ident(index - 1, span)
+            }
+        };
+        let value = &arg.value;
+        quote!(let #name = &#value;)
+    });
+    Ok((quote!(#(#bindings)*), rendered_lines))
+}
+
+/// The name of the local variable that holds the positional argument with the given index. The
+/// span of the format string is used so that the placeholders that capture it can see it.
+fn positional_ident(index: usize, span: Span) -> Ident {
+    Ident::new(&format!("__ratatui_macros_arg{index}"), span)
+}
+
+/// Replaces positional placeholders (`{}`, `{:?}`, `{0}`) with placeholders that capture the
+/// local variables holding the arguments, so that each segment can be formatted on its own.
+fn rewrite_placeholders(
+    text: &str,
+    span: Span,
+    next: &mut usize,
+    used: &mut [bool],
+) -> Result<String, String> {
+    let mut output = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(['{', '}']) {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let len = placeholder_len(rest)?;
+        let placeholder = &rest[..len];
+        rest = &rest[len..];
+        if len == 2 && (placeholder == "{{" || placeholder == "}}") {
+            output.push_str(placeholder);
+            continue;
+        }
+        let inner = &placeholder[1..len - 1];
+        let (argument, spec) = inner.split_at(inner.find(':').unwrap_or(inner.len()));
+        let index = if argument.is_empty() {
+            *next += 1;
+            Some(*next - 1)
+        } else {
+            argument.parse::<usize>().ok()
+        };
+        match index {
+            Some(index) => {
+                let Some(slot) = used.get_mut(index) else {
+                    return Err(format!(
+                        "invalid reference to positional argument {index} ({} arguments were \
+                         given)",
+                        used.len()
+                    ));
+                };
+                *slot = true;
+                output.push_str(&format!("{{{}{spec}}}", positional_ident(index, span)));
+            }
+            None => output.push_str(placeholder),
+        }
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders() {
+        let span = Span::call_site();
+        let mut used = vec![false; 2];
+        let mut next = 0;
+        assert_eq!(
+            rewrite_placeholders("{} {name} {:?} {{}}", span, &mut next, &mut used),
+            Ok("{__ratatui_macros_arg0} {name} {__ratatui_macros_arg1:?} {{}}".to_string())
+        );
+        assert_eq!(used, [true, true]);
+        assert!(rewrite_placeholders("{}", span, &mut next, &mut used).is_err());
+        assert!(rewrite_placeholders("{", span, &mut 0, &mut []).is_err());
+        assert!(rewrite_placeholders("}", span, &mut 0, &mut []).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use proc_macro2::TokenStream;
+use syn::{
+    parse::{ParseStream, Parser},
+    Expr, LitStr, Token,
+};
+
+/// Checks a style followed by a comma and either a format string with its arguments or an
+/// expression.
+pub(crate) fn check(tokens: TokenStream) -> syn::Result<()> {
+    Parser::parse2(check_styled, tokens)
+}
+
+fn check_styled(input: ParseStream) -> syn::Result<()> {
+    if input.is_empty() {
+        return Err(input.error(
+            "expected a style followed by a format string, e.g. `styled!(Color::Red, \"error\")`",
+        ));
+    }
+    input.parse::<Expr>().map_err(|err| {
+        syn::Error::new(
+            err.span(),
+            "expected a style, e.g. `Color::Red`, `Modifier::BOLD`, `(fg, bg)` or a `Style`",
+        )
+    })?;
+    if input.peek(Token![;]) {
+        return Err(input.error(
+            "expected `,` after the style, `styled!` separates the style from the format string \
+             with a comma",
+        ));
+    }
+    if !input.peek(Token![,]) || input.peek2(Token![,]) {
+        return Err(input.error(
+            "expected `,` followed by a format string or an expression after the style, e.g. \
+             `styled!(Color::Red, \"error\")`",
+        ));
+    }
+    input.parse::<Token![,]>()?;
+    if input.is_empty() {
+        return Err(input.error("expected a format string or an expression after the style"));
+    }
+    if input.peek(LitStr) {
+        // the arguments are checked by `format!`
+        input.parse::<TokenStream>()?;
+        return Ok(());
+    }
+    input.parse::<Expr>()?;
+    input.parse::<Option<Token![,]>>()?;
+    if !input.is_empty() {
+        return Err(input.error(
+            "unexpected arguments after the expression, use a format string to format \
+             multiple values, e.g. `styled!(Color::Red, \"{} {}\", a, b)`",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    #[test]
+    fn valid_styled() {
+        assert!(check(quote!(Color::Red, "err: {}", e)).is_ok());
+        assert!(check(quote!((Color::White, Color::Blue), "status")).is_ok());
+        assert!(check(quote!(Style::new().bold(), value,)).is_ok());
+    }
+
+    #[test]
+    fn invalid_styled() {
+        let err = check(quote!(Color::Red; "err")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected `,` after the style, `styled!` separates the style from the format string \
+             with a comma"
+        );
+        let err = check(quote!(Color::Red)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected end of input, expected `,` followed by a format string or an expression after the style, e.g. \
+             `styled!(Color::Red, \"error\")`"
+        );
+        let err = check(quote!(Color::Red, a, b)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unexpected arguments after the expression, use a format string to format multiple \
+             values, e.g. `styled!(Color::Red, \"{} {}\", a, b)`"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    color, markup,
+    rich::{self, placeholder_len, Input, Line, Style},
+};
+
+pub(crate) fn expand(krate: &TokenStream, input: &Input) -> syn::Result<TokenStream> {
+    let line = parse(krate, &input.format.value())
+        .map_err(|err| syn::Error::new(input.format.span(), err))?;
+    rich::expand_line(krate, input, line)
+}
+
+/// The format types of `std::fmt` that look like the name of a style, e.g. `{:x}`.
+const FORMAT_TYPES: &[&str] = &["x", "X", "o", "b", "e", "E", "x?", "X?"];
+
+/// Parses a format string in which each placeholder may have a style, e.g. `{user:bold.cyan}` or
+/// `{n:yellow:>5}`, into a line with a segment for each styled placeholder.
+fn parse(krate: &TokenStream, format: &str) -> Result<Line, String> {
+    let mut line = Line::default();
+    let mut rest = format;
+    while let Some(start) = rest.find(['{', '}', '\n']) {
+        line.push(&rest[..start], &Style::default());
+        rest = &rest[start..];
+        if rest.starts_with('\n') {
+            return Err(
+                "`styled_format!` creates a single line, use `text!` to combine lines".to_string(),
+            );
+        }
+        let len = placeholder_len(rest)?;
+        let placeholder = &rest[..len];
+        rest = &rest[len..];
+        if placeholder == "{{" || placeholder == "}}" {
+            line.push(placeholder, &Style::default());
+            continue;
+        }
+        let inner = &placeholder[1..len - 1];
+        let (argument, spec) = match inner.split_once(':') {
+            Some((argument, spec)) => (argument, Some(spec)),
+            None => (inner, None),
+        };
+        match spec.map(|spec| split_style(spec)) {
+            Some((Some(words), spec)) => {
+                let style = parse_style(krate, words)?;
+                let spec = spec.map(|spec| format!(":{spec}")).unwrap_or_default();
+                line.push(&format!("{{{argument}{spec}}}"), &style);
+            }
+            _ => line.push(placeholder, &Style::default()),
+        }
+    }
+    line.push(rest, &Style::default());
+    Ok(line)
+}
+
+/// Splits the spec of a placeholder into its style and its format spec. The style comes first and
+/// is separated from the format spec by another `:`. A spec that does not start with a letter or a
+/// hex color, or that is a format type like `x` or `?`, has no style.
+fn split_style(spec: &str) -> (Option<&str>, Option<&str>) {
+    let (style, format_spec) = match spec.split_once(':') {
+        Some((style, format_spec)) => (style, Some(format_spec)),
+        None => (spec, None),
+    };
+    let starts_like_style = style.starts_with(|c: char| c.is_ascii_alphabetic())
+        || style.split('.').next().is_some_and(is_hex_color);
+    if !starts_like_style || FORMAT_TYPES.contains(&style) {
+        return (None, Some(spec));
+    }
+    (Some(style), format_spec)
+}
+
+fn is_hex_color(word: &str) -> bool {
+    word.strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Parses the words of a style separated by `.`, e.g. `bold.white.on_blue`.
+fn parse_style(krate: &TokenStream, style: &str) -> Result<Style, String> {
+    let mut parsed = Style::default();
+    for word in style.split('.') {
+        // the one letter abbreviations of `markup!` would be confused with format types
+        let modifier = markup::MODIFIERS
+            .iter()
+            .find(|(name, _)| name.len() > 1 && *name == word);
+        if let Some((_, modifier)) = modifier {
+            parsed.add_modifier(modifier);
+        } else if let Some(color) = word.strip_prefix("on_") {
+            parsed.bg = Some(parse_color(krate, style, color)?);
+        } else {
+            parsed.fg = Some(parse_color(krate, style, word)?);
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_color(krate: &TokenStream, style: &str, color: &str) -> Result<TokenStream, String> {
+    color::to_tokens(krate, color).map_err(|_| {
+        format!(
+            "unknown style `{color}` in `{style}`, expected a modifier like `bold` or `italic`, a \
+             color like `red` or `#1e293b`, or `on_` followed by a background color"
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    fn render(format: &str) -> Result<Vec<(String, String)>, String> {
+        let line = parse(&quote!(krate), format)?;
+        Ok(line
+            .segments
+            .iter()
+            .map(|segment| {
+                let style = segment.style.to_tokens(&quote!(krate)).to_string();
+                (segment.text.clone(), style)
+            })
+            .collect())
+    }
+
+    #[test]
+    fn styled_placeholders() {
+        let segments = render("{user:bold.cyan} pushed {:yellow:>3} to {{{branch}}}").unwrap();
+        let texts: Vec<_> = segments.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(texts, ["{user}", " pushed ", "{:>3}", " to {{{branch}}}"]);
+        assert!(segments[0].1.contains("Color :: Cyan"));
+        assert!(segments[0].1.contains("BOLD"));
+        assert!(segments[2].1.contains("Color :: Yellow"));
+    }
+
+    #[test]
+    fn format_specs() {
+        let segments = render("{:x} {n:?} {n:>5} {n:#x} {n:b} {n:#fff}").unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].0, "{:x} {n:?} {n:>5} {n:#x} {n:b} ");
+        assert!(segments[1].1.contains("Rgb (255u8 , 255u8 , 255u8)"));
+    }
+
+    #[test]
+    fn backgrounds() {
+        let segments = render("{:white.on_light_blue}").unwrap();
+        assert!(segments[0]
+            .1
+            .contains("bg (krate :: ratatui_core :: style :: Color :: LightBlue)"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            render("{user:blod}").unwrap_err(),
+            "unknown style `blod` in `blod`, expected a modifier like `bold` or `italic`, a color \
+             like `red` or `#1e293b`, or `on_` followed by a background color"
+        );
+        assert!(render("{user:bold.on_}").is_err());
+        assert!(render("{user").is_err());
+        assert!(render("a\nb").is_err());
+    }
+}
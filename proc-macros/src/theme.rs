@@ -0,0 +1,131 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitStr, Path, Token,
+};
+
+use crate::derive::parse_style;
+
+/// The input of `include_theme!`, e.g. `Theme, "themes/gruvbox.toml"`.
+pub(crate) struct Input {
+    theme: Path,
+    file: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let theme = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let file = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { theme, file })
+    }
+}
+
+pub(crate) fn expand(krate: &TokenStream, input: &Input) -> syn::Result<TokenStream> {
+    let Input { theme, file } = input;
+    let error = |message: String| syn::Error::new(file.span(), message);
+    let relative = file.value();
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .ok_or_else(|| error("`CARGO_MANIFEST_DIR` is not set".to_string()))?;
+    let path = PathBuf::from(manifest_dir).join(&relative);
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|err| error(format!("failed to read the theme `{relative}`: {err}")))?;
+    let entries = parse(&relative, &contents).map_err(error)?;
+
+    let mut fields = Vec::with_capacity(entries.len());
+    for (key, value) in entries {
+        let name = syn::parse_str::<Ident>(&key).map_err(|_| {
+            error(format!(
+                "the key `{key}` in the theme `{relative}` is not a valid field name"
+            ))
+        })?;
+        let name = Ident::new(&name.to_string(), file.span());
+        let style = parse_style(krate, &value).map_err(|err| {
+            error(format!(
+                "invalid style for the key `{key}` in the theme `{relative}`: {err}"
+            ))
+        })?;
+        let style = style.to_tokens(krate);
+        fields.push(quote!(#name: #style));
+    }
+    let path = path.to_string_lossy().into_owned();
+    Ok(quote! {{
+        // rebuilds the crate when the theme changes
+        const _: &[u8] = ::core::include_bytes!(#path);
+        #theme {
+            #(#fields,)*
+            ..#theme::new()
+        }
+    }})
+}
+
+/// Parses the keys and style strings of a TOML theme, or of a JSON theme if the file name ends
+/// with `.json`.
+fn parse(file: &str, contents: &str) -> Result<BTreeMap<String, String>, String> {
+    let values: BTreeMap<String, Option<String>> = if file.ends_with(".json") {
+        let object = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(contents)
+            .map_err(|err| format!("failed to parse the theme `{file}`: {err}"))?;
+        object
+            .into_iter()
+            .map(|(key, value)| (key, value.as_str().map(str::to_string)))
+            .collect()
+    } else {
+        let table = contents
+            .parse::<toml::Table>()
+            .map_err(|err| format!("failed to parse the theme `{file}`: {err}"))?;
+        table
+            .into_iter()
+            .map(|(key, value)| (key, value.as_str().map(str::to_string)))
+            .collect()
+    };
+    values
+        .into_iter()
+        .map(|(key, value)| match value {
+            Some(value) => Ok((key, value)),
+            None => Err(format!(
+                "the key `{key}` in the theme `{file}` must be a style string like `\"bold red\"`"
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_toml() {
+        let entries = parse("theme.toml", "error = \"bold red\"\nhint = \"dark-gray\"\n").unwrap();
+        assert_eq!(
+            entries.into_iter().collect::<Vec<_>>(),
+            [
+                ("error".to_string(), "bold red".to_string()),
+                ("hint".to_string(), "dark-gray".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_json() {
+        let entries = parse("theme.json", r#"{ "selected": "reversed" }"#).unwrap();
+        assert_eq!(entries["selected"], "reversed");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("theme.toml", "error = 1").unwrap_err(),
+            "the key `error` in the theme `theme.toml` must be a style string like `\"bold red\"`"
+        );
+        assert!(parse("theme.json", "[]")
+            .unwrap_err()
+            .starts_with("failed to parse the theme `theme.json`"));
+        assert!(parse("theme.toml", "error = ")
+            .unwrap_err()
+            .starts_with("failed to parse the theme `theme.toml`"));
+    }
+}
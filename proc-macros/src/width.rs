@@ -0,0 +1,28 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+use unicode_width::UnicodeWidthStr;
+
+/// Expands to the display width of the string as a `usize` literal, which is the width of its
+/// widest line, the same as `Text::width` returns for the string.
+pub(crate) fn expand(input: &LitStr) -> TokenStream {
+    let width = width(&input.value());
+    quote!(#width)
+}
+
+fn width(value: &str) -> usize {
+    value.lines().map(UnicodeWidthStr::width).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::width;
+
+    #[test]
+    fn literal_width() {
+        assert_eq!(width(""), 0);
+        assert_eq!(width("hello"), 5);
+        assert_eq!(width("界a"), 3);
+        assert_eq!(width("ab\nabcd\n"), 4);
+    }
+}
@@ -0,0 +1,439 @@
+use std::{iter::Peekable, str::Chars};
+
+use ratatui_core::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+/// A macro for creating a [`Text`] from a string that contains ANSI escape sequences.
+///
+/// The argument is either a format string and its arguments, like [`format!`], or an expression
+/// that implements `AsRef<str>`, e.g. the output of a subprocess. The string is converted with
+/// [`ansi_to_text`], so the SGR sequences that set colors and modifiers (e.g. `\x1b[1;31m`)
+/// style the text and all other escape sequences are removed.
+///
+/// This macro requires the `ansi` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span, Text}};
+/// use ratatui_macros::ansi;
+///
+/// let text = ansi!("\x1b[1;31merror\x1b[0m: {} failed", "build");
+/// assert_eq!(
+///     text,
+///     Text::from(Line::from(vec![
+///         Span::raw("error").bold().red(),
+///         Span::raw(": build failed"),
+///     ]))
+/// );
+///
+/// let output = String::from("\x1b[32m+ added\x1b[0m\n\x1b[31m- removed\x1b[0m\n");
+/// let text = ansi!(output);
+/// assert_eq!(text.lines.len(), 2);
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`ansi_to_text`]: crate::ansi_to_text
+#[macro_export]
+macro_rules! ansi {
+    ($format:literal $($args:tt)*) => {
+        $crate::ansi_to_text(&::std::format!($format $($args)*))
+    };
+    ($input:expr) => {
+        $crate::ansi_to_text(::core::convert::AsRef::<str>::as_ref(&$input))
+    };
+}
+
+/// A macro for removing the ANSI escape sequences from a string, returning the plain text as a
+/// `String`.
+///
+/// Like [`ansi!`], the argument is either a format string and its arguments, or an expression that
+/// implements `AsRef<str>`. All escape sequences are removed, including the SGR sequences that
+/// [`ansi!`] turns into styles, so the result is the text that a terminal shows. This is useful
+/// for measuring or comparing the output of a subprocess.
+///
+/// This macro requires the `ansi` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::ansi_strip;
+///
+/// let plain = ansi_strip!("\x1b[1;31merror\x1b[0m: {} failed", "build");
+/// assert_eq!(plain, "error: build failed");
+///
+/// let output = String::from("\x1b]0;title\x07\x1b[32mok\x1b[0m\n");
+/// assert_eq!(ansi_strip!(output), "ok\n");
+/// ```
+///
+/// [`ansi!`]: crate::ansi!
+#[macro_export]
+macro_rules! ansi_strip {
+    ($format:literal $($args:tt)*) => {
+        $crate::__ansi_strip(&::std::format!($format $($args)*))
+    };
+    ($input:expr) => {
+        $crate::__ansi_strip(::core::convert::AsRef::<str>::as_ref(&$input))
+    };
+}
+
+/// Returns the string without its escape sequences. See [`ansi_strip!`].
+///
+/// [`ansi_strip!`]: crate::ansi_strip!
+#[doc(hidden)]
+pub fn __ansi_strip(input: &str) -> String {
+    let mut plain = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            skip_escape(&mut chars);
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+/// Converts a string that contains ANSI escape sequences into a styled [`Text`].
+///
+/// The SGR sequences (`ESC [ ... m`) set the style of the text that follows them: modifiers such
+/// as bold (`1`) and italic (`3`), the 16 standard colors (`30`–`37`, `90`–`97` and their
+/// background equivalents), 256 colors (`38;5;n`) and RGB colors (`38;2;r;g;b`). The style carries
+/// over from one line to the next. The `:` separated forms, e.g. `4:3` and `38:2::r:g:b`, are
+/// also supported, and parameters that are not valid are skipped. Other escape sequences, e.g.
+/// cursor movement and OSC titles, are removed. Each line of the input becomes a [`Line`], and a
+/// trailing newline does not add an empty line.
+///
+/// This function requires the `ansi` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Color, Style}, text::{Line, Span, Text}};
+/// use ratatui_macros::ansi_to_text;
+///
+/// let text = ansi_to_text("\x1b[38;5;208mwarning\x1b[39m: unused import\n");
+/// assert_eq!(
+///     text,
+///     Text::from(Line::from(vec![
+///         Span::styled("warning", Style::new().fg(Color::Indexed(208))),
+///         Span::styled(": unused import", Style::new().fg(Color::Reset)),
+///     ]))
+/// );
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`Line`]: ratatui_core::text::Line
+pub fn ansi_to_text(input: &str) -> Text<'static> {
+    let input = input.strip_suffix('\n').unwrap_or(input);
+    let input = input.strip_suffix('\r').unwrap_or(input);
+    let mut style = Style::new();
+    let lines = input
+        .split('\n')
+        .map(|line| parse_line(line.strip_suffix('\r').unwrap_or(line), &mut style))
+        .collect::<Vec<_>>();
+    Text::from(lines)
+}
+
+/// Parses a line of text, updating `style` with the SGR sequences in it.
+fn parse_line(input: &str, style: &mut Style) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut content = String::new();
+    let mut content_style = *style;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            if content_style != *style {
+                push_span(&mut spans, &mut content, content_style);
+                content_style = *style;
+            }
+            content.push(c);
+            continue;
+        }
+        if let Some(params) = skip_escape(&mut chars) {
+            apply_sgr(&params, style);
+        }
+    }
+    push_span(&mut spans, &mut content, content_style);
+    Line::from(spans)
+}
+
+/// Skips the escape sequence after an `ESC`, returning its parameters if it is an SGR sequence.
+fn skip_escape(chars: &mut Peekable<Chars<'_>>) -> Option<String> {
+    match chars.next() {
+        // CSI: parameters followed by a final byte in `@`..=`~`
+        Some('[') => {
+            let mut params = String::new();
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    return (c == 'm').then_some(params);
+                }
+                params.push(c);
+            }
+            None
+        }
+        // OSC: terminated by BEL or ST (`ESC \`)
+        Some(']') => {
+            while let Some(c) = chars.next() {
+                if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                    break;
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn push_span(spans: &mut Vec<Span<'static>>, content: &mut String, style: Style) {
+    if !content.is_empty() {
+        spans.push(Span::styled(std::mem::take(content), style));
+    }
+}
+
+/// Applies the `;` separated parameters of an SGR sequence to the style.
+///
+/// Each parameter may have `:` separated subparameters, e.g. `4:3` for a curly underline or
+/// `38:2::255:0:0` for an RGB color. Parameters and colors that are not valid are skipped.
+fn apply_sgr(params: &str, style: &mut Style) {
+    let mut params = params.split(';');
+    while let Some(param) = params.next() {
+        let mut subparams = param.split(':');
+        // an empty parameter is 0, so an empty sequence (`ESC [ m`) resets the style like
+        // `ESC [ 0 m`
+        let code = match subparams.next().unwrap_or_default() {
+            "" => 0,
+            code => match code.parse::<u16>() {
+                Ok(code) => code,
+                Err(_) => continue,
+            },
+        };
+        let subparams = subparams.collect::<Vec<_>>();
+        *style = match code {
+            0 => Style::new(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            // `4:0` is no underline and `4:1` to `4:5` are the underline styles
+            4 => match subparams.first() {
+                None => style.add_modifier(Modifier::UNDERLINED),
+                Some(&"0") => remove_modifier(*style, Modifier::UNDERLINED),
+                Some(&("1" | "2" | "3" | "4" | "5")) => style.add_modifier(Modifier::UNDERLINED),
+                Some(_) => *style,
+            },
+            5 => style.add_modifier(Modifier::SLOW_BLINK),
+            6 => style.add_modifier(Modifier::RAPID_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            8 => style.add_modifier(Modifier::HIDDEN),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            22 => remove_modifier(*style, Modifier::BOLD | Modifier::DIM),
+            23 => remove_modifier(*style, Modifier::ITALIC),
+            24 => remove_modifier(*style, Modifier::UNDERLINED),
+            25 => remove_modifier(*style, Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => remove_modifier(*style, Modifier::REVERSED),
+            28 => remove_modifier(*style, Modifier::HIDDEN),
+            29 => remove_modifier(*style, Modifier::CROSSED_OUT),
+            30..=37 => style.fg(standard_color(code as u8 - 30)),
+            38 => match extended_color(&subparams, &mut params) {
+                Some(color) => style.fg(color),
+                None => *style,
+            },
+            39 => style.fg(Color::Reset),
+            40..=47 => style.bg(standard_color(code as u8 - 40)),
+            48 => match extended_color(&subparams, &mut params) {
+                Some(color) => style.bg(color),
+                None => *style,
+            },
+            49 => style.bg(Color::Reset),
+            90..=97 => style.fg(standard_color(code as u8 - 90 + 8)),
+            100..=107 => style.bg(standard_color(code as u8 - 100 + 8)),
+            _ => *style,
+        };
+    }
+}
+
+/// Removes the modifiers that were added by earlier parameters. Unlike [`Style::remove_modifier`],
+/// this does not record the modifiers as removed, so that the style matches a style that never had
+/// them.
+const fn remove_modifier(style: Style, modifier: Modifier) -> Style {
+    Style {
+        add_modifier: style.add_modifier.difference(modifier),
+        ..style
+    }
+}
+
+/// Parses the color of a `38` or `48` parameter, either from its subparameters, `5:n`, `2:r:g:b`
+/// or `2:id:r:g:b` with a color space id, or from the parameters after it, `5;n` or `2;r;g;b`.
+fn extended_color<'a>(
+    subparams: &[&str],
+    params: &mut impl Iterator<Item = &'a str>,
+) -> Option<Color> {
+    let component = |value: &str| value.parse::<u8>().ok();
+    match subparams {
+        [] => match params.next()? {
+            "5" => params.next().and_then(component).map(Color::Indexed),
+            "2" => {
+                // the components are taken before they are parsed, so that an invalid component
+                // does not leave the others to be read as parameters
+                let rgb = [params.next()?, params.next()?, params.next()?].map(component);
+                Some(Color::Rgb(rgb[0]?, rgb[1]?, rgb[2]?))
+            }
+            _ => None,
+        },
+        ["5", index] => component(index).map(Color::Indexed),
+        ["2", r, g, b] | ["2", _, r, g, b] => {
+            Some(Color::Rgb(component(r)?, component(g)?, component(b)?))
+        }
+        _ => None,
+    }
+}
+
+/// Returns one of the 16 standard colors, where 8..=15 are the bright colors.
+const fn standard_color(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Span, Text},
+    };
+
+    use super::{__ansi_strip, ansi_to_text};
+
+    #[test]
+    fn ansi_plain_text() {
+        assert_eq!(ansi_to_text(""), Text::from(""));
+        assert_eq!(ansi_to_text("hello\nworld\n"), Text::from("hello\nworld"));
+        assert_eq!(
+            ansi_to_text("a\r\n\r\nb"),
+            Text::from(vec![Line::from("a"), Line::from(""), Line::from("b")])
+        );
+    }
+
+    #[test]
+    fn ansi_modifiers_and_resets() {
+        let text = ansi_to_text("\x1b[1;3mbold italic\x1b[22m italic\x1b[m plain");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("bold italic").bold().italic(),
+                Span::raw(" italic").italic(),
+                Span::raw(" plain"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn ansi_colors() {
+        let text = ansi_to_text("\x1b[31;44ma\x1b[92mb\x1b[38;5;208mc\x1b[48;2;30;41;59md");
+        let style = Style::new().bg(Color::Blue);
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::styled("a", style.fg(Color::Red)),
+                Span::styled("b", style.fg(Color::LightGreen)),
+                Span::styled("c", style.fg(Color::Indexed(208))),
+                Span::styled(
+                    "d",
+                    Style::new()
+                        .fg(Color::Indexed(208))
+                        .bg(Color::Rgb(30, 41, 59))
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn ansi_subparameters() {
+        let text = ansi_to_text("\x1b[4:3ma\x1b[4:0;1;38:2::255:0:0mb\x1b[0;48:5:236mc");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("a").underlined(),
+                Span::raw("b").bold().fg(Color::Rgb(255, 0, 0)),
+                Span::raw("c").bg(Color::Indexed(236)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn ansi_invalid_parameters_are_skipped() {
+        let text = ansi_to_text("\x1b[1ma\x1b[38;5;300;3mb\x1b[99999;999;38;2;1;2;300mc");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("a").bold(),
+                Span::raw("bc").bold().italic(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn ansi_style_carries_over_lines() {
+        let text = ansi_to_text("\x1b[32mfirst\nsecond\x1b[0m");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(Span::raw("first").green()),
+                Line::from(Span::raw("second").green()),
+            ])
+        );
+    }
+
+    #[test]
+    fn ansi_other_sequences_are_removed() {
+        let text = ansi_to_text("\x1b]0;title\x07\x1b[2K\x1b[1Gdone\x1b]8;;\x1b\\");
+        assert_eq!(text, Text::from("done"));
+    }
+
+    #[test]
+    fn ansi_macro() {
+        let text = ansi!("\x1b[1m{}\x1b[0m {count}", "total", count = 3);
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![Span::raw("total").bold(), Span::raw(" 3")]))
+        );
+        let output = String::from("\x1b[31mfailed\x1b[0m");
+        assert_eq!(
+            ansi!(output),
+            Text::from(Line::from(Span::raw("failed").red()))
+        );
+        assert_eq!(ansi!(&*String::from("ok")), Text::from("ok"));
+    }
+
+    #[test]
+    fn ansi_strip() {
+        assert_eq!(__ansi_strip(""), "");
+        assert_eq!(
+            __ansi_strip("\x1b[1;3mbold\x1b[22m\x1b[38;5;208m text\x1b[m\nnext"),
+            "bold text\nnext"
+        );
+        assert_eq!(
+            __ansi_strip("\x1b]8;;https://ratatui.rs\x1b\\link\x1b]8;;\x1b\\\x1b[2K"),
+            "link"
+        );
+        assert_eq!(ansi_strip!("\x1b[31m{}\x1b[0m", 42), "42");
+        assert_eq!(ansi_strip!(String::from("\x1b[1mok")), "ok");
+    }
+}
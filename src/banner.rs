@@ -0,0 +1,252 @@
+use ratatui_core::{
+    style::Color,
+    text::{Line, Text},
+};
+
+/// A macro for creating a [`Text`] that shows a string in large block letters.
+///
+/// The argument is a format string and its arguments, like [`format!`], or an expression that
+/// implements `AsRef<str>`, and the string is rendered with [`banner_text`]. Like [`span!`], a
+/// [`Style`] followed by a `;` may come first to style the whole banner. Alternatively, two or
+/// more colors separated by `->` and followed by a `;` fade the banner from left to right, with
+/// the colors of [`gradient!`].
+///
+/// This macro requires the `banner` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::banner;
+///
+/// let text = banner!("HI");
+/// assert_eq!(
+///     text.lines,
+///     [
+///         "█   █ ███".into(),
+///         "█   █  █ ".into(),
+///         "█████  █ ".into(),
+///         "█   █  █ ".into(),
+///         "█   █ ███".into(),
+///     ]
+/// );
+///
+/// let level = 3;
+/// let text = banner!(Style::new().cyan().bold(); "LEVEL {level}");
+/// let text = banner!("#ff0000" -> "#0000ff"; "RATATUI");
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`Style`]: ratatui_core::style::Style
+/// [`banner_text`]: crate::banner_text
+/// [`gradient!`]: crate::gradient!
+/// [`span!`]: crate::span!
+#[macro_export]
+macro_rules! banner {
+    (@text $format:literal $($args:tt)*) => {
+        $crate::banner_text(&::std::format!($format $($args)*))
+    };
+    (@text $input:expr $(,)?) => {
+        $crate::banner_text(::core::convert::AsRef::<str>::as_ref(&$input))
+    };
+    ($first:tt $(-> $stop:tt)+ ; $($text:tt)+) => {
+        $crate::__banner_gradient(
+            &[
+                $crate::gradient!(@stop $first),
+                $($crate::gradient!(@stop $stop)),+
+            ],
+            $crate::banner!(@text $($text)+),
+        )
+    };
+    ($style:expr ; $($text:tt)+) => {
+        $crate::banner!(@text $($text)+).style($style)
+    };
+    ($($text:tt)+) => {
+        $crate::banner!(@text $($text)+)
+    };
+}
+
+/// The height of a letter in rows.
+const HEIGHT: usize = 5;
+
+/// The letters of the banner font, as the rows of each letter.
+const FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [" ███ ", "█   █", "█████", "█   █", "█   █"]),
+    ('B', ["████ ", "█   █", "████ ", "█   █", "████ "]),
+    ('C', [" ████", "█    ", "█    ", "█    ", " ████"]),
+    ('D', ["████ ", "█   █", "█   █", "█   █", "████ "]),
+    ('E', ["█████", "█    ", "████ ", "█    ", "█████"]),
+    ('F', ["█████", "█    ", "████ ", "█    ", "█    "]),
+    ('G', [" ████", "█    ", "█  ██", "█   █", " ████"]),
+    ('H', ["█   █", "█   █", "█████", "█   █", "█   █"]),
+    ('I', ["███", " █ ", " █ ", " █ ", "███"]),
+    ('J', ["  ███", "    █", "    █", "█   █", " ███ "]),
+    ('K', ["█   █", "█  █ ", "███  ", "█  █ ", "█   █"]),
+    ('L', ["█    ", "█    ", "█    ", "█    ", "█████"]),
+    ('M', ["█   █", "██ ██", "█ █ █", "█   █", "█   █"]),
+    ('N', ["█   █", "██  █", "█ █ █", "█  ██", "█   █"]),
+    ('O', [" ███ ", "█   █", "█   █", "█   █", " ███ "]),
+    ('P', ["████ ", "█   █", "████ ", "█    ", "█    "]),
+    ('Q', [" ███ ", "█   █", "█ █ █", "█  █ ", " ██ █"]),
+    ('R', ["████ ", "█   █", "████ ", "█  █ ", "█   █"]),
+    ('S', [" ████", "█    ", " ███ ", "    █", "████ "]),
+    ('T', ["█████", "  █  ", "  █  ", "  █  ", "  █  "]),
+    ('U', ["█   █", "█   █", "█   █", "█   █", " ███ "]),
+    ('V', ["█   █", "█   █", "█   █", " █ █ ", "  █  "]),
+    ('W', ["█   █", "█   █", "█ █ █", "██ ██", "█   █"]),
+    ('X', ["█   █", " █ █ ", "  █  ", " █ █ ", "█   █"]),
+    ('Y', ["█   █", " █ █ ", "  █  ", "  █  ", "  █  "]),
+    ('Z', ["█████", "   █ ", "  █  ", " █   ", "█████"]),
+    ('0', [" ███ ", "█  ██", "█ █ █", "██  █", " ███ "]),
+    ('1', [" █ ", "██ ", " █ ", " █ ", "███"]),
+    ('2', ["████ ", "    █", " ███ ", "█    ", "█████"]),
+    ('3', ["████ ", "    █", " ███ ", "    █", "████ "]),
+    ('4', ["█   █", "█   █", "█████", "    █", "    █"]),
+    ('5', ["█████", "█    ", "████ ", "    █", "████ "]),
+    ('6', [" ███ ", "█    ", "████ ", "█   █", " ███ "]),
+    ('7', ["█████", "    █", "   █ ", "  █  ", "  █  "]),
+    ('8', [" ███ ", "█   █", " ███ ", "█   █", " ███ "]),
+    ('9', [" ███ ", "█   █", " ████", "    █", " ███ "]),
+    (' ', ["   ", "   ", "   ", "   ", "   "]),
+    ('!', ["█", "█", "█", " ", "█"]),
+    ('.', [" ", " ", " ", " ", "█"]),
+    (',', ["  ", "  ", "  ", " █", "█ "]),
+    (':', [" ", "█", " ", "█", " "]),
+    ('\'', ["█", "█", " ", " ", " "]),
+    ('-', ["   ", "   ", "███", "   ", "   "]),
+    ('_', ["     ", "     ", "     ", "     ", "█████"]),
+    ('/', ["    █", "   █ ", "  █  ", " █   ", "█    "]),
+    ('?', ["███ ", "   █", " ██ ", "    ", " █  "]),
+];
+
+/// Returns the rows of a letter, where lowercase letters use the uppercase letter and any other
+/// character that is not in the font uses `?`.
+fn letter(c: char) -> &'static [&'static str; HEIGHT] {
+    let c = c.to_ascii_uppercase();
+    let (_, rows) = FONT
+        .iter()
+        .find(|(letter, _)| *letter == c)
+        .or_else(|| FONT.iter().find(|(letter, _)| *letter == '?'))
+        .expect("the font contains `?`");
+    rows
+}
+
+/// Renders a string into a [`Text`] of large block letters.
+///
+/// Each letter is 5 rows tall, and the letters are separated by a column of spaces. The font
+/// contains the letters `A` to `Z`, with lowercase letters shown as uppercase, the digits, and
+/// ``!.,:'-_/?``; any other character is shown as `?`. Each line of the string becomes its own
+/// banner, and the banners are separated by an empty line. All rows of a banner have the same
+/// width, so the text stays aligned when it is styled or centered.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::text::Text;
+/// use ratatui_macros::banner_text;
+///
+/// let text = banner_text("-1");
+/// assert_eq!(
+///     text,
+///     Text::from(vec![
+///         "     █ ".into(),
+///         "    ██ ".into(),
+///         "███  █ ".into(),
+///         "     █ ".into(),
+///         "    ███".into(),
+///     ])
+/// );
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+pub fn banner_text(input: &str) -> Text<'static> {
+    let mut lines = Vec::new();
+    for (index, input_line) in input.lines().enumerate() {
+        if index > 0 {
+            lines.push(Line::default());
+        }
+        let letters: Vec<_> = input_line.chars().map(letter).collect();
+        for row in 0..HEIGHT {
+            let row: Vec<_> = letters.iter().map(|letter| letter[row]).collect();
+            lines.push(Line::from(row.join(" ")));
+        }
+    }
+    Text::from(lines)
+}
+
+/// Colors each row of the banner with the gradient, so that the colors fade from left to right.
+#[doc(hidden)]
+#[track_caller]
+pub fn __banner_gradient(stops: &[Color], text: Text<'static>) -> Text<'static> {
+    let lines = text
+        .lines
+        .iter()
+        .map(|line| crate::__gradient(stops, &line.to_string()));
+    Text::from(lines.collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Text},
+    };
+
+    use super::{banner_text, FONT, HEIGHT};
+
+    #[test]
+    fn font_rows_have_equal_widths() {
+        for (letter, rows) in FONT {
+            let width = rows[0].chars().count();
+            assert!(
+                rows.iter().all(|row| row.chars().count() == width),
+                "the rows of {letter:?} have different widths"
+            );
+        }
+    }
+
+    #[test]
+    fn banner_text_letters() {
+        assert_eq!(banner_text("i"), banner_text("I"));
+        assert_eq!(banner_text("~"), banner_text("?"));
+        assert_eq!(
+            banner_text("A.").lines,
+            [
+                " ███   ".into(),
+                "█   █  ".into(),
+                "█████  ".into(),
+                "█   █  ".into(),
+                "█   █ █".into(),
+            ] as [Line; HEIGHT]
+        );
+    }
+
+    #[test]
+    fn banner_text_lines() {
+        let text = banner_text("1\n1");
+        assert_eq!(text.lines.len(), 2 * HEIGHT + 1);
+        assert_eq!(text.lines[HEIGHT], Line::default());
+        assert_eq!(text.lines[HEIGHT + 1], Line::from(" █ "));
+        assert_eq!(banner_text(""), Text::default());
+    }
+
+    #[test]
+    fn banner_forms() {
+        let score = 10;
+        assert_eq!(banner!("{score}!"), banner_text("10!"));
+        assert_eq!(banner!(String::from("ok")), banner_text("ok"));
+        assert_eq!(
+            banner!(Style::new().red(); "ok"),
+            banner_text("ok").style(Style::new().red())
+        );
+    }
+
+    #[test]
+    fn banner_gradient() {
+        let text = banner!("#ff0000" -> (Color::Rgb(0, 0, 255)); "1");
+        let first = &text.lines[0].spans;
+        assert_eq!(first[0].style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(first.last().unwrap().style.fg, Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(text.lines[4].to_string(), "███");
+    }
+}
@@ -0,0 +1,109 @@
+/// A macro for creating a [`BarChart`] using vec! syntax.
+///
+/// Each element is either a `(label, value)` pair or an expression that evaluates to a [`Bar`].
+/// The elements can optionally be followed by a `;` and a list of `key: value` settings. `gap`
+/// sets the gap between bars, and any other key is the name of a builder method on [`BarChart`],
+/// e.g. `bar_width: 3`, `bar_style: Style::new().green()` or `max: 100`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// # use ratatui_widgets::barchart::Bar;
+/// use ratatui_macros::barchart;
+///
+/// let chart = barchart![("mon", 10), ("tue", 42)];
+/// let chart = barchart![("mon", 10), ("tue", 42); bar_width: 3, gap: 1];
+/// let chart = barchart![("mon", 10), Bar::new(42).label("tue").red(); style: Style::new().dim()];
+/// ```
+///
+/// [`BarChart`]: ratatui_widgets::barchart::BarChart
+/// [`Bar`]: ratatui_widgets::barchart::Bar
+#[macro_export]
+macro_rules! barchart {
+    (@bars [$($bars:tt)*] ($label:expr, $value:expr) $(, $($rest:tt)*)?) => {
+        $crate::barchart!(@bars [
+            $($bars)* $crate::ratatui_widgets::barchart::Bar::with_label($label, $value),
+        ] $($($rest)*)?)
+    };
+    (@bars [$($bars:tt)*] ($label:expr, $value:expr) ; $($rest:tt)*) => {
+        $crate::barchart!(@bars [
+            $($bars)* $crate::ratatui_widgets::barchart::Bar::with_label($label, $value),
+        ] ; $($rest)*)
+    };
+    (@bars [$($bars:tt)*] ; $($settings:tt)*) => {
+        $crate::barchart!(@settings
+            $crate::ratatui_widgets::barchart::BarChart::new(vec![$($bars)*]) ;
+            $($settings)*
+        )
+    };
+    (@bars [$($bars:tt)*]) => {
+        $crate::ratatui_widgets::barchart::BarChart::new(vec![$($bars)*])
+    };
+    (@bars [$($bars:tt)*] $bar:expr $(, $($rest:tt)*)?) => {
+        $crate::barchart!(@bars [$($bars)* $bar,] $($($rest)*)?)
+    };
+    (@bars [$($bars:tt)*] $bar:expr ; $($rest:tt)*) => {
+        $crate::barchart!(@bars [$($bars)* $bar,] ; $($rest)*)
+    };
+    (@settings $chart:expr ; $(,)?) => {
+        $chart
+    };
+    (@settings $chart:expr ; gap: $gap:expr $(, $($rest:tt)*)?) => {
+        $crate::barchart!(@settings $chart.bar_gap($gap) ; $($($rest)*)?)
+    };
+    (@settings $chart:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::barchart!(@settings $chart.$key($value) ; $($($rest)*)?)
+    };
+    () => {
+        $crate::ratatui_widgets::barchart::BarChart::default()
+    };
+    ($($tokens:tt)+) => {
+        $crate::barchart!(@bars [] $($tokens)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_widgets::barchart::{Bar, BarChart};
+
+    #[test]
+    fn barchart_empty() {
+        assert_eq!(barchart![], BarChart::default());
+    }
+
+    #[test]
+    fn barchart_pairs() {
+        let chart = barchart![("mon", 10), ("tue", 42)];
+        assert_eq!(
+            chart,
+            BarChart::new(vec![Bar::with_label("mon", 10), Bar::with_label("tue", 42)])
+        );
+    }
+
+    #[test]
+    fn barchart_bars() {
+        let chart = barchart![("mon", 10), Bar::new(42).label("tue").red(),];
+        assert_eq!(
+            chart,
+            BarChart::new(vec![
+                Bar::with_label("mon", 10),
+                Bar::new(42).label("tue").red()
+            ])
+        );
+    }
+
+    #[test]
+    fn barchart_settings() {
+        let chart =
+            barchart![("mon", 10), ("tue", 42); bar_width: 3, gap: 2, style: Style::new().dim()];
+        assert_eq!(
+            chart,
+            BarChart::new(vec![Bar::with_label("mon", 10), Bar::with_label("tue", 42)])
+                .bar_width(3)
+                .bar_gap(2)
+                .style(Style::new().dim())
+        );
+    }
+}
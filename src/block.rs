@@ -0,0 +1,150 @@
+/// A macro for creating a [`Block`] with an optional title and a list of `key: value` settings.
+///
+/// The first argument may be a title, which is anything that can be converted into a [`Line`].
+/// It is followed by `key: value` settings where each key is the name of a builder method on
+/// [`Block`] and the value is passed to it. Two settings accept a shorthand:
+///
+/// - `borders` accepts the names of [`Borders`] flags, e.g. `borders: ALL` or
+///   `borders: TOP | BOTTOM`
+/// - `border_type` accepts the name of a [`BorderType`] variant, e.g. `border_type: Rounded`
+///
+/// Both also accept a full expression, e.g. `borders: Borders::ALL`.
+///
+/// The settings are applied in order, so a block can have several titles, e.g. `title: ...`,
+/// `title_top: ...` and `title_bottom: ...`. The position of a title is set by the setting, and its
+/// alignment by the alignment of the [`Line`], e.g. `title_bottom: line![right; " q:quit "]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::{block, line};
+///
+/// let block = block!();
+/// let block = block!("Title");
+/// let block = block!("Title", borders: ALL, border_type: Rounded, style: Style::new().dim());
+/// let block = block!(borders: TOP | BOTTOM, title_bottom: "Footer".bold());
+/// let block = block!(
+///     title: " Files ",
+///     title_top: line![right; " 3 items "],
+///     title_bottom: line![" q:quit ", " ?:help "],
+///     borders: ALL,
+/// );
+/// ```
+///
+/// [`Block`]: ratatui_widgets::block::Block
+/// [`Borders`]: ratatui_widgets::borders::Borders
+/// [`BorderType`]: ratatui_widgets::borders::BorderType
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! block {
+    (@settings $block:expr ;) => {
+        $block
+    };
+    (@settings $block:expr ; borders: $($flag:ident)|+ $(, $($rest:tt)*)?) => {
+        $crate::block!(@settings
+            $block.borders($($crate::ratatui_widgets::borders::Borders::$flag)|+) ;
+            $($($rest)*)?
+        )
+    };
+    (@settings $block:expr ; border_type: $border_type:ident $(, $($rest:tt)*)?) => {
+        $crate::block!(@settings
+            $block.border_type($crate::ratatui_widgets::borders::BorderType::$border_type) ;
+            $($($rest)*)?
+        )
+    };
+    (@settings $block:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::block!(@settings $block.$key($value) ; $($($rest)*)?)
+    };
+    () => {
+        $crate::ratatui_widgets::block::Block::new()
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::block!(@settings $crate::ratatui_widgets::block::Block::new() ; $key : $($rest)*)
+    };
+    ($title:expr $(, $($rest:tt)*)?) => {
+        $crate::block!(@settings
+            $crate::ratatui_widgets::block::Block::new().title($title) ;
+            $($($rest)*)?
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_widgets::{
+        block::Block,
+        borders::{BorderType, Borders},
+    };
+
+    #[test]
+    fn block_empty() {
+        assert_eq!(block!(), Block::new());
+    }
+
+    #[test]
+    fn block_title() {
+        assert_eq!(block!("Title"), Block::new().title("Title"));
+
+        let title = String::from("Title");
+        assert_eq!(block!(title.clone()), Block::new().title(title));
+    }
+
+    #[test]
+    fn block_shorthand_settings() {
+        let block = block!("Title", borders: ALL, border_type: Rounded, style: Style::new().dim());
+        assert_eq!(
+            block,
+            Block::new()
+                .title("Title")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(Style::new().dim())
+        );
+    }
+
+    #[test]
+    fn block_combined_borders() {
+        let block = block!(borders: TOP | BOTTOM);
+        assert_eq!(block, Block::new().borders(Borders::TOP | Borders::BOTTOM));
+    }
+
+    #[test]
+    fn block_expression_settings() {
+        let block = block!(
+            borders: Borders::LEFT,
+            border_type: BorderType::Thick,
+            title_bottom: "Footer",
+        );
+        assert_eq!(
+            block,
+            Block::new()
+                .borders(Borders::LEFT)
+                .border_type(BorderType::Thick)
+                .title_bottom("Footer")
+        );
+    }
+
+    #[test]
+    fn block_multiple_titles() {
+        use ratatui_core::text::Line;
+
+        let block = block!(
+            " Files ",
+            title_top: crate::line![right; "3 items"],
+            title_bottom: crate::line![" q:quit ", " ?:help "],
+            title_bottom: Line::from("v1").centered(),
+            borders: ALL,
+        );
+        assert_eq!(
+            block,
+            Block::new()
+                .title(" Files ")
+                .title_top(Line::from("3 items").right_aligned())
+                .title_bottom(Line::from(vec![" q:quit ".into(), " ?:help ".into()]))
+                .title_bottom(Line::from("v1").centered())
+                .borders(Borders::ALL)
+        );
+    }
+}
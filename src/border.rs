@@ -0,0 +1,194 @@
+use ratatui_core::symbols::border::Set;
+
+/// A macro for creating a border [`Set`] from a picture of the symbols or a list of symbols.
+///
+/// The picture is a string of 6 or 8 symbols, which are, in order:
+///
+/// - 6 symbols: the top left, top right, bottom left and bottom right corners, then the horizontal
+///   and vertical edges, e.g. `"╭╮╰╯─│"`
+/// - 8 symbols: the four corners, then the top, bottom, left and right edges, e.g. `"┌┐└┘▔▁▏▕"`
+///
+/// Alternatively, the symbols can be given as `key: "symbol"` pairs where each key is the name of a
+/// field of [`Set`], or `horizontal` or `vertical` to set both horizontal or vertical edges. Any
+/// symbol that is not given is taken from the [`PLAIN`] set.
+///
+/// Every symbol must be a single character, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::symbols::border;
+/// use ratatui_macros::border_set;
+///
+/// assert_eq!(border_set!("╭╮╰╯─│"), border::ROUNDED);
+/// assert_eq!(border_set!("┏┓┗┛━━┃┃"), border::THICK);
+///
+/// let set = border_set!(top_left: "╭", top_right: "╮", horizontal: "═");
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::border_set;
+/// let set = border_set!("╭╮╰╯─");
+/// ```
+///
+/// [`Set`]: ratatui_core::symbols::border::Set
+/// [`PLAIN`]: ratatui_core::symbols::border::PLAIN
+#[macro_export]
+macro_rules! border_set {
+    (@symbols $set:ident ; $(,)?) => {};
+    (@symbols $set:ident ; horizontal: $symbol:expr $(, $($rest:tt)*)?) => {
+        $set.horizontal_top = $crate::__border_symbol($symbol);
+        $set.horizontal_bottom = $crate::__border_symbol($symbol);
+        $crate::border_set!(@symbols $set ; $($($rest)*)?);
+    };
+    (@symbols $set:ident ; vertical: $symbol:expr $(, $($rest:tt)*)?) => {
+        $set.vertical_left = $crate::__border_symbol($symbol);
+        $set.vertical_right = $crate::__border_symbol($symbol);
+        $crate::border_set!(@symbols $set ; $($($rest)*)?);
+    };
+    (@symbols $set:ident ; $key:ident : $symbol:expr $(, $($rest:tt)*)?) => {
+        $set.$key = $crate::__border_symbol($symbol);
+        $crate::border_set!(@symbols $set ; $($($rest)*)?);
+    };
+    ($picture:literal) => {{
+        const SET: $crate::ratatui_core::symbols::border::Set = $crate::__border_set($picture);
+        SET
+    }};
+    ($($symbols:tt)+) => {{
+        const SET: $crate::ratatui_core::symbols::border::Set = {
+            let mut set = $crate::ratatui_core::symbols::border::PLAIN;
+            $crate::border_set!(@symbols set ; $($symbols)+);
+            set
+        };
+        SET
+    }};
+}
+
+/// Creates a border [`Set`] from a picture of 6 or 8 symbols, panicking if the picture is invalid.
+///
+/// This is a `const fn` so that an invalid picture in a constant is a compile error.
+#[doc(hidden)]
+pub const fn __border_set(picture: &'static str) -> Set {
+    let mut symbols = [""; 8];
+    let mut count = 0;
+    let mut rest = picture;
+    while !rest.is_empty() {
+        assert!(
+            count < symbols.len(),
+            "border pictures must have 6 or 8 symbols"
+        );
+        let (symbol, tail) = split_first_char(rest);
+        symbols[count] = symbol;
+        count += 1;
+        rest = tail;
+    }
+    match count {
+        6 => Set {
+            top_left: symbols[0],
+            top_right: symbols[1],
+            bottom_left: symbols[2],
+            bottom_right: symbols[3],
+            horizontal_top: symbols[4],
+            horizontal_bottom: symbols[4],
+            vertical_left: symbols[5],
+            vertical_right: symbols[5],
+        },
+        8 => Set {
+            top_left: symbols[0],
+            top_right: symbols[1],
+            bottom_left: symbols[2],
+            bottom_right: symbols[3],
+            horizontal_top: symbols[4],
+            horizontal_bottom: symbols[5],
+            vertical_left: symbols[6],
+            vertical_right: symbols[7],
+        },
+        _ => panic!("border pictures must have 6 or 8 symbols"),
+    }
+}
+
+/// Returns the symbol, panicking if it is not a single character.
+///
+/// This is a `const fn` so that an invalid symbol in a constant is a compile error.
+#[doc(hidden)]
+pub const fn __border_symbol(symbol: &'static str) -> &'static str {
+    assert!(
+        !symbol.is_empty(),
+        "border symbols must be a single character"
+    );
+    let (_, rest) = split_first_char(symbol);
+    assert!(rest.is_empty(), "border symbols must be a single character");
+    symbol
+}
+
+/// Splits a non-empty string after its first character.
+const fn split_first_char(s: &str) -> (&str, &str) {
+    let bytes = s.as_bytes();
+    // the length of a UTF-8 character is encoded in the leading bits of its first byte
+    let len = match bytes[0] {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    };
+    let (first, rest) = bytes.split_at(len);
+    match (core::str::from_utf8(first), core::str::from_utf8(rest)) {
+        (Ok(first), Ok(rest)) => (first, rest),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::symbols::border::{self, Set};
+
+    #[test]
+    fn border_set_picture() {
+        assert_eq!(border_set!("╭╮╰╯─│"), border::ROUNDED);
+        assert_eq!(border_set!("╔╗╚╝═║"), border::DOUBLE);
+        assert_eq!(
+            border_set!("┌┐└┘▔▁▏▕"),
+            Set {
+                top_left: "┌",
+                top_right: "┐",
+                bottom_left: "└",
+                bottom_right: "┘",
+                horizontal_top: "▔",
+                horizontal_bottom: "▁",
+                vertical_left: "▏",
+                vertical_right: "▕",
+            }
+        );
+        assert_eq!(border_set!("++++-|").top_left, "+");
+    }
+
+    #[test]
+    fn border_set_symbols() {
+        assert_eq!(
+            border_set!(top_left: "╭", top_right: "╮", bottom_left: "╰", bottom_right: "╯"),
+            border::ROUNDED
+        );
+        assert_eq!(
+            border_set!(horizontal: "═", vertical: "║",),
+            Set {
+                horizontal_top: "═",
+                horizontal_bottom: "═",
+                vertical_left: "║",
+                vertical_right: "║",
+                ..border::PLAIN
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic = "border symbols must be a single character"]
+    fn border_symbol_too_long() {
+        crate::__border_symbol("══");
+    }
+
+    #[test]
+    #[should_panic = "border pictures must have 6 or 8 symbols"]
+    fn border_set_picture_too_short() {
+        crate::__border_set("╭╮╰╯─");
+    }
+}
@@ -0,0 +1,153 @@
+use ratatui_core::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::__join_line;
+
+/// A macro for creating a [`Line`] of breadcrumbs from path segments, e.g.
+/// `home › projects › ratatui-macros`.
+///
+/// The segments are the elements of a [`line!`], so they can be strings, [`Span`]s, formatted
+/// segments in braces or the items of an iterator prefixed with `..`. The segments are separated
+/// by `" › "` and the last segment, which is the current location, is bold.
+///
+/// The segments can be preceded by `key: value` directives, separated from them by a `;`:
+///
+/// - `separator: " / "` replaces the separator, which is anything that can be converted into a
+///   [`Span`], so it can be styled
+/// - `current_style: style` replaces the style of the last segment
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Style, Stylize}, text::{Line, Span}};
+/// use ratatui_macros::breadcrumbs;
+///
+/// let line = breadcrumbs!["home", "projects", "ratatui-macros"];
+/// assert_eq!(
+///     line,
+///     Line::from(vec![
+///         Span::raw("home"),
+///         Span::raw(" › "),
+///         Span::raw("projects"),
+///         Span::raw(" › "),
+///         Span::raw("ratatui-macros").bold(),
+///     ])
+/// );
+///
+/// let path = ["usr", "local", "bin"];
+/// let line = breadcrumbs![separator: " / ".dim(), current_style: Style::new().cyan(); ..path];
+/// assert_eq!(line.to_string(), "usr / local / bin");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`Span`]: ratatui_core::text::Span
+/// [`line!`]: crate::line!
+#[macro_export]
+macro_rules! breadcrumbs {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::breadcrumbs!(@directives [$($directives)* .$setting($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::breadcrumbs!(@directives [$($directives)* .$setting($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $($segments:tt)*) => {
+        $crate::__Breadcrumbs::default() $($directives)* .line($crate::line![$($segments)*])
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::breadcrumbs!(@directives [] $setting : $($rest)*)
+    };
+    ($($segments:tt)*) => {
+        $crate::__Breadcrumbs::default().line($crate::line![$($segments)*])
+    };
+}
+
+/// The separator and the style of the last segment of breadcrumbs. See [`breadcrumbs!`].
+///
+/// [`breadcrumbs!`]: crate::breadcrumbs!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Breadcrumbs<'a> {
+    separator: Span<'a>,
+    current_style: Style,
+}
+
+impl Default for __Breadcrumbs<'_> {
+    fn default() -> Self {
+        Self {
+            separator: Span::raw(" › "),
+            current_style: Style::new().add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+impl<'a> __Breadcrumbs<'a> {
+    pub fn separator(mut self, separator: impl Into<Span<'a>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn current_style(mut self, style: impl Into<Style>) -> Self {
+        self.current_style = style.into();
+        self
+    }
+
+    pub fn line(self, mut segments: Line<'a>) -> Line<'a> {
+        if let Some(current) = segments.spans.last_mut() {
+            current.style = self.current_style.patch(current.style);
+        }
+        __join_line(segments, self.separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn breadcrumbs_segments() {
+        let project = String::from("ratatui-macros");
+        let line = breadcrumbs!["home", {"{}s", "project"}, project];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("home"),
+                Span::raw(" › "),
+                Span::raw("projects"),
+                Span::raw(" › "),
+                Span::raw("ratatui-macros").bold(),
+            ])
+        );
+        assert_eq!(breadcrumbs!["home"], Line::from(Span::raw("home").bold()));
+        assert_eq!(breadcrumbs![], Line::default());
+    }
+
+    #[test]
+    fn breadcrumbs_directives() {
+        let path = ["a", "b"];
+        let line = breadcrumbs![separator: "/".dim(), current_style: Color::Cyan; ..path];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("a"),
+                Span::raw("/").dim(),
+                Span::raw("b").cyan()
+            ])
+        );
+
+        // the style of the last segment is patched onto the current style
+        let line = breadcrumbs![separator: " > "; "a", "b".italic()];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("a"),
+                Span::raw(" > "),
+                Span::raw("b").bold().italic(),
+            ])
+        );
+    }
+}
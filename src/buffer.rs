@@ -0,0 +1,546 @@
+use std::{
+    fmt::Write,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use ratatui_core::{
+    buffer::{Buffer, Cell},
+    style::Style,
+    text::Span,
+};
+
+/// A macro for creating a [`Buffer`] from string rows and optional style annotations.
+///
+/// The rows are anything that can be converted into a [`Line`], and are passed to
+/// [`Buffer::with_lines`]. They can be followed by a semicolon and a list of style annotations
+/// `(x, y) => style`, which set the style of the cells in the given columns and rows. Both `x` and
+/// `y` are either a single index or a range of indices, e.g. `1..3`, `1..=2`, `2..` or `..`, and
+/// the style is anything that can be converted into a [`Style`]. The annotations are applied in
+/// order, so later annotations are patched onto earlier ones.
+///
+/// Instead of the annotations, the rows can be followed by a semicolon, a list of marker rows and
+/// another semicolon with a legend `{ name: style, ... }`. Each character of the n-th marker row
+/// sets the style of the cell in the same column of the n-th row to the style of the legend entry
+/// with that name, and a space leaves the cell unstyled. The names are single characters, e.g.
+/// `r` or `b`, and a marker that is not in the legend panics. This shows the styles right under
+/// the text they apply to, which is easier to review than ranges of indices.
+///
+/// This is useful for writing the expected buffer in the tests of a widget.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{buffer::Buffer, layout::Rect, style::{Color, Style, Stylize}};
+/// use ratatui_macros::buffer;
+///
+/// let buffer = buffer!["┌────┐", "│ hi │", "└────┘"];
+/// assert_eq!(buffer, Buffer::with_lines(["┌────┐", "│ hi │", "└────┘"]));
+///
+/// let buffer = buffer![
+///     "┌────┐",
+///     "│ hi │",
+///     "└────┘";
+///     (2..4, 1) => Style::new().bold(),
+///     (.., 0) => Color::Blue,
+/// ];
+/// let mut expected = Buffer::with_lines(["┌────┐", "│ hi │", "└────┘"]);
+/// expected.set_style(Rect::new(2, 1, 2, 1), Style::new().bold());
+/// expected.set_style(Rect::new(0, 0, 6, 1), Color::Blue);
+/// assert_eq!(buffer, expected);
+///
+/// let buffer = buffer![
+///     "ERROR: disk full",
+///     "retry? [y/n]    ";
+///     "rrrrr      bbbb ",
+///     "       b b      ";
+///     { r: Color::Red, b: Style::new().bold() }
+/// ];
+/// let mut expected = Buffer::with_lines(["ERROR: disk full", "retry? [y/n]    "]);
+/// expected.set_style(Rect::new(0, 0, 5, 1), Color::Red);
+/// expected.set_style(Rect::new(11, 0, 4, 1), Style::new().bold());
+/// expected.set_style(Rect::new(7, 1, 1, 1), Style::new().bold());
+/// expected.set_style(Rect::new(9, 1, 1, 1), Style::new().bold());
+/// assert_eq!(buffer, expected);
+/// ```
+///
+/// [`Buffer`]: ratatui_core::buffer::Buffer
+/// [`Buffer::with_lines`]: ratatui_core::buffer::Buffer::with_lines
+/// [`Line`]: ratatui_core::text::Line
+/// [`Style`]: ratatui_core::style::Style
+#[macro_export]
+macro_rules! buffer {
+    (
+        $($line:expr),* $(,)? ;
+        $($markers:literal),+ $(,)? ;
+        { $($name:ident : $style:expr),* $(,)? } $(,)?
+    ) => {{
+        let mut buffer = $crate::ratatui_core::buffer::Buffer::with_lines([
+            $($crate::ratatui_core::text::Line::from($line)),*
+        ]);
+        $crate::__buffer_markers(
+            &mut buffer,
+            &[$($markers),+],
+            &[$((stringify!($name), $crate::ratatui_core::style::Style::from($style))),*],
+        );
+        buffer
+    }};
+    ($($line:expr),* $(,)? $(; $(($x:expr, $y:expr) => $style:expr),* $(,)?)?) => {{
+        #[allow(unused_mut)]
+        let mut buffer = $crate::ratatui_core::buffer::Buffer::with_lines([
+            $($crate::ratatui_core::text::Line::from($line)),*
+        ]);
+        $($(
+            let area = buffer.area;
+            let columns = $crate::__BufferRange::range($x, area.width);
+            let rows = $crate::__BufferRange::range($y, area.height);
+            buffer.set_style(
+                $crate::ratatui_core::layout::Rect::new(
+                    area.x + columns.start,
+                    area.y + rows.start,
+                    columns.end.saturating_sub(columns.start),
+                    rows.end.saturating_sub(rows.start),
+                ),
+                $style,
+            );
+        )*)?
+        buffer
+    }};
+}
+
+/// Asserts that a [`Buffer`] is equal to the expected rows and styles.
+///
+/// The expected buffer is either a list of rows and style annotations in square brackets, which is
+/// passed to [`buffer!`], or an expression that evaluates to a [`Buffer`]. When the buffers are not
+/// equal, the panic message shows both buffers as rows of text, marks the cells that differ with
+/// `^` and lists the symbol and style of each of them, which is easier to read than the output of
+/// [`assert_eq!`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{buffer::Buffer, layout::Rect, style::Stylize, widgets::Widget};
+/// use ratatui_macros::assert_buffer;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+/// "hello".bold().render(Rect::new(0, 0, 10, 1), &mut buffer);
+/// "world".render(Rect::new(0, 1, 10, 1), &mut buffer);
+///
+/// assert_buffer!(buffer, [
+///     "hello     ",
+///     "world     ";
+///     (0..5, 0) => ratatui_core::style::Modifier::BOLD,
+/// ]);
+/// ```
+///
+/// A failing assertion panics with a message like:
+///
+/// ```text
+/// buffers are not equal
+/// expected:
+///     "hello     "
+///     "world     "
+/// actual:
+///     "hello     "
+///     "wor1d     "
+///         ^
+/// differences:
+///     (3, 1): expected "l" Style::new().fg(Color::Reset).bg(Color::Reset), found "1" ...
+/// ```
+///
+/// [`Buffer`]: ratatui_core::buffer::Buffer
+/// [`buffer!`]: crate::buffer!
+#[macro_export]
+macro_rules! assert_buffer {
+    ($actual:expr, [$($expected:tt)*] $(,)?) => {
+        $crate::__assert_buffer(&$actual, &$crate::buffer![$($expected)*])
+    };
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::__assert_buffer(&$actual, &$expected)
+    };
+}
+
+/// Asserts that a row of a [`Buffer`] contains the given text, optionally with a required style.
+///
+/// The text is searched in the symbols of each row, so it may not span rows. With
+/// `style: style` as a third argument, where the style is anything that can be converted into a
+/// [`Style`], every cell of the match must have the colors of the style and contain its
+/// modifiers, while other parts of the style of the cells are ignored. When there is no match, the
+/// panic message shows the buffer as rows of text and the search parameters, and marks the
+/// matches that only differ in style with `^`.
+///
+/// This checks what a widget shows without depending on where it shows it, so the tests of the
+/// widget do not break when the layout around the text changes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{buffer::Buffer, layout::Rect, style::{Style, Stylize}, widgets::Widget};
+/// use ratatui_macros::buffer_contains;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+/// "ok".render(Rect::new(0, 0, 20, 1), &mut buffer);
+/// "ERROR: disk full".red().bold().render(Rect::new(2, 1, 18, 1), &mut buffer);
+///
+/// buffer_contains!(buffer, "disk full");
+/// buffer_contains!(buffer, "ERROR", style: Style::new().red());
+/// ```
+///
+/// [`Buffer`]: ratatui_core::buffer::Buffer
+/// [`Style`]: ratatui_core::style::Style
+#[macro_export]
+macro_rules! buffer_contains {
+    ($buffer:expr, $text:expr, style: $style:expr $(,)?) => {
+        $crate::__buffer_contains(
+            &$buffer,
+            ::core::convert::AsRef::<str>::as_ref(&$text),
+            ::core::option::Option::Some($crate::ratatui_core::style::Style::from($style)),
+        )
+    };
+    ($buffer:expr, $text:expr $(,)?) => {
+        $crate::__buffer_contains(
+            &$buffer,
+            ::core::convert::AsRef::<str>::as_ref(&$text),
+            ::core::option::Option::None,
+        )
+    };
+}
+
+/// Sets the styles of the cells from the marker rows and the legend of [`buffer!`].
+///
+/// [`buffer!`]: crate::buffer!
+#[doc(hidden)]
+#[track_caller]
+pub fn __buffer_markers(buffer: &mut Buffer, markers: &[&str], legend: &[(&str, Style)]) {
+    let area = buffer.area;
+    for (y, row) in (area.top()..area.bottom()).zip(markers) {
+        for (x, marker) in (area.left()..area.right()).zip(row.chars()) {
+            if marker == ' ' {
+                continue;
+            }
+            let Some((_, style)) = legend.iter().find(|(name, _)| name.chars().eq([marker])) else {
+                panic!("the style marker `{marker}` at ({x}, {y}) is not in the legend");
+            };
+            buffer[(x, y)].set_style(*style);
+        }
+    }
+}
+
+/// The number of differing cells that are listed in the panic message of [`assert_buffer!`].
+const MAX_DIFFERENCES: usize = 20;
+
+/// Panics with a readable message when the buffers are not equal.
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_buffer(actual: &Buffer, expected: &Buffer) {
+    if actual == expected {
+        return;
+    }
+    let mut message = String::from("buffers are not equal\n");
+    if actual.area != expected.area {
+        let _ = writeln!(
+            message,
+            "expected area {}, found area {}",
+            expected.area, actual.area
+        );
+    }
+    let differences = differences(actual, expected);
+    message.push_str("expected:\n");
+    write_rows(&mut message, expected, &[]);
+    message.push_str("actual:\n");
+    write_rows(&mut message, actual, &differences);
+    if !differences.is_empty() {
+        message.push_str("differences:\n");
+        for &(x, y) in differences.iter().take(MAX_DIFFERENCES) {
+            let expected = &expected[(x, y)];
+            let actual = &actual[(x, y)];
+            let _ = writeln!(
+                message,
+                "    ({x}, {y}): expected {:?} {:?}, found {:?} {:?}",
+                expected.symbol(),
+                expected.style(),
+                actual.symbol(),
+                actual.style()
+            );
+        }
+        if differences.len() > MAX_DIFFERENCES {
+            let _ = writeln!(
+                message,
+                "    ... and {} more",
+                differences.len() - MAX_DIFFERENCES
+            );
+        }
+    }
+    panic!("{message}");
+}
+
+/// Panics with a readable message when no row of the buffer contains the text with the style.
+#[doc(hidden)]
+#[track_caller]
+pub fn __buffer_contains(buffer: &Buffer, text: &str, style: Option<Style>) {
+    let mut mismatches = Vec::new();
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let cells: Vec<u16> = visible_cells(buffer, y).map(|(x, _)| x).collect();
+        let symbols: Vec<&str> = cells.iter().map(|&x| buffer[(x, y)].symbol()).collect();
+        for start in 0..cells.len() {
+            // the cells that the text covers when it starts at this cell
+            let mut len = 0;
+            let mut end = start;
+            while len < text.len() && end < cells.len() && text[len..].starts_with(symbols[end]) {
+                len += symbols[end].len();
+                end += 1;
+            }
+            if len != text.len() || (text.is_empty() && start > 0) {
+                continue;
+            }
+            let differing: Vec<(u16, u16)> = cells[start..end]
+                .iter()
+                .filter(|&&x| style.is_some_and(|style| !has_style(&buffer[(x, y)], style)))
+                .map(|&x| (x, y))
+                .collect();
+            if differing.is_empty() {
+                return;
+            }
+            mismatches.extend(differing);
+        }
+    }
+    let mut message = format!("buffer does not contain {text:?}");
+    if let Some(style) = style {
+        let _ = write!(message, " with style {style:?}");
+    }
+    message.push_str("\nbuffer:\n");
+    write_rows(&mut message, buffer, &mismatches);
+    if !mismatches.is_empty() {
+        message.push_str("found the text with other styles:\n");
+        for &(x, y) in mismatches.iter().take(MAX_DIFFERENCES) {
+            let _ = writeln!(message, "    ({x}, {y}): {:?}", buffer[(x, y)].style());
+        }
+    }
+    panic!("{message}");
+}
+
+/// Returns whether the cell has the colors of the style and contains its modifiers.
+fn has_style(cell: &Cell, style: Style) -> bool {
+    style.fg.is_none_or(|fg| fg == cell.fg)
+        && style.bg.is_none_or(|bg| bg == cell.bg)
+        && cell.modifier.contains(style.add_modifier)
+        && !cell.modifier.intersects(style.sub_modifier)
+}
+
+/// Returns the positions of the cells that differ, if both buffers have the same area.
+fn differences(actual: &Buffer, expected: &Buffer) -> Vec<(u16, u16)> {
+    if actual.area != expected.area {
+        return Vec::new();
+    }
+    actual
+        .area
+        .positions()
+        .filter(|&position| actual[position] != expected[position])
+        .map(|position| (position.x, position.y))
+        .collect()
+}
+
+/// Writes the rows of the buffer as quoted strings, followed by a line that marks the differing
+/// cells with `^` after each row that contains any.
+fn write_rows(message: &mut String, buffer: &Buffer, differences: &[(u16, u16)]) {
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let mut row = String::new();
+        let mut markers = String::new();
+        for (x, width) in visible_cells(buffer, y) {
+            row.push_str(buffer[(x, y)].symbol());
+            let marker = if differences.contains(&(x, y)) {
+                "^"
+            } else {
+                " "
+            };
+            markers.push_str(&marker.repeat(width));
+        }
+        let _ = writeln!(message, "    {row:?}");
+        if markers.contains('^') {
+            let _ = writeln!(message, "     {}", markers.trim_end());
+        }
+    }
+}
+
+/// Returns the column and width of each cell in the row that is not hidden by a wide symbol
+/// before it.
+pub(crate) fn visible_cells(buffer: &Buffer, y: u16) -> impl Iterator<Item = (u16, usize)> + '_ {
+    let area = buffer.area;
+    let mut skip = 0;
+    (area.left()..area.right()).filter_map(move |x| {
+        if skip > 0 {
+            skip -= 1;
+            return None;
+        }
+        let width = Span::raw(buffer[(x, y)].symbol()).width().max(1);
+        skip = width - 1;
+        Some((x, width))
+    })
+}
+
+/// A column or row index, or a range of them, in a style annotation of [`buffer!`].
+///
+/// [`buffer!`]: crate::buffer!
+#[doc(hidden)]
+pub trait __BufferRange {
+    /// Returns the range of indices, where `len` is the width or height of the buffer.
+    fn range(self, len: u16) -> Range<u16>;
+}
+
+impl __BufferRange for u16 {
+    fn range(self, _len: u16) -> Range<u16> {
+        self..self.saturating_add(1)
+    }
+}
+
+impl __BufferRange for Range<u16> {
+    fn range(self, _len: u16) -> Range<u16> {
+        self
+    }
+}
+
+impl __BufferRange for RangeInclusive<u16> {
+    fn range(self, _len: u16) -> Range<u16> {
+        *self.start()..self.end().saturating_add(1)
+    }
+}
+
+impl __BufferRange for RangeFrom<u16> {
+    fn range(self, len: u16) -> Range<u16> {
+        self.start..len
+    }
+}
+
+impl __BufferRange for RangeTo<u16> {
+    fn range(self, _len: u16) -> Range<u16> {
+        0..self.end
+    }
+}
+
+impl __BufferRange for RangeToInclusive<u16> {
+    fn range(self, _len: u16) -> Range<u16> {
+        0..self.end.saturating_add(1)
+    }
+}
+
+impl __BufferRange for RangeFull {
+    fn range(self, len: u16) -> Range<u16> {
+        0..len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Color, Modifier, Style, Stylize},
+    };
+
+    #[test]
+    fn buffer_lines() {
+        assert_eq!(buffer!["ab", "cd",], Buffer::with_lines(["ab", "cd"]));
+        assert_eq!(
+            buffer!["a".red(), String::from("b")],
+            Buffer::with_lines(["a".red(), "b".into()])
+        );
+    }
+
+    #[test]
+    fn assert_buffer_equal() {
+        let buffer = buffer!["ab", "cd"; (0, 0) => Color::Red];
+        assert_buffer!(buffer, ["ab", "cd"; (0, 0) => Color::Red]);
+        assert_buffer!(buffer.clone(), buffer);
+    }
+
+    #[test]
+    #[should_panic = "differences:\n    (1, 1): expected \"d\" Style::new().fg(Color::Reset)"]
+    fn assert_buffer_symbol_differences() {
+        assert_buffer!(buffer!["ab", "cx"], ["ab", "cd"]);
+    }
+
+    #[test]
+    #[should_panic = "    \"ab\"\n      ^\n"]
+    fn assert_buffer_style_differences() {
+        assert_buffer!(buffer!["ab"; (1, 0) => Color::Red], ["ab"]);
+    }
+
+    #[test]
+    #[should_panic = "expected area 2x1+0+0, found area 3x1+0+0"]
+    fn assert_buffer_area_differences() {
+        assert_buffer!(buffer!["abc"], ["ab"]);
+    }
+
+    #[test]
+    fn buffer_contains_text() {
+        let buffer = buffer![
+            "title     ",
+            "  界 ERROR";
+            (5..10, 1) => Style::new().red().bold(),
+        ];
+        buffer_contains!(buffer, "title");
+        buffer_contains!(buffer, String::from("界 E"));
+        buffer_contains!(buffer, "ERROR", style: Color::Red);
+        buffer_contains!(buffer, "RR", style: Style::new().red().bold());
+    }
+
+    #[test]
+    #[should_panic = "buffer does not contain \"warning\"\nbuffer:\n    \"title\"\n"]
+    fn buffer_contains_missing_text() {
+        buffer_contains!(buffer!["title"], "warning");
+    }
+
+    #[test]
+    #[should_panic = "with style Style::new().blue()\nbuffer:\n    \"ab ab\"\n     ^^ ^^\n"]
+    fn buffer_contains_other_style() {
+        let buffer = buffer!["ab ab"; (3.., 0) => Color::Red];
+        buffer_contains!(buffer, "ab", style: Color::Blue);
+    }
+
+    #[test]
+    fn buffer_style_markers() {
+        let buffer = buffer![
+            "┌────┐",
+            "│ hi │",
+            "└────┘";
+            "bbbbbb",
+            "  rr",
+            "";
+            { b: Color::Blue, r: Style::new().red().bold() },
+        ];
+        let mut expected = Buffer::with_lines(["┌────┐", "│ hi │", "└────┘"]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Color::Blue);
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::new().red().bold());
+        assert_eq!(buffer, expected);
+        assert_buffer!(buffer, ["┌────┐", "│ hi │", "└────┘"; "bbbbbb", "  rr"; {
+            b: Color::Blue,
+            r: Style::new().red().bold(),
+        }]);
+    }
+
+    #[test]
+    #[should_panic = "the style marker `x` at (1, 0) is not in the legend"]
+    fn buffer_style_markers_unknown() {
+        buffer!["ab"; " x"; { r: Color::Red }];
+    }
+
+    #[test]
+    fn buffer_style_annotations() {
+        let buffer = buffer![
+            "┌────┐",
+            "│ hi │",
+            "└────┘";
+            (1..3, 1) => Style::new().bold(),
+            (2, 1..=2) => Color::Red,
+            (4.., ..) => Modifier::DIM,
+            (..1, ..=0) => Color::Blue,
+        ];
+        let mut expected = Buffer::with_lines(["┌────┐", "│ hi │", "└────┘"]);
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::new().bold());
+        expected.set_style(Rect::new(2, 1, 1, 2), Color::Red);
+        expected.set_style(Rect::new(4, 0, 2, 3), Modifier::DIM);
+        expected.set_style(Rect::new(0, 0, 1, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,158 @@
+/// A macro for creating a [`Monthly`] calendar from a month and a list of styled dates.
+///
+/// The first argument is the month to display, written as `year-month`, e.g. `2024-06`. It can be
+/// followed by `key: value` settings. `highlight` takes a list of `year-month-day => style` rules
+/// that are added to the [`CalendarEventStore`] of the calendar, and any other key is the name of
+/// a builder method on [`Monthly`], e.g. `show_month_header: Style::new().bold()` or
+/// `default_style: Style::new().dim()`.
+///
+/// Dates are checked at compile time, so an invalid date such as `2024-02-30` fails to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::calendar;
+///
+/// let calendar = calendar!(2024-06);
+/// let calendar = calendar!(
+///     2024-06,
+///     highlight: [
+///         2024-06-15 => Style::new().red(),
+///         2024-06-21 => Style::new().bold(),
+///     ],
+///     show_month_header: Style::new().bold(),
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::calendar;
+/// let calendar = calendar!(2024-13);
+/// ```
+///
+/// [`Monthly`]: ratatui_widgets::calendar::Monthly
+/// [`CalendarEventStore`]: ratatui_widgets::calendar::CalendarEventStore
+#[macro_export]
+macro_rules! calendar {
+    (@settings $calendar:expr ; $(,)?) => {
+        $calendar
+    };
+    (@settings $calendar:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::calendar!(@settings $calendar.$key($value) ; $($($rest)*)?)
+    };
+    (
+        $year:literal - $month:literal
+        , highlight: [$($y:literal - $m:literal - $d:literal => $style:expr),* $(,)?]
+        $(, $($rest:tt)*)?
+    ) => {{
+        #[allow(unused_mut)]
+        let mut events = $crate::ratatui_widgets::calendar::CalendarEventStore::default();
+        $(
+            events.add($crate::__calendar_date!($y - $m - $d), $style);
+        )*
+        $crate::calendar!(@settings
+            $crate::ratatui_widgets::calendar::Monthly::new(
+                $crate::__calendar_date!($year - $month - 1),
+                events,
+            ) ;
+            $($($rest)*)?
+        )
+    }};
+    ($year:literal - $month:literal $(, $($rest:tt)*)?) => {
+        $crate::calendar!(@settings
+            $crate::ratatui_widgets::calendar::Monthly::new(
+                $crate::__calendar_date!($year - $month - 1),
+                $crate::ratatui_widgets::calendar::CalendarEventStore::default(),
+            ) ;
+            $($($rest)*)?
+        )
+    };
+}
+
+/// Creates a [`Date`] from a `year-month-day` literal, failing to compile if the date is invalid.
+///
+/// [`Date`]: time::Date
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __calendar_date {
+    ($year:literal - $month:literal - $day:literal) => {{
+        // dates are usually written with leading zeros, e.g. `2024-06-01`
+        #[allow(clippy::zero_prefixed_literal)]
+        const DATE: $crate::time::Date = {
+            assert!(
+                $month >= 1 && $month <= 12,
+                "month must be between 1 and 12"
+            );
+            match $crate::time::Date::from_calendar_date(
+                $year,
+                $crate::time::Month::December.nth_next($month),
+                $day,
+            ) {
+                Ok(date) => date,
+                Err(_) => panic!("invalid date"),
+            }
+        };
+        DATE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        widgets::Widget,
+    };
+    use ratatui_widgets::calendar::{CalendarEventStore, Monthly};
+    use time::{Date, Month};
+
+    fn render(widget: impl Widget) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 21, 8));
+        widget.render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn calendar_date() {
+        assert_eq!(
+            __calendar_date!(2024 - 06 - 15),
+            Date::from_calendar_date(2024, Month::June, 15).unwrap()
+        );
+        assert_eq!(
+            __calendar_date!(2024 - 12 - 31),
+            Date::from_calendar_date(2024, Month::December, 31).unwrap()
+        );
+    }
+
+    #[test]
+    fn calendar_month() {
+        let june = Date::from_calendar_date(2024, Month::June, 1).unwrap();
+        assert_eq!(
+            render(calendar!(2024 - 06)),
+            render(Monthly::new(june, CalendarEventStore::default()))
+        );
+    }
+
+    #[test]
+    fn calendar_highlight_and_settings() {
+        let calendar = calendar!(
+            2024-06,
+            highlight: [2024-06-15 => Style::new().red(), 2024-06-21 => Style::new().bold()],
+            show_month_header: Style::new().bold(),
+        );
+
+        let june = Date::from_calendar_date(2024, Month::June, 1).unwrap();
+        let mut events = CalendarEventStore::default();
+        events.add(
+            Date::from_calendar_date(2024, Month::June, 15).unwrap(),
+            Style::new().red(),
+        );
+        events.add(
+            Date::from_calendar_date(2024, Month::June, 21).unwrap(),
+            Style::new().bold(),
+        );
+        let expected = Monthly::new(june, events).show_month_header(Style::new().bold());
+        assert_eq!(render(calendar), render(expected));
+    }
+}
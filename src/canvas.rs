@@ -0,0 +1,244 @@
+/// A macro for creating a [`Canvas`] from a list of shapes.
+///
+/// The shapes can optionally be preceded by a list of settings separated from the shapes by a
+/// `;`. `x` and `y` take a range and set the bounds of the canvas, and any other `key: value` calls
+/// the builder method of the same name on [`Canvas`], e.g. `marker: Marker::Braille` or
+/// `background_color: Color::Black`.
+///
+/// Each shape is drawn in order in the paint closure of the canvas and is one of:
+///
+/// - `map!(High)` or `map!(Low, Color::White)` draws a [`Map`] with the named [`MapResolution`]
+/// - `line!(x1, y1, x2, y2, color)` draws a [`Line`](ratatui_widgets::canvas::Line)
+/// - `points!(coords, color)` draws [`Points`]
+/// - `rectangle!(x, y, width, height, color)` draws a [`Rectangle`]
+/// - `circle!(x, y, radius, color)` draws a [`Circle`]
+/// - `print!(x, y, text)` prints text at the given coordinates
+/// - `layer!()` saves the shapes drawn so far as a layer
+/// - any other expression is a [`Shape`] and is drawn directly
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Color, symbols::Marker};
+/// use ratatui_macros::canvas;
+///
+/// let coords = [(20.0, 20.0), (30.0, 40.0)];
+/// let canvas = canvas![
+///     x: -180.0..180.0,
+///     y: -90.0..90.0,
+///     marker: Marker::Braille;
+///     map!(High),
+///     line!(0.0, 0.0, 10.0, 10.0, Color::Red),
+///     points!(&coords, Color::Green),
+///     layer!(),
+///     print!(0.0, 0.0, "origin"),
+/// ];
+/// ```
+///
+/// [`Canvas`]: ratatui_widgets::canvas::Canvas
+/// [`Map`]: ratatui_widgets::canvas::Map
+/// [`MapResolution`]: ratatui_widgets::canvas::MapResolution
+/// [`Points`]: ratatui_widgets::canvas::Points
+/// [`Rectangle`]: ratatui_widgets::canvas::Rectangle
+/// [`Circle`]: ratatui_widgets::canvas::Circle
+/// [`Shape`]: ratatui_widgets::canvas::Shape
+#[macro_export]
+macro_rules! canvas {
+    (@settings [$($settings:tt)*] x: $range:expr , $($rest:tt)*) => {
+        $crate::canvas!(@settings [$($settings)* .x_bounds({
+            let range = $range;
+            [range.start, range.end]
+        })] $($rest)*)
+    };
+    (@settings [$($settings:tt)*] x: $range:expr ; $($rest:tt)*) => {
+        $crate::canvas!(@settings [$($settings)*] x: $range , ; $($rest)*)
+    };
+    (@settings [$($settings:tt)*] y: $range:expr , $($rest:tt)*) => {
+        $crate::canvas!(@settings [$($settings)* .y_bounds({
+            let range = $range;
+            [range.start, range.end]
+        })] $($rest)*)
+    };
+    (@settings [$($settings:tt)*] y: $range:expr ; $($rest:tt)*) => {
+        $crate::canvas!(@settings [$($settings)*] y: $range , ; $($rest)*)
+    };
+    (@settings [$($settings:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::canvas!(@settings [$($settings)* .$key($value)] $($rest)*)
+    };
+    (@settings [$($settings:tt)*] $key:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::canvas!(@settings [$($settings)* .$key($value)] ; $($rest)*)
+    };
+    (@settings [$($settings:tt)*] , ; $($shapes:tt)*) => {
+        $crate::canvas!(@settings [$($settings)*] ; $($shapes)*)
+    };
+    (@settings [$($settings:tt)*] ; $($shapes:tt)*) => {
+        $crate::canvas!(@shapes ctx [$($settings)*] [] $($shapes)*)
+    };
+
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*] $(,)?) => {
+        $crate::ratatui_widgets::canvas::Canvas::default()
+            $($settings)*
+            .paint(|$ctx| { $($draw)* })
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        map!($resolution:ident $(, $color:expr)? $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*]
+            map!($crate::ratatui_widgets::canvas::MapResolution::$resolution $(, $color)?)
+            $(, $($rest)*)?
+        )
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        map!($resolution:expr, $color:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$crate::ratatui_widgets::canvas::Map {
+                resolution: $resolution,
+                color: $color,
+            });
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        map!($resolution:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$crate::ratatui_widgets::canvas::Map {
+                resolution: $resolution,
+                ..::core::default::Default::default()
+            });
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        line!($x1:expr, $y1:expr, $x2:expr, $y2:expr, $color:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$crate::ratatui_widgets::canvas::Line::new($x1, $y1, $x2, $y2, $color));
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        points!($coords:expr, $color:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$crate::ratatui_widgets::canvas::Points::new($coords, $color));
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        rectangle!($x:expr, $y:expr, $width:expr, $height:expr, $color:expr $(,)?)
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$crate::ratatui_widgets::canvas::Rectangle::new(
+                $x, $y, $width, $height, $color,
+            ));
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        circle!($x:expr, $y:expr, $radius:expr, $color:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$crate::ratatui_widgets::canvas::Circle::new($x, $y, $radius, $color));
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*]
+        print!($x:expr, $y:expr, $text:expr $(,)?) $(, $($rest:tt)*)?
+    ) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.print($x, $y, $text);
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*] layer!() $(, $($rest:tt)*)?) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.layer();
+        ] $($($rest)*)?)
+    };
+    (@shapes $ctx:ident [$($settings:tt)*] [$($draw:tt)*] $shape:expr $(, $($rest:tt)*)?) => {
+        $crate::canvas!(@shapes $ctx [$($settings)*] [$($draw)*
+            $ctx.draw(&$shape);
+        ] $($($rest)*)?)
+    };
+
+    ($key:ident : $($rest:tt)*) => {
+        $crate::canvas!(@settings [] $key : $($rest)*)
+    };
+    ($($shapes:tt)*) => {
+        $crate::canvas!(@shapes ctx [] [] $($shapes)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer, layout::Rect, style::Color, symbols::Marker, widgets::Widget,
+    };
+    use ratatui_widgets::canvas::{Canvas, Circle, Line, Map, MapResolution, Points, Rectangle};
+
+    fn render(widget: impl Widget) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        widget.render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn canvas_shapes() {
+        let coords = [(2.0, 2.0), (8.0, 8.0)];
+        let canvas = canvas![
+            x: 0.0..10.0,
+            y: 0.0..10.0;
+            line!(0.0, 0.0, 10.0, 10.0, Color::Red),
+            points!(&coords, Color::Green),
+            rectangle!(1.0, 1.0, 5.0, 5.0, Color::Blue),
+            circle!(5.0, 5.0, 2.0, Color::Yellow),
+        ];
+        let expected = Canvas::default()
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|ctx| {
+                ctx.draw(&Line::new(0.0, 0.0, 10.0, 10.0, Color::Red));
+                ctx.draw(&Points::new(&coords, Color::Green));
+                ctx.draw(&Rectangle::new(1.0, 1.0, 5.0, 5.0, Color::Blue));
+                ctx.draw(&Circle::new(5.0, 5.0, 2.0, Color::Yellow));
+            });
+        assert_eq!(render(canvas), render(expected));
+    }
+
+    #[test]
+    fn canvas_map_layer_and_print() {
+        let canvas = canvas![
+            x: -180.0..180.0,
+            y: -90.0..90.0,
+            marker: Marker::Dot;
+            map!(Low, Color::White),
+            layer!(),
+            print!(0.0, 0.0, "hi"),
+        ];
+        let expected = Canvas::default()
+            .x_bounds([-180.0, 180.0])
+            .y_bounds([-90.0, 90.0])
+            .marker(Marker::Dot)
+            .paint(|ctx| {
+                ctx.draw(&Map {
+                    resolution: MapResolution::Low,
+                    color: Color::White,
+                });
+                ctx.layer();
+                ctx.print(0.0, 0.0, "hi");
+            });
+        assert_eq!(render(canvas), render(expected));
+    }
+
+    #[test]
+    fn canvas_shape_expressions() {
+        let canvas = canvas![
+            map!(MapResolution::High),
+            Circle::new(0.0, 0.0, 1.0, Color::Red)
+        ];
+        let expected = Canvas::default().paint(|ctx| {
+            ctx.draw(&Map {
+                resolution: MapResolution::High,
+                ..Default::default()
+            });
+            ctx.draw(&Circle::new(0.0, 0.0, 1.0, Color::Red));
+        });
+        assert_eq!(render(canvas), render(expected));
+    }
+}
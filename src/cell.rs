@@ -0,0 +1,89 @@
+/// A macro for creating a [`Cell`] using formatting syntax.
+///
+/// `cell!` is similar to the [`span!`] macro, but it returns a [`Cell`] for a [`Table`] instead of
+/// a [`Span`]. It accepts a format string and arguments, or an expression that will be converted to
+/// a string using the [`format!`] macro.
+///
+/// If a semicolon follows the first argument, then the first argument is a [`Style`] that is
+/// applied to the cell. Anything that can be converted into a [`Style`] can be used, such as a
+/// [`Color`] or a [`Modifier`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Modifier, Style, Stylize};
+/// use ratatui_macros::cell;
+///
+/// let bytes = 1024;
+///
+/// let cell = cell!("Size");
+/// let cell = cell!("{:>8}", bytes);
+/// let cell = cell!(bytes);
+///
+/// let cell = cell!(Style::new().bold(); "{:>8}", bytes);
+/// let cell = cell!(Color::Green; "{bytes}");
+/// let cell = cell!(Modifier::DIM; bytes);
+/// ```
+///
+/// [`Cell`]: ratatui_widgets::table::Cell
+/// [`Table`]: ratatui_widgets::table::Table
+/// [`Span`]: ratatui_core::text::Span
+/// [`Style`]: ratatui_core::style::Style
+/// [`Color`]: ratatui_core::style::Color
+/// [`Modifier`]: ratatui_core::style::Modifier
+#[macro_export]
+macro_rules! cell {
+    ($string:literal) => {
+        $crate::ratatui_widgets::table::Cell::new(format!($string))
+    };
+    ($string:literal, $($arg:tt)*) => {
+        $crate::ratatui_widgets::table::Cell::new(format!($string, $($arg)*))
+    };
+    ($expr:expr) => {
+        $crate::ratatui_widgets::table::Cell::new(format!("{}", $expr))
+    };
+    ($style:expr, $($arg:tt)*) => {
+        compile_error!("first parameter must be a formatting specifier followed by a comma OR a `Style` followed by a semicolon")
+    };
+    ($style:expr; $string:literal) => {
+        $crate::ratatui_widgets::table::Cell::new(format!($string)).style($style)
+    };
+    ($style:expr; $string:literal, $($arg:tt)*) => {
+        $crate::ratatui_widgets::table::Cell::new(format!($string, $($arg)*)).style($style)
+    };
+    ($style:expr; $expr:expr) => {
+        $crate::ratatui_widgets::table::Cell::new(format!("{}", $expr)).style($style)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier, Style, Stylize};
+    use ratatui_widgets::table::Cell;
+
+    #[test]
+    fn raw() {
+        let bytes = 1024;
+        assert_eq!(cell!("Size"), Cell::new("Size"));
+        assert_eq!(cell!("{:>6}", bytes), Cell::new("  1024"));
+        assert_eq!(cell!("{bytes} B"), Cell::new("1024 B"));
+        assert_eq!(cell!(bytes), Cell::new("1024"));
+    }
+
+    #[test]
+    fn styled() {
+        let bytes = 1024;
+        assert_eq!(
+            cell!(Style::new().bold(); "{:>6}", bytes),
+            Cell::new("  1024").style(Style::new().bold())
+        );
+        assert_eq!(
+            cell!(Color::Green; "Size"),
+            Cell::new("Size").style(Color::Green)
+        );
+        assert_eq!(
+            cell!(Modifier::DIM; bytes),
+            Cell::new("1024").style(Modifier::DIM)
+        );
+    }
+}
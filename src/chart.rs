@@ -0,0 +1,222 @@
+/// A macro for creating a [`Chart`] from a list of datasets and axes.
+///
+/// Each element is a [`Dataset`], usually created with the [`dataset!`] macro. The datasets can
+/// optionally be followed by a `;` and a list of `key: value` settings. `x` and `y` set the axes,
+/// and any other key is the name of a builder method on [`Chart`], e.g. `block: block!("Chart")`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// use ratatui_macros::{axis, chart, dataset};
+///
+/// let cpu = [(0.0, 10.0), (50.0, 40.0), (100.0, 20.0)];
+/// let mem = [(0.0, 30.0), (50.0, 35.0), (100.0, 60.0)];
+/// let chart = chart![
+///     dataset!("cpu", Line, Color::Cyan; &cpu),
+///     dataset!("mem", Scatter, Color::Yellow; &mem);
+///     x: axis!("time", bounds: [0.0, 100.0], labels: ["0", "50", "100"]),
+///     y: axis!("usage", bounds: [0.0, 100.0]),
+/// ];
+/// ```
+///
+/// [`Chart`]: ratatui_widgets::chart::Chart
+/// [`Dataset`]: ratatui_widgets::chart::Dataset
+#[macro_export]
+macro_rules! chart {
+    (@settings $chart:expr ; $(,)?) => {
+        $chart
+    };
+    (@settings $chart:expr ; x: $axis:expr $(, $($rest:tt)*)?) => {
+        $crate::chart!(@settings $chart.x_axis($axis) ; $($($rest)*)?)
+    };
+    (@settings $chart:expr ; y: $axis:expr $(, $($rest:tt)*)?) => {
+        $crate::chart!(@settings $chart.y_axis($axis) ; $($($rest)*)?)
+    };
+    (@settings $chart:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::chart!(@settings $chart.$key($value) ; $($($rest)*)?)
+    };
+    () => {
+        $crate::ratatui_widgets::chart::Chart::default()
+    };
+    ($($dataset:expr),+ $(,)? ; $($settings:tt)*) => {
+        $crate::chart!(@settings
+            $crate::ratatui_widgets::chart::Chart::new(vec![$($dataset),+]) ;
+            $($settings)*
+        )
+    };
+    ($($dataset:expr),+ $(,)?) => {
+        $crate::ratatui_widgets::chart::Chart::new(vec![$($dataset),+])
+    };
+}
+
+/// A macro for creating a [`Dataset`] for a [`Chart`].
+///
+/// The arguments are the name of the dataset, optionally followed by the name of a [`GraphType`]
+/// variant (`Scatter`, `Line` or `Bar`) and a style, then a `;` and the data. The data can be
+/// followed by `key: value` settings where each key is the name of a builder method on
+/// [`Dataset`], e.g. `marker: Marker::Braille`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Color, Style, Stylize}, symbols::Marker};
+/// use ratatui_macros::dataset;
+///
+/// let points = [(0.0, 0.0), (1.0, 1.0)];
+/// let dataset = dataset!("cpu"; &points);
+/// let dataset = dataset!("cpu", Scatter; &points);
+/// let dataset = dataset!("cpu", Scatter, Color::Cyan; &points);
+/// let dataset = dataset!("cpu", Line, Style::new().red(); &points, marker: Marker::Braille);
+/// ```
+///
+/// [`Chart`]: ratatui_widgets::chart::Chart
+/// [`Dataset`]: ratatui_widgets::chart::Dataset
+/// [`GraphType`]: ratatui_widgets::chart::GraphType
+#[macro_export]
+macro_rules! dataset {
+    ($name:expr, $graph_type:ident, $style:expr ; $data:expr $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::chart::Dataset::default()
+            .name($name)
+            .graph_type($crate::ratatui_widgets::chart::GraphType::$graph_type)
+            .style($style)
+            .data($data)
+            $(.$key($value))*
+    };
+    ($name:expr, $graph_type:ident ; $data:expr $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::chart::Dataset::default()
+            .name($name)
+            .graph_type($crate::ratatui_widgets::chart::GraphType::$graph_type)
+            .data($data)
+            $(.$key($value))*
+    };
+    ($name:expr ; $data:expr $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::chart::Dataset::default()
+            .name($name)
+            .data($data)
+            $(.$key($value))*
+    };
+}
+
+/// A macro for creating an [`Axis`] for a [`Chart`].
+///
+/// The first argument may be a title, which is anything that can be converted into a [`Line`].
+/// It is followed by `key: value` settings where each key is the name of a builder method on
+/// [`Axis`], e.g. `bounds: [0.0, 100.0]`, `labels: ["0", "50", "100"]` or
+/// `style: Style::new().gray()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::axis;
+///
+/// let axis = axis!("x", bounds: [0.0, 100.0], labels: ["0", "50", "100"]);
+/// let axis = axis!(bounds: [0.0, 1.0]);
+/// ```
+///
+/// [`Axis`]: ratatui_widgets::chart::Axis
+/// [`Chart`]: ratatui_widgets::chart::Chart
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! axis {
+    () => {
+        $crate::ratatui_widgets::chart::Axis::default()
+    };
+    ($($key:ident : $value:expr),+ $(,)?) => {
+        $crate::ratatui_widgets::chart::Axis::default()
+            $(.$key($value))+
+    };
+    ($title:expr $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::chart::Axis::default()
+            .title($title)
+            $(.$key($value))*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        symbols::Marker,
+    };
+    use ratatui_widgets::{
+        block::Block,
+        chart::{Axis, Chart, Dataset, GraphType},
+    };
+
+    const POINTS: [(f64, f64); 2] = [(0.0, 0.0), (1.0, 1.0)];
+
+    #[test]
+    fn dataset_forms() {
+        assert_eq!(
+            dataset!("cpu"; &POINTS),
+            Dataset::default().name("cpu").data(&POINTS)
+        );
+        assert_eq!(
+            dataset!("cpu", Scatter; &POINTS),
+            Dataset::default()
+                .name("cpu")
+                .graph_type(GraphType::Scatter)
+                .data(&POINTS)
+        );
+        assert_eq!(
+            dataset!("cpu", Line, Color::Cyan; &POINTS, marker: Marker::Braille),
+            Dataset::default()
+                .name("cpu")
+                .graph_type(GraphType::Line)
+                .style(Color::Cyan)
+                .data(&POINTS)
+                .marker(Marker::Braille)
+        );
+    }
+
+    #[test]
+    fn axis_forms() {
+        assert_eq!(axis!(), Axis::default());
+        assert_eq!(
+            axis!("x", bounds: [0.0, 100.0], labels: ["0", "50", "100"]),
+            Axis::default()
+                .title("x")
+                .bounds([0.0, 100.0])
+                .labels(["0", "50", "100"])
+        );
+        assert_eq!(
+            axis!(bounds: [0.0, 1.0], style: Style::new().gray()),
+            Axis::default()
+                .bounds([0.0, 1.0])
+                .style(Style::new().gray())
+        );
+    }
+
+    #[test]
+    fn chart_datasets_and_axes() {
+        assert_eq!(chart![], Chart::default());
+
+        let chart = chart![
+            dataset!("a"; &POINTS),
+            dataset!("b", Scatter; &POINTS);
+            x: axis!("x"),
+            y: axis!("y", bounds: [0.0, 1.0]),
+            block: Block::bordered(),
+        ];
+        assert_eq!(
+            chart,
+            Chart::new(vec![
+                Dataset::default().name("a").data(&POINTS),
+                Dataset::default()
+                    .name("b")
+                    .graph_type(GraphType::Scatter)
+                    .data(&POINTS),
+            ])
+            .x_axis(Axis::default().title("x"))
+            .y_axis(Axis::default().title("y").bounds([0.0, 1.0]))
+            .block(Block::bordered())
+        );
+
+        let chart = chart![dataset!("a"; &POINTS)];
+        assert_eq!(
+            chart,
+            Chart::new(vec![Dataset::default().name("a").data(&POINTS)])
+        );
+    }
+}
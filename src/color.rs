@@ -0,0 +1,109 @@
+/// A macro for creating a [`Color`] from a string literal that is parsed at compile time.
+///
+/// The string is one of:
+///
+/// - a hex color, e.g. `"#1e293b"` or the shorthand `"#fff"`
+/// - an rgb color, e.g. `"rgb(30, 41, 59)"`
+/// - the name of a color, e.g. `"red"`, `"LightBlue"` or `"dark-gray"`
+/// - an indexed color of the 256-color palette, e.g. `"@208"`, which may also be written without
+///   quotes, e.g. `color!(@208)`
+/// - a color of the [tailwind palette], e.g. `"slate.500"` or `"amber-200"`, which may also be
+///   written without quotes, e.g. `color!(slate.500)`
+///
+/// Hex and rgb colors expand to [`Color::Rgb`], indexed colors expand to [`Color::Indexed`], names
+/// expand to the matching variant of [`Color`] and tailwind colors expand to the matching constant
+/// of the palette, which is also a [`Color::Rgb`]. A malformed color, an index over 255 or an
+/// unknown palette or shade is a compile error instead of a runtime error, so unlike
+/// [`Color::from_str`] there is nothing to `unwrap`. The expansion is a constant expression and
+/// can be used to define constants.
+///
+/// This macro requires the `proc-macros` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// use ratatui_macros::color;
+///
+/// const SURFACE: Color = color!("#1e293b");
+///
+/// assert_eq!(SURFACE, Color::Rgb(30, 41, 59));
+/// assert_eq!(color!("rgb(30, 41, 59)"), Color::Rgb(30, 41, 59));
+/// assert_eq!(color!("light-blue"), Color::LightBlue);
+/// assert_eq!(color!(@208), Color::Indexed(208));
+/// assert_eq!(color!(slate.500), Color::Rgb(0x64, 0x74, 0x8b));
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::color;
+/// let color = color!("#1e293");
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::color;
+/// let color = color!(slate.550);
+/// ```
+///
+/// [`Color`]: ratatui_core::style::Color
+/// [`Color::Indexed`]: ratatui_core::style::Color::Indexed
+/// [`Color::Rgb`]: ratatui_core::style::Color::Rgb
+/// [`Color::from_str`]: std::str::FromStr::from_str
+/// [tailwind palette]: ratatui_core::style::palette::tailwind
+#[macro_export]
+macro_rules! color {
+    ($color:literal) => {
+        $crate::ratatui_macros_proc::color!($crate, $color)
+    };
+    (@ $index:literal) => {
+        $crate::ratatui_macros_proc::color!($crate, @ $index)
+    };
+    ($palette:ident . $shade:literal) => {
+        $crate::ratatui_macros_proc::color!($crate, $palette.$shade)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Color;
+
+    #[test]
+    fn color_hex() {
+        assert_eq!(color!("#1e293b"), Color::Rgb(0x1e, 0x29, 0x3b));
+        assert_eq!(color!("#fff"), Color::Rgb(0xff, 0xff, 0xff));
+    }
+
+    #[test]
+    fn color_rgb() {
+        assert_eq!(color!("rgb(30, 41, 59)"), Color::Rgb(30, 41, 59));
+    }
+
+    #[test]
+    fn color_named() {
+        assert_eq!(color!("red"), Color::Red);
+        assert_eq!(color!("DarkGray"), Color::DarkGray);
+        assert_eq!(color!("light-blue"), Color::LightBlue);
+    }
+
+    #[test]
+    fn color_indexed() {
+        assert_eq!(color!(@208), Color::Indexed(208));
+        assert_eq!(color!("@236"), Color::Indexed(236));
+    }
+
+    #[test]
+    fn color_tailwind() {
+        use ratatui_core::style::palette::tailwind;
+
+        assert_eq!(color!(slate.500), tailwind::SLATE.c500);
+        assert_eq!(color!(amber.200), Color::Rgb(0xfd, 0xe6, 0x8a));
+        assert_eq!(color!("rose-50"), tailwind::ROSE.c50);
+        const BORDER: Color = color!(zinc.700);
+        assert_eq!(BORDER, tailwind::ZINC.c700);
+    }
+
+    #[test]
+    fn color_const() {
+        const ACCENT: Color = color!("#7aa2f7");
+        assert_eq!(ACCENT, Color::Rgb(0x7a, 0xa2, 0xf7));
+    }
+}
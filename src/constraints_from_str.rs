@@ -0,0 +1,305 @@
+use std::{error::Error, fmt};
+
+use ratatui_core::layout::Constraint;
+
+/// Parses a [`Constraint`] from a string in the syntax of [`constraint!`], e.g. `"==50%"`.
+///
+/// The string is an operator followed by a number: `==n` is a length, `==n%` a percentage from 0
+/// to 100, `==a/b` a ratio whose denominator is not 0, `>=n` a minimum, `<=n` a maximum and `*=n`
+/// a fill. Whitespace between the parts is ignored, so `"== 50 %"` is the same as `"==50%"`.
+/// This is useful for layouts that are loaded at runtime, e.g. from a configuration file;
+/// [`constraint!`] creates constraints at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::layout::Constraint;
+/// use ratatui_macros::parse_constraint;
+///
+/// assert_eq!(parse_constraint("==50%")?, Constraint::Percentage(50));
+/// assert_eq!(parse_constraint(">= 3")?, Constraint::Min(3));
+/// assert_eq!(parse_constraint("==1/3")?, Constraint::Ratio(1, 3));
+///
+/// let error = parse_constraint("50%").unwrap_err();
+/// assert_eq!(error.to_string(), "expected a constraint like `==50%`, found `50%`");
+/// # Ok::<(), ratatui_macros::ParseConstraintError>(())
+/// ```
+///
+/// [`Constraint`]: ratatui_core::layout::Constraint
+/// [`constraint!`]: crate::constraint!
+pub fn parse_constraint(spec: &str) -> Result<Constraint, ParseConstraintError> {
+    let error = |message| ParseConstraintError::new(message, spec);
+    let compact = spec.split_whitespace().collect::<String>();
+    if compact.is_empty() {
+        return Err(error(
+            "expected a constraint like `==50%`, found an empty constraint",
+        ));
+    }
+    let Some(operator) = ["==", ">=", "<=", "*="]
+        .into_iter()
+        .find(|operator| compact.starts_with(operator))
+    else {
+        return Err(error("expected a constraint like `==50%`, found"));
+    };
+    let value = &compact[operator.len()..];
+    let number = |value: &str| {
+        value
+            .parse::<u16>()
+            .map_err(|_| error("expected a number from 0 to 65535 in"))
+    };
+    match operator {
+        "==" => {
+            if let Some(percent) = value.strip_suffix('%') {
+                match number(percent)? {
+                    percent @ 0..=100 => Ok(Constraint::Percentage(percent)),
+                    _ => Err(error("expected a percentage from 0 to 100 in")),
+                }
+            } else if let Some((numerator, denominator)) = value.split_once('/') {
+                let ratio = |value: &str| {
+                    value
+                        .parse::<u32>()
+                        .map_err(|_| error("expected a ratio like `==1/3` in"))
+                };
+                match (ratio(numerator)?, ratio(denominator)?) {
+                    (_, 0) => Err(error(
+                        "expected a ratio with a denominator that is not 0 in",
+                    )),
+                    (numerator, denominator) => Ok(Constraint::Ratio(numerator, denominator)),
+                }
+            } else {
+                Ok(Constraint::Length(number(value)?))
+            }
+        }
+        ">=" => Ok(Constraint::Min(number(value)?)),
+        "<=" => Ok(Constraint::Max(number(value)?)),
+        _ => Ok(Constraint::Fill(number(value)?)),
+    }
+}
+
+/// Parses a list of [`Constraint`]s from a string in the syntax of [`constraints!`], e.g.
+/// `"==50%, *=1"`.
+///
+/// The constraints are separated by `,` and written like in [`parse_constraint`], and a trailing
+/// `,` is allowed. A single constraint can be followed by `; count` to repeat it, e.g. `"==1; 3"`.
+/// Like in [`constraints!`], a list of percentages that add up to more than 100% is an error.
+/// This is useful for layouts that are loaded at runtime, e.g. from a configuration file;
+/// [`constraints!`] creates constraints at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::layout::Constraint;
+/// use ratatui_macros::parse_constraints;
+///
+/// assert_eq!(
+///     parse_constraints("==50%, *=1")?,
+///     [Constraint::Percentage(50), Constraint::Fill(1)]
+/// );
+/// assert_eq!(parse_constraints("==1; 3")?, [Constraint::Length(1); 3]);
+///
+/// let error = parse_constraints("==3, >=").unwrap_err();
+/// assert_eq!(error.to_string(), "expected a number from 0 to 65535 in `>=`");
+/// assert_eq!(error.constraint(), ">=");
+/// # Ok::<(), ratatui_macros::ParseConstraintError>(())
+/// ```
+///
+/// [`Constraint`]: ratatui_core::layout::Constraint
+/// [`constraints!`]: crate::constraints!
+/// [`parse_constraint`]: crate::parse_constraint
+pub fn parse_constraints(spec: &str) -> Result<Vec<Constraint>, ParseConstraintError> {
+    let (list, count) = match spec.split_once(';') {
+        Some((list, count)) => (list, Some(count.trim())),
+        None => (spec, None),
+    };
+    let list = list.trim_end();
+    let list = list.strip_suffix(',').unwrap_or(list);
+    let constraints = list
+        .split(',')
+        .map(parse_constraint)
+        .collect::<Result<Vec<_>, _>>()?;
+    let Some(count) = count else {
+        check_percentages(list, &constraints, 1)?;
+        return Ok(constraints);
+    };
+    if constraints.len() > 1 {
+        return Err(ParseConstraintError::new(
+            "repetition with `;` is only supported for a single constraint, e.g. `==1; 3`, in",
+            spec,
+        ));
+    }
+    let count = count.strip_suffix(',').unwrap_or(count).trim_end();
+    let count = count.parse::<usize>().map_err(|_| {
+        ParseConstraintError::new("expected the number of constraints after `;`, found", count)
+    })?;
+    check_percentages(list, &constraints, count)?;
+    Ok(vec![constraints[0]; count])
+}
+
+/// Checks that the percentages add up to at most 100%, when all the constraints are percentages,
+/// like the compile time check of [`constraints!`]. The error names the constraint that exceeds
+/// 100%.
+///
+/// [`constraints!`]: crate::constraints!
+fn check_percentages(
+    list: &str,
+    constraints: &[Constraint],
+    count: usize,
+) -> Result<(), ParseConstraintError> {
+    if !constraints
+        .iter()
+        .all(|constraint| matches!(constraint, Constraint::Percentage(_)))
+    {
+        return Ok(());
+    }
+    let mut total = 0_usize;
+    for (constraint, spec) in constraints.iter().zip(list.split(',')) {
+        if let Constraint::Percentage(percent) = constraint {
+            total = total.saturating_add(usize::from(*percent).saturating_mul(count));
+        }
+        if total > 100 {
+            return Err(ParseConstraintError::new(
+                "the percentages add up to more than 100% at",
+                spec,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The error returned by [`parse_constraint`] and [`parse_constraints`] for an invalid
+/// constraint.
+///
+/// [`parse_constraint`]: crate::parse_constraint
+/// [`parse_constraints`]: crate::parse_constraints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConstraintError {
+    message: &'static str,
+    constraint: String,
+}
+
+impl ParseConstraintError {
+    fn new(message: &'static str, constraint: &str) -> Self {
+        Self {
+            message,
+            constraint: constraint.trim().to_owned(),
+        }
+    }
+
+    /// Returns the constraint that is invalid.
+    pub fn constraint(&self) -> &str {
+        &self.constraint
+    }
+}
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.constraint.is_empty() {
+            f.write_str(self.message)
+        } else {
+            write!(f, "{} `{}`", self.message, self.constraint)
+        }
+    }
+}
+
+impl Error for ParseConstraintError {}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::layout::Constraint;
+
+    use super::{parse_constraint, parse_constraints};
+
+    #[test]
+    fn parse_constraint_syntax() {
+        assert_eq!(parse_constraint("==5"), Ok(Constraint::Length(5)));
+        assert_eq!(
+            parse_constraint(" == 30 % "),
+            Ok(Constraint::Percentage(30))
+        );
+        assert_eq!(parse_constraint("==1/2"), Ok(Constraint::Ratio(1, 2)));
+        assert_eq!(parse_constraint(">=3"), Ok(Constraint::Min(3)));
+        assert_eq!(parse_constraint("<=1"), Ok(Constraint::Max(1)));
+        assert_eq!(parse_constraint("*=2"), Ok(Constraint::Fill(2)));
+    }
+
+    #[test]
+    fn parse_constraint_errors() {
+        let error = |spec| parse_constraint(spec).unwrap_err().to_string();
+        assert_eq!(
+            error(" "),
+            "expected a constraint like `==50%`, found an empty constraint"
+        );
+        assert_eq!(
+            error("=5"),
+            "expected a constraint like `==50%`, found `=5`"
+        );
+        assert_eq!(error("==-1"), "expected a number from 0 to 65535 in `==-1`");
+        assert_eq!(
+            error("==70000"),
+            "expected a number from 0 to 65535 in `==70000`"
+        );
+        assert_eq!(error("==1/x"), "expected a ratio like `==1/3` in `==1/x`");
+        assert_eq!(
+            error("==1/0"),
+            "expected a ratio with a denominator that is not 0 in `==1/0`"
+        );
+        assert_eq!(
+            error("==101%"),
+            "expected a percentage from 0 to 100 in `==101%`"
+        );
+    }
+
+    #[test]
+    fn parse_constraints_lists() {
+        assert_eq!(
+            parse_constraints("==50, ==30%, >=3, <=1, ==1/2, *=1,"),
+            Ok(vec![
+                Constraint::Length(50),
+                Constraint::Percentage(30),
+                Constraint::Min(3),
+                Constraint::Max(1),
+                Constraint::Ratio(1, 2),
+                Constraint::Fill(1),
+            ])
+        );
+        assert_eq!(
+            parse_constraints("*=1 ; 2"),
+            Ok(vec![Constraint::Fill(1); 2])
+        );
+        assert_eq!(parse_constraints("==1; 0"), Ok(vec![]));
+    }
+
+    #[test]
+    fn parse_constraints_errors() {
+        let error = |spec| parse_constraints(spec).unwrap_err().to_string();
+        assert_eq!(
+            error(""),
+            "expected a constraint like `==50%`, found an empty constraint"
+        );
+        assert_eq!(
+            error("==1,,==2"),
+            "expected a constraint like `==50%`, found an empty constraint"
+        );
+        assert_eq!(
+            error("==1, ==2; 3"),
+            "repetition with `;` is only supported for a single constraint, e.g. `==1; 3`, in \
+             `==1, ==2; 3`"
+        );
+        assert_eq!(
+            error("==60%, ==30%, ==20%"),
+            "the percentages add up to more than 100% at `==20%`"
+        );
+        assert_eq!(
+            error("==30%; 4"),
+            "the percentages add up to more than 100% at `==30%`"
+        );
+        assert_eq!(
+            parse_constraints("==60%, ==60%, >=1").map(|constraints| constraints.len()),
+            Ok(3)
+        );
+        assert_eq!(
+            error("==1; many"),
+            "expected the number of constraints after `;`, found `many`"
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use crossterm::style::{Attribute, Attributes, ContentStyle, StyledContent};
+use ratatui_core::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// A macro for converting crossterm styles and styled content into their ratatui equivalents.
+///
+/// The argument is one of the following crossterm types, which is converted with [`IntoRatatui`]:
+///
+/// - a [`crossterm::style::Color`] becomes a [`Color`]
+/// - an [`Attribute`] or [`Attributes`] becomes a [`Modifier`]
+/// - a [`ContentStyle`] becomes a [`Style`]
+/// - a [`StyledContent`], e.g. `"hello".red().bold()`, becomes a [`Span`]
+///
+/// The 16 named colors are mapped like the crossterm backend of ratatui does, e.g. `DarkRed` to
+/// `Red` and `Red` to `LightRed`. All kinds of underlines become [`Modifier::UNDERLINED`], and
+/// attributes that have no [`Modifier`], e.g. `Framed`, and the underline color are left out.
+///
+/// This macro requires the `crossterm` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Color, Modifier, Style}, text::Span};
+/// use crossterm::style::{self, Attribute, Stylize};
+/// use ratatui_macros::from_crossterm;
+///
+/// assert_eq!(from_crossterm!(style::Color::DarkGreen), Color::Green);
+/// assert_eq!(from_crossterm!(Attribute::Bold), Modifier::BOLD);
+///
+/// let span = from_crossterm!("error".red().bold());
+/// let style = Style::new().fg(Color::LightRed).add_modifier(Modifier::BOLD);
+/// assert_eq!(span, Span::styled("error", style));
+/// ```
+///
+/// [`Attribute`]: crossterm::style::Attribute
+/// [`Attributes`]: crossterm::style::Attributes
+/// [`Color`]: ratatui_core::style::Color
+/// [`ContentStyle`]: crossterm::style::ContentStyle
+/// [`crossterm::style::Color`]: crossterm::style::Color
+/// [`IntoRatatui`]: crate::IntoRatatui
+/// [`Modifier`]: ratatui_core::style::Modifier
+/// [`Modifier::UNDERLINED`]: ratatui_core::style::Modifier::UNDERLINED
+/// [`Span`]: ratatui_core::text::Span
+/// [`Style`]: ratatui_core::style::Style
+/// [`StyledContent`]: crossterm::style::StyledContent
+#[macro_export]
+macro_rules! from_crossterm {
+    ($value:expr $(,)?) => {
+        $crate::IntoRatatui::into_ratatui($value)
+    };
+}
+
+/// A conversion from a crossterm style type into its ratatui equivalent. See [`from_crossterm!`].
+///
+/// This trait requires the `crossterm` feature.
+///
+/// [`from_crossterm!`]: crate::from_crossterm!
+pub trait IntoRatatui {
+    /// The ratatui type that this type is converted into.
+    type Output;
+
+    /// Converts the crossterm value into its ratatui equivalent.
+    fn into_ratatui(self) -> Self::Output;
+}
+
+impl IntoRatatui for crossterm::style::Color {
+    type Output = Color;
+
+    fn into_ratatui(self) -> Color {
+        use crossterm::style::Color as C;
+        match self {
+            C::Reset => Color::Reset,
+            C::Black => Color::Black,
+            C::DarkRed => Color::Red,
+            C::DarkGreen => Color::Green,
+            C::DarkYellow => Color::Yellow,
+            C::DarkBlue => Color::Blue,
+            C::DarkMagenta => Color::Magenta,
+            C::DarkCyan => Color::Cyan,
+            C::Grey => Color::Gray,
+            C::DarkGrey => Color::DarkGray,
+            C::Red => Color::LightRed,
+            C::Green => Color::LightGreen,
+            C::Yellow => Color::LightYellow,
+            C::Blue => Color::LightBlue,
+            C::Magenta => Color::LightMagenta,
+            C::Cyan => Color::LightCyan,
+            C::White => Color::White,
+            C::Rgb { r, g, b } => Color::Rgb(r, g, b),
+            C::AnsiValue(index) => Color::Indexed(index),
+        }
+    }
+}
+
+impl IntoRatatui for Attribute {
+    type Output = Modifier;
+
+    fn into_ratatui(self) -> Modifier {
+        match self {
+            Attribute::Bold => Modifier::BOLD,
+            Attribute::Dim => Modifier::DIM,
+            Attribute::Italic => Modifier::ITALIC,
+            Attribute::Underlined
+            | Attribute::DoubleUnderlined
+            | Attribute::Undercurled
+            | Attribute::Underdotted
+            | Attribute::Underdashed => Modifier::UNDERLINED,
+            Attribute::SlowBlink => Modifier::SLOW_BLINK,
+            Attribute::RapidBlink => Modifier::RAPID_BLINK,
+            Attribute::Reverse => Modifier::REVERSED,
+            Attribute::Hidden => Modifier::HIDDEN,
+            Attribute::CrossedOut => Modifier::CROSSED_OUT,
+            _ => Modifier::empty(),
+        }
+    }
+}
+
+impl IntoRatatui for Attributes {
+    type Output = Modifier;
+
+    fn into_ratatui(self) -> Modifier {
+        Attribute::iterator()
+            .filter(|&attribute| self.has(attribute))
+            .map(Attribute::into_ratatui)
+            .collect()
+    }
+}
+
+impl IntoRatatui for ContentStyle {
+    type Output = Style;
+
+    fn into_ratatui(self) -> Style {
+        Style {
+            fg: self.foreground_color.map(IntoRatatui::into_ratatui),
+            bg: self.background_color.map(IntoRatatui::into_ratatui),
+            ..Style::new().add_modifier(self.attributes.into_ratatui())
+        }
+    }
+}
+
+impl<D: Display> IntoRatatui for StyledContent<D> {
+    type Output = Span<'static>;
+
+    fn into_ratatui(self) -> Span<'static> {
+        (&self).into_ratatui()
+    }
+}
+
+impl<D: Display> IntoRatatui for &StyledContent<D> {
+    type Output = Span<'static>;
+
+    fn into_ratatui(self) -> Span<'static> {
+        Span::styled(self.content().to_string(), self.style().into_ratatui())
+    }
+}
+
+impl IntoRatatui for &ContentStyle {
+    type Output = Style;
+
+    fn into_ratatui(self) -> Style {
+        (*self).into_ratatui()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::style::{self, Attribute, Attributes, ContentStyle, Stylize};
+    use ratatui_core::{
+        style::{Color, Modifier, Style},
+        text::Span,
+    };
+
+    #[test]
+    fn from_crossterm_color() {
+        assert_eq!(from_crossterm!(style::Color::Reset), Color::Reset);
+        assert_eq!(from_crossterm!(style::Color::DarkGrey), Color::DarkGray);
+        assert_eq!(from_crossterm!(style::Color::Grey), Color::Gray);
+        assert_eq!(from_crossterm!(style::Color::Blue), Color::LightBlue);
+        assert_eq!(from_crossterm!(style::Color::DarkBlue), Color::Blue);
+        assert_eq!(
+            from_crossterm!(style::Color::Rgb { r: 1, g: 2, b: 3 }),
+            Color::Rgb(1, 2, 3)
+        );
+        assert_eq!(
+            from_crossterm!(style::Color::AnsiValue(208)),
+            Color::Indexed(208)
+        );
+    }
+
+    #[test]
+    fn from_crossterm_attributes() {
+        assert_eq!(
+            from_crossterm!(Attribute::Undercurled),
+            Modifier::UNDERLINED
+        );
+        assert_eq!(from_crossterm!(Attribute::Framed), Modifier::empty());
+        let attributes =
+            Attributes::from(&[Attribute::Bold, Attribute::Reverse, Attribute::Framed][..]);
+        assert_eq!(
+            from_crossterm!(attributes),
+            Modifier::BOLD | Modifier::REVERSED
+        );
+    }
+
+    #[test]
+    fn from_crossterm_style() {
+        let style = ContentStyle::new().on_dark_blue().italic();
+        assert_eq!(
+            from_crossterm!(&style),
+            Style::new().bg(Color::Blue).add_modifier(Modifier::ITALIC)
+        );
+        assert_eq!(from_crossterm!(ContentStyle::new()), Style::new());
+    }
+
+    #[test]
+    fn from_crossterm_styled_content() {
+        let content = "42".green().underlined();
+        assert_eq!(
+            from_crossterm!(&content),
+            Span::styled(
+                "42",
+                Style::new()
+                    .fg(Color::LightGreen)
+                    .add_modifier(Modifier::UNDERLINED)
+            )
+        );
+        assert_eq!(from_crossterm!("plain".stylize()), Span::raw("plain"));
+    }
+}
@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+
+use ratatui_core::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+/// A macro for creating a [`Text`] that shows a unified diff with styled lines.
+///
+/// The argument is the diff, which is anything that can be converted into a `Cow<str>`, e.g. the
+/// output of `git diff`. Each line of the diff becomes a [`Line`] whose style depends on how the
+/// line starts:
+///
+/// - added lines (`+`) are green and removed lines (`-`) are red
+/// - hunk headers (`@@ -1,2 +1,3 @@`) are cyan
+/// - file headers (`diff`, `index`, `---` and `+++`) are bold
+/// - `\ No newline at end of file` is dimmed
+///
+/// The styles are set on the lines, so a background color fills the whole width of a line in a
+/// paragraph. The diff can be preceded by `key: value` directives, separated from it by a `;`,
+/// that replace the styles: `added`, `removed`, `hunk`, `header` and `context`. The text borrows
+/// the diff when it is borrowed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Color, Style, Stylize}, text::Line};
+/// use ratatui_macros::diff;
+///
+/// let patch = "\
+/// --- a/greeting.txt
+/// +++ b/greeting.txt
+/// @@ -1 +1 @@
+/// -hello
+/// +hello world
+/// ";
+/// let text = diff!(patch);
+/// assert_eq!(text.lines[2], Line::from("@@ -1 +1 @@").cyan());
+/// assert_eq!(text.lines[3], Line::from("-hello").red());
+/// assert_eq!(text.lines[4], Line::from("+hello world").green());
+///
+/// let text = diff!(added: Style::new().black().on_green(), removed: Color::LightRed; patch);
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`Text`]: ratatui_core::text::Text
+#[macro_export]
+macro_rules! diff {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::diff!(@directives [$($directives)* .$setting($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::diff!(@directives [$($directives)* .$setting($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $diff:expr $(,)?) => {
+        $crate::__Diff::default() $($directives)* .text($diff)
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::diff!(@directives [] $setting : $($rest)*)
+    };
+    ($diff:expr $(,)?) => {
+        $crate::__Diff::default().text($diff)
+    };
+}
+
+/// The styles of the lines of a diff. See [`diff!`].
+///
+/// [`diff!`]: crate::diff!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Diff {
+    added: Style,
+    removed: Style,
+    hunk: Style,
+    header: Style,
+    context: Style,
+}
+
+impl Default for __Diff {
+    fn default() -> Self {
+        Self {
+            added: Style::new().fg(Color::Green),
+            removed: Style::new().fg(Color::Red),
+            hunk: Style::new().fg(Color::Cyan),
+            header: Style::new().add_modifier(Modifier::BOLD),
+            context: Style::new(),
+        }
+    }
+}
+
+impl __Diff {
+    pub fn added(mut self, style: impl Into<Style>) -> Self {
+        self.added = style.into();
+        self
+    }
+
+    pub fn removed(mut self, style: impl Into<Style>) -> Self {
+        self.removed = style.into();
+        self
+    }
+
+    pub fn hunk(mut self, style: impl Into<Style>) -> Self {
+        self.hunk = style.into();
+        self
+    }
+
+    pub fn header(mut self, style: impl Into<Style>) -> Self {
+        self.header = style.into();
+        self
+    }
+
+    pub fn context(mut self, style: impl Into<Style>) -> Self {
+        self.context = style.into();
+        self
+    }
+
+    pub fn text<'a>(self, diff: impl Into<Cow<'a, str>>) -> Text<'a> {
+        let lines: Vec<_> = match diff.into() {
+            Cow::Borrowed(diff) => diff
+                .lines()
+                .map(|line| self.line(Cow::Borrowed(line)))
+                .collect(),
+            Cow::Owned(diff) => diff
+                .lines()
+                .map(|line| self.line(Cow::Owned(line.to_owned())))
+                .collect(),
+        };
+        Text::from(lines)
+    }
+
+    fn line<'a>(&self, line: Cow<'a, str>) -> Line<'a> {
+        let style = if ["diff ", "index ", "--- ", "+++ "]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+        {
+            self.header
+        } else if line.starts_with("@@") {
+            self.hunk
+        } else if line.starts_with('+') {
+            self.added
+        } else if line.starts_with('-') {
+            self.removed
+        } else if line.starts_with('\\') {
+            Style::new().add_modifier(Modifier::DIM)
+        } else {
+            self.context
+        };
+        Line::from(Span::raw(line)).style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Text},
+    };
+
+    const PATCH: &str = "diff --git a/a.txt b/a.txt
+index 1234567..89abcde 100644
+--- a/a.txt
++++ b/a.txt
+@@ -1,3 +1,3 @@ fn main
+ one
+-two
++2
+ ---
+\\ No newline at end of file";
+
+    #[test]
+    fn diff_styles() {
+        assert_eq!(
+            diff!(PATCH),
+            Text::from(vec![
+                Line::from("diff --git a/a.txt b/a.txt").bold(),
+                Line::from("index 1234567..89abcde 100644").bold(),
+                Line::from("--- a/a.txt").bold(),
+                Line::from("+++ b/a.txt").bold(),
+                Line::from("@@ -1,3 +1,3 @@ fn main").cyan(),
+                Line::from(" one"),
+                Line::from("-two").red(),
+                Line::from("+2").green(),
+                Line::from(" ---"),
+                Line::from("\\ No newline at end of file").dim(),
+            ])
+        );
+    }
+
+    #[test]
+    fn diff_directives() {
+        let text = diff!(
+            added: Style::new().on_green(),
+            removed: Color::LightRed,
+            hunk: Color::Blue,
+            header: Style::new(),
+            context: Color::Gray;
+            String::from("+++ b\n@@ -1 +1 @@\n-a\n+b\n c\r\n"),
+        );
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("+++ b"),
+                Line::from("@@ -1 +1 @@").blue(),
+                Line::from("-a").light_red(),
+                Line::from("+b").on_green(),
+                Line::from(" c").gray(),
+            ])
+        );
+    }
+
+    #[test]
+    fn diff_borrows_input() {
+        let text = diff!("-a\n+b");
+        assert!(text
+            .iter()
+            .all(|line| matches!(line.spans[0].content, Cow::Borrowed(_))));
+        assert_eq!(diff!(""), Text::default());
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use ratatui_core::{
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::{__fill, statusbar::truncate_line};
+
+/// A macro for creating a horizontal rule [`Line`] of an exact width, optionally with a centered
+/// label.
+///
+/// The first argument is the width of the line in cells. It can be followed by a semicolon, an
+/// optional symbol that the rule is made of, which is `─` by default, and `key: value` settings:
+///
+/// - `style: style` sets the style of the rule
+/// - `label: line` embeds anything that can be converted into a [`Line`] in the middle of the rule,
+///   keeping its own styles
+///
+/// A label that is wider than the line is truncated. When the width of the rule around the label is
+/// odd, the extra cell goes to the right of the label, and a symbol that is wider than one
+/// cell is padded with spaces so that the line is always exactly as wide as the width. A symbol
+/// that has no width, e.g. `""`, is replaced by the default `─`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::divider;
+///
+/// assert_eq!(divider!(8).to_string(), "────────");
+/// assert_eq!(divider!(8; "=").to_string(), "========");
+///
+/// let line = divider!(20; "─", style: Style::new().dim(), label: " Settings ");
+/// assert_eq!(line.to_string(), "───── Settings ─────");
+///
+/// let line = divider!(12; label: " ok ".green().bold());
+/// assert_eq!(line.to_string(), "──── ok ────");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! divider {
+    (@settings $width:expr, $divider:expr ; $(,)?) => {
+        $divider.line($width)
+    };
+    (@settings $width:expr, $divider:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::divider!(@settings $width, $divider.$key($value) ; $($($rest)*)?)
+    };
+    ($width:expr ; $key:ident : $($rest:tt)*) => {
+        $crate::divider!(@settings $width, $crate::__Divider::default() ; $key : $($rest)*)
+    };
+    ($width:expr ; $symbol:expr $(, $($rest:tt)*)?) => {
+        $crate::divider!(@settings
+            $width, $crate::__Divider::default().symbol($symbol) ;
+            $($($rest)*)?
+        )
+    };
+    ($width:expr $(;)? $(,)?) => {
+        $crate::__Divider::default().line($width)
+    };
+}
+
+/// The symbol, style and label of a divider. See [`divider!`].
+///
+/// [`divider!`]: crate::divider!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Divider<'a> {
+    symbol: String,
+    style: Style,
+    label: Line<'a>,
+}
+
+impl Default for __Divider<'_> {
+    fn default() -> Self {
+        Self {
+            symbol: String::from("─"),
+            style: Style::new(),
+            label: Line::default(),
+        }
+    }
+}
+
+impl<'a> __Divider<'a> {
+    pub fn symbol(mut self, symbol: impl Display) -> Self {
+        let symbol = symbol.to_string();
+        // `__fill` cannot repeat a symbol without width, so the default symbol is kept
+        if Span::raw(symbol.as_str()).width() > 0 {
+            self.symbol = symbol;
+        }
+        self
+    }
+
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Line<'a>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Lays out the rule and the label in a line of exactly `width` cells.
+    pub fn line(self, width: u16) -> Line<'a> {
+        let Self {
+            symbol,
+            style,
+            label,
+        } = self;
+        let width = usize::from(width);
+        let label = truncate_line(label, width);
+        let rule = width - label.width();
+        // without a label, the whole rule is a single span
+        let left = if label.spans.is_empty() { 0 } else { rule / 2 };
+        let mut spans = Vec::with_capacity(label.spans.len() + 2);
+        push_rule(&mut spans, &symbol, style, left);
+        spans.extend(label.spans);
+        push_rule(&mut spans, &symbol, style, rule - left);
+        Line::from(spans)
+    }
+}
+
+fn push_rule(spans: &mut Vec<Span<'_>>, symbol: &str, style: Style, width: usize) {
+    if width > 0 {
+        spans.push(__fill(symbol, width).style(style));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::{Line, Span},
+    };
+
+    use crate::line;
+
+    #[test]
+    fn divider_rule() {
+        assert_eq!(divider!(3), Line::from(Span::raw("───")));
+        assert_eq!(divider!(2;), Line::from(Span::raw("──")));
+        assert_eq!(divider!(3; '='), Line::from(Span::raw("===")));
+        assert_eq!(divider!(0), Line::default());
+        assert_eq!(divider!(5; ""), Line::from(Span::raw("─────")));
+        assert_eq!(divider!(2; "\u{200b}"), Line::from(Span::raw("──")));
+        assert_eq!(
+            divider!(2; style: Style::new().dim()),
+            Line::from("──".dim())
+        );
+    }
+
+    #[test]
+    fn divider_label() {
+        assert_eq!(
+            divider!(9; "-", style: Style::new().dim(), label: " ab "),
+            Line::from(vec!["--".dim(), " ab ".into(), "---".dim()])
+        );
+        assert_eq!(
+            divider!(6; label: line!["a".red(), "b"].bold(),),
+            Line::from(vec!["──".into(), "a".red().bold(), "b".bold(), "──".into()])
+        );
+    }
+
+    #[test]
+    fn divider_wide_label() {
+        assert_eq!(divider!(3; label: "abcd").to_string(), "abc");
+        assert_eq!(divider!(4; label: "你好").to_string(), "你好");
+        assert_eq!(divider!(3; label: "你好").to_string(), "你─");
+    }
+
+    #[test]
+    fn divider_wide_symbol() {
+        assert_eq!(divider!(5; '界').to_string(), "界界 ");
+        assert_eq!(divider!(7; '界', label: "a").to_string(), "界 a界 ");
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use ratatui_core::text::{Span, Text};
+use ratatui_widgets::table::Cell;
+
+use crate::__TextElement;
+
+/// Converts an element of [`line!`] into a [`Span`].
+///
+/// Anything that can be converted into a [`Span`] is converted with [`Into`], and any other value
+/// that implements [`Display`] is converted with [`ToString`], e.g. numbers.
+///
+/// [`line!`]: crate::line!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __span_element {
+    ($element:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__DisplayElement as _, __IntoSpanElement as _};
+        $crate::__Element($element).__span()
+    }};
+}
+
+/// Adds an element of [`text!`] to a [`Text`].
+///
+/// Any [`__TextElement`] is added as it is, and any other value that implements [`Display`] is
+/// converted with [`ToString`], e.g. numbers.
+///
+/// [`text!`]: crate::text!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __text_element {
+    ($element:expr, $text:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__DisplayElement as _, __IntoTextElement as _};
+        $crate::__Element($element).__push_to($text)
+    }};
+}
+
+/// Converts an element of [`row!`] into a [`Cell`].
+///
+/// Anything that can be converted into a [`Cell`] is converted with [`Into`], and any other value
+/// that implements [`Display`] is converted with [`ToString`], e.g. numbers.
+///
+/// [`row!`]: crate::row!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cell_element {
+    ($element:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__DisplayElement as _, __IntoCellElement as _};
+        $crate::__Element($element).__cell()
+    }};
+}
+
+/// Returns the display width of a reference to an element of [`row!`], which is the width of the
+/// widest line of its [`Display`] output, or zero for values that do not implement [`Display`].
+///
+/// [`row!`]: crate::row!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cell_width {
+    ($element:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__DisplayWidth as _, __NoWidth as _};
+        $crate::__Element($element).__width()
+    }};
+}
+
+/// An element of [`line!`], [`text!`] or [`row!`].
+///
+/// The conversions with [`Into`] are implemented for the element and the conversions with
+/// [`Display`] for a reference to it, so method resolution prefers the conversion and only
+/// falls back to [`Display`] for values that cannot be converted.
+///
+/// [`line!`]: crate::line!
+/// [`text!`]: crate::text!
+/// [`row!`]: crate::row!
+#[doc(hidden)]
+pub struct __Element<T>(pub T);
+
+/// Converts an [`__Element`] into a [`Span`] with [`Into`].
+#[doc(hidden)]
+pub trait __IntoSpanElement<'a> {
+    fn __span(self) -> Span<'a>;
+}
+
+impl<'a, T: Into<Span<'a>>> __IntoSpanElement<'a> for __Element<T> {
+    fn __span(self) -> Span<'a> {
+        self.0.into()
+    }
+}
+
+/// Adds an [`__Element`] to a [`Text`] with [`__TextElement`].
+#[doc(hidden)]
+pub trait __IntoTextElement<'a> {
+    fn __push_to(self, text: &mut Text<'a>);
+}
+
+impl<'a, T: __TextElement<'a>> __IntoTextElement<'a> for __Element<T> {
+    fn __push_to(self, text: &mut Text<'a>) {
+        self.0.push_to(text);
+    }
+}
+
+/// Converts an [`__Element`] into a [`Cell`] with [`Into`].
+#[doc(hidden)]
+pub trait __IntoCellElement<'a> {
+    fn __cell(self) -> Cell<'a>;
+}
+
+impl<'a, T: Into<Cell<'a>>> __IntoCellElement<'a> for __Element<T> {
+    fn __cell(self) -> Cell<'a> {
+        self.0.into()
+    }
+}
+
+/// Converts a reference to an [`__Element`] with [`Display`], for the values that the traits
+/// above do not accept.
+#[doc(hidden)]
+pub trait __DisplayElement {
+    fn __span(self) -> Span<'static>;
+    fn __push_to(self, text: &mut Text<'_>);
+    fn __cell(self) -> Cell<'static>;
+}
+
+impl<T: Display> __DisplayElement for &__Element<T> {
+    fn __span(self) -> Span<'static> {
+        Span::raw(self.0.to_string())
+    }
+
+    fn __push_to(self, text: &mut Text<'_>) {
+        self.0.to_string().push_to(text);
+    }
+
+    fn __cell(self) -> Cell<'static> {
+        Cell::from(self.0.to_string())
+    }
+}
+
+/// Measures a reference to an [`__Element`] with [`Display`].
+#[doc(hidden)]
+pub trait __DisplayWidth {
+    fn __width(self) -> usize;
+}
+
+impl<T: Display + ?Sized> __DisplayWidth for __Element<&T> {
+    fn __width(self) -> usize {
+        Text::raw(self.0.to_string()).width()
+    }
+}
+
+/// Measures the elements that do not implement [`Display`] as zero columns wide.
+#[doc(hidden)]
+pub trait __NoWidth {
+    fn __width(self) -> usize;
+}
+
+impl<T> __NoWidth for &__Element<T> {
+    fn __width(self) -> usize {
+        0
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use ratatui_core::text::Span;
+
+/// A macro for creating a [`Span`] that repeats a symbol to fill a width.
+///
+/// The arguments are the symbol, which is a `char` or anything else that implements [`Display`],
+/// e.g. a `&str` holding a single grapheme, and the width in cells as a `usize`. The symbol is
+/// repeated as often as it fits, so a wide symbol like `'界'` is repeated half as often, and when
+/// the width is not a multiple of the width of the symbol, the rest is filled with spaces so that
+/// the span is exactly as wide as the width. Like [`span!`], a [`Style`] followed by a semicolon
+/// may come first to create a styled span.
+///
+/// This is the building block for separators and for the padding before right-aligned content.
+///
+/// # Panics
+///
+/// Panics if the symbol has no width, e.g. when it is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::fill;
+///
+/// assert_eq!(fill!('─', 5).content, "─────");
+///
+/// let used = 12;
+/// let width = 20;
+/// let padding = fill!(' ', width - used);
+/// assert_eq!(padding.width(), 8);
+///
+/// let separator = fill!(Style::new().dim(); "·", 3);
+/// assert_eq!(separator, "···".dim());
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+/// [`Span`]: ratatui_core::text::Span
+/// [`Style`]: ratatui_core::style::Style
+/// [`span!`]: crate::span!
+#[macro_export]
+macro_rules! fill {
+    ($style:expr; $symbol:expr, $width:expr $(,)?) => {
+        $crate::__fill($symbol, $width).style($style)
+    };
+    ($symbol:expr, $width:expr $(,)?) => {
+        $crate::__fill($symbol, $width)
+    };
+}
+
+/// Repeats the symbol to fill the width, padding the rest with spaces. See [`fill!`].
+///
+/// [`fill!`]: crate::fill!
+#[doc(hidden)]
+#[track_caller]
+pub fn __fill(symbol: impl Display, width: usize) -> Span<'static> {
+    let symbol = symbol.to_string();
+    let symbol_width = Span::raw(symbol.as_str()).width();
+    assert!(symbol_width > 0, "the fill symbol {symbol:?} has no width");
+    let count = width / symbol_width;
+    let rest = width % symbol_width;
+    Span::raw(symbol.repeat(count) + &" ".repeat(rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::Span,
+    };
+
+    #[test]
+    fn fill_symbol() {
+        assert_eq!(fill!('─', 3), Span::raw("───"));
+        assert_eq!(fill!("=", 2,), Span::raw("=="));
+        assert_eq!(fill!(' ', 0), Span::raw(""));
+        let (width, used) = (10usize, 7);
+        assert_eq!(fill!('.', width - used), Span::raw("..."));
+    }
+
+    #[test]
+    fn fill_styled() {
+        assert_eq!(fill!(Style::new().dim(); '─', 2), "──".dim());
+    }
+
+    #[test]
+    fn fill_wide_symbol() {
+        assert_eq!(fill!('界', 4), Span::raw("界界"));
+        assert_eq!(fill!('界', 5), Span::raw("界界 "));
+        assert_eq!(fill!("👨\u{200d}👩\u{200d}👧", 3).width(), 3);
+    }
+
+    #[test]
+    #[should_panic = "the fill symbol \"\" has no width"]
+    fn fill_empty_symbol() {
+        fill!("", 3);
+    }
+}
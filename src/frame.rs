@@ -0,0 +1,101 @@
+/// A macro for rendering several widgets into a [`Frame`] in one call.
+///
+/// The first argument is the frame, which is a `&mut Frame`, e.g. the argument of the closure of
+/// [`Terminal::draw`], followed by a semicolon and a list of entries separated by commas. Each
+/// entry is either `widget => area`, which calls [`Frame::render_widget`], or
+/// `widget @ state => area`, which calls [`Frame::render_stateful_widget`] with the state, e.g.
+/// `&mut table_state`. The widgets are rendered in order, so later widgets are drawn on top of
+/// earlier ones.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{backend::TestBackend, terminal::Terminal};
+/// # use ratatui_widgets::{block::Block, list::{List, ListState}, paragraph::Paragraph};
+/// use ratatui_macros::{frame, vertical};
+///
+/// let mut terminal = Terminal::new(TestBackend::new(20, 6))?;
+/// let mut list_state = ListState::default().with_selected(Some(0));
+/// terminal.draw(|f| {
+///     let [header, body, footer] = vertical![==1, *=1, ==1].areas(f.area());
+///     frame!(f;
+///         Paragraph::new("header") => header,
+///         List::new(["first", "second"]) @ &mut list_state => body,
+///         Block::new().title("footer") => footer,
+///     );
+/// })?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`Frame`]: ratatui_core::terminal::Frame
+/// [`Frame::render_widget`]: ratatui_core::terminal::Frame::render_widget
+/// [`Frame::render_stateful_widget`]: ratatui_core::terminal::Frame::render_stateful_widget
+/// [`Terminal::draw`]: ratatui_core::terminal::Terminal::draw
+#[macro_export]
+macro_rules! frame {
+    (@entries $frame:ident [] $(,)?) => {};
+    (@entries $frame:ident [$($widget:tt)+] => $area:expr $(, $($rest:tt)*)?) => {
+        $frame.render_widget($($widget)+, $area);
+        $crate::frame!(@entries $frame [] $($($rest)*)?);
+    };
+    (@entries $frame:ident [$($widget:tt)+] @ $state:expr => $area:expr $(, $($rest:tt)*)?) => {
+        $frame.render_stateful_widget($($widget)+, $area, $state);
+        $crate::frame!(@entries $frame [] $($($rest)*)?);
+    };
+    (@entries $frame:ident [$($widget:tt)*]) => {
+        ::core::compile_error!(concat!(
+            "expected `=> area` after the widget `",
+            stringify!($($widget)*),
+            "`"
+        ));
+    };
+    (@entries $frame:ident [$($widget:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::frame!(@entries $frame [$($widget)* $next] $($rest)*);
+    };
+    ($frame:expr; $($entries:tt)*) => {{
+        let frame: &mut $crate::ratatui_core::terminal::Frame<'_> = &mut *$frame;
+        $crate::frame!(@entries frame [] $($entries)*);
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        backend::TestBackend,
+        buffer::Buffer,
+        layout::Rect,
+        terminal::{Frame, Terminal},
+    };
+    use ratatui_widgets::list::{List, ListState};
+
+    fn draw(render: impl FnOnce(&mut Frame)) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(6, 3)).unwrap();
+        terminal.draw(render).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    #[test]
+    fn frame_widgets() {
+        let buffer = draw(|f| {
+            frame!(f;
+                "top" => Rect::new(0, 0, 6, 1),
+                "bottom" => Rect::new(0, 2, 6, 1)
+            );
+        });
+        assert_eq!(buffer, Buffer::with_lines(["top   ", "      ", "bottom"]));
+    }
+
+    #[test]
+    fn frame_stateful_widgets() {
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = draw(|f| {
+            let area = f.area();
+            frame!(f;
+                List::new(["a", "b", "c"]).highlight_symbol(">") @ &mut state => area,
+                "x" => Rect::new(5, 0, 1, 1),
+            );
+        });
+        assert_eq!(buffer, Buffer::with_lines([" a   x", ">b", " c"]));
+        assert_eq!(state.selected(), Some(1));
+    }
+}
@@ -0,0 +1,120 @@
+/// A macro for creating a [`Gauge`] from a ratio or a percentage.
+///
+/// The first argument is either a ratio between `0.0` and `1.0`, or a percentage written as
+/// `42%`. It can be followed by `key: value` settings where each key is the name of a builder
+/// method on [`Gauge`], e.g. `label: span!("42%")`, `gauge_style: Style::new().green()` or
+/// `block: block!("Progress")`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::{gauge, span};
+///
+/// let gauge = gauge!(0.42);
+/// let gauge = gauge!(42%);
+/// let gauge = gauge!(0.42, label: span!("42%"), gauge_style: Style::new().green());
+/// ```
+///
+/// [`Gauge`]: ratatui_widgets::gauge::Gauge
+#[macro_export]
+macro_rules! gauge {
+    ($percent:tt % $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::gauge::Gauge::default()
+            .percent($percent)
+            $(.$key($value))*
+    };
+    ($ratio:expr $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::gauge::Gauge::default()
+            .ratio($ratio)
+            $(.$key($value))*
+    };
+}
+
+/// A macro for creating a [`LineGauge`] from a ratio or a percentage.
+///
+/// The first argument is either a ratio between `0.0` and `1.0`, or a percentage written as
+/// `42%`. It can be followed by `key: value` settings where each key is the name of a builder
+/// method on [`LineGauge`], e.g. `label: "CPU"`, `filled_style: Style::new().green()` or
+/// `line_set: symbols::line::THICK`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::line_gauge;
+///
+/// let gauge = line_gauge!(0.42);
+/// let gauge = line_gauge!(42%, label: "CPU", filled_style: Style::new().green());
+/// ```
+///
+/// [`LineGauge`]: ratatui_widgets::gauge::LineGauge
+#[macro_export]
+macro_rules! line_gauge {
+    ($percent:tt % $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::gauge::LineGauge::default()
+            .ratio(f64::from($percent) / 100.0)
+            $(.$key($value))*
+    };
+    ($ratio:expr $(, $key:ident : $value:expr)* $(,)?) => {
+        $crate::ratatui_widgets::gauge::LineGauge::default()
+            .ratio($ratio)
+            $(.$key($value))*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_widgets::gauge::{Gauge, LineGauge};
+
+    #[test]
+    fn gauge_ratio() {
+        assert_eq!(gauge!(0.42), Gauge::default().ratio(0.42));
+
+        let ratio = 0.5;
+        assert_eq!(gauge!(ratio), Gauge::default().ratio(0.5));
+    }
+
+    #[test]
+    fn gauge_percent() {
+        assert_eq!(gauge!(42%), Gauge::default().percent(42));
+
+        let percent = 50;
+        assert_eq!(gauge!(percent %), Gauge::default().percent(50));
+    }
+
+    #[test]
+    fn gauge_settings() {
+        let gauge = gauge!(
+            0.42,
+            label: crate::span!("42%"),
+            gauge_style: Style::new().green(),
+        );
+        assert_eq!(
+            gauge,
+            Gauge::default()
+                .ratio(0.42)
+                .label("42%")
+                .gauge_style(Style::new().green())
+        );
+    }
+
+    #[test]
+    fn line_gauge_ratio_and_percent() {
+        assert_eq!(line_gauge!(0.25), LineGauge::default().ratio(0.25));
+        assert_eq!(line_gauge!(25%), LineGauge::default().ratio(0.25));
+    }
+
+    #[test]
+    fn line_gauge_settings() {
+        let gauge = line_gauge!(0.5, label: "CPU", filled_style: Style::new().green());
+        assert_eq!(
+            gauge,
+            LineGauge::default()
+                .ratio(0.5)
+                .label("CPU")
+                .filled_style(Style::new().green())
+        );
+    }
+}
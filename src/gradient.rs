@@ -0,0 +1,187 @@
+use ratatui_core::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+/// A macro for creating a [`Line`] whose characters fade between two or more colors.
+///
+/// The colors come first, separated by `->`, followed by a `;` and either a format string and its
+/// arguments, like [`format!`], or an expression that implements `AsRef<str>`. Each color is a
+/// hex string, e.g. `"#ff0000"` or `"#f00"`, or a [`Color::Rgb`] expression in parentheses, e.g.
+/// `(Color::Rgb(255, 0, 0))` or `(ACCENT)`. The first grapheme of the text has the first color,
+/// the last grapheme has the last color, and the graphemes in between are interpolated between
+/// the neighboring colors, so wide characters and emoji get a single color each.
+///
+/// A malformed hex color is a compile error. A color in parentheses that is not a
+/// [`Color::Rgb`] panics, because only RGB colors can be interpolated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Color, Stylize}, text::{Line, Span}};
+/// use ratatui_macros::gradient;
+///
+/// let line = gradient!("#ff0000" -> "#0000ff"; "abc");
+/// assert_eq!(
+///     line,
+///     Line::from(vec![
+///         Span::raw("a").fg(Color::Rgb(255, 0, 0)),
+///         Span::raw("b").fg(Color::Rgb(128, 0, 128)),
+///         Span::raw("c").fg(Color::Rgb(0, 0, 255)),
+///     ])
+/// );
+///
+/// let progress = 42;
+/// let line = gradient!("#f00" -> "#ff0" -> (Color::Rgb(0, 255, 0)); "loading {progress}%");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`Color::Rgb`]: ratatui_core::style::Color::Rgb
+#[macro_export]
+macro_rules! gradient {
+    (@stop $hex:literal) => {{
+        const COLOR: $crate::ratatui_core::style::Color = $crate::__hex_color($hex);
+        COLOR
+    }};
+    (@stop ($color:expr)) => {
+        $color
+    };
+    ($($stop:tt)->+ ; $format:literal $($args:tt)*) => {
+        $crate::__gradient(
+            &[$($crate::gradient!(@stop $stop)),+],
+            &::std::format!($format $($args)*),
+        )
+    };
+    ($($stop:tt)->+ ; $input:expr $(,)?) => {
+        $crate::__gradient(
+            &[$($crate::gradient!(@stop $stop)),+],
+            ::core::convert::AsRef::<str>::as_ref(&$input),
+        )
+    };
+}
+
+/// Colors each grapheme of the text with the color at its position in the gradient. Consecutive
+/// graphemes with the same color share a span.
+#[doc(hidden)]
+#[track_caller]
+pub fn __gradient(stops: &[Color], text: &str) -> Line<'static> {
+    let stops: Vec<_> = stops.iter().map(|&color| rgb(color)).collect();
+    let span = Span::raw(text);
+    let graphemes: Vec<_> = span.styled_graphemes(Style::new()).collect();
+    let steps = graphemes.len().saturating_sub(1).max(1);
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (i, grapheme) in graphemes.iter().enumerate() {
+        let color = interpolate(&stops, i as f64 / steps as f64);
+        match spans.last_mut() {
+            Some(last) if last.style.fg == Some(color) => {
+                last.content.to_mut().push_str(grapheme.symbol);
+            }
+            _ => spans.push(Span::styled(
+                grapheme.symbol.to_string(),
+                Style::new().fg(color),
+            )),
+        }
+    }
+    Line::from(spans)
+}
+
+/// Returns the components of an RGB color.
+#[track_caller]
+fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        _ => panic!("gradient colors must be RGB colors, found {color:?}"),
+    }
+}
+
+/// Returns the color at `t` between 0.0 and 1.0, where the stops are spread evenly.
+fn interpolate(stops: &[(u8, u8, u8)], t: f64) -> Color {
+    let [first, .., last] = stops else {
+        let (r, g, b) = stops.first().copied().unwrap_or_default();
+        return Color::Rgb(r, g, b);
+    };
+    if t <= 0.0 {
+        return Color::Rgb(first.0, first.1, first.2);
+    }
+    if t >= 1.0 {
+        return Color::Rgb(last.0, last.1, last.2);
+    }
+    let position = t * (stops.len() - 1) as f64;
+    let index = (position as usize).min(stops.len() - 2);
+    let (from, to) = (stops[index], stops[index + 1]);
+    let t = position - index as f64;
+    let mix =
+        |from: u8, to: u8| (f64::from(from) + (f64::from(to) - f64::from(from)) * t).round() as u8;
+    Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn gradient_two_colors() {
+        let line = gradient!("#000000" -> "#ffffff"; "abcde");
+        let colors: Vec<_> = line.spans.iter().map(|span| span.style.fg).collect();
+        assert_eq!(
+            colors,
+            [0, 64, 128, 191, 255].map(|value| Some(Color::Rgb(value, value, value)))
+        );
+        assert_eq!(line.to_string(), "abcde");
+    }
+
+    #[test]
+    fn gradient_many_colors() {
+        let line = gradient!("#f00" -> (Color::Rgb(0, 255, 0)) -> "#00f"; "{}", "abcde");
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("a").fg(Color::Rgb(255, 0, 0)),
+                Span::raw("b").fg(Color::Rgb(128, 128, 0)),
+                Span::raw("c").fg(Color::Rgb(0, 255, 0)),
+                Span::raw("d").fg(Color::Rgb(0, 128, 128)),
+                Span::raw("e").fg(Color::Rgb(0, 0, 255)),
+            ])
+        );
+    }
+
+    #[test]
+    fn gradient_graphemes() {
+        // the family emoji is a single grapheme, and the accent is combined with the `e`
+        let text = String::from("👨\u{200d}👩\u{200d}👧e\u{301}世");
+        let line = gradient!("#000000" -> "#0000ff"; text);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("👨\u{200d}👩\u{200d}👧").fg(Color::Rgb(0, 0, 0)),
+                Span::raw("e\u{301}").fg(Color::Rgb(0, 0, 128)),
+                Span::raw("世").fg(Color::Rgb(0, 0, 255)),
+            ])
+        );
+    }
+
+    #[test]
+    fn gradient_merges_spans() {
+        let line = gradient!("#123456"; "abc");
+        assert_eq!(
+            line,
+            Line::from(Span::raw("abc").fg(Color::Rgb(0x12, 0x34, 0x56)))
+        );
+
+        let line = gradient!("#123456" -> "#123456"; "ab");
+        assert_eq!(line.spans.len(), 1);
+
+        let line = gradient!("#000" -> "#fff"; "a");
+        assert_eq!(line, Line::from(Span::raw("a").fg(Color::Rgb(0, 0, 0))));
+        assert_eq!(gradient!("#000" -> "#fff"; ""), Line::default());
+    }
+
+    #[test]
+    #[should_panic = "gradient colors must be RGB colors, found Red"]
+    fn gradient_named_color() {
+        gradient!((Color::Red) -> "#fff"; "ab");
+    }
+}
@@ -0,0 +1,205 @@
+use std::{borrow::Cow, ops::Range};
+
+use ratatui_core::{
+    style::Style,
+    text::{Line, Span},
+};
+use regex::Regex;
+
+/// A macro for creating a [`Line`] in which the matches of a pattern are styled, e.g. to highlight
+/// the results of a search.
+///
+/// The arguments are the text, which is anything that can be converted into a `Cow<str>`, the
+/// pattern, and the style of the matches, which is anything that can be converted into a
+/// [`Style`]. The pattern is either a string, which matches each of its occurrences, or a
+/// [`Regex`], which matches like [`Regex::find_iter`]. An empty pattern and empty matches of a
+/// regex highlight nothing. Like [`span!`], a [`Style`] followed by a semicolon may come first to
+/// set the style of the line.
+///
+/// The line alternates between spans of the text that does not match and spans of the matches, and
+/// borrows the text when it is borrowed. This macro requires the `highlight` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Style, Stylize}, text::{Line, Span}};
+/// use ratatui_macros::highlight;
+/// use regex::Regex;
+///
+/// let line = highlight!("error: file not found", "not", Style::new().black().on_yellow());
+/// assert_eq!(
+///     line,
+///     Line::from(vec![
+///         Span::raw("error: file "),
+///         Span::raw("not").black().on_yellow(),
+///         Span::raw(" found"),
+///     ])
+/// );
+///
+/// let numbers = Regex::new(r"\d+").unwrap();
+/// let log = "took 12ms, 3 retries";
+/// let line = highlight!(Style::new().dim(); log, &numbers, Style::new().bold());
+/// assert_eq!(line.spans[1], Span::raw("12").bold());
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`Style`]: ratatui_core::style::Style
+/// [`Regex`]: regex::Regex
+/// [`Regex::find_iter`]: regex::Regex::find_iter
+/// [`span!`]: crate::span!
+#[macro_export]
+macro_rules! highlight {
+    ($style:expr; $text:expr, $pattern:expr, $match_style:expr $(,)?) => {
+        $crate::__highlight($text, $pattern, $match_style).style($style)
+    };
+    ($text:expr, $pattern:expr, $match_style:expr $(,)?) => {
+        $crate::__highlight($text, $pattern, $match_style)
+    };
+}
+
+/// A pattern whose matches are highlighted by [`highlight!`].
+///
+/// [`highlight!`]: crate::highlight!
+#[doc(hidden)]
+pub trait __HighlightPattern {
+    /// Returns the byte ranges of the non-empty matches in the text, in order.
+    fn find_matches(&self, text: &str) -> Vec<Range<usize>>;
+}
+
+impl __HighlightPattern for str {
+    fn find_matches(&self, text: &str) -> Vec<Range<usize>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        text.match_indices(self)
+            .map(|(start, matched)| start..start + matched.len())
+            .collect()
+    }
+}
+
+impl __HighlightPattern for String {
+    fn find_matches(&self, text: &str) -> Vec<Range<usize>> {
+        self.as_str().find_matches(text)
+    }
+}
+
+impl __HighlightPattern for Regex {
+    fn find_matches(&self, text: &str) -> Vec<Range<usize>> {
+        self.find_iter(text)
+            .map(|matched| matched.range())
+            .filter(|range| !range.is_empty())
+            .collect()
+    }
+}
+
+impl<T: __HighlightPattern + ?Sized> __HighlightPattern for &T {
+    fn find_matches(&self, text: &str) -> Vec<Range<usize>> {
+        (**self).find_matches(text)
+    }
+}
+
+/// Splits the text into spans at the matches of the pattern and styles the matches. See
+/// [`highlight!`].
+///
+/// [`highlight!`]: crate::highlight!
+#[doc(hidden)]
+pub fn __highlight<'a>(
+    text: impl Into<Cow<'a, str>>,
+    pattern: impl __HighlightPattern,
+    style: impl Into<Style>,
+) -> Line<'a> {
+    let text = text.into();
+    let style = style.into();
+    let matches = pattern.find_matches(&text);
+    let slice = |range: Range<usize>| match &text {
+        Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+        Cow::Owned(text) => Cow::Owned(text[range].to_owned()),
+    };
+    let mut spans = Vec::with_capacity(matches.len() * 2 + 1);
+    let mut end = 0;
+    for range in matches {
+        if range.start > end {
+            spans.push(Span::raw(slice(end..range.start)));
+        }
+        end = range.end;
+        spans.push(Span::styled(slice(range), style));
+    }
+    if end < text.len() {
+        spans.push(Span::raw(slice(end..text.len())));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::{Line, Span},
+    };
+    use regex::Regex;
+
+    #[test]
+    fn highlight_substring() {
+        let style = Style::new().on_yellow();
+        assert_eq!(
+            highlight!("abcab", "ab", style),
+            Line::from(vec![
+                Span::raw("ab").on_yellow(),
+                Span::raw("c"),
+                Span::raw("ab").on_yellow(),
+            ])
+        );
+        let query = String::from("b");
+        assert_eq!(
+            highlight!(String::from("abc"), &query, style,),
+            Line::from(vec![
+                Span::raw("a"),
+                Span::raw("b").on_yellow(),
+                Span::raw("c")
+            ])
+        );
+    }
+
+    #[test]
+    fn highlight_without_matches() {
+        assert_eq!(
+            highlight!("abc", "x", Style::new().red()),
+            Line::from("abc")
+        );
+        assert_eq!(highlight!("abc", "", Style::new().red()), Line::from("abc"));
+        assert_eq!(highlight!("", "", Style::new().red()), Line::default());
+    }
+
+    #[test]
+    fn highlight_regex() {
+        let pattern = Regex::new(r"\d*").unwrap();
+        assert_eq!(
+            highlight!("a1b22", pattern, Style::new().bold()),
+            Line::from(vec![
+                Span::raw("a"),
+                Span::raw("1").bold(),
+                Span::raw("b"),
+                Span::raw("22").bold(),
+            ])
+        );
+    }
+
+    #[test]
+    fn highlight_styled_line() {
+        assert_eq!(
+            highlight!(Style::new().dim(); "ab", "b", Style::new().bold()),
+            Line::from(vec![Span::raw("a"), Span::raw("b").bold()]).dim()
+        );
+    }
+
+    #[test]
+    fn highlight_borrows_text() {
+        let line = highlight!("abc", "b", Style::new());
+        assert!(line
+            .spans
+            .iter()
+            .all(|span| matches!(span.content, Cow::Borrowed(_))));
+    }
+}
@@ -0,0 +1,238 @@
+use std::sync::OnceLock;
+
+use ratatui_core::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+use syntect::{
+    easy::HighlightLines,
+    highlighting::{FontStyle, Theme, ThemeSet},
+    parsing::SyntaxSet,
+    util::LinesWithEndings,
+};
+
+/// A macro for creating a [`Text`] from syntax-highlighted source code, using the syntaxes and
+/// themes that are bundled with [syntect].
+///
+/// The arguments are the language and the source code, which are both anything that implements
+/// `AsRef<str>`. The language is a file extension like `"rs"` or the name of a syntax like
+/// `"Rust"`, and an unknown language is shown as plain text in the colors of the theme. Each line
+/// of the source code is one line of the text.
+///
+/// The language can be preceded by `key: value` directives, separated from it by a `;`:
+///
+/// - `theme: name` sets the theme, which is one of `"base16-ocean.dark"` (the default),
+///   `"base16-eighties.dark"`, `"base16-mocha.dark"`, `"base16-ocean.light"`, `"InspiredGitHub"`,
+///   `"Solarized (dark)"` and `"Solarized (light)"`, and any other name panics
+/// - `background: true` also sets the background colors of the theme, which are left out by
+///   default so that the code is shown on the background of the terminal
+///
+/// The colors of the themes are 24-bit [`Color::Rgb`] colors, and their bold, italic and underline
+/// font styles are added as modifiers. This macro requires the `syntect` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Modifier};
+/// use ratatui_macros::highlight_code;
+///
+/// let source = "fn main() {\n    println!(\"hello\");\n}\n";
+/// let text = highlight_code!("rs", source);
+/// assert_eq!(text.lines.len(), 3);
+/// assert_eq!(text.lines[0].spans[0].content, "fn");
+/// assert!(matches!(text.lines[0].spans[0].style.fg, Some(Color::Rgb(..))));
+///
+/// let text = highlight_code!(theme: "Solarized (light)", background: true; "toml", "a = 1");
+/// assert!(text.lines[0].spans[0].style.bg.is_some());
+/// ```
+///
+/// [syntect]: https://docs.rs/syntect
+/// [`Text`]: ratatui_core::text::Text
+/// [`Color::Rgb`]: ratatui_core::style::Color::Rgb
+#[macro_export]
+macro_rules! highlight_code {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::highlight_code!(@directives [$($directives)* .$setting($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::highlight_code!(@directives [$($directives)* .$setting($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $language:expr, $source:expr $(,)?) => {
+        $crate::__HighlightCode::default() $($directives)* .text(
+            ::core::convert::AsRef::<str>::as_ref(&$language),
+            ::core::convert::AsRef::<str>::as_ref(&$source),
+        )
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::highlight_code!(@directives [] $setting : $($rest)*)
+    };
+    ($language:expr, $source:expr $(,)?) => {
+        $crate::highlight_code!(@directives [] ; $language, $source)
+    };
+}
+
+/// Converts source code into syntax-highlighted [`Text`].
+///
+/// The language is a file extension like `"rs"` or the name of a syntax like `"Rust"`. This uses
+/// the default theme of [`highlight_code!`], which can also change the theme. This function
+/// requires the `syntect` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::code_to_text;
+///
+/// let text = code_to_text("py", "def greet():\n    return 'hi'");
+/// assert_eq!(text.to_string(), "def greet():\n    return 'hi'");
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`highlight_code!`]: crate::highlight_code!
+pub fn code_to_text(language: &str, source: &str) -> Text<'static> {
+    __HighlightCode::default().text(language, source)
+}
+
+/// The bundled syntaxes, which are loaded on first use.
+fn syntaxes() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+/// The bundled themes, which are loaded on first use.
+fn themes() -> &'static ThemeSet {
+    static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+    THEMES.get_or_init(ThemeSet::load_defaults)
+}
+
+/// The theme and settings of [`highlight_code!`].
+///
+/// [`highlight_code!`]: crate::highlight_code!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __HighlightCode<'a> {
+    theme: &'a str,
+    background: bool,
+}
+
+impl Default for __HighlightCode<'_> {
+    fn default() -> Self {
+        Self {
+            theme: "base16-ocean.dark",
+            background: false,
+        }
+    }
+}
+
+impl<'a> __HighlightCode<'a> {
+    pub const fn theme(mut self, theme: &'a str) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub const fn background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    #[track_caller]
+    pub fn text(self, language: &str, source: &str) -> Text<'static> {
+        let syntaxes = syntaxes();
+        let syntax = syntaxes
+            .find_syntax_by_token(language)
+            .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+        let mut highlighter = HighlightLines::new(syntax, self.load_theme());
+        let mut text = Text::default();
+        let mut start = 0;
+        for line in LinesWithEndings::from(source) {
+            let Ok(regions) = highlighter.highlight_line(line, syntaxes) else {
+                // the highlighter cannot continue after an error, so the rest is left unstyled
+                text.extend(source[start..].lines().map(str::to_owned));
+                break;
+            };
+            start += line.len();
+            let spans = regions
+                .into_iter()
+                .map(|(style, piece)| (style, piece.trim_end_matches(['\n', '\r'])))
+                .filter(|(_, piece)| !piece.is_empty())
+                .map(|(style, piece)| Span::styled(piece.to_owned(), self.style(style)));
+            text.push_line(Line::from_iter(spans));
+        }
+        text
+    }
+
+    #[track_caller]
+    fn load_theme(&self) -> &'static Theme {
+        let themes = themes();
+        themes.themes.get(self.theme).unwrap_or_else(|| {
+            let names = themes.themes.keys().map(String::as_str);
+            panic!(
+                "unknown theme `{}`, expected one of `{}`",
+                self.theme,
+                names.collect::<Vec<_>>().join("`, `")
+            )
+        })
+    }
+
+    fn style(&self, style: syntect::highlighting::Style) -> Style {
+        let color = |color: syntect::highlighting::Color| Color::Rgb(color.r, color.g, color.b);
+        let mut converted = Style::new().fg(color(style.foreground));
+        if self.background {
+            converted = converted.bg(color(style.background));
+        }
+        for (font_style, modifier) in [
+            (FontStyle::BOLD, Modifier::BOLD),
+            (FontStyle::ITALIC, Modifier::ITALIC),
+            (FontStyle::UNDERLINE, Modifier::UNDERLINED),
+        ] {
+            if style.font_style.contains(font_style) {
+                converted = converted.add_modifier(modifier);
+            }
+        }
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier};
+
+    use super::code_to_text;
+
+    #[test]
+    fn highlight_code_lines() {
+        let source = "let x = 1;\r\n// done\n";
+        let text = highlight_code!("rs", source);
+        assert_eq!(text.to_string(), "let x = 1;\n// done");
+        let keyword = &text.lines[0].spans[0];
+        assert_eq!(keyword.content, "let");
+        assert!(matches!(keyword.style.fg, Some(Color::Rgb(..))));
+        assert_eq!(keyword.style.bg, None);
+        assert_ne!(text.lines[1].spans[0].style, keyword.style);
+        assert_eq!(
+            highlight_code!("rs", ""),
+            ratatui_core::text::Text::default()
+        );
+    }
+
+    #[test]
+    fn highlight_code_settings() {
+        let text = highlight_code!(theme: "InspiredGitHub", background: true; "Rust", "fn f() {}");
+        let keyword = &text.lines[0].spans[0];
+        assert_eq!(keyword.content, "fn");
+        assert!(keyword.style.bg.is_some());
+        assert!(keyword.style.add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn highlight_code_unknown_language() {
+        let text = code_to_text("no-such-language", "plain {text}");
+        assert_eq!(text.lines[0].spans.len(), 1);
+        assert_eq!(text.to_string(), "plain {text}");
+    }
+
+    #[test]
+    #[should_panic = "unknown theme `dracula`"]
+    fn highlight_code_unknown_theme() {
+        highlight_code!(theme: "dracula"; "rs", "fn main() {}");
+    }
+}
@@ -0,0 +1,96 @@
+/// A macro for creating a [`Text`] from HTML-like tags that are parsed at compile time.
+///
+/// The first argument is a string literal that supports the tags:
+///
+/// - `<b>` or `<strong>` for bold text
+/// - `<i>` or `<em>` for italic text
+/// - `<u>` for underlined text
+/// - `<s>` or `<del>` for crossed out text
+/// - `<span fg="red" bg="black">` for colors, which accept the same colors as [`color!`]
+/// - `<br>` for a line break
+///
+/// Each line of the string and each `<br>` start a new [`Line`]. The entities `&lt;`, `&gt;`,
+/// `&amp;`, `&quot;`, `&apos;` and `&nbsp;` write the matching character. The string is also a
+/// format string: it may contain placeholders such as `{}` or `{name:?}` that are formatted with
+/// the remaining arguments, like [`format!`]. Unknown tags, unclosed tags and unused arguments are
+/// compile errors.
+///
+/// This macro requires the `proc-macros` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span, Text}};
+/// use ratatui_macros::html;
+///
+/// let text = html!("<b>Save</b> <span fg=\"red\">unsaved changes</span> will be lost");
+/// assert_eq!(
+///     text,
+///     Text::from(Line::from(vec![
+///         Span::raw("Save").bold(),
+///         Span::raw(" "),
+///         Span::raw("unsaved changes").red(),
+///         Span::raw(" will be lost"),
+///     ]))
+/// );
+///
+/// let name = "world";
+/// let text = html!("<i>Hello</i>, <span fg='#89b4fa' bg='black'>{name}</span>!<br>Bye");
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::html;
+/// let text = html!("<blink>unsupported</blink>");
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`Line`]: ratatui_core::text::Line
+/// [`color!`]: crate::color!
+#[macro_export]
+macro_rules! html {
+    ($($tokens:tt)*) => {
+        $crate::ratatui_macros_proc::html!($crate, $($tokens)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Span, Text},
+    };
+
+    #[test]
+    fn html_tags() {
+        let text = html!("<b>Save</b> <i><u>now</u></i> or <del>never</del>");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("Save").bold(),
+                Span::raw(" "),
+                Span::raw("now").italic().underlined(),
+                Span::raw(" or "),
+                Span::raw("never").crossed_out(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn html_span_colors_and_arguments() {
+        let count = 3;
+        let text = html!(
+            "<span fg=\"white\" bg='blue'>{}</span> {count} files<br>&lt;done&gt;",
+            "INFO"
+        );
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec![
+                    Span::styled("INFO", Style::new().fg(Color::White).bg(Color::Blue)),
+                    Span::raw(" 3 files"),
+                ]),
+                Line::from("<done>"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::{borrow::Cow, fmt::Display};
+
+use ratatui_core::text::Span;
+
+/// A macro for creating a [`Span`] that links to a URL with an OSC 8 hyperlink escape sequence.
+///
+/// The first argument is the URL and the second argument is the text that is shown, which is
+/// anything that can be converted into a `Cow<str>`. Terminals that support OSC 8 show the text as
+/// a link that opens the URL when clicked; other terminals show the text. Like [`span!`], a
+/// [`Style`] followed by a semicolon may come first to create a styled span.
+///
+/// With the `no-osc8` feature, the escape sequences are left out and the span only contains the
+/// text, e.g. for terminals or tests where the sequences get in the way.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::hyperlink;
+///
+/// let span = hyperlink!("https://ratatui.rs", "ratatui");
+/// # #[cfg(not(feature = "no-osc8"))]
+/// assert_eq!(span.content, "\x1b]8;;https://ratatui.rs\x1b\\ratatui\x1b]8;;\x1b\\");
+///
+/// let url = String::from("https://docs.rs/ratatui");
+/// let span = hyperlink!(Style::new().blue().underlined(); url, "docs");
+/// ```
+///
+/// [`Span`]: ratatui_core::text::Span
+/// [`Style`]: ratatui_core::style::Style
+/// [`span!`]: crate::span!
+#[macro_export]
+macro_rules! hyperlink {
+    ($style:expr; $url:expr, $text:expr $(,)?) => {
+        $crate::__hyperlink($url, $text).style($style)
+    };
+    ($url:expr, $text:expr $(,)?) => {
+        $crate::__hyperlink($url, $text)
+    };
+}
+
+/// Wraps the text in the OSC 8 sequences that open and close a hyperlink to the URL.
+#[cfg(not(feature = "no-osc8"))]
+#[doc(hidden)]
+pub fn __hyperlink<'a>(url: impl Display, text: impl Into<Cow<'a, str>>) -> Span<'a> {
+    let text = text.into();
+    Span::raw(format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\"))
+}
+
+/// Returns the text without the OSC 8 sequences, because the `no-osc8` feature is enabled.
+#[cfg(feature = "no-osc8")]
+#[doc(hidden)]
+pub fn __hyperlink<'a>(_url: impl Display, text: impl Into<Cow<'a, str>>) -> Span<'a> {
+    Span::raw(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::Span,
+    };
+
+    #[cfg(not(feature = "no-osc8"))]
+    #[test]
+    fn hyperlink_osc8() {
+        assert_eq!(
+            hyperlink!("https://ratatui.rs", "ratatui"),
+            Span::raw("\x1b]8;;https://ratatui.rs\x1b\\ratatui\x1b]8;;\x1b\\")
+        );
+        let url = String::from("https://docs.rs");
+        let text = String::from("docs");
+        assert_eq!(
+            hyperlink!(Style::new().blue(); &url, text),
+            Span::raw("\x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\").blue()
+        );
+    }
+
+    #[cfg(feature = "no-osc8")]
+    #[test]
+    fn hyperlink_without_osc8() {
+        assert_eq!(
+            hyperlink!("https://ratatui.rs", "ratatui"),
+            Span::raw("ratatui")
+        );
+        assert_eq!(
+            hyperlink!(Style::new().blue(); "https://docs.rs", "docs"),
+            Span::raw("docs").blue()
+        );
+    }
+}
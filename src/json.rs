@@ -0,0 +1,313 @@
+use std::mem;
+
+use ratatui_core::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// A macro for creating a [`Text`] that shows a value as pretty-printed and syntax-highlighted
+/// JSON.
+///
+/// The argument is a [`serde_json::Value`] or anything else that implements [`Serialize`]. The
+/// JSON is indented by two spaces like [`serde_json::to_string_pretty`], and object keys,
+/// strings, numbers, `true`, `false` and `null`, and punctuation each have their own style. A
+/// value that cannot be serialized, e.g. a map whose keys are not strings, is shown as a red error
+/// message instead.
+///
+/// The value can be preceded by `key: value` directives, separated from it by a `;`, that replace
+/// the styles: `key`, `string`, `number`, `literal` and `punctuation`.
+///
+/// This macro requires the `json` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// use ratatui_macros::json_text;
+/// use serde_json::json;
+///
+/// let value = json!({ "name": "ratatui", "stars": 10000, "tags": ["tui", "rust"] });
+/// let text = json_text!(value);
+/// assert_eq!(text.lines[2].to_string(), r#"  "stars": 10000,"#);
+/// assert_eq!(text.to_string(), serde_json::to_string_pretty(&value).unwrap());
+///
+/// let text = json_text!(key: Color::Magenta, punctuation: Style::new(); [1, 2, 3]);
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`Serialize`]: serde::Serialize
+/// [`serde_json::Value`]: serde_json::Value
+/// [`serde_json::to_string_pretty`]: serde_json::to_string_pretty
+#[macro_export]
+macro_rules! json_text {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::json_text!(@directives [$($directives)* .$setting($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::json_text!(@directives [$($directives)* .$setting($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $value:expr $(,)?) => {
+        $crate::__Json::default() $($directives)* .text(&$value)
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::json_text!(@directives [] $setting : $($rest)*)
+    };
+    ($value:expr $(,)?) => {
+        $crate::__Json::default().text(&$value)
+    };
+}
+
+/// Converts a JSON value into pretty-printed and syntax-highlighted [`Text`].
+///
+/// This uses the default styles of [`json_text!`], which also accepts any value that implements
+/// [`Serialize`]. This function requires the `json` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::json_to_text;
+/// use serde_json::json;
+///
+/// let text = json_to_text(&json!({ "ok": true }));
+/// assert_eq!(text.to_string(), "{\n  \"ok\": true\n}");
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`Serialize`]: serde::Serialize
+/// [`json_text!`]: crate::json_text!
+pub fn json_to_text(value: &Value) -> Text<'static> {
+    __Json::default().text(value)
+}
+
+/// The styles of the tokens of pretty-printed JSON. See [`json_text!`].
+///
+/// [`json_text!`]: crate::json_text!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Json {
+    key: Style,
+    string: Style,
+    number: Style,
+    literal: Style,
+    punctuation: Style,
+}
+
+impl Default for __Json {
+    fn default() -> Self {
+        Self {
+            key: Style::new().fg(Color::Blue),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Yellow),
+            literal: Style::new().fg(Color::Magenta),
+            punctuation: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+}
+
+impl __Json {
+    pub fn key(mut self, style: impl Into<Style>) -> Self {
+        self.key = style.into();
+        self
+    }
+
+    pub fn string(mut self, style: impl Into<Style>) -> Self {
+        self.string = style.into();
+        self
+    }
+
+    pub fn number(mut self, style: impl Into<Style>) -> Self {
+        self.number = style.into();
+        self
+    }
+
+    pub fn literal(mut self, style: impl Into<Style>) -> Self {
+        self.literal = style.into();
+        self
+    }
+
+    pub fn punctuation(mut self, style: impl Into<Style>) -> Self {
+        self.punctuation = style.into();
+        self
+    }
+
+    pub fn text<T: Serialize + ?Sized>(self, value: &T) -> Text<'static> {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(error) => {
+                let message = format!("failed to serialize JSON: {error}");
+                return Text::from(Span::styled(message, Style::new().fg(Color::Red)));
+            }
+        };
+        let mut writer = Writer {
+            styles: &self,
+            lines: Vec::new(),
+            current: Vec::new(),
+        };
+        writer.value(&value, 0);
+        writer.finish_line();
+        Text::from(writer.lines)
+    }
+}
+
+/// Collects the lines of pretty-printed JSON.
+struct Writer<'s> {
+    styles: &'s __Json,
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+}
+
+impl Writer<'_> {
+    fn value(&mut self, value: &Value, indent: usize) {
+        match value {
+            Value::Null => self.push("null", self.styles.literal),
+            Value::Bool(value) => self.push(value.to_string(), self.styles.literal),
+            Value::Number(number) => self.push(number.to_string(), self.styles.number),
+            Value::String(string) => self.push(quote(string), self.styles.string),
+            Value::Array(values) if values.is_empty() => self.push("[]", self.styles.punctuation),
+            Value::Object(map) if map.is_empty() => self.push("{}", self.styles.punctuation),
+            Value::Array(values) => {
+                self.push("[", self.styles.punctuation);
+                for (i, value) in values.iter().enumerate() {
+                    self.start_line(indent + 1);
+                    self.value(value, indent + 1);
+                    if i + 1 < values.len() {
+                        self.push(",", self.styles.punctuation);
+                    }
+                }
+                self.start_line(indent);
+                self.push("]", self.styles.punctuation);
+            }
+            Value::Object(map) => {
+                self.push("{", self.styles.punctuation);
+                for (i, (key, value)) in map.iter().enumerate() {
+                    self.start_line(indent + 1);
+                    self.push(quote(key), self.styles.key);
+                    self.push(": ", self.styles.punctuation);
+                    self.value(value, indent + 1);
+                    if i + 1 < map.len() {
+                        self.push(",", self.styles.punctuation);
+                    }
+                }
+                self.start_line(indent);
+                self.push("}", self.styles.punctuation);
+            }
+        }
+    }
+
+    fn push(&mut self, content: impl Into<String>, style: Style) {
+        self.current.push(Span::styled(content.into(), style));
+    }
+
+    /// Finishes the current line and starts a new one with the indentation.
+    fn start_line(&mut self, indent: usize) {
+        self.finish_line();
+        if indent > 0 {
+            self.current.push(Span::raw("  ".repeat(indent)));
+        }
+    }
+
+    fn finish_line(&mut self) {
+        self.lines.push(Line::from(mem::take(&mut self.current)));
+    }
+}
+
+/// Returns the string as a quoted and escaped JSON string.
+fn quote(string: &str) -> String {
+    Value::from(string).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Span, Text},
+    };
+    use serde_json::json;
+
+    use super::json_to_text;
+
+    #[test]
+    fn json_text_matches_serde_json() {
+        let value = json!({
+            "a": [1, 2.5, -3],
+            "b": { "c": null, "d": [] },
+            "e": {},
+            "f": "quote \" and newline \n",
+        });
+        assert_eq!(
+            json_text!(value).to_string(),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+        assert_eq!(json_to_text(&json!(true)).to_string(), "true");
+    }
+
+    #[test]
+    fn json_text_styles() {
+        assert_eq!(
+            json_text!(json!({ "k": ["s", 1, false] })),
+            Text::from(vec![
+                Line::from(Span::raw("{").dim()),
+                Line::from(vec![
+                    Span::raw("  "),
+                    Span::raw("\"k\"").blue(),
+                    Span::raw(": ").dim(),
+                    Span::raw("[").dim(),
+                ]),
+                Line::from(vec![
+                    Span::raw("    "),
+                    Span::raw("\"s\"").green(),
+                    Span::raw(",").dim(),
+                ]),
+                Line::from(vec![
+                    Span::raw("    "),
+                    Span::raw("1").yellow(),
+                    Span::raw(",").dim(),
+                ]),
+                Line::from(vec![Span::raw("    "), Span::raw("false").magenta()]),
+                Line::from(vec![Span::raw("  "), Span::raw("]").dim()]),
+                Line::from(Span::raw("}").dim()),
+            ])
+        );
+    }
+
+    #[test]
+    fn json_text_directives() {
+        let text = json_text!(
+            key: Color::Red,
+            string: Color::Cyan,
+            number: Style::new().bold(),
+            literal: Color::Gray,
+            punctuation: Style::new();
+            [("x", 1)],
+        );
+        assert_eq!(
+            text.lines[1],
+            Line::from(vec![Span::raw("  "), Span::raw("[")])
+        );
+        assert_eq!(
+            text.lines[2],
+            Line::from(vec![
+                Span::raw("    "),
+                Span::raw("\"x\"").cyan(),
+                Span::raw(","),
+            ])
+        );
+        assert_eq!(
+            text.lines[3],
+            Line::from(vec![Span::raw("    "), Span::raw("1").bold()])
+        );
+    }
+
+    #[test]
+    fn json_text_serialize_error() {
+        let map = BTreeMap::from([((1, 2), "tuple key")]);
+        assert_eq!(
+            json_text!(map),
+            Text::from(Span::raw("failed to serialize JSON: key must be a string").red())
+        );
+    }
+}
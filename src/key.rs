@@ -0,0 +1,920 @@
+#[cfg(feature = "crossterm")]
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// A macro for matching a [`KeyEvent`] in a `match` arm or `if let`.
+///
+/// The key is written as a list of modifiers followed by a key code, separated by `-`:
+///
+/// - the modifiers are `ctrl`, `shift`, `alt`, `super`, `hyper` and `meta`
+/// - the key code is either a char literal, e.g. `'c'`, which matches [`KeyCode::Char`], or the
+///   name of a [`KeyCode`] variant, e.g. `Esc`, `Enter` or `F(5)`
+///
+/// The pattern only matches when exactly the given modifiers are pressed, so `key!('c')` does not
+/// match `ctrl-c`. The [`KeyEventKind`] and [`KeyEventState`] of the event are ignored.
+///
+/// Like [`KeyChord`], the `SHIFT` modifier is ignored for chars, as crossterm reports a shifted
+/// letter as the uppercase char with or without `SHIFT`. `shift` with a lowercase letter matches
+/// the uppercase letter, so `key!('A')`, `key!(shift-'a')` and `key!(shift-'A')` all match a
+/// shifted `a`.
+///
+/// The pattern matches the key events of crossterm with the `crossterm` feature, of [termion] with
+/// the `termion` feature and of [termwiz] with the `termwiz` feature. When more than one of these
+/// features is enabled, crossterm is preferred over termion and termion over termwiz, and the
+/// backend can be chosen with a `backend: name;` directive before the key, e.g.
+/// `key!(backend: termion; ctrl-'c')`:
+///
+/// - termion reports a [`termion::event::Key`], which only has modifiers for some keys: `ctrl` and
+///   `alt` with a char, `shift`, `ctrl` and `alt` with `Left`, `Right`, `Up` and `Down`, `ctrl`
+///   with `Home` and `End`, and `shift-Tab`. Any other combination is a compile error. `Enter` and
+///   `Tab` match the chars `'\n'` and `'\t'`, which is how termion reports them.
+/// - termwiz reports a [`termwiz::input::KeyEvent`], which has no `hyper` and `meta` modifiers.
+///   `Esc`, `Left`, `Right`, `Up`, `Down` and `F(n)` match the termwiz key codes `Escape`,
+///   `LeftArrow`, `RightArrow`, `UpArrow`, `DownArrow` and `Function(n)`, and the other names are
+///   the names of the termwiz key codes.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "crossterm")]
+/// # {
+/// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui_macros::key;
+///
+/// let event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+/// let action = match event {
+///     key!(ctrl-'c') | key!('q') => "quit",
+///     key!(shift-F(5)) => "refresh",
+///     key!(ctrl-shift-Up) => "move up",
+///     key!(Esc) => "cancel",
+///     _ => "unknown",
+/// };
+/// assert_eq!(action, "quit");
+/// # }
+/// ```
+///
+/// With termion:
+///
+/// ```rust
+/// # #[cfg(feature = "termion")]
+/// # {
+/// use ratatui_macros::key;
+/// use termion::event::Key;
+///
+/// let action = match Key::CtrlUp {
+///     key!(backend: termion; ctrl-'c') => "quit",
+///     key!(backend: termion; ctrl-Up) => "move up",
+///     key!(backend: termion; Enter) => "confirm",
+///     _ => "unknown",
+/// };
+/// assert_eq!(action, "move up");
+/// # }
+/// ```
+///
+/// [termion]: https://docs.rs/termion
+/// [termwiz]: https://docs.rs/termwiz
+/// [`KeyEvent`]: https://docs.rs/crossterm/latest/crossterm/event/struct.KeyEvent.html
+/// [`KeyCode`]: https://docs.rs/crossterm/latest/crossterm/event/enum.KeyCode.html
+/// [`KeyCode::Char`]: https://docs.rs/crossterm/latest/crossterm/event/enum.KeyCode.html#variant.Char
+/// [`KeyEventKind`]: https://docs.rs/crossterm/latest/crossterm/event/enum.KeyEventKind.html
+/// [`KeyEventState`]: https://docs.rs/crossterm/latest/crossterm/event/struct.KeyEventState.html
+/// [`KeyChord`]: https://docs.rs/ratatui-macros/latest/ratatui_macros/struct.KeyChord.html
+/// [`termion::event::Key`]: https://docs.rs/termion/latest/termion/event/enum.Key.html
+/// [`termwiz::input::KeyEvent`]: https://docs.rs/termwiz/latest/termwiz/input/struct.KeyEvent.html
+#[macro_export]
+macro_rules! key {
+    (@key $backend:ident [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt]
+        shift - $($key:tt)+
+    ) => {
+        $crate::key!(@key $backend [true $ctrl $alt $super $hyper $meta] $($key)+)
+    };
+    (@key $backend:ident [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt]
+        ctrl - $($key:tt)+
+    ) => {
+        $crate::key!(@key $backend [$shift true $alt $super $hyper $meta] $($key)+)
+    };
+    (@key $backend:ident [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt]
+        alt - $($key:tt)+
+    ) => {
+        $crate::key!(@key $backend [$shift $ctrl true $super $hyper $meta] $($key)+)
+    };
+    (@key $backend:ident [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt]
+        super - $($key:tt)+
+    ) => {
+        $crate::key!(@key $backend [$shift $ctrl $alt true $hyper $meta] $($key)+)
+    };
+    (@key $backend:ident [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt]
+        hyper - $($key:tt)+
+    ) => {
+        $crate::key!(@key $backend [$shift $ctrl $alt $super true $meta] $($key)+)
+    };
+    (@key $backend:ident [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt]
+        meta - $($key:tt)+
+    ) => {
+        $crate::key!(@key $backend [$shift $ctrl $alt $super $hyper true] $($key)+)
+    };
+    (@key crossterm [$shift:tt $ctrl:tt $alt:tt $super:tt $hyper:tt $meta:tt] $char:literal) => {
+        $crate::crossterm::event::KeyEvent {
+            code: $crate::__KeyChar::<$char, $shift>::CODE,
+            modifiers:
+                $crate::__KeyModifiers::<false, $ctrl, $alt, $super, $hyper, $meta>::MODIFIERS
+                    | $crate::__KeyModifiers::<true, $ctrl, $alt, $super, $hyper, $meta>::MODIFIERS,
+            ..
+        }
+    };
+    (@key crossterm [$($modifiers:tt)*] $code:ident $(($($args:tt)*))?) => {
+        $crate::key!(@crossterm [$($modifiers)*]
+            $crate::crossterm::event::KeyCode::$code $(($($args)*))?
+        )
+    };
+    (@crossterm [$($modifiers:tt)*] $code:pat) => {
+        $crate::crossterm::event::KeyEvent {
+            code: $code,
+            modifiers: $crate::__KeyModifiers::<$($modifiers),*>::MODIFIERS,
+            ..
+        }
+    };
+    (@key termion [false false false false false false] $char:literal) => {
+        $crate::termion::event::Key::Char($char)
+    };
+    (@key termion [false true false false false false] $char:literal) => {
+        $crate::termion::event::Key::Ctrl($char)
+    };
+    (@key termion [false false true false false false] $char:literal) => {
+        $crate::termion::event::Key::Alt($char)
+    };
+    (@key termion [false false false false false false] Enter) => {
+        $crate::termion::event::Key::Char('\n')
+    };
+    (@key termion [false false false false false false] Tab) => {
+        $crate::termion::event::Key::Char('\t')
+    };
+    (@key termion [true false false false false false] Tab) => {
+        $crate::termion::event::Key::BackTab
+    };
+    (@key termion [true false false false false false] Left) => {
+        $crate::termion::event::Key::ShiftLeft
+    };
+    (@key termion [false true false false false false] Left) => {
+        $crate::termion::event::Key::CtrlLeft
+    };
+    (@key termion [false false true false false false] Left) => {
+        $crate::termion::event::Key::AltLeft
+    };
+    (@key termion [true false false false false false] Right) => {
+        $crate::termion::event::Key::ShiftRight
+    };
+    (@key termion [false true false false false false] Right) => {
+        $crate::termion::event::Key::CtrlRight
+    };
+    (@key termion [false false true false false false] Right) => {
+        $crate::termion::event::Key::AltRight
+    };
+    (@key termion [true false false false false false] Up) => {
+        $crate::termion::event::Key::ShiftUp
+    };
+    (@key termion [false true false false false false] Up) => {
+        $crate::termion::event::Key::CtrlUp
+    };
+    (@key termion [false false true false false false] Up) => {
+        $crate::termion::event::Key::AltUp
+    };
+    (@key termion [true false false false false false] Down) => {
+        $crate::termion::event::Key::ShiftDown
+    };
+    (@key termion [false true false false false false] Down) => {
+        $crate::termion::event::Key::CtrlDown
+    };
+    (@key termion [false false true false false false] Down) => {
+        $crate::termion::event::Key::AltDown
+    };
+    (@key termion [false true false false false false] Home) => {
+        $crate::termion::event::Key::CtrlHome
+    };
+    (@key termion [false true false false false false] End) => {
+        $crate::termion::event::Key::CtrlEnd
+    };
+    (@key termion [false false false false false false] $code:ident $(($($args:tt)*))?) => {
+        $crate::termion::event::Key::$code $(($($args)*))?
+    };
+    (@key termion [$($modifiers:tt)*] $($key:tt)+) => {
+        compile_error!(concat!(
+            "termion does not report `",
+            stringify!($($key)+),
+            "` with these modifiers, expected `ctrl` or `alt` with a char, ",
+            "`shift`, `ctrl` or `alt` with an arrow key, `ctrl` with `Home` or `End`, or `shift-Tab`"
+        ))
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] $char:literal) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super]
+            $crate::termwiz::input::KeyCode::Char($char)
+        )
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] Esc) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super] $crate::termwiz::input::KeyCode::Escape)
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] Left) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super] $crate::termwiz::input::KeyCode::LeftArrow)
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] Right) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super]
+            $crate::termwiz::input::KeyCode::RightArrow
+        )
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] Up) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super] $crate::termwiz::input::KeyCode::UpArrow)
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] Down) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super] $crate::termwiz::input::KeyCode::DownArrow)
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false] F($($args:tt)*)) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super]
+            $crate::termwiz::input::KeyCode::Function($($args)*)
+        )
+    };
+    (@key termwiz [$shift:tt $ctrl:tt $alt:tt $super:tt false false]
+        $code:ident $(($($args:tt)*))?
+    ) => {
+        $crate::key!(@termwiz [$shift $ctrl $alt $super]
+            $crate::termwiz::input::KeyCode::$code $(($($args)*))?
+        )
+    };
+    (@key termwiz [$($modifiers:tt)*] $($key:tt)+) => {
+        compile_error!("termwiz does not report the `hyper` and `meta` modifiers")
+    };
+    (@termwiz [$($modifiers:tt)*] $code:pat) => {
+        $crate::termwiz::input::KeyEvent {
+            key: $code,
+            modifiers: $crate::__TermwizModifiers::<$($modifiers),*>::MODIFIERS,
+        }
+    };
+    (backend : crossterm ; $($key:tt)+) => {
+        $crate::key!(@key crossterm [false false false false false false] $($key)+)
+    };
+    (backend : termion ; $($key:tt)+) => {
+        $crate::key!(@key termion [false false false false false false] $($key)+)
+    };
+    (backend : termwiz ; $($key:tt)+) => {
+        $crate::key!(@key termwiz [false false false false false false] $($key)+)
+    };
+    (backend : $backend:ident ; $($key:tt)+) => {
+        compile_error!(concat!(
+            "unknown backend `",
+            stringify!($backend),
+            "`, expected `crossterm`, `termion` or `termwiz`"
+        ))
+    };
+    ($($key:tt)+) => {
+        $crate::__default_backend!(key!($($key)+))
+    };
+}
+
+/// A macro for matching an [`Event`] in a `match` arm or `if let`.
+///
+/// The argument is the kind of event, optionally followed by the patterns of its fields:
+///
+/// - `key: ctrl-'c'` matches a [`KeyEvent`] with the syntax of [`key!`], and `key` matches any key
+///   event, or binds it with `key(event)`
+/// - `mouse: Down(Left)` matches a [`MouseEvent`] of the [`MouseEventKind`] variant with the given
+///   [`MouseButton`], e.g. `mouse: ScrollUp` or `mouse: Drag(Right)`, and `mouse` matches any mouse
+///   event, or binds it with `mouse(event)`
+/// - `resize` matches a resize of the terminal, or binds the new size with `resize(width, height)`
+/// - `paste` matches pasted text, or binds it with `paste(text)`
+/// - `focus_gained` and `focus_lost` match the terminal gaining and losing the focus
+///
+/// Like [`key!`], the pattern matches the events of crossterm, [termion] or [termwiz], depending on
+/// the enabled features, and a `backend: name;` directive before the kind chooses the backend.
+/// Their events are more limited than those of crossterm:
+///
+/// - a termion [`termion::event::Event`] is only a `key` or a `mouse` event, where
+///   `mouse: Down(Left)` matches a press of a button, `ScrollUp`, `ScrollDown`, `ScrollLeft` and
+///   `ScrollRight` match the scroll wheel, and `Up` and `Drag` match the release and holding of
+///   any button, since termion does not report which button it is
+/// - a termwiz [`termwiz::input::InputEvent`] is a `key`, a `mouse` event, a `resize` or a
+///   `paste`, and mouse events only support `mouse` and `mouse(event)`
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "crossterm")]
+/// # {
+/// # use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui_macros::event;
+///
+/// let event = Event::Resize(80, 24);
+/// let message = match event {
+///     event!(key: ctrl-'c') | event!(key: 'q') => "quit".to_string(),
+///     event!(key(key)) => format!("pressed {:?}", key.code),
+///     event!(mouse: Down(Left)) => "click".to_string(),
+///     event!(resize(width, height)) => format!("resized to {width}x{height}"),
+///     event!(paste(text)) => format!("pasted {text}"),
+///     event!(focus_gained) | event!(focus_lost) => "focus changed".to_string(),
+///     _ => "other".to_string(),
+/// };
+/// assert_eq!(message, "resized to 80x24");
+/// # }
+/// ```
+///
+/// With termwiz:
+///
+/// ```rust
+/// # #[cfg(feature = "termwiz")]
+/// # {
+/// use ratatui_macros::event;
+/// use termwiz::input::InputEvent;
+///
+/// let event = InputEvent::Paste("hello".to_string());
+/// let message = match event {
+///     event!(backend: termwiz; key: ctrl-'c') => "quit".to_string(),
+///     event!(backend: termwiz; resize(cols, rows)) => format!("resized to {cols}x{rows}"),
+///     event!(backend: termwiz; paste(text)) => format!("pasted {text}"),
+///     _ => "other".to_string(),
+/// };
+/// assert_eq!(message, "pasted hello");
+/// # }
+/// ```
+///
+/// [termion]: https://docs.rs/termion
+/// [termwiz]: https://docs.rs/termwiz
+/// [`Event`]: https://docs.rs/crossterm/latest/crossterm/event/enum.Event.html
+/// [`KeyEvent`]: https://docs.rs/crossterm/latest/crossterm/event/struct.KeyEvent.html
+/// [`MouseEvent`]: https://docs.rs/crossterm/latest/crossterm/event/struct.MouseEvent.html
+/// [`MouseEventKind`]: https://docs.rs/crossterm/latest/crossterm/event/enum.MouseEventKind.html
+/// [`MouseButton`]: https://docs.rs/crossterm/latest/crossterm/event/enum.MouseButton.html
+/// [`termion::event::Event`]: https://docs.rs/termion/latest/termion/event/enum.Event.html
+/// [`termwiz::input::InputEvent`]: https://docs.rs/termwiz/latest/termwiz/input/enum.InputEvent.html
+/// [`key!`]: crate::key!
+#[macro_export]
+macro_rules! event {
+    (@crossterm key : $($key:tt)+) => {
+        $crate::crossterm::event::Event::Key($crate::key!(backend: crossterm; $($key)+))
+    };
+    (@crossterm key ($event:pat)) => {
+        $crate::crossterm::event::Event::Key($event)
+    };
+    (@crossterm key) => {
+        $crate::crossterm::event::Event::Key(_)
+    };
+    (@crossterm mouse : $kind:ident $(($button:ident))?) => {
+        $crate::crossterm::event::Event::Mouse($crate::crossterm::event::MouseEvent {
+            kind: $crate::crossterm::event::MouseEventKind::$kind
+                $(($crate::crossterm::event::MouseButton::$button))?,
+            ..
+        })
+    };
+    (@crossterm mouse ($event:pat)) => {
+        $crate::crossterm::event::Event::Mouse($event)
+    };
+    (@crossterm mouse) => {
+        $crate::crossterm::event::Event::Mouse(_)
+    };
+    (@crossterm resize ($width:pat, $height:pat $(,)?)) => {
+        $crate::crossterm::event::Event::Resize($width, $height)
+    };
+    (@crossterm resize) => {
+        $crate::crossterm::event::Event::Resize(..)
+    };
+    (@crossterm paste ($text:pat)) => {
+        $crate::crossterm::event::Event::Paste($text)
+    };
+    (@crossterm paste) => {
+        $crate::crossterm::event::Event::Paste(_)
+    };
+    (@crossterm focus_gained) => {
+        $crate::crossterm::event::Event::FocusGained
+    };
+    (@crossterm focus_lost) => {
+        $crate::crossterm::event::Event::FocusLost
+    };
+    (@crossterm $($event:tt)*) => {
+        compile_error!(concat!(
+            "unknown event `",
+            stringify!($($event)*),
+            "`, expected `key`, `mouse`, `resize`, `paste`, `focus_gained` or `focus_lost`"
+        ))
+    };
+    (@termion key : $($key:tt)+) => {
+        $crate::termion::event::Event::Key($crate::key!(backend: termion; $($key)+))
+    };
+    (@termion key ($event:pat)) => {
+        $crate::termion::event::Event::Key($event)
+    };
+    (@termion key) => {
+        $crate::termion::event::Event::Key(_)
+    };
+    (@termion mouse : Down($button:ident)) => {
+        $crate::termion::event::Event::Mouse($crate::termion::event::MouseEvent::Press(
+            $crate::termion::event::MouseButton::$button,
+            ..
+        ))
+    };
+    (@termion mouse : Up) => {
+        $crate::termion::event::Event::Mouse($crate::termion::event::MouseEvent::Release(..))
+    };
+    (@termion mouse : Drag) => {
+        $crate::termion::event::Event::Mouse($crate::termion::event::MouseEvent::Hold(..))
+    };
+    (@termion mouse : ScrollUp) => {
+        $crate::event!(@termion mouse: Down(WheelUp))
+    };
+    (@termion mouse : ScrollDown) => {
+        $crate::event!(@termion mouse: Down(WheelDown))
+    };
+    (@termion mouse : ScrollLeft) => {
+        $crate::event!(@termion mouse: Down(WheelLeft))
+    };
+    (@termion mouse : ScrollRight) => {
+        $crate::event!(@termion mouse: Down(WheelRight))
+    };
+    (@termion mouse : $($kind:tt)*) => {
+        compile_error!(concat!(
+            "unknown kind of termion mouse event `",
+            stringify!($($kind)*),
+            "`, expected `Down(button)`, `Up`, `Drag`, `ScrollUp`, `ScrollDown`, `ScrollLeft` or ",
+            "`ScrollRight`"
+        ))
+    };
+    (@termion mouse ($event:pat)) => {
+        $crate::termion::event::Event::Mouse($event)
+    };
+    (@termion mouse) => {
+        $crate::termion::event::Event::Mouse(_)
+    };
+    (@termion $($event:tt)*) => {
+        compile_error!(concat!(
+            "unknown termion event `",
+            stringify!($($event)*),
+            "`, expected `key` or `mouse`"
+        ))
+    };
+    (@termwiz key : $($key:tt)+) => {
+        $crate::termwiz::input::InputEvent::Key($crate::key!(backend: termwiz; $($key)+))
+    };
+    (@termwiz key ($event:pat)) => {
+        $crate::termwiz::input::InputEvent::Key($event)
+    };
+    (@termwiz key) => {
+        $crate::termwiz::input::InputEvent::Key(_)
+    };
+    (@termwiz mouse ($event:pat)) => {
+        $crate::termwiz::input::InputEvent::Mouse($event)
+    };
+    (@termwiz mouse) => {
+        $crate::termwiz::input::InputEvent::Mouse(_)
+    };
+    (@termwiz resize ($width:pat, $height:pat $(,)?)) => {
+        $crate::termwiz::input::InputEvent::Resized { cols: $width, rows: $height }
+    };
+    (@termwiz resize) => {
+        $crate::termwiz::input::InputEvent::Resized { .. }
+    };
+    (@termwiz paste ($text:pat)) => {
+        $crate::termwiz::input::InputEvent::Paste($text)
+    };
+    (@termwiz paste) => {
+        $crate::termwiz::input::InputEvent::Paste(_)
+    };
+    (@termwiz $($event:tt)*) => {
+        compile_error!(concat!(
+            "unknown termwiz event `",
+            stringify!($($event)*),
+            "`, expected `key`, `mouse`, `mouse(event)`, `resize` or `paste`"
+        ))
+    };
+    (backend : crossterm ; $($event:tt)*) => {
+        $crate::event!(@crossterm $($event)*)
+    };
+    (backend : termion ; $($event:tt)*) => {
+        $crate::event!(@termion $($event)*)
+    };
+    (backend : termwiz ; $($event:tt)*) => {
+        $crate::event!(@termwiz $($event)*)
+    };
+    (backend : $backend:ident ; $($event:tt)*) => {
+        compile_error!(concat!(
+            "unknown backend `",
+            stringify!($backend),
+            "`, expected `crossterm`, `termion` or `termwiz`"
+        ))
+    };
+    ($($event:tt)*) => {
+        $crate::__default_backend!(event!($($event)*))
+    };
+}
+
+/// Expands a `key!` or `event!` invocation for the preferred backend among the enabled features:
+/// crossterm, then termion, then termwiz.
+#[cfg(feature = "crossterm")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __default_backend {
+    ($macro:ident ! ($($args:tt)*)) => {
+        $crate::$macro!(backend: crossterm; $($args)*)
+    };
+}
+
+/// Expands a `key!` or `event!` invocation for the preferred backend among the enabled features:
+/// crossterm, then termion, then termwiz.
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __default_backend {
+    ($macro:ident ! ($($args:tt)*)) => {
+        $crate::$macro!(backend: termion; $($args)*)
+    };
+}
+
+/// Expands a `key!` or `event!` invocation for the preferred backend among the enabled features:
+/// crossterm, then termion, then termwiz.
+#[cfg(all(
+    feature = "termwiz",
+    not(feature = "crossterm"),
+    not(feature = "termion")
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __default_backend {
+    ($macro:ident ! ($($args:tt)*)) => {
+        $crate::$macro!(backend: termwiz; $($args)*)
+    };
+}
+
+/// The [`KeyModifiers`] that are pressed, as constant parameters so that `key!` can refer to any
+/// combination of modifiers in a pattern.
+#[cfg(feature = "crossterm")]
+#[doc(hidden)]
+pub struct __KeyModifiers<
+    const SHIFT: bool,
+    const CONTROL: bool,
+    const ALT: bool,
+    const SUPER: bool,
+    const HYPER: bool,
+    const META: bool,
+>;
+
+#[cfg(feature = "crossterm")]
+impl<
+        const SHIFT: bool,
+        const CONTROL: bool,
+        const ALT: bool,
+        const SUPER: bool,
+        const HYPER: bool,
+        const META: bool,
+    > __KeyModifiers<SHIFT, CONTROL, ALT, SUPER, HYPER, META>
+{
+    pub const MODIFIERS: KeyModifiers = {
+        let mut modifiers = KeyModifiers::NONE;
+        if SHIFT {
+            modifiers = modifiers.union(KeyModifiers::SHIFT);
+        }
+        if CONTROL {
+            modifiers = modifiers.union(KeyModifiers::CONTROL);
+        }
+        if ALT {
+            modifiers = modifiers.union(KeyModifiers::ALT);
+        }
+        if SUPER {
+            modifiers = modifiers.union(KeyModifiers::SUPER);
+        }
+        if HYPER {
+            modifiers = modifiers.union(KeyModifiers::HYPER);
+        }
+        if META {
+            modifiers = modifiers.union(KeyModifiers::META);
+        }
+        modifiers
+    };
+}
+
+/// The termwiz [`Modifiers`] that are pressed, as constant parameters like [`__KeyModifiers`].
+///
+/// [`Modifiers`]: termwiz::input::Modifiers
+/// The [`KeyCode::Char`] of a char in `key!`, which is uppercased when `shift` is given.
+///
+/// [`KeyCode::Char`]: crossterm::event::KeyCode::Char
+#[cfg(feature = "crossterm")]
+#[doc(hidden)]
+pub struct __KeyChar<const CHAR: char, const SHIFT: bool>;
+
+#[cfg(feature = "crossterm")]
+impl<const CHAR: char, const SHIFT: bool> __KeyChar<CHAR, SHIFT> {
+    pub const CODE: KeyCode = if SHIFT {
+        KeyCode::Char(CHAR.to_ascii_uppercase())
+    } else {
+        KeyCode::Char(CHAR)
+    };
+}
+
+#[cfg(feature = "termwiz")]
+#[doc(hidden)]
+pub struct __TermwizModifiers<
+    const SHIFT: bool,
+    const CONTROL: bool,
+    const ALT: bool,
+    const SUPER: bool,
+>;
+
+#[cfg(feature = "termwiz")]
+impl<const SHIFT: bool, const CONTROL: bool, const ALT: bool, const SUPER: bool>
+    __TermwizModifiers<SHIFT, CONTROL, ALT, SUPER>
+{
+    pub const MODIFIERS: termwiz::input::Modifiers = {
+        use termwiz::input::Modifiers;
+
+        let mut modifiers = Modifiers::NONE;
+        if SHIFT {
+            modifiers = modifiers.union(Modifiers::SHIFT);
+        }
+        if CONTROL {
+            modifiers = modifiers.union(Modifiers::CTRL);
+        }
+        if ALT {
+            modifiers = modifiers.union(Modifiers::ALT);
+        }
+        if SUPER {
+            modifiers = modifiers.union(Modifiers::SUPER);
+        }
+        modifiers
+    };
+}
+
+#[cfg(all(test, feature = "crossterm"))]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+    fn event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn key_char() {
+        assert!(matches!(
+            event(KeyCode::Char('q'), KeyModifiers::NONE),
+            key!('q')
+        ));
+        assert!(!matches!(
+            event(KeyCode::Char('q'), KeyModifiers::CONTROL),
+            key!('q')
+        ));
+    }
+
+    #[test]
+    fn key_ignores_shift_for_chars() {
+        let shifted = event(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        assert!(matches!(shifted, key!('A')));
+        assert!(matches!(shifted, key!(shift - 'a')));
+        assert!(matches!(shifted, key!(shift - 'A')));
+        assert!(!matches!(shifted, key!('a')));
+        assert!(matches!(
+            event(KeyCode::Char('A'), KeyModifiers::NONE),
+            key!(shift - 'a')
+        ));
+        assert!(matches!(
+            event(
+                KeyCode::Char('P'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ),
+            key!(ctrl - shift - 'p')
+        ));
+        assert!(!matches!(
+            event(KeyCode::Char('P'), KeyModifiers::SHIFT),
+            key!(ctrl - shift - 'p')
+        ));
+    }
+
+    #[test]
+    fn key_modifiers() {
+        assert!(matches!(
+            event(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            key!(ctrl - 'c')
+        ));
+        assert!(matches!(
+            event(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+            key!(ctrl - shift - Up)
+        ));
+        assert!(matches!(
+            event(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+            key!(shift - ctrl - Up)
+        ));
+        assert!(matches!(
+            event(KeyCode::Enter, KeyModifiers::ALT | KeyModifiers::SUPER),
+            key!(alt - super - Enter)
+        ));
+        assert!(!matches!(
+            event(KeyCode::Up, KeyModifiers::CONTROL),
+            key!(ctrl - shift - Up)
+        ));
+    }
+
+    #[test]
+    fn key_codes() {
+        assert!(matches!(event(KeyCode::Esc, KeyModifiers::NONE), key!(Esc)));
+        assert!(matches!(
+            event(KeyCode::F(5), KeyModifiers::SHIFT),
+            key!(shift - F(5))
+        ));
+        assert!(!matches!(
+            event(KeyCode::F(4), KeyModifiers::SHIFT),
+            key!(shift - F(5))
+        ));
+    }
+
+    #[test]
+    fn key_ignores_kind() {
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL,
+            KeyEventKind::Release,
+        );
+        assert!(matches!(release, key!(ctrl - 'c')));
+    }
+
+    #[test]
+    fn event_kinds() {
+        use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+
+        let mouse = |kind| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column: 1,
+                row: 2,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let key = Event::Key(event(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(matches!(key, event!(key: ctrl - 'c')));
+        assert!(!matches!(key, event!(key: 'c')));
+        assert!(matches!(key, event!(key)));
+        assert!(matches!(
+            &key,
+            event!(key(KeyEvent {
+                code: KeyCode::Char('c'),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            mouse(MouseEventKind::Down(MouseButton::Left)),
+            event!(mouse: Down(Left))
+        ));
+        assert!(!matches!(
+            mouse(MouseEventKind::Down(MouseButton::Right)),
+            event!(mouse: Down(Left))
+        ));
+        assert!(matches!(
+            mouse(MouseEventKind::ScrollUp),
+            event!(mouse: ScrollUp)
+        ));
+        assert!(matches!(
+            mouse(MouseEventKind::Moved),
+            event!(mouse(MouseEvent { row: 2, .. }))
+        ));
+        assert!(matches!(Event::Resize(80, 24), event!(resize)));
+        assert!(matches!(Event::Resize(80, 24), event!(resize(80, _))));
+        assert!(matches!(Event::Paste("hi".into()), event!(paste)));
+        assert!(matches!(&Event::Paste("hi".into()), event!(paste(text)) if text == "hi"));
+        assert!(matches!(Event::FocusGained, event!(focus_gained)));
+        assert!(matches!(Event::FocusLost, event!(focus_lost)));
+        assert!(!matches!(Event::FocusLost, event!(focus_gained)));
+    }
+}
+
+#[cfg(all(test, feature = "termion"))]
+mod termion_tests {
+    use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+    #[test]
+    fn termion_keys() {
+        assert!(matches!(Key::Char('q'), key!(backend: termion; 'q')));
+        assert!(matches!(Key::Ctrl('c'), key!(backend: termion; ctrl - 'c')));
+        assert!(!matches!(
+            Key::Char('c'),
+            key!(backend: termion; ctrl - 'c')
+        ));
+        assert!(matches!(Key::Alt('x'), key!(backend: termion; alt - 'x')));
+        assert!(matches!(Key::Char('\n'), key!(backend: termion; Enter)));
+        assert!(matches!(Key::Char('\t'), key!(backend: termion; Tab)));
+        assert!(matches!(Key::BackTab, key!(backend: termion; shift - Tab)));
+        assert!(matches!(
+            Key::ShiftLeft,
+            key!(backend: termion; shift - Left)
+        ));
+        assert!(matches!(Key::CtrlDown, key!(backend: termion; ctrl - Down)));
+        assert!(matches!(Key::AltUp, key!(backend: termion; alt - Up)));
+        assert!(matches!(Key::CtrlEnd, key!(backend: termion; ctrl - End)));
+        assert!(matches!(Key::F(5), key!(backend: termion; F(5))));
+        assert!(matches!(Key::Esc, key!(backend: termion; Esc)));
+        assert!(!matches!(Key::Esc, key!(backend: termion; Backspace)));
+    }
+
+    #[test]
+    fn termion_events() {
+        let press = |button| Event::Mouse(MouseEvent::Press(button, 1, 2));
+        assert!(matches!(
+            Event::Key(Key::Ctrl('c')),
+            event!(backend: termion; key: ctrl - 'c')
+        ));
+        assert!(matches!(
+            Event::Key(Key::Esc),
+            event!(backend: termion; key)
+        ));
+        assert!(matches!(
+            Event::Key(Key::Up),
+            event!(backend: termion; key(Key::Up))
+        ));
+        assert!(matches!(
+            press(MouseButton::Left),
+            event!(backend: termion; mouse: Down(Left))
+        ));
+        assert!(!matches!(
+            press(MouseButton::Right),
+            event!(backend: termion; mouse: Down(Left))
+        ));
+        assert!(matches!(
+            press(MouseButton::WheelUp),
+            event!(backend: termion; mouse: ScrollUp)
+        ));
+        assert!(matches!(
+            Event::Mouse(MouseEvent::Release(1, 2)),
+            event!(backend: termion; mouse: Up)
+        ));
+        assert!(matches!(
+            Event::Mouse(MouseEvent::Hold(1, 2)),
+            event!(backend: termion; mouse(MouseEvent::Hold(1, _)))
+        ));
+        assert!(!matches!(
+            Event::Unsupported(vec![0]),
+            event!(backend: termion; mouse)
+        ));
+    }
+
+    #[cfg(not(feature = "crossterm"))]
+    #[test]
+    fn termion_default_backend() {
+        assert!(matches!(Key::Ctrl('c'), key!(ctrl - 'c')));
+        assert!(matches!(Event::Key(Key::Esc), event!(key: Esc)));
+    }
+}
+
+#[cfg(all(test, feature = "termwiz"))]
+mod termwiz_tests {
+    use termwiz::input::{InputEvent, KeyCode, KeyEvent, Modifiers, MouseButtons, MouseEvent};
+
+    fn event(key: KeyCode, modifiers: Modifiers) -> KeyEvent {
+        KeyEvent { key, modifiers }
+    }
+
+    #[test]
+    fn termwiz_keys() {
+        assert!(matches!(
+            event(KeyCode::Char('q'), Modifiers::NONE),
+            key!(backend: termwiz; 'q')
+        ));
+        assert!(!matches!(
+            event(KeyCode::Char('q'), Modifiers::CTRL),
+            key!(backend: termwiz; 'q')
+        ));
+        assert!(matches!(
+            event(KeyCode::UpArrow, Modifiers::CTRL | Modifiers::SHIFT),
+            key!(backend: termwiz; ctrl - shift - Up)
+        ));
+        assert!(matches!(
+            event(KeyCode::Enter, Modifiers::ALT | Modifiers::SUPER),
+            key!(backend: termwiz; alt - super - Enter)
+        ));
+        assert!(matches!(
+            event(KeyCode::Escape, Modifiers::NONE),
+            key!(backend: termwiz; Esc)
+        ));
+        assert!(matches!(
+            event(KeyCode::Function(5), Modifiers::SHIFT),
+            key!(backend: termwiz; shift - F(5))
+        ));
+        assert!(matches!(
+            event(KeyCode::PageDown, Modifiers::NONE),
+            key!(backend: termwiz; PageDown)
+        ));
+    }
+
+    #[test]
+    fn termwiz_events() {
+        let key = InputEvent::Key(event(KeyCode::Char('c'), Modifiers::CTRL));
+        assert!(matches!(key, event!(backend: termwiz; key: ctrl - 'c')));
+        assert!(matches!(key, event!(backend: termwiz; key)));
+        let mouse = InputEvent::Mouse(MouseEvent {
+            x: 1,
+            y: 2,
+            mouse_buttons: MouseButtons::LEFT,
+            modifiers: Modifiers::NONE,
+        });
+        assert!(matches!(
+            mouse,
+            event!(backend: termwiz; mouse(MouseEvent { y: 2, .. }))
+        ));
+        let resize = InputEvent::Resized { cols: 80, rows: 24 };
+        assert!(matches!(resize, event!(backend: termwiz; resize(80, _))));
+        assert!(matches!(
+            &InputEvent::Paste("hi".into()),
+            event!(backend: termwiz; paste(text)) if text == "hi"
+        ));
+        assert!(!matches!(InputEvent::Wake, event!(backend: termwiz; paste)));
+    }
+}
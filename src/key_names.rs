@@ -0,0 +1,10 @@
+//! The syntax of a key press like `ctrl-shift-p`, which is shared by [`KeyChord`],
+//! [`parse_key_sequence`], `keymap!` and `#[derive(KeyChord)]` so that they accept the same names.
+//!
+//! The names are defined in `proc-macros/src/key_names.rs`, which is the `key_names` module of the
+//! proc-macro crate and is included here.
+//!
+//! [`KeyChord`]: https://docs.rs/ratatui-macros/latest/ratatui_macros/struct.KeyChord.html
+//! [`parse_key_sequence`]: https://docs.rs/ratatui-macros/latest/ratatui_macros/fn.parse_key_sequence.html
+
+include!("../proc-macros/src/key_names.rs");
@@ -0,0 +1,511 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::key_names::{self, Code, KeyError};
+
+/// A macro for creating a [`Keymap`] from a list of key bindings.
+///
+/// Each binding is a `"keys" => action` pair. The keys are a sequence of key presses separated by
+/// spaces, e.g. `"g g"` or `"ctrl-x ctrl-s"`. Each key press is a list of modifiers followed by a
+/// key, separated by `-`:
+///
+/// - the modifiers are `ctrl`, `shift`, `alt`, `super`, `hyper` and `meta`
+/// - the key is a single character, e.g. `q` or `?`, a function key, e.g. `f5`, or the name of a
+///   key, e.g. `esc`, `enter`, `space`, `up` or `pagedown`
+///
+/// The keys are parsed at compile time, so a malformed key, a key sequence that is bound more than
+/// once, or a key sequence that is a prefix of another one is a compile error.
+///
+/// The expansion is a constant expression when the actions are, so a keymap can be stored in a
+/// `static` or `const` item.
+///
+/// This macro requires the `crossterm` and `proc-macros` features.
+///
+/// # Examples
+///
+/// ```rust
+/// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui_macros::{keymap, Keymap, KeymapMatch};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Action {
+///     Quit,
+///     Top,
+///     Save,
+/// }
+///
+/// static KEYMAP: Keymap<Action> = keymap! {
+///     "ctrl-q" => Action::Quit,
+///     "g g" => Action::Top,
+///     "ctrl-x ctrl-s" => Action::Save,
+/// };
+///
+/// let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+/// assert_eq!(KEYMAP.get(&[g]), KeymapMatch::Pending);
+/// assert_eq!(KEYMAP.get(&[g, g]), KeymapMatch::Action(&Action::Top));
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::keymap;
+/// let keymap = keymap! { "ctrl-" => () };
+/// ```
+#[cfg(feature = "proc-macros")]
+#[macro_export]
+macro_rules! keymap {
+    ($($bindings:tt)*) => {
+        $crate::ratatui_macros_proc::keymap!($crate, $($bindings)*)
+    };
+}
+
+/// A key press, with the modifiers that must be held.
+///
+/// When matching a [`KeyEvent`], the kind and state of the event are ignored. The `SHIFT` modifier
+/// is also ignored for characters, as the case of the character already says whether shift was
+/// pressed, so `G` matches a `G` key event whether the terminal reports shift or not. A letter with
+/// `shift` is uppercased when it is parsed, so `shift-g` is the same chord as `G`.
+///
+/// A key chord can also be parsed at runtime with [`str::parse`], in the syntax of a key press of
+/// [`keymap!`], e.g. for key bindings that are loaded from a configuration file.
+///
+/// ```rust
+/// # use crossterm::event::{KeyCode, KeyModifiers};
+/// use ratatui_macros::KeyChord;
+///
+/// let chord: KeyChord = "ctrl-shift-up".parse()?;
+/// assert_eq!(
+///     chord,
+///     KeyChord::new(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+/// );
+/// # Ok::<(), ratatui_macros::ParseKeyError>(())
+/// ```
+///
+/// [`keymap!`]: crate::keymap!
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    /// The key that is pressed
+    pub code: KeyCode,
+    /// The modifiers that are held while the key is pressed
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Creates a key chord from a key code and modifiers.
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Returns whether the given key event is this key chord.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        if self.code != event.code {
+            return false;
+        }
+        match self.code {
+            KeyCode::Char(_) => {
+                self.modifiers.difference(KeyModifiers::SHIFT)
+                    == event.modifiers.difference(KeyModifiers::SHIFT)
+            }
+            _ => self.modifiers == event.modifiers,
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ParseKeyError;
+
+    /// Parses a key press, e.g. `q`, `ctrl-q`, `shift-f5` or `esc`.
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (pressed, code) = key_names::parse_key(spec).map_err(|err| match err {
+            KeyError::DuplicateModifier(_) => ParseKeyError::new("duplicate modifier in", spec),
+            KeyError::UnknownKey(_) => {
+                let message = "unknown key, expected a character, a function key like `f5` or a \
+                               key name like `esc`, optionally preceded by modifiers like `ctrl-`, \
+                               in";
+                ParseKeyError::new(message, spec)
+            }
+        })?;
+        let modifiers = [
+            KeyModifiers::SHIFT,
+            KeyModifiers::CONTROL,
+            KeyModifiers::ALT,
+            KeyModifiers::SUPER,
+            KeyModifiers::HYPER,
+            KeyModifiers::META,
+        ]
+        .into_iter()
+        .zip(pressed)
+        .filter(|(_, pressed)| *pressed)
+        .fold(KeyModifiers::NONE, |modifiers, (modifier, _)| {
+            modifiers | modifier
+        });
+        Ok(Self::new(key_code(code), modifiers))
+    }
+}
+
+/// Converts a key of [`key_names`] into the crossterm key code of the same name.
+fn key_code(code: Code) -> KeyCode {
+    match code {
+        Code::Char(c) => KeyCode::Char(c),
+        Code::F(number) => KeyCode::F(number),
+        Code::Named("Backspace") => KeyCode::Backspace,
+        Code::Named("Enter") => KeyCode::Enter,
+        Code::Named("Left") => KeyCode::Left,
+        Code::Named("Right") => KeyCode::Right,
+        Code::Named("Up") => KeyCode::Up,
+        Code::Named("Down") => KeyCode::Down,
+        Code::Named("Home") => KeyCode::Home,
+        Code::Named("End") => KeyCode::End,
+        Code::Named("PageUp") => KeyCode::PageUp,
+        Code::Named("PageDown") => KeyCode::PageDown,
+        Code::Named("Tab") => KeyCode::Tab,
+        Code::Named("BackTab") => KeyCode::BackTab,
+        Code::Named("Delete") => KeyCode::Delete,
+        Code::Named("Insert") => KeyCode::Insert,
+        Code::Named("Esc") => KeyCode::Esc,
+        Code::Named(variant) => unreachable!("`{variant}` is not a key name of `key_names`"),
+    }
+}
+
+/// Parses a sequence of key presses from a string in the syntax of [`keymap!`], e.g. `"g g"` or
+/// `"ctrl-x ctrl-s"`.
+///
+/// The key presses are separated by whitespace and each one is parsed like a [`KeyChord`]. This is
+/// useful for key bindings that are loaded at runtime, e.g. from a configuration file;
+/// [`keymap!`] parses key bindings at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use crossterm::event::{KeyCode, KeyModifiers};
+/// use ratatui_macros::{parse_key_sequence, KeyChord};
+///
+/// let keys = parse_key_sequence("ctrl-x ctrl-s")?;
+/// assert_eq!(
+///     keys,
+///     [
+///         KeyChord::new(KeyCode::Char('x'), KeyModifiers::CONTROL),
+///         KeyChord::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+///     ]
+/// );
+///
+/// let error = parse_key_sequence("g ctrl-ctrl-q").unwrap_err();
+/// assert_eq!(error.to_string(), "duplicate modifier in `ctrl-ctrl-q`");
+/// assert_eq!(error.key(), "ctrl-ctrl-q");
+/// # Ok::<(), ratatui_macros::ParseKeyError>(())
+/// ```
+///
+/// [`keymap!`]: crate::keymap!
+pub fn parse_key_sequence(spec: &str) -> Result<Vec<KeyChord>, ParseKeyError> {
+    let keys = spec
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if keys.is_empty() {
+        return Err(ParseKeyError::new(
+            "expected a key like `ctrl-q`, found an empty string",
+            "",
+        ));
+    }
+    Ok(keys)
+}
+
+/// The error returned when parsing a [`KeyChord`] or a [`parse_key_sequence`] fails.
+///
+/// [`parse_key_sequence`]: crate::parse_key_sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyError {
+    message: &'static str,
+    key: String,
+}
+
+impl ParseKeyError {
+    fn new(message: &'static str, key: &str) -> Self {
+        Self {
+            message,
+            key: key.to_owned(),
+        }
+    }
+
+    /// Returns the key press that is invalid.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key.is_empty() {
+            f.write_str(self.message)
+        } else {
+            write!(f, "{} `{}`", self.message, self.key)
+        }
+    }
+}
+
+impl Error for ParseKeyError {}
+
+/// A table of key sequences and the actions they are bound to, usually created with [`keymap!`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Keymap<'a, A> {
+    bindings: &'a [(&'a [KeyChord], A)],
+}
+
+/// The result of looking up a key sequence in a [`Keymap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeymapMatch<'a, A> {
+    /// The keys are bound to the action
+    Action(&'a A),
+    /// The keys are the start of one or more key sequences, so more keys are needed
+    Pending,
+    /// The keys are not bound
+    None,
+}
+
+impl<'a, A> Keymap<'a, A> {
+    /// Creates a keymap from a list of key sequences and actions.
+    pub const fn new(bindings: &'a [(&'a [KeyChord], A)]) -> Self {
+        Self { bindings }
+    }
+
+    /// Returns the key sequences and actions of the keymap.
+    pub const fn bindings(&self) -> &'a [(&'a [KeyChord], A)] {
+        self.bindings
+    }
+
+    /// Looks up the action that is bound to the given key events, which are the keys pressed
+    /// since the last action, in order.
+    pub fn get(&self, keys: &[KeyEvent]) -> KeymapMatch<'a, A> {
+        let mut result = KeymapMatch::None;
+        for (sequence, action) in self.bindings {
+            if sequence.len() < keys.len() {
+                continue;
+            }
+            let matches = sequence
+                .iter()
+                .zip(keys)
+                .all(|(chord, event)| chord.matches(event));
+            if !matches {
+                continue;
+            }
+            if sequence.len() == keys.len() {
+                return KeymapMatch::Action(action);
+            }
+            result = KeymapMatch::Pending;
+        }
+        result
+    }
+}
+
+#[cfg(all(test, feature = "proc-macros"))]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+    use crate::{KeyChord, Keymap, KeymapMatch};
+
+    #[derive(Debug, PartialEq)]
+    enum Action {
+        Quit,
+        Top,
+        Bottom,
+        Save,
+        Help,
+    }
+
+    static KEYMAP: Keymap<Action> = keymap! {
+        "ctrl-q" => Action::Quit,
+        "g g" => Action::Top,
+        "G" => Action::Bottom,
+        "ctrl-x ctrl-s" => Action::Save,
+        "f1" => Action::Help,
+    };
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn keymap_bindings() {
+        assert_eq!(
+            KEYMAP.bindings()[0],
+            (
+                &[KeyChord::new(KeyCode::Char('q'), KeyModifiers::CONTROL)][..],
+                Action::Quit
+            )
+        );
+        assert_eq!(KEYMAP.bindings().len(), 5);
+    }
+
+    #[test]
+    fn keymap_get() {
+        let ctrl_q = key(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        let q = key(KeyCode::Char('q'), KeyModifiers::NONE);
+        let f1 = key(KeyCode::F(1), KeyModifiers::NONE);
+        assert_eq!(KEYMAP.get(&[ctrl_q]), KeymapMatch::Action(&Action::Quit));
+        assert_eq!(KEYMAP.get(&[f1]), KeymapMatch::Action(&Action::Help));
+        assert_eq!(KEYMAP.get(&[q]), KeymapMatch::None);
+        assert_eq!(KEYMAP.get(&[]), KeymapMatch::Pending);
+    }
+
+    #[test]
+    fn keymap_sequences() {
+        let g = key(KeyCode::Char('g'), KeyModifiers::NONE);
+        let ctrl_x = key(KeyCode::Char('x'), KeyModifiers::CONTROL);
+        let ctrl_s = key(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(KEYMAP.get(&[g]), KeymapMatch::Pending);
+        assert_eq!(KEYMAP.get(&[g, g]), KeymapMatch::Action(&Action::Top));
+        assert_eq!(KEYMAP.get(&[g, g, g]), KeymapMatch::None);
+        assert_eq!(KEYMAP.get(&[ctrl_x]), KeymapMatch::Pending);
+        assert_eq!(
+            KEYMAP.get(&[ctrl_x, ctrl_s]),
+            KeymapMatch::Action(&Action::Save)
+        );
+        assert_eq!(KEYMAP.get(&[ctrl_x, g]), KeymapMatch::None);
+    }
+
+    #[test]
+    fn keymap_ignores_shift_for_chars() {
+        let shift_g = key(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        let g = key(KeyCode::Char('G'), KeyModifiers::NONE);
+        assert_eq!(KEYMAP.get(&[shift_g]), KeymapMatch::Action(&Action::Bottom));
+        assert_eq!(KEYMAP.get(&[g]), KeymapMatch::Action(&Action::Bottom));
+    }
+
+    #[test]
+    fn keymap_shift_letter() {
+        let keymap = keymap! { "shift-a" => 1, "ctrl-shift-p" => 2 };
+        let shift_a = key(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        let ctrl_shift_p = key(
+            KeyCode::Char('P'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        );
+        assert_eq!(keymap.get(&[shift_a]), KeymapMatch::Action(&1));
+        assert_eq!(keymap.get(&[ctrl_shift_p]), KeymapMatch::Action(&2));
+    }
+
+    #[test]
+    fn key_chord_matches() {
+        let chord = KeyChord::new(KeyCode::Up, KeyModifiers::CONTROL);
+        assert!(chord.matches(&KeyEvent::new_with_kind(
+            KeyCode::Up,
+            KeyModifiers::CONTROL,
+            KeyEventKind::Release
+        )));
+        assert!(!chord.matches(&key(KeyCode::Up, KeyModifiers::NONE)));
+        assert!(!chord.matches(&key(
+            KeyCode::Up,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+    }
+
+    #[test]
+    fn keymap_local() {
+        let keymap = keymap! { "q" => 1, "esc" => 2 };
+        let esc = key(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(keymap.get(&[esc]), KeymapMatch::Action(&2));
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    use crate::{key_names, parse_key_sequence, KeyChord};
+
+    fn parse(spec: &str) -> Result<KeyChord, String> {
+        spec.parse::<KeyChord>().map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn parse_key() {
+        let key = |code| Ok(KeyChord::new(code, KeyModifiers::NONE));
+        assert_eq!(parse("q"), key(KeyCode::Char('q')));
+        assert_eq!(parse("Q"), key(KeyCode::Char('Q')));
+        assert_eq!(parse("-"), key(KeyCode::Char('-')));
+        assert_eq!(parse("space"), key(KeyCode::Char(' ')));
+        assert_eq!(parse("F5"), key(KeyCode::F(5)));
+        assert_eq!(parse("esc"), key(KeyCode::Esc));
+        assert_eq!(parse("PageDown"), key(KeyCode::PageDown));
+        assert!(parse("f25").is_err());
+        assert!(parse("escc").is_err());
+    }
+
+    #[test]
+    fn parse_shift_letter() {
+        let shift_a: KeyChord = "shift-a".parse().unwrap();
+        assert_eq!(
+            shift_a,
+            KeyChord::new(KeyCode::Char('A'), KeyModifiers::SHIFT)
+        );
+        assert!(shift_a.matches(&crossterm::event::KeyEvent::new(
+            KeyCode::Char('A'),
+            KeyModifiers::SHIFT
+        )));
+        assert_eq!(
+            parse("shift-1"),
+            Ok(KeyChord::new(KeyCode::Char('1'), KeyModifiers::SHIFT))
+        );
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        let ctrl_shift = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+        assert_eq!(
+            parse("Ctrl--"),
+            Ok(KeyChord::new(KeyCode::Char('-'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse("ctrl-shift-up"),
+            Ok(KeyChord::new(KeyCode::Up, ctrl_shift))
+        );
+        assert_eq!(
+            parse("shift-control-up"),
+            Ok(KeyChord::new(KeyCode::Up, ctrl_shift))
+        );
+        assert_eq!(
+            parse("alt-super-hyper-meta-x"),
+            Ok(KeyChord::new(
+                KeyCode::Char('x'),
+                KeyModifiers::ALT | KeyModifiers::SUPER | KeyModifiers::HYPER | KeyModifiers::META
+            ))
+        );
+        assert_eq!(
+            parse("ctrl-ctrl-q"),
+            Err("duplicate modifier in `ctrl-ctrl-q`".to_string())
+        );
+        assert_eq!(
+            parse("cmd-q"),
+            Err(
+                "unknown key, expected a character, a function key like `f5` or a key name like \
+                 `esc`, optionally preceded by modifiers like `ctrl-`, in `cmd-q`"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_sequence() {
+        assert_eq!(
+            parse_key_sequence(" g  g "),
+            Ok(vec![
+                KeyChord::new(KeyCode::Char('g'), KeyModifiers::NONE);
+                2
+            ])
+        );
+        assert_eq!(
+            parse_key_sequence("  ").unwrap_err().to_string(),
+            "expected a key like `ctrl-q`, found an empty string"
+        );
+        assert_eq!(parse_key_sequence("g f99").unwrap_err().key(), "f99");
+    }
+
+    #[test]
+    fn parse_shared_names() {
+        // `keymap!` and `#[derive(KeyChord)]` parse the same table of names at compile time
+        for (modifier, _) in key_names::MODIFIERS {
+            for (name, variant) in key_names::NAMED_KEYS {
+                let chord = parse(&format!("{modifier}-{name}")).unwrap();
+                assert_eq!(format!("{:?}", chord.code), *variant);
+            }
+        }
+    }
+}
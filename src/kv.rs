@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use ratatui_core::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+/// A macro for creating a [`Line`] that shows a key and a value, e.g. `branch: main`.
+///
+/// The arguments are the key and the value, which are formatted with their `Display`
+/// implementations. The key and the separator are dimmed, and the separator is `": "` by default.
+/// The line contains the key and the separator in one span and the value in another, so it can be
+/// inserted into [`line!`] with `..kv!(...)`.
+///
+/// The key and value can be preceded by `key: value` directives, separated from them by a `;`:
+///
+/// - `key_style: style` sets the style of the key and the separator
+/// - `value_style: style` sets the style of the value
+/// - `separator: " = "` replaces the separator
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Style, Stylize}, text::{Line, Span}};
+/// use ratatui_macros::{kv, line};
+///
+/// let branch = "main";
+/// let line = kv!("branch", branch);
+/// assert_eq!(
+///     line,
+///     Line::from(vec![Span::raw("branch: ").dim(), Span::raw("main")])
+/// );
+///
+/// let line = kv!(value_style: Style::new().green(), separator: " = "; "ahead", 3);
+/// assert_eq!(line.to_string(), "ahead = 3");
+///
+/// let line = line!["dev ", ..kv!("pid", 1234)];
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`line!`]: crate::line!
+#[macro_export]
+macro_rules! kv {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::kv!(@directives [$($directives)* .$setting($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::kv!(@directives [$($directives)* .$setting($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $key:expr, $value:expr $(,)?) => {
+        $crate::__KeyValue::default() $($directives)* .line(&$key, &$value)
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::kv!(@directives [] $setting : $($rest)*)
+    };
+    ($key:expr, $value:expr $(,)?) => {
+        $crate::__KeyValue::default().line(&$key, &$value)
+    };
+}
+
+/// The styles and separator of a key-value pair. See [`kv!`].
+///
+/// [`kv!`]: crate::kv!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __KeyValue<'a> {
+    key_style: Style,
+    value_style: Style,
+    separator: &'a str,
+}
+
+impl Default for __KeyValue<'_> {
+    fn default() -> Self {
+        Self {
+            key_style: Style::new().add_modifier(Modifier::DIM),
+            value_style: Style::new(),
+            separator: ": ",
+        }
+    }
+}
+
+impl<'a> __KeyValue<'a> {
+    pub fn key_style(mut self, style: impl Into<Style>) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    pub fn value_style(mut self, style: impl Into<Style>) -> Self {
+        self.value_style = style.into();
+        self
+    }
+
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    pub fn line(self, key: &impl Display, value: &impl Display) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("{key}{}", self.separator), self.key_style),
+            Span::styled(value.to_string(), self.value_style),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Span},
+    };
+
+    use crate::line;
+
+    #[test]
+    fn kv_default() {
+        let name = String::from("main");
+        assert_eq!(
+            kv!("branch", name),
+            Line::from(vec![Span::raw("branch: ").dim(), Span::raw("main")])
+        );
+        assert_eq!(kv!("count", 3,).to_string(), "count: 3");
+    }
+
+    #[test]
+    fn kv_directives() {
+        let line = kv!(
+            key_style: Color::Gray,
+            value_style: Style::new().bold(),
+            separator: " = ";
+            "pid",
+            42,
+        );
+        assert_eq!(
+            line,
+            Line::from(vec![Span::raw("pid = ").gray(), Span::raw("42").bold()])
+        );
+        assert_eq!(
+            kv!(separator: "=" ; "a", 1),
+            Line::from(vec![Span::raw("a=").dim(), Span::raw("1")])
+        );
+    }
+
+    #[test]
+    fn kv_in_line() {
+        let line = line![..kv!("a", 1), " ", ..kv!("b", 2)];
+        assert_eq!(line.to_string(), "a: 1 b: 2");
+        assert_eq!(line.spans.len(), 5);
+    }
+}
@@ -1,8 +1,24 @@
+use ratatui_core::layout::{Constraint, Direction, Layout, Rect};
+
 /// Creates a single constraint.
 ///
 /// If creating an array of constraints, you probably want to use
 /// [`constraints!`] instead.
 ///
+/// Each variant of [`Constraint`] has its own syntax:
+///
+/// | Syntax    | Constraint                   |
+/// |-----------|------------------------------|
+/// | `==n`     | [`Constraint::Length`]`(n)`     |
+/// | `==n%`    | [`Constraint::Percentage`]`(n)` |
+/// | `==a/b`   | [`Constraint::Ratio`]`(a, b)`   |
+/// | `>=n`     | [`Constraint::Min`]`(n)`        |
+/// | `<=n`     | [`Constraint::Max`]`(n)`        |
+/// | `*=n`     | [`Constraint::Fill`]`(n)`       |
+///
+/// The weight of a fill is the share of the remaining space that it gets, e.g. `*=2` is twice as
+/// large as `*=1`.
+///
 /// # Examples
 ///
 /// ```
@@ -14,7 +30,16 @@
 /// assert_eq!(constraint!(== 3), Constraint::Length(3));
 /// assert_eq!(constraint!(== 10 %), Constraint::Percentage(10));
 /// assert_eq!(constraint!(*= 1), Constraint::Fill(1));
+/// assert_eq!(constraint!(*= 2), Constraint::Fill(2));
 /// ```
+///
+/// [`Constraint`]: ratatui_core::layout::Constraint
+/// [`Constraint::Length`]: ratatui_core::layout::Constraint::Length
+/// [`Constraint::Percentage`]: ratatui_core::layout::Constraint::Percentage
+/// [`Constraint::Ratio`]: ratatui_core::layout::Constraint::Ratio
+/// [`Constraint::Min`]: ratatui_core::layout::Constraint::Min
+/// [`Constraint::Max`]: ratatui_core::layout::Constraint::Max
+/// [`Constraint::Fill`]: ratatui_core::layout::Constraint::Fill
 #[macro_export]
 macro_rules! constraint {
     (== $token:tt %) => {
@@ -44,6 +69,10 @@ macro_rules! constraint {
 /// If you want to solve the constraints, see
 /// [`vertical!`] and [`horizontal!`] macros.
 ///
+/// An empty list of constraints is a compile error. With the `proc-macros` feature, a list of
+/// literal percentages that add up to more than 100%, e.g. `==60%, ==60%`, a literal percentage
+/// over 100% and a ratio with a literal denominator of 0, e.g. `==1/0`, are also compile errors.
+///
 /// # Examples
 ///
 /// ```rust
@@ -69,6 +98,10 @@ macro_rules! constraint {
 /// ```
 #[macro_export]
 macro_rules! constraints {
+    () => {
+        compile_error!("expected at least one constraint, e.g. `constraints![==50%, *=1]`")
+    };
+
     // Note: this implementation forgoes speed for the sake of simplicity. Adding variations of the
     // comma and semicolon rules for each constraint type would be faster, but would result in a lot
     // of duplicated code.
@@ -149,27 +182,77 @@ macro_rules! constraints {
     // Entrypoint where there's no comma at the end.
     // We add a comma to make sure there's always a trailing comma.
     // Right-hand side will accumulate the actual `Constraint` literals.
-    ($( $constraint:tt )+) => {
+    (@checked $( $constraint:tt )+) => {
         $crate::constraints!([ $($constraint)+ , ] -> () [])
     };
+    ($( $constraint:tt )+) => {
+        $crate::__check!(check_constraints [$crate::constraints!] [@checked] $($constraint)+)
+    };
 }
 
 /// Creates a vertical layout with specified constraints.
 ///
 /// It accepts a series of constraints and applies them to create a vertical layout. The constraints
-/// can include fixed sizes, minimum and maximum sizes, percentages, and ratios.
+/// can include fixed sizes, minimum and maximum sizes, percentages, ratios and weighted fills.
 ///
 /// See [`constraint!`]  or [`constraints!`] for more information.
 ///
+/// The constraints can be preceded by a list of `key: value` directives separated from the
+/// constraints by a `;`. Each key is the name of a builder method on [`Layout`], e.g. `margin: 1`,
+/// `horizontal_margin: 2`, `vertical_margin: 1`, `spacing: 1` or `flex: Flex::Center`. The `flex`
+/// directive also accepts the name of a [`Flex`] variant, e.g. `flex: SpaceBetween`,
+/// `flex: Center`, `flex: Start`, `flex: End` or `flex: SpaceAround`.
+///
 /// # Examples
 ///
 /// ```
 /// // Vertical layout with a fixed size and a percentage constraint
 /// use ratatui_macros::vertical;
 /// vertical![== 50, == 30%];
+///
+/// // Five rows of a single line each
+/// vertical![== 1; 5];
+///
+/// // A body that is twice as tall as the footer
+/// vertical![*= 2, *= 1];
+///
+/// // A header and a body with a margin around them and a line between them
+/// vertical![margin: 1, spacing: 1; == 3, *= 1];
+///
+/// // Three rows that are centered in the area
+/// vertical![flex: Center; == 1; 3];
 /// ```
+///
+/// [`Layout`]: ratatui_core::layout::Layout
+/// [`Flex`]: ratatui_core::layout::Flex
 #[macro_export]
 macro_rules! vertical {
+    () => {
+        compile_error!("expected at least one constraint, e.g. `vertical![==50%, *=1]`")
+    };
+    (@directives [$($directives:tt)*] flex : $flex:ident , $($rest:tt)*) => {
+        $crate::vertical!(@directives
+            [$($directives)* .flex($crate::ratatui_core::layout::Flex::$flex)]
+            $($rest)*
+        )
+    };
+    (@directives [$($directives:tt)*] flex : $flex:ident ; $($constraint:tt)+) => {
+        $crate::vertical!(@directives
+            [$($directives)*]
+            flex : $crate::ratatui_core::layout::Flex::$flex ; $($constraint)+
+        )
+    };
+    (@directives [$($directives:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::vertical!(@directives [$($directives)* .$key($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $key:ident : $value:expr ; $($constraint:tt)+) => {
+        $crate::ratatui_core::layout::Layout::vertical($crate::constraints!( $($constraint)+ ))
+            $($directives)*
+            .$key($value)
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::vertical!(@directives [] $key : $($rest)*)
+    };
     ($( $constraint:tt )+) => {
         $crate::ratatui_core::layout::Layout::vertical($crate::constraints!( $($constraint)+ ))
     };
@@ -178,20 +261,886 @@ macro_rules! vertical {
 /// Creates a horizontal layout with specified constraints.
 ///
 /// It takes a series of constraints and applies them to create a horizontal layout. The constraints
-/// can include fixed sizes, minimum and maximum sizes, percentages, and ratios.
+/// can include fixed sizes, minimum and maximum sizes, percentages, ratios and weighted fills.
 ///
 /// See [`constraint!`]  or [`constraints!`] for more information.
 ///
+/// The constraints can be preceded by `key: value` directives, like in [`vertical!`].
+///
 /// # Examples
 ///
 /// ```
 /// // Horizontal layout with a ratio constraint and a minimum size constraint
 /// use ratatui_macros::horizontal;
 /// horizontal![== 1/3, >= 100];
+///
+/// // Three columns of equal width
+/// horizontal![*= 1; 3];
+///
+/// // A third, and then two columns that share the rest of the width
+/// horizontal![== 1/3, *= 1, *= 3];
+///
+/// // Two columns with a gap between them
+/// horizontal![horizontal_margin: 2, spacing: 1; *= 1, *= 1];
+///
+/// // Three buttons with the remaining space between them
+/// horizontal![flex: SpaceBetween; == 10, == 10, == 10];
 /// ```
 #[macro_export]
 macro_rules! horizontal {
+    () => {
+        compile_error!("expected at least one constraint, e.g. `horizontal![==50%, *=1]`")
+    };
+    (@directives [$($directives:tt)*] flex : $flex:ident , $($rest:tt)*) => {
+        $crate::horizontal!(@directives
+            [$($directives)* .flex($crate::ratatui_core::layout::Flex::$flex)]
+            $($rest)*
+        )
+    };
+    (@directives [$($directives:tt)*] flex : $flex:ident ; $($constraint:tt)+) => {
+        $crate::horizontal!(@directives
+            [$($directives)*]
+            flex : $crate::ratatui_core::layout::Flex::$flex ; $($constraint)+
+        )
+    };
+    (@directives [$($directives:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::horizontal!(@directives [$($directives)* .$key($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $key:ident : $value:expr ; $($constraint:tt)+) => {
+        $crate::ratatui_core::layout::Layout::horizontal($crate::constraints!( $($constraint)+ ))
+            $($directives)*
+            .$key($value)
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::horizontal!(@directives [] $key : $($rest)*)
+    };
     ($( $constraint:tt )+) => {
         $crate::ratatui_core::layout::Layout::horizontal($crate::constraints!( $($constraint)+ ))
     };
 }
+
+/// Splits an area into an array of areas, e.g. `let [top, bottom] = areas!(area; ==3, *=1);`.
+///
+/// The first argument is the area, optionally followed by `, horizontal` or `, vertical` (the
+/// default) for the direction of the layout, and the constraints come after a `;` (see
+/// [`constraints!`] for more information). Unlike [`Layout::areas`], which panics when the number
+/// of areas does not match the number of constraints, the length of the array is the number of
+/// constraints, so destructuring it into the wrong number of variables is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::areas;
+///
+/// let area = Rect::new(0, 0, 10, 10);
+/// let [top, middle, bottom] = areas!(area; ==3, *=1, ==1);
+/// assert_eq!(middle, Rect::new(0, 3, 10, 6));
+///
+/// let [left, right] = areas!(area, horizontal; *=1; 2);
+/// assert_eq!(right, Rect::new(5, 0, 5, 10));
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_core::layout::Rect;
+/// # use ratatui_macros::areas;
+/// let [top, bottom] = areas!(Rect::new(0, 0, 10, 10); ==3, *=1, ==1);
+/// ```
+///
+/// [`Layout::areas`]: ratatui_core::layout::Layout::areas
+#[macro_export]
+macro_rules! areas {
+    ($area:expr, horizontal; $($constraint:tt)+) => {
+        $crate::__areas(
+            $crate::ratatui_core::layout::Direction::Horizontal,
+            $crate::constraints![$($constraint)+],
+            $area,
+        )
+    };
+    ($area:expr, vertical; $($constraint:tt)+) => {
+        $crate::__areas(
+            $crate::ratatui_core::layout::Direction::Vertical,
+            $crate::constraints![$($constraint)+],
+            $area,
+        )
+    };
+    ($area:expr; $($constraint:tt)+) => {
+        $crate::areas!($area, vertical; $($constraint)+)
+    };
+}
+
+/// Splits an area into a grid of rows and columns and returns a 2D array of the cells.
+///
+/// The `rows` and `cols` are lists of constraints in square brackets, with the syntax of
+/// [`constraints!`]. The area is split vertically into the rows, and each row is split
+/// horizontally into the columns, so `grid[row][col]` is the [`Rect`] of a cell. The array has one
+/// element per constraint, so its size is checked by the compiler like in [`areas!`].
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::grid;
+///
+/// let area = Rect::new(0, 0, 40, 3);
+/// let cells: [[Rect; 4]; 3] = grid!(area, rows: [==1; 3], cols: [*=1; 4]);
+/// assert_eq!(cells[1][2], Rect::new(20, 1, 10, 1));
+///
+/// let [[header, _], [sidebar, main]] = grid!(area, rows: [==1, *=1], cols: [==10, *=1]);
+/// assert_eq!(main, Rect::new(10, 1, 30, 2));
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`areas!`]: crate::areas!
+/// [`constraints!`]: crate::constraints!
+#[macro_export]
+macro_rules! grid {
+    ($area:expr, rows: [$($rows:tt)+], cols: [$($cols:tt)+] $(,)?) => {
+        $crate::__grid(
+            $area,
+            $crate::constraints![$($rows)+],
+            $crate::constraints![$($cols)+],
+        )
+    };
+}
+
+/// Splits the area into the rows and then each row into the columns. See [`grid!`].
+///
+/// [`grid!`]: crate::grid!
+#[doc(hidden)]
+pub fn __grid<const R: usize, const C: usize>(
+    area: Rect,
+    rows: [Constraint; R],
+    cols: [Constraint; C],
+) -> [[Rect; C]; R] {
+    let columns = Layout::horizontal(cols);
+    Layout::vertical(rows)
+        .areas(area)
+        .map(|row| columns.areas(row))
+}
+
+/// Splits an area into a struct with a named field for each area, e.g.
+/// `let chunks = split!(area; header: ==3, body: *=1);`.
+///
+/// The first argument is the area, optionally followed by `, horizontal` or `, vertical` (the
+/// default) like in [`areas!`], and after a `;` comes a list of `name: constraint` pairs (see
+/// [`constraint!`] for the syntax of a constraint). The macro returns a value of an anonymous
+/// struct whose fields are the [`Rect`]s of the areas, named after the pairs, so that the areas
+/// are accessed as `chunks.header` instead of `chunks[0]`. The struct implements `Clone`, `Copy`,
+/// `Debug` and `PartialEq`.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::split;
+///
+/// let area = Rect::new(0, 0, 10, 10);
+/// let chunks = split!(area; header: ==3, body: *=1, footer: ==1);
+/// assert_eq!(chunks.body, Rect::new(0, 3, 10, 6));
+///
+/// let columns = split!(area, horizontal; sidebar: ==30%, main: *=1);
+/// assert_eq!(columns.main, Rect::new(3, 0, 7, 10));
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+#[macro_export]
+macro_rules! split {
+    ($area:expr, horizontal; $($fields:tt)+) => {
+        $crate::split!(@name Horizontal $area; [] [] $($fields)+)
+    };
+    ($area:expr, vertical; $($fields:tt)+) => {
+        $crate::split!(@name Vertical $area; [] [] $($fields)+)
+    };
+    (@name $direction:ident $area:expr; [$($names:ident)*] [$($constraints:tt)*]) => {{
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        struct Areas {
+            $($names: $crate::ratatui_core::layout::Rect,)*
+        }
+        let [$($names),*] = $crate::__areas(
+            $crate::ratatui_core::layout::Direction::$direction,
+            [$($constraints)*],
+            $area,
+        );
+        Areas { $($names),* }
+    }};
+    (@name $direction:ident $area:expr; [$($names:ident)*] [$($constraints:tt)*]
+        $name:ident : $($rest:tt)+
+    ) => {
+        $crate::split!(@constraint $direction $area; [$($names)* $name] [$($constraints)*] ()
+            $($rest)+
+        )
+    };
+    (@constraint $direction:ident $area:expr; [$($names:ident)*] [$($constraints:tt)*]
+        ($($partial:tt)+) $(, $($rest:tt)*)?
+    ) => {
+        $crate::split!(@name $direction $area; [$($names)*]
+            [$($constraints)* $crate::constraint!($($partial)+),]
+            $($($rest)*)?
+        )
+    };
+    (@constraint $direction:ident $area:expr; [$($names:ident)*] [$($constraints:tt)*]
+        ($($partial:tt)*) $head:tt $($rest:tt)*
+    ) => {
+        $crate::split!(@constraint $direction $area; [$($names)*] [$($constraints)*]
+            ($($partial)* $head) $($rest)*
+        )
+    };
+    ($area:expr; $($fields:tt)+) => {
+        $crate::split!($area, vertical; $($fields)+)
+    };
+}
+
+/// Splits the area with one constraint per area, so that the number of areas is checked by the
+/// compiler. See [`areas!`].
+///
+/// [`areas!`]: crate::areas!
+#[doc(hidden)]
+pub fn __areas<const N: usize>(
+    direction: Direction,
+    constraints: [Constraint; N],
+    area: Rect,
+) -> [Rect; N] {
+    Layout::new(direction, constraints).areas(area)
+}
+
+/// Creates a [`Layout`] once and returns a `&'static` reference to it on every later call.
+///
+/// The first argument is `vertical` or `horizontal`, followed by a `;` and the arguments of
+/// [`vertical!`] or [`horizontal!`], including their directives. The layout is stored in a
+/// `static` [`OnceLock`] that is local to the invocation, so a render loop that runs the same
+/// invocation every frame only builds the layout and allocates its constraints on the first frame.
+///
+/// The arguments are only evaluated on the first call, so they should not depend on values that
+/// change between calls, e.g. the size of a window. Use [`vertical!`] or [`horizontal!`] for those.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::{Layout, Rect};
+/// use ratatui_macros::cached_layout;
+///
+/// fn render(area: Rect) -> [Rect; 3] {
+///     let layout: &'static Layout = cached_layout![vertical; ==3, *=1, ==1];
+///     layout.areas(area)
+/// }
+///
+/// let [header, body, footer] = render(Rect::new(0, 0, 10, 10));
+/// assert_eq!(body, Rect::new(0, 3, 10, 6));
+/// ```
+///
+/// [`Layout`]: ratatui_core::layout::Layout
+/// [`OnceLock`]: std::sync::OnceLock
+#[macro_export]
+macro_rules! cached_layout {
+    (vertical; $($rest:tt)+) => {
+        $crate::cached_layout!(@cached $crate::vertical![$($rest)+])
+    };
+    (horizontal; $($rest:tt)+) => {
+        $crate::cached_layout!(@cached $crate::horizontal![$($rest)+])
+    };
+    (@cached $layout:expr) => {{
+        static LAYOUT: ::std::sync::OnceLock<$crate::ratatui_core::layout::Layout> =
+            ::std::sync::OnceLock::new();
+        LAYOUT.get_or_init(|| $layout)
+    }};
+}
+
+/// Creates a [`Rect`] from a position and a size, or from a size at the origin.
+///
+/// The position and size can be followed by `clamp: area` to move and resize the rect so that it
+/// fits inside `area` (see [`Rect::clamp`]).
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::rect;
+///
+/// assert_eq!(rect!(1, 2, 30, 10), Rect::new(1, 2, 30, 10));
+/// assert_eq!(rect!(30, 10), Rect::new(0, 0, 30, 10));
+///
+/// let area = rect!(100, 100);
+/// assert_eq!(rect!(80, 80, 30, 30, clamp: area), Rect::new(70, 70, 30, 30));
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`Rect::clamp`]: ratatui_core::layout::Rect::clamp
+#[macro_export]
+macro_rules! rect {
+    ($x:expr, $y:expr, $width:expr, $height:expr, clamp: $area:expr $(,)?) => {
+        $crate::ratatui_core::layout::Rect::new($x, $y, $width, $height).clamp($area)
+    };
+    ($width:expr, $height:expr, clamp: $area:expr $(,)?) => {
+        $crate::ratatui_core::layout::Rect::new(0, 0, $width, $height).clamp($area)
+    };
+    ($x:expr, $y:expr, $width:expr, $height:expr $(,)?) => {
+        $crate::ratatui_core::layout::Rect::new($x, $y, $width, $height)
+    };
+    ($width:expr, $height:expr $(,)?) => {
+        $crate::ratatui_core::layout::Rect::new(0, 0, $width, $height)
+    };
+}
+
+/// Creates a [`Rect`] of the given width and height that is centered in an area.
+///
+/// The width and height are either a percentage of the area, e.g. `60%`, or a constraint, e.g.
+/// `==40` for a fixed size or `==50%` for a percentage (see [`constraint!`] for more information).
+/// This is useful for popups and modal dialogs.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::centered_rect;
+///
+/// let area = Rect::new(0, 0, 100, 50);
+/// assert_eq!(centered_rect!(area, 60%, 20%), Rect::new(20, 20, 60, 10));
+/// assert_eq!(centered_rect!(area, ==40, ==10), Rect::new(30, 20, 40, 10));
+/// assert_eq!(centered_rect!(area, 50%, ==6), Rect::new(25, 22, 50, 6));
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+#[macro_export]
+macro_rules! centered_rect {
+    // A percentage shorthand at the start of the width or height, e.g. `60%`
+    (@size $area:expr ; [$($parsed:tt)*] $percent:literal % $(, $($rest:tt)*)?) => {
+        $crate::centered_rect!(@constraint $area ; [$($parsed)* == $percent %] $(, $($rest)*)?)
+    };
+    (@size $area:expr ; [$($parsed:tt)*]) => {{
+        let area: $crate::ratatui_core::layout::Rect = $area;
+        let [width, height] = $crate::constraints![$($parsed)*];
+        let [area] = $crate::ratatui_core::layout::Layout::horizontal([width])
+            .flex($crate::ratatui_core::layout::Flex::Center)
+            .areas(area);
+        let [area] = $crate::ratatui_core::layout::Layout::vertical([height])
+            .flex($crate::ratatui_core::layout::Flex::Center)
+            .areas(area);
+        area
+    }};
+    (@size $area:expr ; [$($parsed:tt)*] $($rest:tt)+) => {
+        $crate::centered_rect!(@constraint $area ; [$($parsed)*] $($rest)+)
+    };
+    // Any other constraint, which is copied until the next `,`
+    (@constraint $area:expr ; [$($parsed:tt)*] $(,)?) => {
+        $crate::centered_rect!(@size $area ; [$($parsed)*])
+    };
+    (@constraint $area:expr ; [$($parsed:tt)*] , $($rest:tt)*) => {
+        $crate::centered_rect!(@size $area ; [$($parsed)* ,] $($rest)*)
+    };
+    (@constraint $area:expr ; [$($parsed:tt)*] $head:tt $($rest:tt)*) => {
+        $crate::centered_rect!(@constraint $area ; [$($parsed)* $head] $($rest)*)
+    };
+    ($area:expr, $($size:tt)+) => {
+        $crate::centered_rect!(@size $area ; [] $($size)+)
+    };
+}
+
+/// Creates a [`Rect`] of the given size that is anchored at a corner, an edge or the center of an
+/// area.
+///
+/// The size is either `WIDTHxHEIGHT`, where each of the width and height is a number of cells or a
+/// percentage of the area, e.g. `30x10`, `50%x30%` or `40x50%`, or a `(width, height)` tuple of
+/// expressions in cells. The anchor is one of `top_left`, `top_center`, `top_right`, `center_left`,
+/// `center`, `center_right`, `bottom_left`, `bottom_center` and `bottom_right`, and any other name
+/// is a compile error. The size is clamped to the area, so the returned [`Rect`] always fits.
+///
+/// This is useful for toasts, tooltips and floating panels; [`centered_rect!`] also accepts the
+/// constraints of [`constraint!`].
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::anchor;
+///
+/// let area = Rect::new(0, 0, 100, 50);
+/// assert_eq!(anchor!(area, 30x10, bottom_right), Rect::new(70, 40, 30, 10));
+/// assert_eq!(anchor!(area, 50%x30%, top_center), Rect::new(25, 0, 50, 15));
+/// assert_eq!(anchor!(area, 200x1, center_left), Rect::new(0, 24, 100, 1));
+///
+/// let (width, height) = (20, 6);
+/// assert_eq!(anchor!(area, (width, height), center), Rect::new(40, 22, 20, 6));
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`centered_rect!`]: crate::centered_rect!
+/// [`constraint!`]: crate::constraint!
+#[macro_export]
+macro_rules! anchor {
+    (@anchor top_left) => { $crate::__anchor_position!(Start, Start) };
+    (@anchor top_center) => { $crate::__anchor_position!(Center, Start) };
+    (@anchor top_right) => { $crate::__anchor_position!(End, Start) };
+    (@anchor center_left) => { $crate::__anchor_position!(Start, Center) };
+    (@anchor center) => { $crate::__anchor_position!(Center, Center) };
+    (@anchor center_right) => { $crate::__anchor_position!(End, Center) };
+    (@anchor bottom_left) => { $crate::__anchor_position!(Start, End) };
+    (@anchor bottom_center) => { $crate::__anchor_position!(Center, End) };
+    (@anchor bottom_right) => { $crate::__anchor_position!(End, End) };
+    (@anchor $anchor:ident) => {
+        compile_error!(concat!(
+            "unknown anchor `",
+            stringify!($anchor),
+            "`, expected one of `top_left`, `top_center`, `top_right`, `center_left`, `center`, ",
+            "`center_right`, `bottom_left`, `bottom_center` or `bottom_right`"
+        ))
+    };
+    (@size $parent:expr, $anchor:ident, $($size:tt)+) => {{
+        const SIZE: [$crate::__AnchorLength; 2] = $crate::__parse_size(stringify!($($size)+));
+        $crate::__anchor($parent, SIZE, $crate::anchor!(@anchor $anchor))
+    }};
+    ($parent:expr, ($width:expr, $height:expr), $anchor:ident $(,)?) => {
+        $crate::__anchor(
+            $parent,
+            [
+                $crate::__AnchorLength::Cells($width),
+                $crate::__AnchorLength::Cells($height),
+            ],
+            $crate::anchor!(@anchor $anchor),
+        )
+    };
+    // `30x10` and `50%x30%` are split into different tokens, so each form has its own rule
+    ($parent:expr, $size:tt, $anchor:ident $(,)?) => {
+        $crate::anchor!(@size $parent, $anchor, $size)
+    };
+    ($parent:expr, $size:tt %, $anchor:ident $(,)?) => {
+        $crate::anchor!(@size $parent, $anchor, $size %)
+    };
+    ($parent:expr, $width:tt % $height:tt, $anchor:ident $(,)?) => {
+        $crate::anchor!(@size $parent, $anchor, $width % $height)
+    };
+    ($parent:expr, $width:tt % $height:tt %, $anchor:ident $(,)?) => {
+        $crate::anchor!(@size $parent, $anchor, $width % $height %)
+    };
+}
+
+/// Creates the horizontal and vertical [`__AnchorPosition`] of an anchor of [`anchor!`].
+///
+/// [`anchor!`]: crate::anchor!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __anchor_position {
+    ($x:ident, $y:ident) => {
+        ($crate::__AnchorPosition::$x, $crate::__AnchorPosition::$y)
+    };
+}
+
+/// The width or height of an [`anchor!`], in cells or as a percentage of the area.
+///
+/// [`anchor!`]: crate::anchor!
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum __AnchorLength {
+    Cells(u16),
+    Percent(u16),
+}
+
+impl __AnchorLength {
+    /// Returns the length in cells, where `len` is the width or height of the area.
+    fn resolve(self, len: u16) -> u16 {
+        let cells = match self {
+            Self::Cells(cells) => cells,
+            Self::Percent(percent) => (u32::from(len) * u32::from(percent) / 100)
+                .try_into()
+                .unwrap_or(u16::MAX),
+        };
+        cells.min(len)
+    }
+}
+
+/// Where an [`anchor!`] is placed along the width or height of the area.
+///
+/// [`anchor!`]: crate::anchor!
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum __AnchorPosition {
+    Start,
+    Center,
+    End,
+}
+
+impl __AnchorPosition {
+    /// Returns the offset of a length of `size` in a length of `len`, where `size <= len`.
+    const fn offset(self, size: u16, len: u16) -> u16 {
+        match self {
+            Self::Start => 0,
+            Self::Center => (len - size) / 2,
+            Self::End => len - size,
+        }
+    }
+}
+
+/// Returns the area of the given size at the anchor in the parent. See [`anchor!`].
+///
+/// [`anchor!`]: crate::anchor!
+#[doc(hidden)]
+pub fn __anchor(
+    parent: Rect,
+    [width, height]: [__AnchorLength; 2],
+    (x, y): (__AnchorPosition, __AnchorPosition),
+) -> Rect {
+    let width = width.resolve(parent.width);
+    let height = height.resolve(parent.height);
+    Rect::new(
+        parent.x + x.offset(width, parent.width),
+        parent.y + y.offset(height, parent.height),
+        width,
+        height,
+    )
+}
+
+/// Parses a size like `30x10` or `50%x30%` into its width and height, each of which is a number of
+/// cells or a percentage. This is evaluated in a constant, so an invalid size is a compile error.
+/// It parses the sizes of [`anchor!`], and through [`__parse_cells`] those of [`render_test!`] and
+/// [`ensure_min!`].
+///
+/// [`anchor!`]: crate::anchor!
+/// [`render_test!`]: crate::render_test!
+/// [`ensure_min!`]: crate::ensure_min!
+#[doc(hidden)]
+pub const fn __parse_size(size: &str) -> [__AnchorLength; 2] {
+    let bytes = size.as_bytes();
+    let mut lengths: [u16; 2] = [0, 0];
+    let mut percents = [false, false];
+    let mut digits = [0, 0];
+    let mut index = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if byte == b' ' {
+            // `stringify!` separates the tokens of `50 % x30 %` with spaces
+        } else if byte == b'x' && index == 0 && digits[0] > 0 {
+            index = 1;
+        } else if byte == b'%' && digits[index] > 0 && !percents[index] {
+            percents[index] = true;
+        } else if byte.is_ascii_digit() && !percents[index] {
+            let digit = (byte - b'0') as u16;
+            lengths[index] = match lengths[index].checked_mul(10) {
+                Some(value) => match value.checked_add(digit) {
+                    Some(value) => value,
+                    None => panic!("the size does not fit in a u16"),
+                },
+                None => panic!("the size does not fit in a u16"),
+            };
+            digits[index] += 1;
+        } else {
+            panic!("expected a size like `30x5` or `50%x30%`");
+        }
+        i += 1;
+    }
+    if index == 0 || digits[1] == 0 {
+        panic!("expected a size like `30x5` or `50%x30%`");
+    }
+    let mut size = [
+        __AnchorLength::Cells(lengths[0]),
+        __AnchorLength::Cells(lengths[1]),
+    ];
+    if percents[0] {
+        size[0] = __AnchorLength::Percent(lengths[0]);
+    }
+    if percents[1] {
+        size[1] = __AnchorLength::Percent(lengths[1]);
+    }
+    size
+}
+
+/// Parses a size in cells like `30x5` into its width and height with [`__parse_size`], where a
+/// percentage is a compile error.
+#[doc(hidden)]
+pub const fn __parse_cells(size: &str) -> (u16, u16) {
+    match __parse_size(size) {
+        [__AnchorLength::Cells(width), __AnchorLength::Cells(height)] => (width, height),
+        _ => panic!("expected a size in cells like `30x5`, percentages are not supported here"),
+    }
+}
+
+/// Shrinks a [`Rect`] by a margin on each side.
+///
+/// The second argument is either a single margin for all four sides, or `x: margin` and `y: margin`
+/// for the left and right sides and the top and bottom sides, where either may be left out. The
+/// arithmetic saturates, and a margin that does not fit into the area returns an empty [`Rect`]
+/// (see [`Rect::inner`]).
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::inset;
+///
+/// let area = Rect::new(0, 0, 10, 10);
+/// assert_eq!(inset!(area, 1), Rect::new(1, 1, 8, 8));
+/// assert_eq!(inset!(area, x: 2, y: 1), Rect::new(2, 1, 6, 8));
+/// assert_eq!(inset!(area, y: 3), Rect::new(0, 3, 10, 4));
+/// assert_eq!(inset!(area, 6), Rect::ZERO);
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`Rect::inner`]: ratatui_core::layout::Rect::inner
+#[macro_export]
+macro_rules! inset {
+    ($area:expr, x: $x:expr, y: $y:expr $(,)?) => {
+        $crate::ratatui_core::layout::Rect::inner(
+            $area,
+            $crate::ratatui_core::layout::Margin::new($x, $y),
+        )
+    };
+    ($area:expr, x: $x:expr $(,)?) => {
+        $crate::inset!($area, x: $x, y: 0)
+    };
+    ($area:expr, y: $y:expr $(,)?) => {
+        $crate::inset!($area, x: 0, y: $y)
+    };
+    ($area:expr, $margin:expr $(,)?) => {{
+        let margin = $margin;
+        $crate::inset!($area, x: margin, y: margin)
+    }};
+}
+
+/// Moves a [`Rect`] by an offset without changing its size.
+///
+/// The offsets are `i32`s, or any other integer that converts into one, where positive values
+/// move the area to the right and down. The position saturates at the edges of the coordinate
+/// space (see [`Rect::offset`]). The offsets can be followed by `clamp: area` to keep the moved
+/// area inside `area` (see [`Rect::clamp`]), e.g. for a tooltip next to the cursor.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::offset;
+///
+/// let area = Rect::new(5, 5, 10, 2);
+/// assert_eq!(offset!(area, 2, -1), Rect::new(7, 4, 10, 2));
+/// assert_eq!(offset!(area, -10, 0), Rect::new(0, 5, 10, 2));
+///
+/// let screen = Rect::new(0, 0, 20, 10);
+/// assert_eq!(offset!(area, 10, 10, clamp: screen), Rect::new(10, 8, 10, 2));
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`Rect::offset`]: ratatui_core::layout::Rect::offset
+/// [`Rect::clamp`]: ratatui_core::layout::Rect::clamp
+#[macro_export]
+macro_rules! offset {
+    ($area:expr, $dx:expr, $dy:expr, clamp: $clamp:expr $(,)?) => {
+        $crate::offset!($area, $dx, $dy).clamp($clamp)
+    };
+    ($area:expr, $dx:expr, $dy:expr $(,)?) => {
+        $crate::ratatui_core::layout::Rect::offset(
+            $area,
+            $crate::ratatui_core::layout::Offset {
+                x: ::core::convert::From::from($dx),
+                y: ::core::convert::From::from($dy),
+            },
+        )
+    };
+}
+
+/// Returns the part of a [`Rect`] that lies inside one or more other areas.
+///
+/// The first argument is the child area, e.g. a popup, and the other arguments are the areas that
+/// it must stay inside, e.g. the area of the frame. The result is the intersection of all of them
+/// (see [`Rect::intersection`]), so a widget rendered into it never writes outside the parents.
+/// When the areas do not overlap, the result has a width or height of zero. Unlike `clamp:` in
+/// [`rect!`] and [`offset!`], which move the child into the parent and keep its size, this cuts
+/// off the parts that stick out.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::clamp_rect;
+///
+/// let screen = Rect::new(0, 0, 20, 10);
+/// let popup = Rect::new(15, 8, 10, 4);
+/// assert_eq!(clamp_rect!(popup, screen), Rect::new(15, 8, 5, 2));
+///
+/// let sidebar = Rect::new(0, 0, 16, 10);
+/// assert_eq!(clamp_rect!(popup, screen, sidebar), Rect::new(15, 8, 1, 2));
+/// assert!(clamp_rect!(Rect::new(30, 0, 5, 5), screen).is_empty());
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`Rect::intersection`]: ratatui_core::layout::Rect::intersection
+/// [`rect!`]: crate::rect!
+/// [`offset!`]: crate::offset!
+#[macro_export]
+macro_rules! clamp_rect {
+    ($child:expr, $($parent:expr),+ $(,)?) => {
+        $crate::ratatui_core::layout::Rect::from($child)$(.intersection($parent))+
+    };
+}
+
+/// Returns a [`Rect`] if it is at least as large as a minimum size, and `None` otherwise.
+///
+/// The size is either `WIDTHxHEIGHT`, e.g. `20x5`, or a `(width, height)` tuple of expressions.
+/// This guards popups and overlays on small terminals: render the widget only when its area is
+/// large enough, or fall back to another area or a short message with the methods of [`Option`].
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::ensure_min;
+///
+/// let area = Rect::new(0, 0, 30, 4);
+/// assert_eq!(ensure_min!(area, 20x4), Some(area));
+/// assert_eq!(ensure_min!(area, 20x5), None);
+///
+/// let (width, height) = (40, 1);
+/// let fallback = Rect::new(0, 0, 10, 1);
+/// assert_eq!(ensure_min!(area, (width, height)).unwrap_or(fallback), fallback);
+/// ```
+///
+/// [`Rect`]: ratatui_core::layout::Rect
+#[macro_export]
+macro_rules! ensure_min {
+    ($area:expr, ($width:expr, $height:expr) $(,)?) => {{
+        let area: $crate::ratatui_core::layout::Rect = $area;
+        let (width, height): (u16, u16) = ($width, $height);
+        if area.width >= width && area.height >= height {
+            ::core::option::Option::Some(area)
+        } else {
+            ::core::option::Option::None
+        }
+    }};
+    ($area:expr, $size:tt $(,)?) => {{
+        const SIZE: (u16, u16) = $crate::__parse_cells(stringify!($size));
+        $crate::ensure_min!($area, (SIZE.0, SIZE.1))
+    }};
+}
+
+/// Splits an area into a tree of nested layouts and binds each area to a variable.
+///
+/// The macro is used as a statement, e.g. `layout!(area => vertical { ... });`, and declares a
+/// variable for each named area, so that the areas are used after the macro like the result of
+/// `let [a, b] = vertical![...].areas(area);`. The root is either `vertical` or `horizontal`
+/// followed by a list of `name: constraint` entries in braces, where the constraint is written like
+/// in [`constraint!`], e.g. `header: ==3`.
+///
+/// An entry can also split its area again with a nested `vertical { ... }` or
+/// `horizontal { ... }`, e.g. `body: horizontal { ... }`. The area of a nested layout fills the
+/// remaining space (`*=1`) unless a constraint comes before it, e.g.
+/// `body: ==10 horizontal { ... }`, and its name can be left out if only the areas inside it are
+/// used.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::layout;
+///
+/// let area = Rect::new(0, 0, 100, 20);
+/// layout!(area => vertical {
+///     header: ==3,
+///     body: horizontal {
+///         sidebar: ==30,
+///         main: *=1,
+///     },
+///     footer: ==1,
+/// });
+///
+/// assert_eq!(header, Rect::new(0, 0, 100, 3));
+/// assert_eq!(body, Rect::new(0, 3, 100, 16));
+/// assert_eq!(sidebar, Rect::new(0, 3, 30, 16));
+/// assert_eq!(main, Rect::new(30, 3, 70, 16));
+/// assert_eq!(footer, Rect::new(0, 19, 100, 1));
+/// ```
+#[macro_export]
+macro_rules! layout {
+    // A named entry, whose constraint is copied until the next `,` or a nested layout
+    (@entries $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident : $($rest:tt)*
+    ) => {
+        $crate::layout!(@entry $dir $area [$($names)*] [$($constraints)*] [$($nested)*]
+            $name [] $($rest)*)
+    };
+    // All entries are parsed, so split the area and then split the nested areas
+    (@entries $dir:ident ($area:expr) [$($names:ident)*] [$([$($constraint:tt)*])*]
+        [$([$nested:ident $inner:ident { $($entries:tt)* }])*]
+    ) => {
+        let [$($names),*] = $crate::areas!($area, $dir; $($($constraint)*),*);
+        $($crate::layout!($nested => $inner { $($entries)* });)*
+    };
+    // A nested layout without a name, whose area is only used to split it again
+    (@entries $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $($rest:tt)+
+    ) => {
+        $crate::layout!(@entry $dir $area [$($names)*] [$($constraints)*] [$($nested)*]
+            nested [] $($rest)+)
+    };
+    (@entry $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident [$($partial:tt)*] vertical { $($entries:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@nested $dir $area [$($names)*] [$($constraints)*] [$($nested)*]
+            $name [$($partial)*] vertical { $($entries)* } $(, $($rest)*)?)
+    };
+    (@entry $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident [$($partial:tt)*] horizontal { $($entries:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@nested $dir $area [$($names)*] [$($constraints)*] [$($nested)*]
+            $name [$($partial)*] horizontal { $($entries)* } $(, $($rest)*)?)
+    };
+    (@entry $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident [$($partial:tt)+] $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@entries $dir $area [$($names)* $name] [$($constraints)* [$($partial)+]]
+            [$($nested)*] $($($rest)*)?)
+    };
+    (@entry $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident [$($partial:tt)*] $head:tt $($rest:tt)*
+    ) => {
+        $crate::layout!(@entry $dir $area [$($names)*] [$($constraints)*] [$($nested)*]
+            $name [$($partial)* $head] $($rest)*)
+    };
+    // A named nested layout, which fills the remaining space unless it has a constraint
+    (@nested $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident [] $inner:ident { $($entries:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@nested $dir $area [$($names)*] [$($constraints)*] [$($nested)*]
+            $name [*= 1] $inner { $($entries)* } $(, $($rest)*)?)
+    };
+    (@nested $dir:ident $area:tt [$($names:tt)*] [$($constraints:tt)*] [$($nested:tt)*]
+        $name:ident [$($partial:tt)+] $inner:ident { $($entries:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@entries $dir $area [$($names)* $name] [$($constraints)* [$($partial)+]]
+            [$($nested)* [$name $inner { $($entries)* }]] $($($rest)*)?)
+    };
+    ($area:expr => vertical { $($entries:tt)* }) => {
+        $crate::layout!(@entries vertical ($area) [] [] [] $($entries)*)
+    };
+    ($area:expr => horizontal { $($entries:tt)* }) => {
+        $crate::layout!(@entries horizontal ($area) [] [] [] $($entries)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{__AnchorLength, __parse_cells, __parse_size};
+
+    #[test]
+    fn parse_size() {
+        assert_eq!(__parse_cells("30x5"), (30, 5));
+        assert_eq!(__parse_cells("1x65535"), (1, u16::MAX));
+        assert_eq!(
+            __parse_size("50 % x30 %"),
+            [__AnchorLength::Percent(50), __AnchorLength::Percent(30)]
+        );
+        assert_eq!(
+            __parse_size("40x50%"),
+            [__AnchorLength::Cells(40), __AnchorLength::Percent(50)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "expected a size like `30x5`"]
+    fn parse_size_invalid() {
+        __parse_size("30");
+    }
+
+    #[test]
+    #[should_panic = "expected a size in cells like `30x5`"]
+    fn parse_cells_percent() {
+        __parse_cells("50%x5");
+    }
+}
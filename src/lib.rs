@@ -1,10 +1,279 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+#[cfg(feature = "ansi")]
+mod ansi;
+#[cfg(feature = "banner")]
+mod banner;
+mod barchart;
+mod block;
+mod border;
+mod breadcrumbs;
+mod buffer;
+#[cfg(feature = "calendar")]
+mod calendar;
+mod canvas;
+mod cell;
+mod chart;
+#[cfg(feature = "proc-macros")]
+mod color;
+mod constraints_from_str;
+#[cfg(feature = "crossterm")]
+mod crossterm_style;
+mod diff;
+mod divider;
+mod element;
+mod fill;
+mod frame;
+mod gauge;
+mod gradient;
+#[cfg(feature = "highlight")]
+mod highlight;
+#[cfg(feature = "syntect")]
+mod highlight_code;
+#[cfg(feature = "proc-macros")]
+mod html;
+mod hyperlink;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+mod key;
+#[cfg(feature = "crossterm")]
+mod key_names;
+#[cfg(feature = "crossterm")]
+mod keymap;
+mod kv;
 mod layout;
 mod line;
+mod list;
+mod log_line;
+#[cfg(feature = "proc-macros")]
+mod markdown;
+#[cfg(feature = "proc-macros")]
+mod markup;
+mod menu;
+#[cfg(feature = "crossterm")]
+mod mouse;
+mod numbered;
+mod padding;
+mod palette;
+mod paragraph;
+mod progress;
+mod render;
 mod row;
+mod scrollbar;
+#[cfg(feature = "insta")]
+mod snapshot;
 mod span;
+mod sparkline;
+mod state;
+mod statusbar;
+mod style;
+mod style_from_str;
+#[cfg(feature = "proc-macros")]
+mod styled_format;
+mod symbols;
+mod table;
+mod tabs;
 mod text;
+mod theme;
+mod throbber;
+mod title;
+mod toast;
+#[cfg(feature = "tracing")]
+mod tracing_line;
+mod truncate;
+mod widget;
+mod width;
+#[cfg(feature = "wrap")]
+mod wrap;
 
-// Re-export the core crate to use the types in macros
+#[cfg(feature = "ansi")]
+#[doc(hidden)]
+pub use ansi::__ansi_strip;
+#[cfg(feature = "ansi")]
+pub use ansi::ansi_to_text;
+#[cfg(feature = "banner")]
+#[doc(hidden)]
+pub use banner::__banner_gradient;
+#[cfg(feature = "banner")]
+pub use banner::banner_text;
+#[doc(hidden)]
+pub use border::{__border_set, __border_symbol};
+#[doc(hidden)]
+pub use breadcrumbs::__Breadcrumbs;
+#[doc(hidden)]
+pub use buffer::{__BufferRange, __assert_buffer, __buffer_contains, __buffer_markers};
+pub use constraints_from_str::{parse_constraint, parse_constraints, ParseConstraintError};
+#[cfg(feature = "crossterm")]
+pub use crossterm_style::IntoRatatui;
+#[doc(hidden)]
+pub use diff::__Diff;
+#[doc(hidden)]
+pub use divider::__Divider;
+#[doc(hidden)]
+pub use element::{
+    __DisplayElement, __DisplayWidth, __Element, __IntoCellElement, __IntoSpanElement,
+    __IntoTextElement, __NoWidth,
+};
+#[doc(hidden)]
+pub use fill::__fill;
+#[doc(hidden)]
+pub use gradient::__gradient;
+#[cfg(feature = "highlight")]
+#[doc(hidden)]
+pub use highlight::{__HighlightPattern, __highlight};
+#[cfg(feature = "syntect")]
+#[doc(hidden)]
+pub use highlight_code::__HighlightCode;
+#[cfg(feature = "syntect")]
+pub use highlight_code::code_to_text;
+#[doc(hidden)]
+pub use hyperlink::__hyperlink;
+#[cfg(feature = "json")]
+#[doc(hidden)]
+pub use json::__Json;
+#[cfg(feature = "json")]
+pub use json::json_to_text;
+#[cfg(feature = "termwiz")]
+#[doc(hidden)]
+pub use key::__TermwizModifiers;
+#[cfg(feature = "crossterm")]
+#[doc(hidden)]
+pub use key::{__KeyChar, __KeyModifiers};
+#[cfg(feature = "crossterm")]
+pub use keymap::{parse_key_sequence, KeyChord, Keymap, KeymapMatch, ParseKeyError};
+#[doc(hidden)]
+pub use kv::__KeyValue;
+#[doc(hidden)]
+pub use layout::{
+    __AnchorLength, __AnchorPosition, __anchor, __areas, __grid, __parse_cells, __parse_size,
+};
+#[doc(hidden)]
+pub use line::{__join_line, __repeat};
+#[cfg(feature = "log")]
+pub use log_line::log_record_to_line;
+#[doc(hidden)]
+pub use log_line::{__LogLevel, __LogLine};
+#[doc(hidden)]
+pub use menu::__Menu;
+#[doc(hidden)]
+pub use numbered::__Numbered;
+#[doc(hidden)]
+pub use palette::__hex_color;
+#[doc(hidden)]
+pub use progress::{__Progress, __ProgressLabel};
+#[cfg(all(feature = "crossterm", feature = "derive"))]
+pub use ratatui_macros_proc::KeyChord;
+#[cfg(feature = "derive")]
+pub use ratatui_macros_proc::{ToLine, ToRow, ToText};
+#[doc(hidden)]
+pub use render::{__ansi_string, __plain_string, __render_test};
+#[doc(hidden)]
+pub use row::__rows;
+#[cfg(feature = "insta")]
+#[doc(hidden)]
+pub use snapshot::__snapshot_string;
+#[doc(hidden)]
+pub use span::__pad_span;
+#[doc(hidden)]
+pub use state::__clamp_selection;
+#[doc(hidden)]
+pub use statusbar::__StatusBar;
+#[doc(hidden)]
+pub use style::__indexed_color;
+#[doc(hidden)]
+pub use style_from_str::{__StyleError, __parse_style};
+pub use style_from_str::{parse_color, parse_style, ParseStyleError};
+#[doc(hidden)]
+pub use table::{__ColumnWidths, __sorted_header};
+pub use table::{column_widths, SortDirection};
+#[doc(hidden)]
+pub use text::__TextElement;
+#[doc(hidden)]
+pub use throbber::__Throbber;
+#[doc(hidden)]
+pub use toast::{__Toast, __ToastLevel};
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing_line::__tracing_line;
+#[cfg(feature = "tracing")]
+pub use tracing_line::{tracing_event_to_line, TracingLineLayer};
+#[doc(hidden)]
+pub use truncate::__truncate;
+#[doc(hidden)]
+pub use widget::{__StatefulWidget, __Widget};
+#[doc(hidden)]
+pub use width::__DisplayWidthOf;
+#[cfg(feature = "wrap")]
+pub use wrap::wrap_text;
+
+// Re-export the core and widgets crates to use the types in macros
+#[cfg(feature = "crossterm")]
+#[doc(hidden)]
+pub use crossterm;
+#[cfg(feature = "insta")]
+#[doc(hidden)]
+pub use insta as __insta;
 pub use ratatui_core;
+#[cfg(feature = "proc-macros")]
+#[doc(hidden)]
+pub use ratatui_macros_proc;
+pub use ratatui_widgets;
+#[cfg(feature = "termion")]
+#[doc(hidden)]
+pub use termion;
+#[cfg(feature = "termwiz")]
+#[doc(hidden)]
+pub use termwiz;
+#[cfg(feature = "calendar")]
+#[doc(hidden)]
+pub use time;
+
+/// Expands an alignment keyword (`Left`, `Center` or `Right`) to the matching [`Alignment`]
+/// variant. Any other identifier is passed through as a variable.
+///
+/// [`Alignment`]: ratatui_core::layout::Alignment
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alignment {
+    (Left) => {
+        $crate::ratatui_core::layout::Alignment::Left
+    };
+    (Center) => {
+        $crate::ratatui_core::layout::Alignment::Center
+    };
+    (Right) => {
+        $crate::ratatui_core::layout::Alignment::Right
+    };
+    ($alignment:ident) => {
+        $alignment
+    };
+}
+
+/// Passes the tokens to a macro, e.g. `__check!(check_line [line!] [@spans line []] "a", "b")`
+/// expands to `line!(@spans line [] "a", "b")`.
+///
+/// With the `proc-macros` feature, the tokens are first checked by the named proc macro, which
+/// reports invalid arguments with an error that points at the offending tokens.
+#[cfg(feature = "proc-macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check {
+    ($check:ident [$($callback:tt)*] [$($args:tt)*] $($tokens:tt)*) => {
+        $crate::ratatui_macros_proc::$check!([$($callback)*] [$($args)*] $($tokens)*)
+    };
+}
+
+/// Passes the tokens to a macro, e.g. `__check!(check_line [line!] [@spans line []] "a", "b")`
+/// expands to `line!(@spans line [] "a", "b")`.
+///
+/// With the `proc-macros` feature, the tokens are first checked by the named proc macro, which
+/// reports invalid arguments with an error that points at the offending tokens.
+#[cfg(not(feature = "proc-macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check {
+    ($check:ident [$($callback:tt)*] [$($args:tt)*] $($tokens:tt)*) => {
+        $($callback)*($($args)* $($tokens)*)
+    };
+}
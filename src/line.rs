@@ -1,6 +1,12 @@
+use std::iter;
+
+use ratatui_core::text::{Line, Span};
+
 /// A macro for creating a [`Line`] using vec! syntax.
 ///
-/// `line!` is similar to the [`vec!`] macro, but it returns a [`Line`] instead of a `Vec`.
+/// `line!` is similar to the [`vec!`] macro, but it returns a [`Line`] instead of a `Vec`. Each
+/// element is anything that can be converted into a [`Span`], or any other value that implements
+/// [`Display`], e.g. a number or a `char`, which is converted with [`ToString`].
 ///
 /// # Examples
 ///
@@ -12,9 +18,12 @@
 ///
 /// let line = line!["hello", "world"];
 /// let line = line!["hello".red(), "world".red().bold()];
+/// let line = line!["count: ", 42, " items"];
+/// let line = line!['[', "x".bold(), ']'];
 /// ```
 ///
-/// * Create a [`Line`] from a given [`Span`] repeated some amount of times:
+/// * Create a [`Line`] from a given [`Span`] repeated some amount of times. The expression is
+///   evaluated and converted into a [`Span`] once, so it does not need to implement `Clone`:
 ///
 /// ```rust
 /// # use ratatui_macros::line;
@@ -30,28 +39,279 @@
 /// let line = line![span!("hello {}", "world"), span!(Modifier::BOLD; "goodbye {}", "world")];
 /// ```
 ///
+/// * Format a [`Span`] in place by wrapping a format string and its arguments in braces:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// # use ratatui_macros::line;
+/// let (key, value) = ("name", "ratatui");
+/// let line = line![{"{}: ", key}, value.bold()];
+/// let line = line![{"loaded {} items in {:?}", 3, std::time::Duration::from_millis(20)}];
+/// ```
+///
+///   A block that only contains a string literal is also a format string, so that it can capture
+///   variables like `{"{count} items"}`. A literal that contains braces is therefore formatted and
+///   must be written without the block, e.g. `line!["{}"]` instead of `line![{ "{}" }]`.
+///
+/// * Include a [`Span`] only when a condition holds with `if condition => span`:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// # use ratatui_macros::line;
+/// let (modified, readonly) = (true, false);
+/// let line = line!["file.rs", if modified => " [+]".red(), if readonly => " [RO]".dim()];
+/// ```
+///
+///   An `if` expression without `=>`, e.g. `if modified { "*" } else { " " }`, is an ordinary
+///   [`Span`].
+///
+/// * Include an [`Option`] of a [`Span`] only when it is `Some` by prefixing it with `?`:
+///
+/// ```rust
+/// # use ratatui_macros::line;
+/// let branch = Some(" main");
+/// let line = line!["~/src/ratatui", ?branch, ?None::<&str>];
+/// ```
+///
+/// * Insert every [`Span`] of an iterator by prefixing it with `..`:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// # use ratatui_macros::line;
+/// let tags = ["rust", "tui"];
+/// let line = line!["[", ..tags.iter().map(|tag| tag.cyan()), "]"];
+/// ```
+///
+/// * Align the [`Line`] with a leading `left`, `center` or `right` keyword followed by a `;`:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// # use ratatui_macros::line;
+/// let line = line![center; "hello", "world".bold()];
+/// let line = line![right; "goodbye"; 2];
+/// ```
+///
+/// * Insert a separator between the [`Span`]s with a leading `join separator;`, where the
+///   separator is anything that can be converted into a [`Span`]:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// # use ratatui_macros::line;
+/// let line = line![join " | "; "home", "logs", "help"];
+/// let items = ["a", "b", "c"];
+/// let line = line![join ", ".dim(); ..items.iter().copied()];
+/// ```
+///
+/// * Set the style of the whole [`Line`] with a leading `style: style;`, where the style is
+///   anything that can be converted into a [`Style`]. The style of each [`Span`] is patched onto
+///   the style of the line when it is rendered:
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// # use ratatui_macros::line;
+/// let count = 3;
+/// let line = line![style: Style::new().green(); "ok: ", count.to_string().bold()];
+/// let line = line![center; style: Color::Red; "error"];
+/// ```
+///
+/// [`Display`]: std::fmt::Display
 /// [`Line`]: crate::text::Line
+/// [`Style`]: ratatui_core::style::Style
 /// [`Span`]: crate::text::Span
 #[macro_export]
 macro_rules! line {
     () => {
         $crate::ratatui_core::text::Line::default()
     };
-    ($span:expr; $n:expr) => {
-      $crate::ratatui_core::text::Line::from(vec![$span.into(); $n])
-    };
-    ($($span:expr),+ $(,)?) => {{
-        $crate::ratatui_core::text::Line::from(vec![
-        $(
-            $span.into(),
-        )+
-        ])
+    (left; $($rest:tt)*) => {
+        $crate::line![$($rest)*].alignment($crate::ratatui_core::layout::Alignment::Left)
+    };
+    (center; $($rest:tt)*) => {
+        $crate::line![$($rest)*].alignment($crate::ratatui_core::layout::Alignment::Center)
+    };
+    (right; $($rest:tt)*) => {
+        $crate::line![$($rest)*].alignment($crate::ratatui_core::layout::Alignment::Right)
+    };
+    (style: $style:expr; $($rest:tt)*) => {
+        $crate::line![$($rest)*].style($style)
+    };
+    (join $separator:expr; $($rest:tt)*) => {
+        $crate::__join_line($crate::line![$($rest)*], $crate::__span_element!($separator))
+    };
+    // Adds each span in one step, for a list of plain spans that `line!` matches as a whole
+    (@elements $line:ident $($head:tt $(. $method:ident ($($args:tt)*))*),+) => {{
+        let mut $line = $crate::ratatui_core::text::Line::default();
+        $($crate::line!(@element $line $head $(. $method($($args)*))*);)+
+        $line
     }};
+    (@element $line:ident { $format:literal $(, $($args:tt)*)? }) => {
+        $line.push_span(format!($format $(, $($args)*)?))
+    };
+    (@element $line:ident $span:expr) => {
+        $line.push_span($crate::__span_element!($span))
+    };
+    (@spans $line:ident [$($push:tt)*] $(,)?) => {{
+        let mut $line = $crate::ratatui_core::text::Line::default();
+        $($push)*
+        $line
+    }};
+    (@spans $line:ident [$($push:tt)*] if $($rest:tt)*) => {
+        $crate::line!(@if $line [$($push)*] [] $($rest)*)
+    };
+    // the condition is collected up to `=>`, as it cannot be parsed as an `expr` before a block
+    (@if $line:ident [$($push:tt)*] [$($condition:tt)+]
+        => { $format:literal $(, $($args:tt)*)? } $(, $($rest:tt)*)?
+    ) => {
+        $crate::line!(@spans $line [$($push)*
+            if $($condition)+ {
+                $line.push_span(format!($format $(, $($args)*)?));
+            }
+        ] $($($rest)*)?)
+    };
+    (@if $line:ident [$($push:tt)*] [$($condition:tt)+] => $span:expr $(, $($rest:tt)*)?) => {
+        $crate::line!(@spans $line [$($push)*
+            if $($condition)+ {
+                $line.push_span($crate::__span_element!($span));
+            }
+        ] $($($rest)*)?)
+    };
+    // without `=>`, the element is an `if` expression, e.g. `if flag { "x" } else { "y" }`
+    (@if $line:ident [] [$($span:tt)+] ; $n:expr) => {
+        $crate::line![{ if $($span)+ }; $n]
+    };
+    (@if $line:ident [$($push:tt)*] [$($span:tt)+] $(, $($rest:tt)*)?) => {
+        $crate::line!(@spans $line [$($push)*
+            $line.push_span($crate::__span_element!(if $($span)+));
+        ] $($($rest)*)?)
+    };
+    (@if $line:ident [$($push:tt)*] [$($condition:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::line!(@if $line [$($push)*] [$($condition)* $next] $($rest)*)
+    };
+    (@spans $line:ident [$($push:tt)*] ? $span:expr $(, $($rest:tt)*)?) => {
+        $crate::line!(@spans $line [$($push)*
+            if let ::core::option::Option::Some(span) = $span {
+                $line.push_span($crate::__span_element!(span));
+            }
+        ] $($($rest)*)?)
+    };
+    (@spans $line:ident [$($push:tt)*] .. $spans:expr $(, $($rest:tt)*)?) => {
+        $crate::line!(@spans $line [$($push)*
+            for span in $spans {
+                $line.push_span($crate::__span_element!(span));
+            }
+        ] $($($rest)*)?)
+    };
+    (@spans $line:ident [$($push:tt)*]
+        { $format:literal $(, $($args:tt)*)? } $(, $($rest:tt)*)?
+    ) => {
+        $crate::line!(@spans $line [$($push)*
+            $line.push_span(format!($format $(, $($args)*)?));
+        ] $($($rest)*)?)
+    };
+    (@spans $line:ident [$($push:tt)*] $span:expr $(, $($rest:tt)*)?) => {
+        $crate::line!(@spans $line [$($push)*
+            $line.push_span($crate::__span_element!($span));
+        ] $($($rest)*)?)
+    };
+    ({ $format:literal $(, $($args:tt)*)? } ; $n:expr) => {
+        $crate::ratatui_core::text::Line::from($crate::__repeat::<
+            $crate::ratatui_core::text::Span,
+        >(format!($format $(, $($args)*)?), $n))
+    };
+    // A list of spans that are each a single token tree followed by method calls, e.g. `"a"`,
+    // `name` or `"b".bold()`, is matched as a whole instead of one span at a time, which keeps long
+    // lines within the recursion limit. The other elements are matched by the `@spans` arms.
+    ($($head:tt $(. $method:ident ($($args:tt)*))*),+ $(,)?) => {
+        $crate::__check!(check_line [$crate::line!] [@elements line]
+            $($head $(. $method($($args)*))*),+
+        )
+    };
+    ({ $format:literal $($args:tt)* } $($rest:tt)*) => {
+        $crate::__check!(check_line [$crate::line!] [@spans line []] { $format $($args)* } $($rest)*)
+    };
+    (if $($rest:tt)*) => {
+        $crate::__check!(check_line [$crate::line!] [@spans line []] if $($rest)*)
+    };
+    ($span:expr; $n:expr) => {
+        $crate::ratatui_core::text::Line::from(
+            $crate::__repeat::<$crate::ratatui_core::text::Span>($span, $n),
+        )
+    };
+    ($($spans:tt)+) => {
+        $crate::__check!(check_line [$crate::line!] [@spans line []] $($spans)+)
+    };
+}
+
+/// A macro for creating a `Vec` of [`Span`]s using the syntax of [`line!`].
+///
+/// `spans!` accepts the same elements as [`line!`], including repetition with `;`, braced format
+/// strings, `if condition => span`, `?option`, `..iter` and a leading `join separator;`, but it
+/// returns the spans instead of a [`Line`]. This is useful for groups of spans that are reused in
+/// several lines, or extended before they are turned into a [`Line`] with `Line::from`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span}};
+/// use ratatui_macros::{line, spans};
+///
+/// let branch = Some("main");
+/// let status: Vec<Span> = spans![" ", ?branch, " ", "3 changes".yellow()];
+///
+/// let mut spans = spans!["~/src/ratatui"];
+/// spans.extend(status.clone());
+/// let line = Line::from(spans);
+/// let line = line!["repo", ..status];
+/// ```
+///
+/// [`Line`]: crate::text::Line
+/// [`Span`]: crate::text::Span
+/// [`line!`]: crate::line!
+#[macro_export]
+macro_rules! spans {
+    () => {
+        ::std::vec::Vec::<$crate::ratatui_core::text::Span>::new()
+    };
+    ($($tokens:tt)+) => {
+        $crate::line![$($tokens)+].spans
+    };
+}
+
+/// Converts the value once and repeats it `n` times, cloning it for all but the last element. This
+/// is used for the `value; n` syntax of [`line!`], [`text!`] and [`row!`].
+///
+/// [`line!`]: crate::line!
+/// [`text!`]: crate::text!
+/// [`row!`]: crate::row!
+#[doc(hidden)]
+pub fn __repeat<T: Clone>(value: impl Into<T>, n: usize) -> Vec<T> {
+    let mut values = Vec::with_capacity(n);
+    values.extend(iter::repeat_n(value.into(), n));
+    values
+}
+
+/// Inserts the separator between the spans of the line. See [`line!`].
+///
+/// [`line!`]: crate::line!
+#[doc(hidden)]
+pub fn __join_line<'a>(line: Line<'a>, separator: impl Into<Span<'a>>) -> Line<'a> {
+    let separator = separator.into();
+    let mut spans = Vec::with_capacity((line.spans.len() * 2).saturating_sub(1));
+    for (i, span) in line.spans.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(separator.clone());
+        }
+        spans.push(span);
+    }
+    Line { spans, ..line }
 }
 
 #[cfg(test)]
 mod tests {
-    use ratatui_core::text::{Line, Span};
+    use ratatui_core::{
+        style::{Color, Modifier, Style, Stylize},
+        text::{Line, Span},
+    };
 
     #[test]
     fn line_literal() {
@@ -77,6 +337,21 @@ mod tests {
         assert_eq!(line, Line::from(vec!["hello".into(), "hello".into()]));
     }
 
+    #[test]
+    fn line_repeated_without_clone() {
+        struct Label;
+        impl From<Label> for Span<'static> {
+            fn from(_: Label) -> Self {
+                Span::raw("label")
+            }
+        }
+        let mut evaluated = 0;
+        let line = line![{ evaluated += 1; Label }; 3];
+        assert_eq!(line, Line::from(vec![Span::raw("label"); 3]));
+        assert_eq!(evaluated, 1);
+        assert_eq!(line![Label; 0], Line::default());
+    }
+
     #[test]
     fn line_empty() {
         let line = line![];
@@ -94,4 +369,227 @@ mod tests {
         let line = line![Span::raw("foo"); 2];
         assert_eq!(line, Line::from(vec!["foo".into(), "foo".into()]));
     }
+
+    #[test]
+    fn line_alignment() {
+        let line = line![center; "hello", "world".bold()];
+        assert_eq!(
+            line,
+            Line::from(vec!["hello".into(), "world".bold()]).centered()
+        );
+
+        assert_eq!(line![left; "hello"], Line::from("hello").left_aligned());
+        assert_eq!(line![right; "hello"], Line::from("hello").right_aligned());
+    }
+
+    #[test]
+    fn line_alignment_repeated_span() {
+        let line = line![right; "foo"; 2];
+        assert_eq!(
+            line,
+            Line::from(vec!["foo".into(), "foo".into()]).right_aligned()
+        );
+        assert_eq!(line![center;], Line::default().centered());
+    }
+
+    #[test]
+    fn line_format_spans() {
+        let key = "name";
+        let value = "ratatui";
+        let line = line![{"{}: ", key}, value.bold()];
+        assert_eq!(
+            line,
+            Line::from(vec![Span::raw("name: "), Span::raw("ratatui").bold()])
+        );
+
+        let line = line!["loaded ", {"{} items", 3}, {" in {value}"},];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("loaded "),
+                Span::raw("3 items"),
+                Span::raw(" in ratatui"),
+            ])
+        );
+    }
+
+    #[test]
+    fn line_many_spans() {
+        let line = line![
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-"
+        ];
+        assert_eq!(line, Line::from(vec![Span::raw("-"); 150]));
+    }
+
+    #[test]
+    fn line_format_span_repeated() {
+        let line = line![{"{}", 1}; 2];
+        assert_eq!(line, Line::from(vec![Span::raw("1"), Span::raw("1")]));
+    }
+
+    #[test]
+    fn line_block_expression() {
+        let line = line![{ "a".to_string() + "b" }, "c"];
+        assert_eq!(line, Line::from(vec![Span::raw("ab"), Span::raw("c")]));
+    }
+
+    #[test]
+    fn line_conditional_spans() {
+        let (modified, readonly) = (true, false);
+        let line = line!["file.rs", if modified => " [+]".red(), if readonly => " [RO]".dim()];
+        assert_eq!(
+            line,
+            Line::from(vec![Span::raw("file.rs"), Span::raw(" [+]").red()])
+        );
+
+        let count = 2;
+        let line = line![if count > 1 => {"{count} files"}, if count == 0 => "empty",];
+        assert_eq!(line, Line::from(vec![Span::raw("2 files")]));
+
+        let line = line![if readonly => "readonly"];
+        assert_eq!(line, Line::default());
+    }
+
+    #[test]
+    fn line_if_expressions() {
+        let flag = true;
+        assert_eq!(line![if flag { "x" } else { "y" }], Line::from("x"));
+        assert_eq!(
+            line![if !flag { "x" } else { "y" }, if flag => "z"],
+            Line::from(vec![Span::raw("y"), Span::raw("z")])
+        );
+        assert_eq!(
+            line!["a", if flag { "b".bold() } else { "c".into() }],
+            Line::from(vec![Span::raw("a"), Span::raw("b").bold()])
+        );
+        assert_eq!(line![if flag { "-" } else { "=" }; 2].to_string(), "--");
+    }
+
+    #[test]
+    fn line_optional_spans() {
+        let branch = Some(" main".green());
+        let dirty: Option<Span> = None;
+        let line = line!["~/src", ?branch, ?dirty, " $"];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("~/src"),
+                Span::raw(" main").green(),
+                Span::raw(" $"),
+            ])
+        );
+
+        let line = line![?None::<&str>];
+        assert_eq!(line, Line::default());
+    }
+
+    #[test]
+    fn line_spread_spans() {
+        let tags = ["rust", "tui"];
+        let line = line!["[", ..tags.iter().map(|tag| tag.cyan()), "]"];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("["),
+                Span::raw("rust").cyan(),
+                Span::raw("tui").cyan(),
+                Span::raw("]"),
+            ])
+        );
+
+        let words = vec!["a", "b"];
+        let line = line![..words];
+        assert_eq!(line, Line::from(vec![Span::raw("a"), Span::raw("b")]));
+    }
+
+    #[test]
+    fn line_join() {
+        let line = line![join " | "; "home", "logs", "help"];
+        assert_eq!(line.to_string(), "home | logs | help");
+        assert_eq!(line.spans.len(), 5);
+
+        let items = ["a", "b"];
+        let line = line![join ", ".dim(); "[", ..items.iter().copied(), ?None::<&str>, "]"];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("["),
+                Span::raw(", ").dim(),
+                Span::raw("a"),
+                Span::raw(", ").dim(),
+                Span::raw("b"),
+                Span::raw(", ").dim(),
+                Span::raw("]"),
+            ])
+        );
+
+        assert_eq!(line![join ", "; "a"], Line::from("a"));
+        assert_eq!(line![join ", ";], Line::default());
+        assert_eq!(
+            line![center; join "/"; "a", "b"],
+            Line::from(vec![Span::raw("a"), Span::raw("/"), Span::raw("b")]).centered()
+        );
+    }
+
+    #[test]
+    fn line_style() {
+        let line = line![style: Style::new().green(); "ok: ", "3".bold()];
+        assert_eq!(line, Line::from(vec!["ok: ".into(), "3".bold()]).green());
+        let line = line![right; style: Color::Red; join "/"; "a", "b"];
+        assert_eq!(
+            line,
+            Line::from(vec![Span::raw("a"), Span::raw("/"), Span::raw("b")])
+                .red()
+                .right_aligned()
+        );
+        let line = line![style: Modifier::DIM; "-"; 2];
+        assert_eq!(line, Line::from(vec![Span::raw("-"); 2]).dim());
+    }
+
+    #[test]
+    fn spans() {
+        let spans: Vec<Span> = spans![];
+        assert!(spans.is_empty());
+        let branch = Some("main");
+        assert_eq!(
+            spans!["a", ?branch, {"{}", 1}, "b".bold()],
+            vec![
+                Span::raw("a"),
+                Span::raw("main"),
+                Span::raw("1"),
+                Span::raw("b").bold()
+            ]
+        );
+        assert_eq!(spans!["ab"; 2], vec![Span::raw("ab"); 2]);
+        assert_eq!(
+            spans![join ","; "a", "b"],
+            vec![Span::raw("a"), Span::raw(","), Span::raw("b")]
+        );
+    }
+
+    #[test]
+    fn line_display_elements() {
+        let count = 42;
+        let line = line!["count: ", count, " items", if count > 1 => 2.5, ..[1, 2]];
+        assert_eq!(line.to_string(), "count: 42 items2.512");
+        assert_eq!(line.spans[1], Span::raw("42"));
+    }
+
+    #[test]
+    fn line_char_elements() {
+        let line = line!['[', "x".bold(), ']'];
+        assert_eq!(
+            line,
+            Line::from(vec![Span::raw("["), Span::raw("x").bold(), Span::raw("]")])
+        );
+        assert_eq!(line![join '/'; "a", 'b'].to_string(), "a/b");
+    }
 }
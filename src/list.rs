@@ -0,0 +1,174 @@
+/// A macro for creating a [`List`] using vec! syntax.
+///
+/// `list!` is similar to the [`vec!`] macro, but it returns a [`List`] instead of a `Vec`. Each
+/// element can be anything that can be converted into a [`ListItem`], such as string literals,
+/// [`Span`]s, [`Line`]s, [`Text`]s or the result of the [`list_item!`] macro.
+///
+/// The elements can optionally be preceded by a list of directives separated from the elements by
+/// a `;`:
+///
+/// - `highlight: ">> "` sets the highlight symbol when given a string literal and the highlight
+///   style otherwise, e.g. `highlight: Style::new().reversed()`
+/// - any other `key: value` calls the builder method of the same name on [`List`], e.g.
+///   `highlight_symbol: ">> "`, `block: block!("Title")` or `style: Style::new().dim()`
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::{line, list};
+///
+/// let list = list!["item 1", "item 2"];
+/// let list = list!["item"; 3];
+/// let list = list![highlight: ">> "; "item 1", line!["item ", "2".bold()]];
+/// let list = list![
+///     highlight: ">> ",
+///     highlight: Style::new().reversed();
+///     "item 1",
+///     "item 2",
+/// ];
+/// ```
+///
+/// [`List`]: ratatui_widgets::list::List
+/// [`ListItem`]: ratatui_widgets::list::ListItem
+/// [`Span`]: ratatui_core::text::Span
+/// [`Line`]: ratatui_core::text::Line
+/// [`Text`]: ratatui_core::text::Text
+#[macro_export]
+macro_rules! list {
+    (@directives [$($settings:tt)*] highlight: $symbol:literal , $($rest:tt)*) => {
+        $crate::list!(@directives [$($settings)* .highlight_symbol($symbol)] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] highlight: $symbol:literal ; $($rest:tt)*) => {
+        $crate::list!(@directives [$($settings)* .highlight_symbol($symbol)] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] highlight: $style:expr , $($rest:tt)*) => {
+        $crate::list!(@directives [$($settings)* .highlight_style($style)] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] highlight: $style:expr ; $($rest:tt)*) => {
+        $crate::list!(@directives [$($settings)* .highlight_style($style)] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::list!(@directives [$($settings)* .$key($value)] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] $key:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::list!(@directives [$($settings)* .$key($value)] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] ; $($items:tt)*) => {
+        $crate::list![$($items)*] $($settings)*
+    };
+    () => {
+        $crate::ratatui_widgets::list::List::default()
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::list!(@directives [] $key : $($rest)*)
+    };
+    ($item:expr; $n:expr) => {
+        $crate::ratatui_widgets::list::List::new(
+            vec![$crate::ratatui_widgets::list::ListItem::from($item); $n]
+        )
+    };
+    ($($item:expr),+ $(,)?) => {{
+        $crate::ratatui_widgets::list::List::new(vec![
+        $(
+            $crate::ratatui_widgets::list::ListItem::from($item),
+        )+
+        ])
+    }};
+}
+
+/// A macro for creating a [`ListItem`] using vec! syntax.
+///
+/// `list_item!` accepts the same elements as the [`text!`] macro and wraps the resulting [`Text`]
+/// in a [`ListItem`]. This is useful for list items that span multiple lines.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{line, list, list_item};
+///
+/// let item = list_item!["first line", line!["second ", "line".bold()]];
+/// let list = list![list_item!["one", "two"], list_item!["three"]];
+/// ```
+///
+/// [`ListItem`]: ratatui_widgets::list::ListItem
+/// [`Text`]: ratatui_core::text::Text
+#[macro_export]
+macro_rules! list_item {
+    ($($lines:tt)*) => {
+        $crate::ratatui_widgets::list::ListItem::new($crate::text![$($lines)*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::{Line, Text},
+    };
+    use ratatui_widgets::{
+        block::Block,
+        list::{List, ListItem},
+    };
+
+    #[test]
+    fn list_empty() {
+        assert_eq!(list![], List::default());
+    }
+
+    #[test]
+    fn list_items() {
+        let list = list!["item 1", crate::line!["item ", "2".bold()]];
+        assert_eq!(
+            list,
+            List::new([
+                ListItem::new("item 1"),
+                ListItem::new(Line::from(vec!["item ".into(), "2".bold()])),
+            ])
+        );
+    }
+
+    #[test]
+    fn list_repeated_item() {
+        let list = list!["item"; 2];
+        assert_eq!(list, List::new(["item", "item"]));
+    }
+
+    #[test]
+    fn list_highlight_directives() {
+        let list = list![highlight: ">> "; "item 1", "item 2"];
+        assert_eq!(
+            list,
+            List::new(["item 1", "item 2"]).highlight_symbol(">> ")
+        );
+
+        let list = list![highlight: ">> ", highlight: Style::new().reversed(); "item"];
+        assert_eq!(
+            list,
+            List::new(["item"])
+                .highlight_symbol(">> ")
+                .highlight_style(Style::new().reversed())
+        );
+    }
+
+    #[test]
+    fn list_builder_directives() {
+        let list = list![block: Block::bordered(), style: Style::new().dim(); "item"];
+        assert_eq!(
+            list,
+            List::new(["item"])
+                .block(Block::bordered())
+                .style(Style::new().dim())
+        );
+    }
+
+    #[test]
+    fn list_item_lines() {
+        let item = list_item!["first", crate::line!["second"]];
+        assert_eq!(
+            item,
+            ListItem::new(Text::from(vec![Line::from("first"), Line::from("second")]))
+        );
+    }
+}
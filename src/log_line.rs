@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+use ratatui_core::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// A macro for creating a [`Line`] for a log message with a conventionally styled level.
+///
+/// The first argument is the level, and the other arguments are a format string and its
+/// arguments, like [`format!`]. The level is a string like `"warn"`, which is matched case
+/// insensitively, a [`log::Level`] with the `log` feature or a [`tracing::Level`] with the `tracing`
+/// feature. It is shown in uppercase and padded to five cells so that the messages of consecutive
+/// lines are aligned:
+///
+/// - `ERROR` is red and bold
+/// - `WARN` is yellow
+/// - `INFO` is green
+/// - `DEBUG` is blue
+/// - `TRACE` is magenta
+///
+/// Any other string is shown as given, without a style. The level can be preceded by
+/// `key: value` directives, separated from it by a `;`: `timestamp: value` and `target: value`
+/// put a dimmed timestamp before the level and a dimmed target (e.g. the module path) before the
+/// message, where both values implement [`Display`].
+///
+/// With the `log` feature, [`log_record_to_line`] converts a [`log::Record`] in the same way, and
+/// with the `tracing` feature, [`tracing_line!`] converts a [`tracing::Event`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span}};
+/// use ratatui_macros::log_line;
+///
+/// let retries = 3;
+/// let line = log_line!("warn", "{retries} retries left");
+/// assert_eq!(
+///     line,
+///     Line::from(vec![Span::raw("WARN ").yellow(), Span::raw(" 3 retries left")])
+/// );
+///
+/// let line = log_line!(timestamp: "12:00:01", target: "app::net"; "error", "connection lost");
+/// assert_eq!(line.to_string(), "12:00:01 ERROR app::net: connection lost");
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+/// [`Line`]: ratatui_core::text::Line
+/// [`log::Level`]: https://docs.rs/log/latest/log/enum.Level.html
+/// [`tracing::Level`]: https://docs.rs/tracing/latest/tracing/struct.Level.html
+/// [`tracing::Event`]: https://docs.rs/tracing/latest/tracing/struct.Event.html
+/// [`tracing_line!`]: https://docs.rs/ratatui-macros/latest/ratatui_macros/macro.tracing_line.html
+/// [`log::Record`]: https://docs.rs/log/latest/log/struct.Record.html
+/// [`log_record_to_line`]: https://docs.rs/ratatui-macros/latest/ratatui_macros/fn.log_record_to_line.html
+#[macro_export]
+macro_rules! log_line {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::log_line!(@directives [$($directives)* .$setting(&$value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::log_line!(@directives [$($directives)* .$setting(&$value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $level:expr, $($message:tt)+) => {
+        $crate::__LogLine::default() $($directives)* .line(&$level, format!($($message)+))
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::log_line!(@directives [] $setting : $($rest)*)
+    };
+    ($level:expr, $($message:tt)+) => {
+        $crate::__LogLine::default().line(&$level, format!($($message)+))
+    };
+}
+
+/// Converts a [`log::Record`] into a [`Line`] with a styled level, a dimmed target and the message.
+///
+/// The line is styled like the lines of [`log_line!`]. This function requires the `log` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use log::{Level, Record};
+/// use ratatui_macros::log_record_to_line;
+///
+/// let line = log_record_to_line(
+///     &Record::builder()
+///         .level(Level::Info)
+///         .target("app")
+///         .args(format_args!("started in {}ms", 12))
+///         .build(),
+/// );
+/// assert_eq!(line.to_string(), "INFO  app: started in 12ms");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`log::Record`]: log::Record
+/// [`log_line!`]: crate::log_line!
+#[cfg(feature = "log")]
+pub fn log_record_to_line(record: &log::Record<'_>) -> Line<'static> {
+    __LogLine::default()
+        .target(&record.target())
+        .line(&record.level(), record.args().to_string())
+}
+
+/// A log level whose label and style are shown by [`log_line!`].
+///
+/// [`log_line!`]: crate::log_line!
+#[doc(hidden)]
+pub trait __LogLevel {
+    /// Returns the label of the level and its style.
+    fn label(&self) -> (String, Style);
+}
+
+impl __LogLevel for str {
+    fn label(&self) -> (String, Style) {
+        let style = match self.to_ascii_lowercase().as_str() {
+            "error" => Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+            "warn" | "warning" => Style::new().fg(Color::Yellow),
+            "info" => Style::new().fg(Color::Green),
+            "debug" => Style::new().fg(Color::Blue),
+            "trace" => Style::new().fg(Color::Magenta),
+            _ => return (self.to_owned(), Style::new()),
+        };
+        let label = if self.eq_ignore_ascii_case("warning") {
+            String::from("WARN")
+        } else {
+            self.to_ascii_uppercase()
+        };
+        (label, style)
+    }
+}
+
+impl __LogLevel for String {
+    fn label(&self) -> (String, Style) {
+        self.as_str().label()
+    }
+}
+
+#[cfg(feature = "log")]
+impl __LogLevel for log::Level {
+    fn label(&self) -> (String, Style) {
+        self.as_str().label()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl __LogLevel for tracing::Level {
+    fn label(&self) -> (String, Style) {
+        self.as_str().label()
+    }
+}
+
+impl<T: __LogLevel + ?Sized> __LogLevel for &T {
+    fn label(&self) -> (String, Style) {
+        (**self).label()
+    }
+}
+
+/// The timestamp and target of a log line. See [`log_line!`].
+///
+/// [`log_line!`]: crate::log_line!
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct __LogLine {
+    timestamp: Option<String>,
+    target: Option<String>,
+}
+
+impl __LogLine {
+    pub fn timestamp(mut self, timestamp: &impl Display) -> Self {
+        self.timestamp = Some(timestamp.to_string());
+        self
+    }
+
+    pub fn target(mut self, target: &impl Display) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+
+    pub fn line(self, level: &impl __LogLevel, message: String) -> Line<'static> {
+        let dim = Style::new().add_modifier(Modifier::DIM);
+        let (label, style) = level.label();
+        let mut spans = Vec::with_capacity(4);
+        if let Some(timestamp) = self.timestamp {
+            spans.push(Span::styled(format!("{timestamp} "), dim));
+        }
+        spans.push(Span::styled(format!("{label:<5}"), style));
+        if let Some(target) = self.target {
+            spans.push(Span::styled(format!(" {target}:"), dim));
+        }
+        spans.push(Span::raw(format!(" {message}")));
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::Stylize,
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn log_line_levels() {
+        let level = String::from("Error");
+        assert_eq!(
+            log_line!(level, "failed: {}", 42),
+            Line::from(vec![
+                Span::raw("ERROR").red().bold(),
+                Span::raw(" failed: 42"),
+            ])
+        );
+        assert_eq!(
+            log_line!("warning", "a").spans[0],
+            Span::raw("WARN ").yellow()
+        );
+        assert_eq!(log_line!("INFO", "a").spans[0], Span::raw("INFO ").green());
+        assert_eq!(log_line!("debug", "a").spans[0], Span::raw("DEBUG").blue());
+        assert_eq!(
+            log_line!("trace", "a").spans[0],
+            Span::raw("TRACE").magenta()
+        );
+        assert_eq!(log_line!("fatal!", "a").spans[0], Span::raw("fatal!"));
+    }
+
+    #[test]
+    fn log_line_directives() {
+        let n = 2;
+        assert_eq!(
+            log_line!(timestamp: 1234, target: "db"; "info", "{n} rows"),
+            Line::from(vec![
+                Span::raw("1234 ").dim(),
+                Span::raw("INFO ").green(),
+                Span::raw(" db:").dim(),
+                Span::raw(" 2 rows"),
+            ])
+        );
+        assert_eq!(
+            log_line!(target: "db" ; "info", "a").to_string(),
+            "INFO  db: a"
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_record() {
+        use log::{Level, Record};
+
+        let line = super::log_record_to_line(
+            &Record::builder()
+                .level(Level::Warn)
+                .target("app::net")
+                .args(format_args!("{} retries left", 3))
+                .build(),
+        );
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("WARN ").yellow(),
+                Span::raw(" app::net:").dim(),
+                Span::raw(" 3 retries left"),
+            ])
+        );
+        assert_eq!(log_line!(Level::Debug, "a").to_string(), "DEBUG a");
+    }
+}
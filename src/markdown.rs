@@ -0,0 +1,107 @@
+/// A macro for creating a [`Text`] from inline markdown that is parsed at compile time.
+///
+/// The first argument is a string literal that supports:
+///
+/// - `**bold**` or `__bold__`
+/// - `*italic*` or `_italic_`
+/// - `~~strikethrough~~`
+/// - `` `code` ``, which is shown reversed
+/// - `# heading` (bold and underlined) and `## heading` (bold) at the start of a line
+///
+/// The markers follow the flanking rules of CommonMark, so a marker that is surrounded by
+/// whitespace, e.g. in `2 * 3`, or a `_` inside a word, e.g. in `snake_case`, is shown as is, and
+/// a run like `***` closes more than one marker.
+///
+/// Each line of the string becomes a [`Line`], and a `\` escapes a marker, e.g. `\*`. The string
+/// is also a format string: it may contain placeholders such as `{}` or `{name:?}` that are
+/// formatted with the remaining arguments, like [`format!`]. Unclosed markers and unused arguments
+/// are compile errors.
+///
+/// This macro requires the `proc-macros` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span, Text}};
+/// use ratatui_macros::markdown;
+///
+/// let path = "config.toml";
+/// let text = markdown!("**Error:** could not open `{}`", path);
+/// assert_eq!(
+///     text,
+///     Text::from(Line::from(vec![
+///         Span::raw("Error:").bold(),
+///         Span::raw(" could not open "),
+///         Span::raw("config.toml").reversed(),
+///     ]))
+/// );
+///
+/// let help = markdown!("# Help\nPress *q* to quit");
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::markdown;
+/// let text = markdown!("**unclosed");
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! markdown {
+    ($($tokens:tt)*) => {
+        $crate::ratatui_macros_proc::markdown!($crate, $($tokens)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::Stylize,
+        text::{Line, Span, Text},
+    };
+
+    #[test]
+    fn markdown_inline_styles() {
+        let text = markdown!("plain *italic* **bold** ~~gone~~ `code`");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("plain "),
+                Span::raw("italic").italic(),
+                Span::raw(" "),
+                Span::raw("bold").bold(),
+                Span::raw(" "),
+                Span::raw("gone").crossed_out(),
+                Span::raw(" "),
+                Span::raw("code").reversed(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn markdown_headings() {
+        let text = markdown!("# Help\n## Keys\n`q` quit");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(Span::raw("Help").bold().underlined()),
+                Line::from(Span::raw("Keys").bold()),
+                Line::from(vec![Span::raw("q").reversed(), Span::raw(" quit")]),
+            ])
+        );
+    }
+
+    #[test]
+    fn markdown_format_arguments() {
+        let path = "config.toml";
+        let line = 3;
+        let text = markdown!("**{}:{line}** {:?} {name}", path, "oops", name = line + 1);
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("config.toml:3").bold(),
+                Span::raw(" \"oops\" 4"),
+            ]))
+        );
+    }
+}
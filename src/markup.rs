@@ -0,0 +1,92 @@
+/// A macro for creating a [`Line`] from BBCode-style markup that is parsed at compile time.
+///
+/// The first argument is a string literal in which tags set the style of the text up to the
+/// matching closing tag. An opening tag contains one or more words separated by spaces:
+///
+/// - a modifier: `b` or `bold`, `d` or `dim`, `i` or `italic`, `u` or `underlined`, `s` or
+///   `strike`, `blink`, `reversed` and `hidden`
+/// - a foreground color, e.g. `red`, `light-blue`, `#1e293b` or `fg=red`
+/// - a background color, e.g. `on black` or `bg=black`
+///
+/// A tag is closed with `[/tag]`, repeating the contents of the opening tag, or with `[/]`, which
+/// closes the innermost open tag. `[[` writes a literal `[`. The string is also a format string:
+/// it may contain placeholders such as `{}` or `{name:?}` that are formatted with the remaining
+/// arguments, like [`format!`]. Unknown and unclosed tags are compile errors.
+///
+/// This macro requires the `proc-macros` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span}};
+/// use ratatui_macros::markup;
+///
+/// let line = markup!("[b]Save[/b] [red]unsaved changes[/red] will be lost");
+/// assert_eq!(
+///     line,
+///     Line::from(vec![
+///         Span::raw("Save").bold(),
+///         Span::raw(" "),
+///         Span::raw("unsaved changes").red(),
+///         Span::raw(" will be lost"),
+///     ])
+/// );
+///
+/// let count = 3;
+/// let line = markup!("[bold white on blue] {} [/] {count} files", "INFO");
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::markup;
+/// let line = markup!("[blod]typo[/]");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! markup {
+    ($($tokens:tt)*) => {
+        $crate::ratatui_macros_proc::markup!($crate, $($tokens)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn markup_tags() {
+        let line = markup!("[b]Save[/b] [red]unsaved [i]changes[/][/red] will be lost");
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("Save").bold(),
+                Span::raw(" "),
+                Span::raw("unsaved ").red(),
+                Span::raw("changes").red().italic(),
+                Span::raw(" will be lost"),
+            ])
+        );
+    }
+
+    #[test]
+    fn markup_colors_and_arguments() {
+        let count = 3;
+        let line = markup!("[bold #ffffff on blue] {} [/] {count} [[files]", "INFO");
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::styled(
+                    " INFO ",
+                    Style::new()
+                        .fg(Color::Rgb(255, 255, 255))
+                        .bg(Color::Blue)
+                        .bold()
+                ),
+                Span::raw(" 3 [files]"),
+            ])
+        );
+    }
+}
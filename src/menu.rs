@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+
+use ratatui_core::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+/// A macro for creating a [`Line`] of hotkeys and their labels, like the menu at the bottom of a
+/// terminal application, e.g. `F1 Help  F2 Save  F10 Quit`.
+///
+/// The items are `(key, label)` pairs, where both are anything that can be converted into a
+/// `Cow<str>`. Each key is bold and followed by its label, and the items are separated by two
+/// spaces.
+///
+/// The items can be preceded by `key: value` directives, separated from them by a `;`:
+///
+/// - `selected: index` highlights the label of the item at the index, which is a `usize` or an
+///   `Option<usize>`
+/// - `key_style: style`, `label_style: style` and `selected_style: style` replace the styles of
+///   the keys, the labels and the selected label, which is reversed by default
+/// - `separator: " | "` replaces the separator between the items
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::menu;
+///
+/// let line = menu![("F1", "Help"), ("F2", "Save"), ("F10", "Quit")];
+/// assert_eq!(line.to_string(), "F1 Help  F2 Save  F10 Quit");
+///
+/// let line = menu![
+///     selected: 1, key_style: Style::new().black().on_cyan();
+///     ("F1", "Help"), ("F2", "Save"),
+/// ];
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! menu {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::menu!(@directives [$($directives)* .$setting($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::menu!(@directives [$($directives)* .$setting($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $(($key:expr, $label:expr)),* $(,)?) => {
+        $crate::__Menu::default() $($directives)* $(.item($key, $label))* .line()
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::menu!(@directives [] $setting : $($rest)*)
+    };
+    ($(($key:expr, $label:expr)),* $(,)?) => {
+        $crate::__Menu::default() $(.item($key, $label))* .line()
+    };
+}
+
+/// The items and styles of a menu. See [`menu!`].
+///
+/// [`menu!`]: crate::menu!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Menu<'a> {
+    items: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    selected: Option<usize>,
+    key_style: Style,
+    label_style: Style,
+    selected_style: Style,
+    separator: Cow<'a, str>,
+}
+
+impl Default for __Menu<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            selected: None,
+            key_style: Style::new().add_modifier(Modifier::BOLD),
+            label_style: Style::new(),
+            selected_style: Style::new().add_modifier(Modifier::REVERSED),
+            separator: Cow::Borrowed("  "),
+        }
+    }
+}
+
+impl<'a> __Menu<'a> {
+    pub fn item(mut self, key: impl Into<Cow<'a, str>>, label: impl Into<Cow<'a, str>>) -> Self {
+        self.items.push((key.into(), label.into()));
+        self
+    }
+
+    pub fn selected(mut self, selected: impl Into<Option<usize>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    pub fn key_style(mut self, style: impl Into<Style>) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    pub fn label_style(mut self, style: impl Into<Style>) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    pub fn selected_style(mut self, style: impl Into<Style>) -> Self {
+        self.selected_style = style.into();
+        self
+    }
+
+    pub fn separator(mut self, separator: impl Into<Cow<'a, str>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn line(self) -> Line<'a> {
+        let mut spans = Vec::with_capacity(self.items.len() * 3);
+        for (i, (key, label)) in self.items.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(self.separator.clone()));
+            }
+            let label_style = if self.selected == Some(i) {
+                self.label_style.patch(self.selected_style)
+            } else {
+                self.label_style
+            };
+            spans.push(Span::styled(key, self.key_style));
+            spans.push(Span::styled(format!(" {label}"), label_style));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn menu_items() {
+        let quit = String::from("Quit");
+        let line = menu![("F1", "Help"), ("F10", quit),];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("F1").bold(),
+                Span::raw(" Help"),
+                Span::raw("  "),
+                Span::raw("F10").bold(),
+                Span::raw(" Quit"),
+            ])
+        );
+        assert_eq!(menu![], Line::default());
+    }
+
+    #[test]
+    fn menu_selected() {
+        let line = menu![selected: 1; ("a", "One"), ("b", "Two")];
+        assert_eq!(line.spans[4], Span::raw(" Two").reversed());
+        assert_eq!(line.spans[1], Span::raw(" One"));
+
+        let line = menu![selected: None; ("a", "One")];
+        assert_eq!(line.spans[1], Span::raw(" One"));
+    }
+
+    #[test]
+    fn menu_directives() {
+        let line = menu![
+            selected: Some(0),
+            key_style: Color::Cyan,
+            label_style: Color::Gray,
+            selected_style: Color::White,
+            separator: " | ";
+            ("a", "One"),
+            ("b", "Two"),
+        ];
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("a").cyan(),
+                Span::raw(" One").white(),
+                Span::raw(" | "),
+                Span::raw("b").cyan(),
+                Span::raw(" Two").gray(),
+            ])
+        );
+    }
+}
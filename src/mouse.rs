@@ -0,0 +1,196 @@
+/// A macro for matching a crossterm [`MouseEvent`] in a `match` arm or `if let`, or for checking
+/// whether it happened inside an area.
+///
+/// The pattern is the kind of the event, optionally followed by `at (column, row)` with patterns
+/// for the position of the pointer:
+///
+/// - `left_down`, `right_down` and `middle_down` match a button that is pressed, and `down(left)`
+///   matches the same as `left_down`
+/// - `left_up`, `right_up` and `middle_up`, or `up(button)`, match a button that is released
+/// - `left_drag`, `right_drag` and `middle_drag`, or `drag(button)`, match moving the pointer while
+///   a button is held down
+/// - `moved` matches moving the pointer without a button
+/// - `scroll_up`, `scroll_down`, `scroll_left` and `scroll_right` match the scroll wheel
+/// - `any` matches every kind of event
+///
+/// A pattern cannot check where the event happened, so `mouse!(event, kind in area)` is an
+/// expression instead, which is `true` if the event matches the kind and its position is inside
+/// the [`Rect`] `area` (see [`Rect::contains`]). Use it in a match guard, e.g.
+/// `event @ mouse!(left_down) if mouse!(event, any in button)`, or in an `if`. The
+/// [`KeyModifiers`] of the event are ignored.
+///
+/// To match a mouse event of a crossterm [`Event`], wrap the pattern in [`event!`], e.g.
+/// `event!(mouse(mouse!(scroll_up)))`.
+///
+/// This macro requires the `crossterm` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+/// # use ratatui_core::layout::Rect;
+/// use ratatui_macros::mouse;
+///
+/// let event = MouseEvent {
+///     kind: MouseEventKind::Down(MouseButton::Left),
+///     column: 3,
+///     row: 1,
+///     modifiers: KeyModifiers::NONE,
+/// };
+/// let button = Rect::new(0, 0, 10, 3);
+///
+/// let action = match event {
+///     event if mouse!(event, left_down in button) => "press button".to_string(),
+///     mouse!(left_down at (column, row)) => format!("click at {column}, {row}"),
+///     mouse!(drag(left)) => "drag".to_string(),
+///     mouse!(scroll_up) | mouse!(scroll_down) => "scroll".to_string(),
+///     _ => "other".to_string(),
+/// };
+/// assert_eq!(action, "press button");
+/// ```
+///
+/// [`MouseEvent`]: crossterm::event::MouseEvent
+/// [`KeyModifiers`]: crossterm::event::KeyModifiers
+/// [`Event`]: crossterm::event::Event
+/// [`Rect`]: ratatui_core::layout::Rect
+/// [`Rect::contains`]: ratatui_core::layout::Rect::contains
+/// [`event!`]: crate::event!
+#[macro_export]
+macro_rules! mouse {
+    (@button left) => { $crate::crossterm::event::MouseButton::Left };
+    (@button right) => { $crate::crossterm::event::MouseButton::Right };
+    (@button middle) => { $crate::crossterm::event::MouseButton::Middle };
+    (@button $button:ident) => {
+        compile_error!(concat!(
+            "unknown mouse button `",
+            stringify!($button),
+            "`, expected `left`, `right` or `middle`"
+        ))
+    };
+    (@kind left_down) => { $crate::mouse!(@kind down(left)) };
+    (@kind right_down) => { $crate::mouse!(@kind down(right)) };
+    (@kind middle_down) => { $crate::mouse!(@kind down(middle)) };
+    (@kind left_up) => { $crate::mouse!(@kind up(left)) };
+    (@kind right_up) => { $crate::mouse!(@kind up(right)) };
+    (@kind middle_up) => { $crate::mouse!(@kind up(middle)) };
+    (@kind left_drag) => { $crate::mouse!(@kind drag(left)) };
+    (@kind right_drag) => { $crate::mouse!(@kind drag(right)) };
+    (@kind middle_drag) => { $crate::mouse!(@kind drag(middle)) };
+    (@kind down($button:ident)) => {
+        $crate::crossterm::event::MouseEventKind::Down($crate::mouse!(@button $button))
+    };
+    (@kind up($button:ident)) => {
+        $crate::crossterm::event::MouseEventKind::Up($crate::mouse!(@button $button))
+    };
+    (@kind drag($button:ident)) => {
+        $crate::crossterm::event::MouseEventKind::Drag($crate::mouse!(@button $button))
+    };
+    (@kind moved) => { $crate::crossterm::event::MouseEventKind::Moved };
+    (@kind scroll_up) => { $crate::crossterm::event::MouseEventKind::ScrollUp };
+    (@kind scroll_down) => { $crate::crossterm::event::MouseEventKind::ScrollDown };
+    (@kind scroll_left) => { $crate::crossterm::event::MouseEventKind::ScrollLeft };
+    (@kind scroll_right) => { $crate::crossterm::event::MouseEventKind::ScrollRight };
+    (@kind any) => { _ };
+    (@kind $($kind:tt)*) => {
+        compile_error!(concat!(
+            "unknown kind of mouse event `",
+            stringify!($($kind)*),
+            "`, expected e.g. `left_down`, `drag(left)`, `moved`, `scroll_up` or `any`"
+        ))
+    };
+    ($event:expr, $kind:ident $(($button:ident))? in $area:expr $(,)?) => {
+        match ::core::borrow::Borrow::<$crate::crossterm::event::MouseEvent>::borrow(&$event) {
+            $crate::crossterm::event::MouseEvent {
+                kind: $crate::mouse!(@kind $kind $(($button))?),
+                column,
+                row,
+                ..
+            } => $crate::ratatui_core::layout::Rect::contains(
+                $area,
+                $crate::ratatui_core::layout::Position { x: *column, y: *row },
+            ),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    };
+    ($kind:ident $(($button:ident))? at ($column:pat, $row:pat $(,)?)) => {
+        $crate::crossterm::event::MouseEvent {
+            kind: $crate::mouse!(@kind $kind $(($button))?),
+            column: $column,
+            row: $row,
+            ..
+        }
+    };
+    ($kind:ident $(($button:ident))?) => {
+        $crate::crossterm::event::MouseEvent {
+            kind: $crate::mouse!(@kind $kind $(($button))?),
+            ..
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui_core::layout::Rect;
+
+    use crate::event;
+
+    fn event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::SHIFT,
+        }
+    }
+
+    #[test]
+    fn mouse_kinds() {
+        let down = event(MouseEventKind::Down(MouseButton::Right), 0, 0);
+        assert!(matches!(down, mouse!(right_down)));
+        assert!(matches!(down, mouse!(down(right))));
+        assert!(!matches!(down, mouse!(left_down)));
+        assert!(!matches!(down, mouse!(right_up)));
+        let drag = event(MouseEventKind::Drag(MouseButton::Middle), 0, 0);
+        assert!(matches!(drag, mouse!(middle_drag)));
+        assert!(matches!(drag, mouse!(drag(middle))));
+        assert!(matches!(drag, mouse!(any)));
+        let scroll = event(MouseEventKind::ScrollLeft, 0, 0);
+        assert!(matches!(scroll, mouse!(scroll_left)));
+        assert!(!matches!(scroll, mouse!(scroll_right)));
+        assert!(matches!(event(MouseEventKind::Moved, 0, 0), mouse!(moved)));
+        assert!(matches!(
+            event(MouseEventKind::Up(MouseButton::Left), 0, 0),
+            mouse!(up(left))
+        ));
+    }
+
+    #[test]
+    fn mouse_position() {
+        let click = event(MouseEventKind::Down(MouseButton::Left), 4, 2);
+        assert!(matches!(click, mouse!(left_down at (4, 2))));
+        assert!(!matches!(click, mouse!(left_down at (4, 3))));
+        let mouse!(left_down at (column, row)) = click else {
+            panic!("expected a left click");
+        };
+        assert_eq!((column, row), (4, 2));
+    }
+
+    #[test]
+    fn mouse_in_area() {
+        let click = event(MouseEventKind::Down(MouseButton::Left), 4, 2);
+        let area = Rect::new(2, 2, 3, 1);
+        assert!(mouse!(click, left_down in area));
+        assert!(mouse!(&click, any in area));
+        assert!(!mouse!(click, right_down in area));
+        assert!(!mouse!(click, left_down in Rect::new(0, 0, 4, 2)));
+    }
+
+    #[test]
+    fn mouse_in_event() {
+        let scroll = Event::Mouse(event(MouseEventKind::ScrollDown, 0, 0));
+        assert!(matches!(scroll, event!(mouse(mouse!(scroll_down)))));
+        assert!(!matches!(scroll, event!(mouse(mouse!(scroll_up)))));
+    }
+}
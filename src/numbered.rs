@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use ratatui_core::{
+    style::{Modifier, Style},
+    text::{Span, Text},
+};
+
+/// A macro for creating a [`Text`] with a gutter of line numbers in front of its lines.
+///
+/// The first argument is anything that can be converted into a [`Text`], e.g. a `&str` with
+/// several lines, a `Vec<Line>` or a [`Text`] collected from an iterator of lines. It can be
+/// followed by `key: value` settings:
+///
+/// - `start: 10` sets the number of the first line, which is 1 by default
+/// - `gutter_style: style` sets the style of the line numbers, which are dimmed by default
+/// - `separator: " │ "` sets what comes between the numbers and the lines, which is `" "` by
+///   default
+///
+/// The numbers are right-aligned to the width of the largest number, so the lines stay aligned
+/// with each other. The style and alignment of the text and of each line are kept. This is useful
+/// for code viewers and log panes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::numbered;
+///
+/// let source = "fn main() {\n    println!(\"hi\");\n}";
+/// let text = numbered!(source);
+/// assert_eq!(text.to_string(), "1 fn main() {\n2     println!(\"hi\");\n3 }");
+///
+/// let text = numbered!(source, start: 9, separator: " │ ");
+/// assert_eq!(text.lines[0].to_string(), " 9 │ fn main() {");
+/// assert_eq!(text.lines[2].to_string(), "11 │ }");
+///
+/// let text = numbered!(source, gutter_style: Style::new().dark_gray());
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+#[macro_export]
+macro_rules! numbered {
+    (@settings $numbered:expr ; $(,)?) => {
+        $numbered.text()
+    };
+    (@settings $numbered:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::numbered!(@settings $numbered.$key($value) ; $($($rest)*)?)
+    };
+    ($text:expr $(, $($rest:tt)*)?) => {
+        $crate::numbered!(@settings $crate::__Numbered::new($text) ; $($($rest)*)?)
+    };
+}
+
+/// The text and the settings of its line numbers. See [`numbered!`].
+///
+/// [`numbered!`]: crate::numbered!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Numbered<'a> {
+    text: Text<'a>,
+    start: usize,
+    gutter_style: Style,
+    separator: Cow<'a, str>,
+}
+
+impl<'a> __Numbered<'a> {
+    pub fn new(text: impl Into<Text<'a>>) -> Self {
+        Self {
+            text: text.into(),
+            start: 1,
+            gutter_style: Style::new().add_modifier(Modifier::DIM),
+            separator: Cow::Borrowed(" "),
+        }
+    }
+
+    pub const fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn gutter_style(mut self, style: impl Into<Style>) -> Self {
+        self.gutter_style = style.into();
+        self
+    }
+
+    pub fn separator(mut self, separator: impl Into<Cow<'a, str>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Returns the text with the gutter in front of each line.
+    pub fn text(self) -> Text<'a> {
+        let Self {
+            mut text,
+            start,
+            gutter_style,
+            separator,
+        } = self;
+        let last = start.saturating_add(text.lines.len().saturating_sub(1));
+        let width = last.to_string().len();
+        for (number, line) in (start..).zip(&mut text.lines) {
+            let gutter = Span::styled(format!("{number:>width$}{separator}"), gutter_style);
+            line.spans.insert(0, gutter);
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        layout::Alignment,
+        style::{Style, Stylize},
+        text::{Line, Span, Text},
+    };
+
+    use crate::text;
+
+    #[test]
+    fn numbered_lines() {
+        assert_eq!(
+            numbered!("a\nb"),
+            Text::from(vec![
+                Line::from(vec![Span::raw("1 ").dim(), Span::raw("a")]),
+                Line::from(vec![Span::raw("2 ").dim(), Span::raw("b")]),
+            ])
+        );
+        assert_eq!(numbered!(Text::default()), Text::default());
+    }
+
+    #[test]
+    fn numbered_alignment() {
+        let lines = vec![Line::from("x"); 10];
+        let text = numbered!(lines, separator: "|",);
+        assert_eq!(text.lines[0].to_string(), " 1|x");
+        assert_eq!(text.lines[9].to_string(), "10|x");
+
+        let text = numbered!(Text::from_iter(["a", "b"]), start: 99);
+        assert_eq!(text.to_string(), " 99 a\n100 b");
+    }
+
+    #[test]
+    fn numbered_keeps_styles() {
+        let text = text!["a".red(), Line::from("b").right_aligned()]
+            .bold()
+            .centered();
+        let text = numbered!(text, gutter_style: Style::new().blue());
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec![Span::raw("1 ").blue(), "a".red()]),
+                Line::from(vec![Span::raw("2 ").blue(), "b".into()]).right_aligned(),
+            ])
+            .bold()
+            .alignment(Alignment::Center)
+        );
+    }
+}
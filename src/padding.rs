@@ -0,0 +1,70 @@
+/// A macro for creating a [`Padding`] using a CSS-like shorthand.
+///
+/// - `padding!(1)` sets the same padding on all sides
+/// - `padding!(2, 1)` sets the horizontal (left and right) and vertical (top and bottom) padding
+/// - `padding!(left: 2, top: 1)` sets the given sides, and the other sides are 0
+/// - `padding!()` is no padding
+///
+/// The expansion is a constant expression, so the macro can be used to define constants.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_widgets::block::Padding;
+/// use ratatui_macros::{block, padding};
+///
+/// assert_eq!(padding!(1), Padding::uniform(1));
+/// assert_eq!(padding!(2, 1), Padding::symmetric(2, 1));
+/// assert_eq!(padding!(left: 2, top: 1), Padding::new(2, 0, 1, 0));
+///
+/// let block = block!("Title", padding: padding!(2, 1));
+/// ```
+///
+/// [`Padding`]: ratatui_widgets::block::Padding
+#[macro_export]
+macro_rules! padding {
+    () => {
+        $crate::ratatui_widgets::block::Padding::ZERO
+    };
+    ($($side:ident : $value:expr),+ $(,)?) => {{
+        let mut padding = $crate::ratatui_widgets::block::Padding::ZERO;
+        $(padding.$side = $value;)+
+        padding
+    }};
+    ($value:expr $(,)?) => {
+        $crate::ratatui_widgets::block::Padding::uniform($value)
+    };
+    ($horizontal:expr, $vertical:expr $(,)?) => {
+        $crate::ratatui_widgets::block::Padding::symmetric($horizontal, $vertical)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_widgets::block::Padding;
+
+    #[test]
+    fn padding_shorthand() {
+        assert_eq!(padding!(), Padding::ZERO);
+        assert_eq!(padding!(1), Padding::uniform(1));
+        assert_eq!(padding!(2, 1), Padding::symmetric(2, 1));
+
+        let width = 3;
+        assert_eq!(padding!(width * 2, width), Padding::new(6, 6, 3, 3));
+    }
+
+    #[test]
+    fn padding_sides() {
+        assert_eq!(padding!(left: 2, top: 1), Padding::new(2, 0, 1, 0));
+        assert_eq!(
+            padding!(left: 1, right: 2, top: 3, bottom: 4,),
+            Padding::new(1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn padding_const() {
+        const PADDING: Padding = padding!(bottom: 1);
+        assert_eq!(PADDING, Padding::bottom(1));
+    }
+}
@@ -0,0 +1,136 @@
+use ratatui_core::style::Color;
+
+/// A macro for defining a set of named [`Color`] constants.
+///
+/// Each entry is a `NAME: color` pair that expands to a `pub const NAME: Color` item. The color is
+/// one of:
+///
+/// - a hex string, e.g. `"#7aa2f7"` or the shorthand `"#fff"`, which is checked at compile time
+/// - the name of a [`Color`] variant, e.g. `Red`, `Rgb(30, 41, 59)` or `Indexed(208)`
+/// - any other constant expression, e.g. `Color::from_u32(0x7aa2f7)`
+///
+/// Entries can have attributes and doc comments, which are added to the generated constant.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// use ratatui_macros::palette;
+///
+/// palette! {
+///     /// The color of highlighted elements
+///     PRIMARY: "#7aa2f7",
+///     SURFACE: Rgb(30, 41, 59),
+///     ACCENT: Indexed(208),
+///     ERROR: LightRed,
+/// }
+///
+/// assert_eq!(PRIMARY, Color::Rgb(0x7a, 0xa2, 0xf7));
+/// assert_eq!(SURFACE, Color::Rgb(30, 41, 59));
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::palette;
+/// palette! { PRIMARY: "#7aa2f" }
+/// ```
+///
+/// [`Color`]: ratatui_core::style::Color
+#[macro_export]
+macro_rules! palette {
+    () => {};
+    ($(#[$meta:meta])* $name:ident : $hex:literal $(, $($rest:tt)*)?) => {
+        $(#[$meta])*
+        pub const $name: $crate::ratatui_core::style::Color = $crate::__hex_color($hex);
+        $crate::palette!($($($rest)*)?);
+    };
+    ($(#[$meta:meta])* $name:ident : $color:ident $(, $($rest:tt)*)?) => {
+        $(#[$meta])*
+        pub const $name: $crate::ratatui_core::style::Color = $crate::__color!($color);
+        $crate::palette!($($($rest)*)?);
+    };
+    ($(#[$meta:meta])* $name:ident : $color:ident ($($args:tt)*) $(, $($rest:tt)*)?) => {
+        $(#[$meta])*
+        pub const $name: $crate::ratatui_core::style::Color = $crate::__color!($color($($args)*));
+        $crate::palette!($($($rest)*)?);
+    };
+    ($(#[$meta:meta])* $name:ident : $color:expr $(, $($rest:tt)*)?) => {
+        $(#[$meta])*
+        pub const $name: $crate::ratatui_core::style::Color = $color;
+        $crate::palette!($($($rest)*)?);
+    };
+}
+
+/// Parses a hex color such as `#7aa2f7` or `#fff`, panicking if it is malformed.
+///
+/// This is a `const fn` so that a malformed color in a constant is a compile error.
+#[doc(hidden)]
+pub const fn __hex_color(hex: &str) -> Color {
+    let bytes = hex.as_bytes();
+    assert!(
+        !bytes.is_empty() && bytes[0] == b'#',
+        "hex colors must start with `#`"
+    );
+    let (r, g, b) = match bytes.len() {
+        // `#fff` is a shorthand for `#ffffff`
+        4 => (
+            hex_digit(bytes[1]) * 0x11,
+            hex_digit(bytes[2]) * 0x11,
+            hex_digit(bytes[3]) * 0x11,
+        ),
+        7 => (
+            hex_digit(bytes[1]) << 4 | hex_digit(bytes[2]),
+            hex_digit(bytes[3]) << 4 | hex_digit(bytes[4]),
+            hex_digit(bytes[5]) << 4 | hex_digit(bytes[6]),
+        ),
+        _ => panic!("hex colors must be written as `#rrggbb` or `#rgb`"),
+    };
+    Color::Rgb(r, g, b)
+}
+
+const fn hex_digit(byte: u8) -> u8 {
+    match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'f' => byte - b'a' + 10,
+        b'A'..=b'F' => byte - b'A' + 10,
+        _ => panic!("hex colors must only contain hex digits"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Color;
+
+    mod colors {
+        palette! {
+            PRIMARY: "#7aa2f7",
+            SURFACE: Rgb(30, 41, 59),
+            ACCENT: Indexed(208),
+            ERROR: LightRed,
+            WHITE: "#FFF",
+            /// A color defined with an expression
+            MUTED: ratatui_core::style::Color::from_u32(0x565f89),
+        }
+    }
+
+    #[test]
+    fn palette_constants() {
+        assert_eq!(colors::PRIMARY, Color::Rgb(0x7a, 0xa2, 0xf7));
+        assert_eq!(colors::SURFACE, Color::Rgb(30, 41, 59));
+        assert_eq!(colors::ACCENT, Color::Indexed(208));
+        assert_eq!(colors::ERROR, Color::LightRed);
+        assert_eq!(colors::WHITE, Color::Rgb(0xff, 0xff, 0xff));
+        assert_eq!(colors::MUTED, Color::Rgb(0x56, 0x5f, 0x89));
+    }
+
+    #[test]
+    #[should_panic = "hex colors must only contain hex digits"]
+    fn hex_color_invalid_digit() {
+        crate::__hex_color("#7aa2g7");
+    }
+
+    #[test]
+    #[should_panic = "hex colors must be written as `#rrggbb` or `#rgb`"]
+    fn hex_color_invalid_length() {
+        crate::__hex_color("#7aa2f");
+    }
+}
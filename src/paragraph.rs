@@ -0,0 +1,199 @@
+/// A macro for creating a [`Paragraph`] using vec! syntax.
+///
+/// `paragraph!` accepts the same elements as the [`text!`] macro. The elements can optionally be
+/// preceded by a list of directives separated from the elements by a `;`:
+///
+/// - `wrap` wraps the text, trimming leading whitespace (see [`Wrap`])
+/// - `trim: false` wraps the text and keeps the leading whitespace of the wrapped lines, e.g. for
+///   indented code, and `trim: true` is the same as `wrap`
+/// - `align: Center` sets the alignment to `Left`, `Center` or `Right` (see [`Alignment`]), `align`
+///   also accepts an expression
+/// - any other `key: value` calls the builder method of the same name on [`Paragraph`], e.g.
+///   `scroll: (y, x)` to scroll down by `y` lines and right by `x` columns,
+///   `block: block!("Title")` or `style: Style::new().dim()`
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::{block, line, paragraph};
+///
+/// let paragraph = paragraph!["hello", "world"];
+/// let paragraph = paragraph![wrap, align: Center; "line one", line!["two".bold()]];
+/// let paragraph = paragraph![block: block!("Title"), style: Style::new().dim(); "content"];
+///
+/// let offset = 3;
+/// let paragraph = paragraph![
+///     block: block!("Log", borders: ALL),
+///     trim: false,
+///     scroll: (offset, 0);
+///     "  indented line",
+///     "another line",
+/// ];
+/// ```
+///
+/// [`Paragraph`]: ratatui_widgets::paragraph::Paragraph
+/// [`Wrap`]: ratatui_widgets::paragraph::Wrap
+/// [`Alignment`]: ratatui_core::layout::Alignment
+#[macro_export]
+macro_rules! paragraph {
+    (@directives [$($settings:tt)*] wrap , $($rest:tt)*) => {
+        $crate::paragraph!(@directives [
+            $($settings)* .wrap($crate::ratatui_widgets::paragraph::Wrap { trim: true })
+        ] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] wrap ; $($rest:tt)*) => {
+        $crate::paragraph!(@directives [
+            $($settings)* .wrap($crate::ratatui_widgets::paragraph::Wrap { trim: true })
+        ] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] trim: $trim:expr , $($rest:tt)*) => {
+        $crate::paragraph!(@directives [
+            $($settings)* .wrap($crate::ratatui_widgets::paragraph::Wrap { trim: $trim })
+        ] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] trim: $trim:expr ; $($rest:tt)*) => {
+        $crate::paragraph!(@directives [
+            $($settings)* .wrap($crate::ratatui_widgets::paragraph::Wrap { trim: $trim })
+        ] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] align: $alignment:ident , $($rest:tt)*) => {
+        $crate::paragraph!(@directives [
+            $($settings)* .alignment($crate::__alignment!($alignment))
+        ] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] align: $alignment:ident ; $($rest:tt)*) => {
+        $crate::paragraph!(@directives [
+            $($settings)* .alignment($crate::__alignment!($alignment))
+        ] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] align: $alignment:expr , $($rest:tt)*) => {
+        $crate::paragraph!(@directives [$($settings)* .alignment($alignment)] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] align: $alignment:expr ; $($rest:tt)*) => {
+        $crate::paragraph!(@directives [$($settings)* .alignment($alignment)] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::paragraph!(@directives [$($settings)* .$key($value)] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] $key:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::paragraph!(@directives [$($settings)* .$key($value)] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] ; $($lines:tt)*) => {
+        $crate::ratatui_widgets::paragraph::Paragraph::new($crate::text![$($lines)*])
+            $($settings)*
+    };
+    () => {
+        $crate::ratatui_widgets::paragraph::Paragraph::default()
+    };
+    (wrap , $($rest:tt)*) => {
+        $crate::paragraph!(@directives [] wrap , $($rest)*)
+    };
+    (wrap ; $($rest:tt)*) => {
+        $crate::paragraph!(@directives [] wrap ; $($rest)*)
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::paragraph!(@directives [] $key : $($rest)*)
+    };
+    ($($lines:tt)+) => {
+        $crate::ratatui_widgets::paragraph::Paragraph::new($crate::text![$($lines)+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        layout::Alignment,
+        style::{Style, Stylize},
+        text::{Line, Text},
+    };
+    use ratatui_widgets::{
+        block::Block,
+        paragraph::{Paragraph, Wrap},
+    };
+
+    #[test]
+    fn paragraph_empty() {
+        assert_eq!(paragraph![], Paragraph::default());
+    }
+
+    #[test]
+    fn paragraph_lines() {
+        let paragraph = paragraph!["hello", "world"];
+        assert_eq!(
+            paragraph,
+            Paragraph::new(Text::from(vec!["hello".into(), "world".into()]))
+        );
+
+        let paragraph = paragraph!["hello"; 2];
+        assert_eq!(
+            paragraph,
+            Paragraph::new(Text::from(vec!["hello".into(), "hello".into()]))
+        );
+    }
+
+    #[test]
+    fn paragraph_wrap_and_align() {
+        let paragraph = paragraph![wrap, align: Center; "line one", crate::line!["two".bold()]];
+        assert_eq!(
+            paragraph,
+            Paragraph::new(Text::from(vec![
+                Line::from("line one"),
+                Line::from("two".bold()),
+            ]))
+            .wrap(Wrap { trim: true })
+            .alignment(Alignment::Center)
+        );
+
+        let paragraph = paragraph![wrap; "hello"];
+        assert_eq!(paragraph, Paragraph::new("hello").wrap(Wrap { trim: true }));
+
+        let alignment = Alignment::Right;
+        let paragraph = paragraph![align: alignment; "hello"];
+        assert_eq!(
+            paragraph,
+            Paragraph::new("hello").alignment(Alignment::Right)
+        );
+    }
+
+    #[test]
+    fn paragraph_builder_directives() {
+        let paragraph = paragraph![
+            scroll: (1, 2),
+            block: Block::bordered(),
+            style: Style::new().dim();
+            "hello",
+        ];
+        assert_eq!(
+            paragraph,
+            Paragraph::new("hello")
+                .scroll((1, 2))
+                .block(Block::bordered())
+                .style(Style::new().dim())
+        );
+    }
+
+    #[test]
+    fn paragraph_trim() {
+        let paragraph = paragraph![trim: false; "  code"];
+        assert_eq!(
+            paragraph,
+            Paragraph::new("  code").wrap(Wrap { trim: false })
+        );
+
+        let trim = true;
+        let paragraph = paragraph![trim: trim, scroll: (2, 0); "hello"];
+        assert_eq!(
+            paragraph,
+            Paragraph::new("hello")
+                .wrap(Wrap { trim: true })
+                .scroll((2, 0))
+        );
+
+        let paragraph = paragraph![wrap, trim: false; "hello"];
+        assert_eq!(
+            paragraph,
+            Paragraph::new("hello").wrap(Wrap { trim: false })
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use ratatui_core::{
+    style::Style,
+    text::{Line, Span},
+};
+
+/// A macro for creating a [`Line`] that shows a textual progress bar, e.g. `██████░░░░ 62%`.
+///
+/// The first argument is either a ratio between `0.0` and `1.0`, or a percentage written as
+/// `62%`. It can be followed by `key: value` settings:
+///
+/// - `width: 30` sets the width of the bar in cells, which is 20 by default
+/// - `filled: '█'` and `empty: '░'` set the characters of the filled and empty parts of the bar
+/// - `filled_style: style` and `empty_style: style` set the styles of the two parts
+/// - `label: Right`, `label: Left` or `label: Hidden` places the percentage after the bar (the
+///   default), before the bar or leaves it out
+///
+/// The ratio is clamped between `0.0` and `1.0`. This is useful where a `Gauge` widget does not
+/// fit, e.g. in the items of a list or the cells of a table.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::progress;
+///
+/// let line = progress!(0.62, width: 10);
+/// assert_eq!(line.to_string(), "██████░░░░ 62%");
+///
+/// let line = progress!(25%, width: 8, filled: '#', empty: '-', label: Left);
+/// assert_eq!(line.to_string(), "25% ##------");
+///
+/// let line = progress!(0.5, filled_style: Style::new().green(), label: Hidden);
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! progress {
+    (@settings $progress:expr ; $(,)?) => {
+        $progress.line()
+    };
+    (@settings $progress:expr ; label: $label:ident $(, $($rest:tt)*)?) => {
+        $crate::progress!(@settings
+            $progress.label($crate::__ProgressLabel::$label) ;
+            $($($rest)*)?
+        )
+    };
+    (@settings $progress:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::progress!(@settings $progress.$key($value) ; $($($rest)*)?)
+    };
+    ($percent:tt % $(, $($rest:tt)*)?) => {
+        $crate::progress!(@settings
+            $crate::__Progress::new(f64::from($percent) / 100.0) ;
+            $($($rest)*)?
+        )
+    };
+    ($ratio:expr $(, $($rest:tt)*)?) => {
+        $crate::progress!(@settings $crate::__Progress::new($ratio) ; $($($rest)*)?)
+    };
+}
+
+/// Where the percentage is shown by [`progress!`].
+///
+/// [`progress!`]: crate::progress!
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum __ProgressLabel {
+    Left,
+    Right,
+    Hidden,
+}
+
+/// The ratio and settings of a progress bar. See [`progress!`].
+///
+/// [`progress!`]: crate::progress!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Progress {
+    ratio: f64,
+    width: u16,
+    filled: char,
+    empty: char,
+    filled_style: Style,
+    empty_style: Style,
+    label: __ProgressLabel,
+}
+
+impl __Progress {
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            ratio: if ratio.is_nan() {
+                0.0
+            } else {
+                ratio.clamp(0.0, 1.0)
+            },
+            width: 20,
+            filled: '█',
+            empty: '░',
+            filled_style: Style::new(),
+            empty_style: Style::new(),
+            label: __ProgressLabel::Right,
+        }
+    }
+
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub const fn filled(mut self, filled: char) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    pub const fn empty(mut self, empty: char) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    pub fn filled_style(mut self, style: impl Into<Style>) -> Self {
+        self.filled_style = style.into();
+        self
+    }
+
+    pub fn empty_style(mut self, style: impl Into<Style>) -> Self {
+        self.empty_style = style.into();
+        self
+    }
+
+    pub const fn label(mut self, label: __ProgressLabel) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn line(self) -> Line<'static> {
+        let width = usize::from(self.width);
+        let filled = ((self.ratio * width as f64).round() as usize).min(width);
+        let percent = (self.ratio * 100.0).round();
+        let mut spans = Vec::with_capacity(3);
+        if self.label == __ProgressLabel::Left {
+            spans.push(Span::raw(format!("{percent}% ")));
+        }
+        if filled > 0 {
+            let content = self.filled.to_string().repeat(filled);
+            spans.push(Span::styled(content, self.filled_style));
+        }
+        if filled < width {
+            let content = self.empty.to_string().repeat(width - filled);
+            spans.push(Span::styled(content, self.empty_style));
+        }
+        if self.label == __ProgressLabel::Right {
+            spans.push(Span::raw(format!(" {percent}%")));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn progress_ratio() {
+        assert_eq!(
+            progress!(0.62, width: 30).to_string(),
+            "███████████████████░░░░░░░░░░░ 62%"
+        );
+        assert_eq!(progress!(0.0, width: 4).to_string(), "░░░░ 0%");
+        assert_eq!(progress!(1.0, width: 4).to_string(), "████ 100%");
+        assert_eq!(progress!(0.5).width(), 24);
+    }
+
+    #[test]
+    fn progress_clamps() {
+        assert_eq!(progress!(1.5, width: 2).to_string(), "██ 100%");
+        assert_eq!(progress!(-1.0, width: 2).to_string(), "░░ 0%");
+        assert_eq!(progress!(f64::NAN, width: 2).to_string(), "░░ 0%");
+    }
+
+    #[test]
+    fn progress_percent() {
+        let percent = 75_u16;
+        assert_eq!(progress!(percent %, width: 4).to_string(), "███░ 75%");
+        assert_eq!(progress!(10%, width: 10,).to_string(), "█░░░░░░░░░ 10%");
+    }
+
+    #[test]
+    fn progress_settings() {
+        let line = progress!(
+            0.5,
+            width: 4,
+            filled: '=',
+            empty: ' ',
+            filled_style: Color::Green,
+            empty_style: Color::DarkGray,
+            label: Left,
+        );
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("50% "),
+                Span::raw("==").green(),
+                Span::raw("  ").dark_gray(),
+            ])
+        );
+        assert_eq!(
+            progress!(0.5, width: 2, label: Hidden),
+            Line::from(vec![Span::raw("█"), Span::raw("░")])
+        );
+    }
+}
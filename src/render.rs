@@ -0,0 +1,263 @@
+use std::fmt::Write;
+
+use ratatui_core::{
+    backend::TestBackend,
+    buffer::Buffer,
+    style::{Color, Modifier, Style},
+    terminal::Terminal,
+    widgets::Widget,
+};
+
+use crate::buffer::visible_cells;
+
+/// A macro for testing a widget by rendering it into a [`TestBackend`] of the given size.
+///
+/// The first argument is the size, either `WIDTHxHEIGHT`, e.g. `30x5`, or a `(width, height)`
+/// tuple of expressions. The second argument is the widget, and the third argument is a closure
+/// that receives the rendered [`Buffer`]. The macro creates the backend and a [`Terminal`], draws
+/// the widget over the whole area of the terminal and returns the result of the closure.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::buffer::Buffer;
+/// use ratatui_macros::{assert_buffer, render_test};
+///
+/// render_test!(7x1, "hello", |buffer| {
+///     assert_buffer!(buffer, ["hello  "]);
+/// });
+///
+/// let width = 3;
+/// let area = render_test!((width, 2), "hi", |buffer: &Buffer| buffer.area);
+/// assert_eq!((area.width, area.height), (3, 2));
+/// ```
+///
+/// [`TestBackend`]: ratatui_core::backend::TestBackend
+/// [`Buffer`]: ratatui_core::buffer::Buffer
+/// [`Terminal`]: ratatui_core::terminal::Terminal
+#[macro_export]
+macro_rules! render_test {
+    (($width:expr, $height:expr), $widget:expr, $test:expr $(,)?) => {
+        $crate::__render_test($width, $height, $widget, $test)
+    };
+    ($size:tt, $widget:expr, $test:expr $(,)?) => {{
+        const SIZE: (u16, u16) = $crate::__parse_cells(stringify!($size));
+        $crate::__render_test(SIZE.0, SIZE.1, $widget, $test)
+    }};
+}
+
+/// A macro for rendering a widget at the given size and returning the result as a `String`.
+///
+/// The first argument is the size, either `WIDTHxHEIGHT`, e.g. `40x10`, or a `(width, height)`
+/// tuple of expressions, and the second argument is the widget. The rows of the rendered buffer
+/// are joined with `\n`, and each row keeps its trailing spaces so that the string shows the whole
+/// area. This is useful for snapshot tests and for printing a widget while debugging.
+///
+/// With `ansi` as a third argument, the string also contains the ANSI escape sequences that set
+/// the colors and modifiers of the cells, so printing it to a terminal shows the styled widget.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::render_to_string;
+/// use ratatui_widgets::block::Block;
+///
+/// let output = render_to_string!(6x3, Block::bordered().title("hi"));
+/// assert_eq!(output, "┌hi──┐\n│    │\n└────┘");
+///
+/// let output = render_to_string!((5, 1), "hi".red(), ansi);
+/// assert_eq!(output, "\x1b[31mhi\x1b[0m   ");
+/// ```
+#[macro_export]
+macro_rules! render_to_string {
+    (($width:expr, $height:expr), $widget:expr, ansi $(,)?) => {
+        $crate::__render_test($width, $height, $widget, $crate::__ansi_string)
+    };
+    (($width:expr, $height:expr), $widget:expr $(,)?) => {
+        $crate::__render_test($width, $height, $widget, $crate::__plain_string)
+    };
+    ($size:tt, $widget:expr $(, $($ansi:ident)?)? $(,)?) => {{
+        const SIZE: (u16, u16) = $crate::__parse_cells(stringify!($size));
+        $crate::render_to_string!((SIZE.0, SIZE.1), $widget $(, $($ansi)?)?)
+    }};
+}
+
+/// Renders the widget into a [`TestBackend`] of the given size and passes its buffer to `test`.
+#[doc(hidden)]
+#[track_caller]
+pub fn __render_test<W, R>(width: u16, height: u16, widget: W, test: impl FnOnce(&Buffer) -> R) -> R
+where
+    W: Widget,
+{
+    let mut terminal =
+        Terminal::new(TestBackend::new(width, height)).expect("the test backend never fails");
+    terminal
+        .draw(|frame| frame.render_widget(widget, frame.area()))
+        .expect("the test backend never fails");
+    test(terminal.backend().buffer())
+}
+
+/// Returns the symbols of the buffer, with the rows joined by `\n`.
+#[doc(hidden)]
+pub fn __plain_string(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let rows = (area.top()..area.bottom()).map(|y| {
+        visible_cells(buffer, y)
+            .map(|(x, _)| buffer[(x, y)].symbol())
+            .collect::<String>()
+    });
+    rows.collect::<Vec<_>>().join("\n")
+}
+
+/// Returns the symbols of the buffer with the SGR sequences that set their styles, with the rows
+/// joined by `\n`. The style is reset at the end of each row that changes it.
+#[doc(hidden)]
+pub fn __ansi_string(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let rows = (area.top()..area.bottom()).map(|y| {
+        let mut row = String::new();
+        let mut current = Style::new();
+        for (x, _) in visible_cells(buffer, y) {
+            let cell = &buffer[(x, y)];
+            // `Color::Reset` is the default color of a cell, which needs no parameter
+            let style = Style {
+                fg: Some(cell.fg).filter(|fg| *fg != Color::Reset),
+                bg: Some(cell.bg).filter(|bg| *bg != Color::Reset),
+                ..Style::new().add_modifier(cell.modifier)
+            };
+            if style != current {
+                row.push_str(&sgr(style));
+                current = style;
+            }
+            row.push_str(cell.symbol());
+        }
+        if current != Style::new() {
+            row.push_str("\x1b[0m");
+        }
+        row
+    });
+    rows.collect::<Vec<_>>().join("\n")
+}
+
+/// Returns the SGR sequence that resets the style and then sets the colors and modifiers of
+/// `style`.
+fn sgr(style: Style) -> String {
+    let mut sequence = String::from("\x1b[0");
+    let modifiers = style.add_modifier;
+    for (code, modifier) in [
+        (1, Modifier::BOLD),
+        (2, Modifier::DIM),
+        (3, Modifier::ITALIC),
+        (4, Modifier::UNDERLINED),
+        (5, Modifier::SLOW_BLINK),
+        (6, Modifier::RAPID_BLINK),
+        (7, Modifier::REVERSED),
+        (8, Modifier::HIDDEN),
+        (9, Modifier::CROSSED_OUT),
+    ] {
+        if modifiers.contains(modifier) {
+            let _ = write!(sequence, ";{code}");
+        }
+    }
+    if let Some(fg) = style.fg {
+        write_color(&mut sequence, fg, 30);
+    }
+    if let Some(bg) = style.bg {
+        write_color(&mut sequence, bg, 40);
+    }
+    // `ESC [ 0 ; 31 m` is shortened to `ESC [ 31 m`
+    let sequence = match sequence.strip_prefix("\x1b[0;") {
+        Some(rest) => format!("\x1b[{rest}"),
+        None => sequence,
+    };
+    sequence + "m"
+}
+
+/// Writes the SGR parameters of a color, where `base` is 30 for the foreground and 40 for the
+/// background.
+fn write_color(sequence: &mut String, color: Color, base: u8) {
+    let _ = match color {
+        Color::Reset => Ok(()),
+        Color::Black => write!(sequence, ";{base}"),
+        Color::Red => write!(sequence, ";{}", base + 1),
+        Color::Green => write!(sequence, ";{}", base + 2),
+        Color::Yellow => write!(sequence, ";{}", base + 3),
+        Color::Blue => write!(sequence, ";{}", base + 4),
+        Color::Magenta => write!(sequence, ";{}", base + 5),
+        Color::Cyan => write!(sequence, ";{}", base + 6),
+        Color::Gray => write!(sequence, ";{}", base + 7),
+        Color::DarkGray => write!(sequence, ";{}", base + 60),
+        Color::LightRed => write!(sequence, ";{}", base + 61),
+        Color::LightGreen => write!(sequence, ";{}", base + 62),
+        Color::LightYellow => write!(sequence, ";{}", base + 63),
+        Color::LightBlue => write!(sequence, ";{}", base + 64),
+        Color::LightMagenta => write!(sequence, ";{}", base + 65),
+        Color::LightCyan => write!(sequence, ";{}", base + 66),
+        Color::White => write!(sequence, ";{}", base + 67),
+        Color::Indexed(index) => write!(sequence, ";{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => write!(sequence, ";{};2;{r};{g};{b}", base + 8),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        style::{Color, Stylize},
+        text::Line,
+    };
+    use ratatui_widgets::{block::Block, borders::Borders};
+
+    use crate::assert_buffer;
+
+    #[test]
+    fn render_test_size() {
+        let area = render_test!(6x3, Block::new(), |buffer: &Buffer| buffer.area);
+        assert_eq!((area.width, area.height), (6, 3));
+
+        let (width, height) = (4, 2);
+        let area = render_test!((width, height), Block::new(), |buffer: &Buffer| buffer.area);
+        assert_eq!((area.width, area.height), (4, 2));
+    }
+
+    #[test]
+    fn render_test_buffer() {
+        render_test!(
+            6x3,
+            Block::new().borders(Borders::ALL).title("hi"),
+            |buffer| {
+                assert_buffer!(buffer, ["┌hi──┐", "│    │", "└────┘"]);
+            },
+        );
+        render_test!(5x1, Line::from("bold".bold()), |buffer| {
+            assert_buffer!(buffer, ["bold "; (0..4, 0) => ratatui_core::style::Modifier::BOLD]);
+        });
+    }
+
+    #[test]
+    fn render_to_string_plain() {
+        let output = render_to_string!(6x3, Block::new().borders(Borders::ALL).title("hi"));
+        assert_eq!(output, "┌hi──┐\n│    │\n└────┘");
+
+        let (width, height) = (4, 1);
+        assert_eq!(render_to_string!((width, height), "界a"), "界a ");
+    }
+
+    #[test]
+    fn render_to_string_ansi() {
+        let line = Line::from(vec![
+            "a".bold().red(),
+            "b".on_blue(),
+            "c".fg(Color::Rgb(1, 2, 3)),
+        ]);
+        assert_eq!(
+            render_to_string!(4x2, line, ansi),
+            "\x1b[1;31ma\x1b[44mb\x1b[38;2;1;2;3mc\x1b[0m \n    "
+        );
+        assert_eq!(
+            render_to_string!((1, 1), "x".fg(Color::Indexed(208)).dim(), ansi),
+            "\x1b[2;38;5;208mx\x1b[0m"
+        );
+    }
+}
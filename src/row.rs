@@ -1,6 +1,11 @@
+use ratatui_core::style::{Style, Styled};
+use ratatui_widgets::table::Row;
+
 /// A macro for creating a [`Row`] using vec! syntax.
 ///
-/// `row!` is similar to the [`vec!`] macro, but it returns a [`Row`] instead of a `Vec`.
+/// `row!` is similar to the [`vec!`] macro, but it returns a [`Row`] instead of a `Vec`. Each
+/// element is anything that can be converted into a [`Cell`], or any other value that implements
+/// [`Display`], e.g. a number, which is converted with [`ToString`].
 ///
 /// # Examples
 ///
@@ -12,6 +17,8 @@
 ///
 /// let row = row!["hello", "world"];
 /// let row = row!["hello".red(), "world".red().bold()];
+/// let (name, size) = ("Cargo.toml", 1024);
+/// let row = row![name, size];
 /// ```
 ///
 /// * Create an empty [`Row`]:
@@ -40,31 +47,260 @@
 /// ];
 /// ```
 ///
-/// [`Row`]: crate::widgets::Row
-/// [`Cell`]: crate::widgets::Cell
+/// * Format a [`Cell`] in place by wrapping a format string and its arguments in braces, like in
+///   [`line!`] and [`text!`]:
+///
+/// ```rust
+/// # use ratatui_macros::row;
+/// let count = 3;
+/// let row = row!["files", {"{} items", count}, {"{count} total"}];
+/// ```
+///
+/// * Style a single [`Cell`] with `cell => style`, e.g. `sum => Style::new().yellow()`, where the
+///   style is anything that can be converted into a [`Style`]:
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// # use ratatui_macros::row;
+/// let sum = 42;
+/// let row = row!["total".bold(), sum => Style::new().yellow(), {"{sum}%"} => Color::Red];
+/// ```
+///
+/// * Call builder methods of [`Row`] by listing `key: value` pairs after the cells, following a
+///   `;`, e.g. `height: 2`, `top_margin: 1`, `bottom_margin: 1` or `style: Style::new().dim()`:
+///
+/// ```rust
+/// # use ratatui_macros::{row, text};
+/// let row = row!["name", text!["first", "second"]; height: 2, bottom_margin: 1];
+/// ```
+///
+/// * Insert every [`Cell`] of an iterator by prefixing it with `..`:
+///
+/// ```rust
+/// # use ratatui_macros::row;
+/// let scores = [90, 85, 77];
+/// let row = row!["Alice", ..scores.iter().map(|score| score.to_string())];
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+/// [`Row`]: ratatui_widgets::table::Row
+/// [`Cell`]: ratatui_widgets::table::Cell
+/// [`Style`]: ratatui_core::style::Style
+/// [`line!`]: crate::line!
+/// [`text!`]: crate::text!
 #[macro_export]
 macro_rules! row {
     () => {
-        ::ratatui_widgets::table::Row::default()
+        $crate::ratatui_widgets::table::Row::default()
+    };
+    // Measures the width of each cell with the `__ColumnWidths` named by `$widths`, which is how
+    // `table!` computes `widths: auto`
+    (@measure $widths:ident ;) => {
+        $crate::ratatui_widgets::table::Row::default()
+    };
+    (@measure $widths:ident ; { $format:literal $(, $($args:tt)*)? } ; $n:expr) => {
+        $crate::row!(@measure $widths ; ::std::format!($format $(, $($args)*)?); $n)
+    };
+    (@measure $widths:ident ; { $format:literal $(, $($args:tt)*)? } $($rest:tt)*) => {
+        $crate::row!(@cells ($widths) [] { $format $(, $($args)*)? } $($rest)*)
+    };
+    (@measure $widths:ident ; $cell:expr; $n:expr) => {{
+        let cell = $cell;
+        let n = $n;
+        for _ in 0..n {
+            $widths.measure($crate::__cell_width!(&cell));
+        }
+        $crate::ratatui_widgets::table::Row::new(
+            $crate::__repeat::<$crate::ratatui_widgets::table::Cell>(cell, n),
+        )
+    }};
+    (@measure $widths:ident ; $($head:tt $(. $method:ident ($($args:tt)*))*),+ ,) => {
+        $crate::row!(@elements ($widths) [$($head $(. $method($($args)*))*),+])
+    };
+    (@measure $widths:ident ;
+        $($head:tt $(. $method:ident ($($args:tt)*))*),+
+        $(; $($key:ident : $value:expr),+ $(,)?)?
+    ) => {
+        $crate::row!(@elements ($widths) [$($head $(. $method($($args)*))*),+]
+            $(; $($key : $value),+)?
+        )
+    };
+    (@measure $widths:ident ; $($cells:tt)+) => {
+        $crate::row!(@cells ($widths) [] $($cells)+)
+    };
+    // Like `line!`, a list of cells that are each a single token tree followed by method calls is
+    // matched as a whole, which keeps long rows within the recursion limit
+    ($($head:tt $(. $method:ident ($($args:tt)*))*),+ ,) => {
+        $crate::row!(@elements () [$($head $(. $method($($args)*))*),+])
+    };
+    (
+        $($head:tt $(. $method:ident ($($args:tt)*))*),+
+        $(; $($key:ident : $value:expr),+ $(,)?)?
+    ) => {
+        $crate::row!(@elements () [$($head $(. $method($($args)*))*),+] $(; $($key : $value),+)?)
+    };
+    ({ $format:literal $(, $($args:tt)*)? } ; $n:expr) => {
+        $crate::row!(::std::format!($format $(, $($args)*)?); $n)
+    };
+    ({ $format:literal $(, $($args:tt)*)? } $($rest:tt)*) => {
+        $crate::row!(@cells () [] { $format $(, $($args)*)? } $($rest)*)
     };
     ($cell:expr; $n:expr) => {
-        ::ratatui_widgets::table::Row::new(vec![::ratatui_widgets::table::Cell::from($cell); $n])
-    };
-    ($($cell:expr),+ $(,)?) => {{
-        ::ratatui_widgets::table::Row::new(vec![
-        $(
-            ::ratatui_widgets::table::Cell::from($cell),
-        )+
-        ])
+        $crate::ratatui_widgets::table::Row::new(
+            $crate::__repeat::<$crate::ratatui_widgets::table::Cell>($cell, $n),
+        )
+    };
+    // Converts each cell in one step, for a list of plain cells that `row!` matches as a whole
+    (@elements $widths:tt [$($head:tt $(. $method:ident ($($args:tt)*))*),+]
+        $(; $($key:ident : $value:expr),+)?
+    ) => {
+        $crate::ratatui_widgets::table::Row::new([
+            $($crate::row!(@element $widths $head $(. $method($($args)*))*)),+
+        ]) $($(.$key($value))+)?
+    };
+    (@element ($($widths:ident)?) { $format:literal $(, $($args:tt)*)? }) => {
+        $crate::row!(@cell ($($widths)?) ::std::format!($format $(, $($args)*)?))
+    };
+    (@element ($($widths:ident)?) $cell:expr) => {
+        $crate::row!(@cell ($($widths)?) $cell)
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*] $(,)? $(; $($key:ident : $value:expr),+ $(,)?)?) => {
+        $crate::ratatui_widgets::table::Row::new(
+            ::core::iter::empty::<$crate::ratatui_widgets::table::Cell>() $($cells)*
+        ) $($(.$key($value))+)?
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*] .. $iter:expr $(, $($rest:tt)*)?) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*
+            .chain(
+                ::core::iter::IntoIterator::into_iter($iter)
+                    .map(|cell| $crate::row!(@cell ($($widths)?) cell))
+            )
+        ] $($($rest)*)?)
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*] .. $iter:expr ; $($rest:tt)*) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*] .. $iter, ; $($rest)*)
+    };
+    // a braced format string is turned into a `format!` call first, as a block with a `,` in it
+    // cannot be parsed as an `expr`
+    (@cells ($($widths:ident)?) [$($cells:tt)*]
+        { $format:literal $(, $($args:tt)*)? } $($rest:tt)*
+    ) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*]
+            ::std::format!($format $(, $($args)*)?) $($rest)*
+        )
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*]
+        $cell:expr => $style:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*
+            .chain([$crate::row!(@cell ($($widths)?) $cell).style($style)])
+        ] $($($rest)*)?)
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*] $cell:expr => $style:expr ; $($rest:tt)*) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*] $cell => $style, ; $($rest)*)
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*] $cell:expr $(, $($rest:tt)*)?) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*
+            .chain([$crate::row!(@cell ($($widths)?) $cell)])
+        ] $($($rest)*)?)
+    };
+    (@cells ($($widths:ident)?) [$($cells:tt)*] $cell:expr ; $($rest:tt)*) => {
+        $crate::row!(@cells ($($widths)?) [$($cells)*] $cell, ; $($rest)*)
+    };
+    // Converts a single element into a cell, measuring it first when there is a `$widths`
+    (@cell () $cell:expr) => {
+        $crate::__cell_element!($cell)
+    };
+    (@cell ($widths:ident) $cell:expr) => {{
+        let cell = $cell;
+        $widths.measure($crate::__cell_width!(&cell));
+        $crate::__cell_element!(cell)
     }};
+    ($($cells:tt)+) => {
+        $crate::row!(@cells () [] $($cells)+)
+    };
+}
+
+/// A macro for creating a `Vec` of [`Row`]s from the items of a collection, with striped rows.
+///
+/// The first argument is anything that can be iterated over, and the second argument is a closure
+/// that turns an item into a [`Row`], e.g. with [`row!`]. The closure can be followed by
+/// `; stripe: style`, where the style is anything that can be converted into a [`Style`]. It is
+/// applied to every second row, starting with the second one, so that the rows of a table
+/// alternate between two backgrounds. The style that the closure sets on a row is patched onto
+/// the stripe, so it takes precedence.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style};
+/// use ratatui_macros::{row, rows};
+/// use ratatui_widgets::table::Table;
+///
+/// struct File {
+///     name: &'static str,
+///     size: u64,
+/// }
+///
+/// let files = [File { name: "a.rs", size: 120 }, File { name: "b.rs", size: 64 }];
+/// let rows = rows!(&files, |file| row![file.name, file.size.to_string()]);
+///
+/// let rows = rows!(
+///     &files,
+///     |file| row![file.name, file.size.to_string()];
+///     stripe: Style::new().bg(Color::Rgb(30, 30, 30))
+/// );
+/// let table = Table::new(rows, [20, 10]);
+/// ```
+///
+/// [`Row`]: ratatui_widgets::table::Row
+/// [`Style`]: ratatui_core::style::Style
+/// [`row!`]: crate::row!
+#[macro_export]
+macro_rules! rows {
+    ($items:expr, $map:expr; stripe: $stripe:expr $(,)?) => {
+        $crate::__rows(
+            $items,
+            $map,
+            ::core::option::Option::Some(::core::convert::Into::into($stripe)),
+        )
+    };
+    ($items:expr, $map:expr $(,)?) => {
+        $crate::__rows($items, $map, ::core::option::Option::None)
+    };
+}
+
+/// Maps the items to rows and applies the stripe style to every second row. See [`rows!`].
+///
+/// [`rows!`]: crate::rows!
+#[doc(hidden)]
+pub fn __rows<'a, I, F>(items: I, map: F, stripe: Option<Style>) -> Vec<Row<'a>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Row<'a>,
+{
+    let rows = items.into_iter().map(map).enumerate();
+    rows.map(|(i, row)| match stripe {
+        Some(stripe) if i % 2 == 1 => {
+            let style = stripe.patch(Styled::style(&row));
+            row.style(style)
+        }
+        _ => row,
+    })
+    .collect()
 }
 
 #[cfg(test)]
 mod tests {
 
-    use ratatui_core::text::Text;
+    use ratatui_core::{
+        style::{Color, Modifier, Style, Stylize},
+        text::{Line, Span, Text},
+    };
     use ratatui_widgets::table::{Cell, Row};
 
+    use crate::text;
+
     #[test]
     fn row_literal() {
         let row = row!["hello", "world"];
@@ -92,6 +328,22 @@ mod tests {
         assert_eq!(row, Row::new(vec![Cell::from("foo"), Cell::from("foo")]));
     }
 
+    #[test]
+    fn row_many_cells() {
+        let row = row![
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-"
+        ];
+        assert_eq!(row, Row::new(vec![Cell::from("-"); 150]));
+    }
+
     #[test]
     fn row_explicit_use_of_span_and_line() {
         let row = row![crate::line!("hello"), crate::span!["world"]];
@@ -110,6 +362,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn row_styled_cells() {
+        let row = row![
+            "name",
+            "value".bold(),
+            crate::span!(Modifier::ITALIC; "{}", 42)
+        ];
+        assert_eq!(
+            row,
+            Row::new(vec![
+                Cell::from("name"),
+                Cell::from(Line::from("value".bold())),
+                Cell::from(Span::styled("42", Modifier::ITALIC)),
+            ])
+        );
+    }
+
     #[test]
     fn multiple_rows() {
         use crate::text;
@@ -136,4 +405,133 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn row_spread_cells() {
+        let scores = [90, 85];
+        let row = row![
+            "Alice",
+            ..scores.iter().map(|score| score.to_string()),
+            "done"
+        ];
+        assert_eq!(
+            row,
+            Row::new(vec![
+                Cell::from("Alice"),
+                Cell::from("90"),
+                Cell::from("85"),
+                Cell::from("done"),
+            ])
+        );
+    }
+
+    #[test]
+    fn rows_from_items() {
+        let sizes = [("a", 1), ("b", 2)];
+        let rows = rows!(sizes, |(name, size)| row![name, size.to_string()]);
+        assert_eq!(rows, [row!["a", "1"], row!["b", "2"]]);
+
+        let rows = rows!(Vec::<&str>::new(), |name| row![name]);
+        assert_eq!(rows, []);
+    }
+
+    #[test]
+    fn rows_stripes() {
+        let stripe = Style::new().bg(Color::Rgb(30, 30, 30));
+        let rows = rows!(1..=4, |i| row![i.to_string()]; stripe: stripe);
+        assert_eq!(
+            rows,
+            [
+                row!["1"],
+                row!["2"].style(stripe),
+                row!["3"],
+                row!["4"].style(stripe),
+            ]
+        );
+
+        // the style of the row is patched onto the stripe
+        let rows = rows!(["a", "b"], |name| row![name].red(); stripe: Color::Gray);
+        assert_eq!(
+            rows,
+            [row!["a"].red(), row!["b"].style(Style::new().red()),]
+        );
+        let rows = rows!(["a", "b"], |name| row![name].bold(); stripe: Style::new().on_gray());
+        assert_eq!(rows[1], row!["b"].style(Style::new().on_gray().bold()));
+    }
+
+    #[test]
+    fn row_display_elements() {
+        let (name, size) = ("Cargo.toml", 1_024_u64);
+        let row = row![name, size, ..[1.5, 2.0]];
+        assert_eq!(
+            row,
+            Row::new(vec![
+                Cell::from("Cargo.toml"),
+                Cell::from("1024"),
+                Cell::from("1.5"),
+                Cell::from("2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn row_char_elements() {
+        assert_eq!(
+            row!['✓', "done"],
+            Row::new(vec![Cell::from("✓"), Cell::from("done")])
+        );
+    }
+
+    #[test]
+    fn row_format_cells() {
+        let count = 3;
+        let row = row!["files", {"{} items", count}, {"{count} total"}, { "x".to_string() }];
+        assert_eq!(row, Row::new(["files", "3 items", "3 total", "x"]));
+        assert_eq!(row![{"{}", count}; 2], Row::new(["3", "3"]));
+        assert_eq!(
+            row![{"{} items", count}, ..["a"]; height: 2],
+            Row::new(["3 items", "a"]).height(2)
+        );
+    }
+
+    #[test]
+    fn row_cell_styles() {
+        let sum = 42;
+        let row = row![
+            "total".bold(),
+            sum => Style::new().yellow(),
+            "x" => Color::Red,
+            {"{sum}%"} => Style::new().bold(),
+        ];
+        assert_eq!(
+            row,
+            Row::new(vec![
+                Cell::from(Span::raw("total").bold()),
+                Cell::from("42").yellow(),
+                Cell::from("x").red(),
+                Cell::from("42%").bold(),
+            ])
+        );
+    }
+
+    #[test]
+    fn row_directives() {
+        let row = row!["a", text!["b", "c"]; height: 2, bottom_margin: 1];
+        assert_eq!(
+            row,
+            Row::new(vec![Cell::from("a"), Cell::from(Text::from("b\nc"))])
+                .height(2)
+                .bottom_margin(1)
+        );
+        assert_eq!(row!["a",; height: 3], Row::new(["a"]).height(3));
+        assert_eq!(
+            row![..["a", "b"]; top_margin: 1],
+            Row::new(["a", "b"]).top_margin(1)
+        );
+        assert_eq!(
+            row!["a" => Color::Red; style: Style::new().bold()],
+            Row::new([Cell::from("a").red()]).bold()
+        );
+        assert_eq!(row![""; 3], Row::new(["", "", ""]));
+    }
 }
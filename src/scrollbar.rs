@@ -0,0 +1,142 @@
+/// A macro for creating a [`Scrollbar`] from an orientation and a list of symbols.
+///
+/// The first argument is the orientation, either `Vertical` (on the right), `Horizontal` (at the
+/// bottom), or the name of any [`ScrollbarOrientation`] variant. It defaults to `Vertical` when
+/// omitted. It can be followed by `key: value` settings:
+///
+/// - `thumb`, `track`, `begin` and `end` set the symbols of the scrollbar. `track`, `begin` and
+///   `end` also accept `None` to hide that part of the scrollbar
+/// - any other key is the name of a builder method on [`Scrollbar`], e.g.
+///   `thumb_style: Style::new().white()` or `symbols: scrollbar::HORIZONTAL`
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::scrollbar;
+///
+/// let scrollbar = scrollbar!();
+/// let scrollbar = scrollbar!(Horizontal);
+/// let scrollbar = scrollbar!(Vertical, thumb: "█", track: "│", begin: "↑", end: "↓");
+/// let scrollbar = scrollbar!(VerticalLeft, begin: None, thumb_style: Style::new().white());
+/// ```
+///
+/// [`Scrollbar`]: ratatui_widgets::scrollbar::Scrollbar
+/// [`ScrollbarOrientation`]: ratatui_widgets::scrollbar::ScrollbarOrientation
+#[macro_export]
+macro_rules! scrollbar {
+    (@settings $scrollbar:expr ; $(,)?) => {
+        $scrollbar
+    };
+    (@settings $scrollbar:expr ; thumb: $symbol:expr $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings $scrollbar.thumb_symbol($symbol) ; $($($rest)*)?)
+    };
+    (@settings $scrollbar:expr ; track: $symbol:expr $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings $scrollbar.track_symbol($symbol.into()) ; $($($rest)*)?)
+    };
+    (@settings $scrollbar:expr ; begin: $symbol:expr $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings $scrollbar.begin_symbol($symbol.into()) ; $($($rest)*)?)
+    };
+    (@settings $scrollbar:expr ; end: $symbol:expr $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings $scrollbar.end_symbol($symbol.into()) ; $($($rest)*)?)
+    };
+    (@settings $scrollbar:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings $scrollbar.$key($value) ; $($($rest)*)?)
+    };
+    (@orientation Vertical) => {
+        $crate::ratatui_widgets::scrollbar::ScrollbarOrientation::VerticalRight
+    };
+    (@orientation Horizontal) => {
+        $crate::ratatui_widgets::scrollbar::ScrollbarOrientation::HorizontalBottom
+    };
+    (@orientation $orientation:ident) => {
+        $crate::ratatui_widgets::scrollbar::ScrollbarOrientation::$orientation
+    };
+    () => {
+        $crate::ratatui_widgets::scrollbar::Scrollbar::default()
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::scrollbar!(@settings
+            $crate::ratatui_widgets::scrollbar::Scrollbar::default() ;
+            $key : $($rest)*
+        )
+    };
+    ($orientation:ident $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings
+            $crate::ratatui_widgets::scrollbar::Scrollbar::new(
+                $crate::scrollbar!(@orientation $orientation)
+            ) ;
+            $($($rest)*)?
+        )
+    };
+    ($orientation:expr $(, $($rest:tt)*)?) => {
+        $crate::scrollbar!(@settings
+            $crate::ratatui_widgets::scrollbar::Scrollbar::new($orientation) ;
+            $($($rest)*)?
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        symbols::scrollbar,
+    };
+    use ratatui_widgets::scrollbar::{Scrollbar, ScrollbarOrientation};
+
+    #[test]
+    fn scrollbar_orientation() {
+        assert_eq!(scrollbar!(), Scrollbar::default());
+        assert_eq!(
+            scrollbar!(Vertical),
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        );
+        assert_eq!(
+            scrollbar!(Horizontal),
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+        );
+        assert_eq!(
+            scrollbar!(HorizontalTop),
+            Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+        );
+        assert_eq!(
+            scrollbar!(ScrollbarOrientation::VerticalLeft),
+            Scrollbar::new(ScrollbarOrientation::VerticalLeft)
+        );
+    }
+
+    #[test]
+    fn scrollbar_symbols() {
+        let scrollbar = scrollbar!(Vertical, thumb: "█", track: "│", begin: "↑", end: "↓");
+        assert_eq!(
+            scrollbar,
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .thumb_symbol("█")
+                .track_symbol(Some("│"))
+                .begin_symbol(Some("↑"))
+                .end_symbol(Some("↓"))
+        );
+
+        let scrollbar = scrollbar!(begin: None, end: None);
+        assert_eq!(
+            scrollbar,
+            Scrollbar::default().begin_symbol(None).end_symbol(None)
+        );
+    }
+
+    #[test]
+    fn scrollbar_builder_settings() {
+        let scrollbar = scrollbar!(
+            Horizontal,
+            symbols: scrollbar::HORIZONTAL,
+            thumb_style: Style::new().white(),
+        );
+        assert_eq!(
+            scrollbar,
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .symbols(scrollbar::HORIZONTAL)
+                .thumb_style(Style::new().white())
+        );
+    }
+}
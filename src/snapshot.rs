@@ -0,0 +1,212 @@
+use std::fmt::Write;
+
+use ratatui_core::{
+    buffer::Buffer,
+    style::{Color, Style},
+};
+
+use crate::{
+    __plain_string,
+    style_from_str::{COLORS, MODIFIERS},
+};
+
+/// A macro for asserting an [insta] snapshot of a widget, rendered into a [`TestBackend`] of the
+/// given size.
+///
+/// The arguments are the widget and the size, either `WIDTHxHEIGHT`, e.g. `80x24`, or a
+/// `(width, height)` tuple of expressions, like [`render_test!`]. They can be preceded by the name
+/// of the snapshot and followed by an inline snapshot `@"..."`, like [`insta::assert_snapshot!`].
+///
+/// The snapshot contains the rows of the rendered buffer, like [`render_to_string!`] but without
+/// trailing spaces, which editors tend to remove from snapshot files. When any cell is styled, the
+/// rows are followed by an empty line, one marker row per row of the buffer and a legend, like the
+/// marker rows of [`buffer!`]: each marker stands for the style on the legend line
+/// `marker: style`, written in the syntax of [`parse_style`], and a space is an unstyled cell.
+///
+/// This macro requires the `insta` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{assert_widget_snapshot, line};
+///
+/// assert_widget_snapshot!(line!["ok ", "3 files".green().bold()], 10x1, @r"
+/// ok 3 files
+///
+///    aaaaaaa
+/// a: bold green
+/// ");
+/// ```
+///
+/// [insta]: https://insta.rs
+/// [`TestBackend`]: ratatui_core::backend::TestBackend
+/// [`buffer!`]: crate::buffer!
+/// [`parse_style`]: crate::parse_style
+/// [`render_test!`]: crate::render_test!
+/// [`render_to_string!`]: crate::render_to_string!
+/// [`insta::assert_snapshot!`]: https://docs.rs/insta/latest/insta/macro.assert_snapshot.html
+#[macro_export]
+macro_rules! assert_widget_snapshot {
+    ($name:literal, $widget:expr, $size:tt $(,)?) => {{
+        let widget = $crate::render_test!($size, $widget, $crate::__snapshot_string);
+        $crate::__insta::assert_snapshot!($name, widget)
+    }};
+    ($widget:expr, $size:tt, @$snapshot:literal $(,)?) => {{
+        let widget = $crate::render_test!($size, $widget, $crate::__snapshot_string);
+        $crate::__insta::assert_snapshot!(widget, @$snapshot)
+    }};
+    ($widget:expr, $size:tt $(,)?) => {{
+        let widget = $crate::render_test!($size, $widget, $crate::__snapshot_string);
+        $crate::__insta::assert_snapshot!(widget)
+    }};
+}
+
+/// The markers of the styles in a snapshot of [`assert_widget_snapshot!`], in the order in which
+/// the styles first appear in the buffer. Any further styles share the last marker.
+///
+/// [`assert_widget_snapshot!`]: crate::assert_widget_snapshot!
+const MARKERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789?";
+
+/// Returns the rows of the buffer, followed by marker rows and a legend for the styled cells. See
+/// [`assert_widget_snapshot!`].
+///
+/// [`assert_widget_snapshot!`]: crate::assert_widget_snapshot!
+#[doc(hidden)]
+pub fn __snapshot_string(buffer: &Buffer) -> String {
+    let mut snapshot = __plain_string(buffer)
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let area = buffer.area;
+    let mut styles: Vec<Style> = Vec::new();
+    let mut markers = Vec::with_capacity(usize::from(area.height));
+    for y in area.top()..area.bottom() {
+        let mut row = String::new();
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            let style = Style::new()
+                .fg(cell.fg)
+                .bg(cell.bg)
+                .add_modifier(cell.modifier);
+            if style == Style::new().fg(Color::Reset).bg(Color::Reset) {
+                row.push(' ');
+                continue;
+            }
+            let index = match styles.iter().position(|known| *known == style) {
+                Some(index) => index,
+                None => {
+                    styles.push(style);
+                    styles.len() - 1
+                }
+            };
+            row.push(MARKERS.chars().nth(index).unwrap_or('?'));
+        }
+        markers.push(row.trim_end().to_owned());
+    }
+    if styles.is_empty() {
+        return snapshot;
+    }
+    snapshot.push_str("\n\n");
+    snapshot.push_str(&markers.join("\n"));
+    for (marker, style) in MARKERS.chars().zip(&styles) {
+        let _ = write!(snapshot, "\n{marker}: {}", describe_style(*style));
+        if marker == '?' && styles.len() > MARKERS.len() {
+            snapshot.push_str(" and other styles");
+        }
+    }
+    snapshot
+}
+
+/// Describes the modifiers and colors of a style in the syntax of [`parse_style`], e.g.
+/// `bold red on blue`.
+///
+/// [`parse_style`]: crate::parse_style
+fn describe_style(style: Style) -> String {
+    let mut words = MODIFIERS
+        .iter()
+        .filter(|(_, modifier)| style.add_modifier.contains(*modifier))
+        .map(|(name, _)| (*name).to_owned())
+        .collect::<Vec<_>>();
+    if let Some(fg) = style.fg.filter(|fg| *fg != Color::Reset) {
+        words.push(describe_color(fg));
+    }
+    if let Some(bg) = style.bg.filter(|bg| *bg != Color::Reset) {
+        words.push(format!("on {}", describe_color(bg)));
+    }
+    words.join(" ")
+}
+
+fn describe_color(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Indexed(index) => index.to_string(),
+        color => COLORS
+            .iter()
+            .find(|(_, known)| *known == color)
+            .map_or_else(|| format!("{color:?}"), |(name, _)| (*name).to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Style, Stylize};
+    use ratatui_widgets::{block::Block, paragraph::Paragraph};
+
+    use crate::{line, render_test};
+
+    use super::__snapshot_string;
+
+    #[test]
+    fn snapshot_plain() {
+        let snapshot = render_test!(6x3, Block::bordered().title("hi"), __snapshot_string);
+        assert_eq!(snapshot, "┌hi──┐\n│    │\n└────┘");
+    }
+
+    #[test]
+    fn snapshot_styles() {
+        let widget = Paragraph::new(line![
+            "a".red(),
+            "b".on_blue().italic(),
+            "c".fg(Color::Rgb(255, 136, 0)),
+            "d".fg(Color::Indexed(208)).bold().underlined(),
+            "e".red(),
+        ]);
+        let snapshot = render_test!(7x2, widget, __snapshot_string);
+        assert_eq!(
+            snapshot,
+            concat!(
+                "abcde\n",
+                "\n",
+                "\n",
+                "abcda\n",
+                "\n",
+                "a: red\n",
+                "b: italic on blue\n",
+                "c: #ff8800\n",
+                "d: bold underlined 208",
+            )
+        );
+    }
+
+    #[test]
+    fn snapshot_macro() {
+        assert_widget_snapshot!("hi".bold(), (4, 1), @r"
+        hi
+
+        aa
+        a: bold
+        ");
+        let style = Style::new().reversed();
+        let block = Block::bordered().border_style(style);
+        assert_widget_snapshot!(block, 3x2, @r"
+        ┌─┐
+        └─┘
+
+        aaa
+        aaa
+        a: reversed
+        ");
+    }
+}
@@ -1,3 +1,7 @@
+use ratatui_core::{layout::Alignment, text::Span};
+
+use crate::truncate::truncate_str;
+
 /// A macro for creating a [`Span`] using formatting syntax.
 ///
 /// `span!` is similar to the [`format!`] macro, but it returns a [`Span`] instead of a `String`. In
@@ -8,6 +12,19 @@
 /// [`Span`] will be created. Otherwise, the [`Span`] will be created as a raw span (i.e. with style
 /// set to `Style::default()`).
 ///
+/// The style can also be written as `fg on bg`, where both colors are the name of a [`Color`]
+/// variant, in PascalCase or lowercase (e.g. `Red`, `red`, `light_blue` or `Rgb(30, 30, 46)`), an
+/// indexed color of the 256-color palette written `@index` (e.g. `@208`), or a variable that holds
+/// a [`Color`]. An indexed color can also be the whole style, e.g. `span!(@208; "warning")`, and an
+/// index over 255 is a compile error.
+///
+/// A string literal that does not capture any variables is borrowed by the [`Span`] instead of
+/// being formatted into a new `String`, so `span!("hello")` does not allocate. Because a literal is
+/// a format string, and a declarative macro cannot tell a `char` literal from a string literal,
+/// `span!('✓')` does not compile. A `char` is written `span!("{}", '✓')` or `span!("✓")`, or with
+/// [`const_span!`], which accepts any literal. The elements of [`line!`], [`text!`] and [`row!`]
+/// can be a `char`, e.g. `line!['[', content, ']']`.
+///
 /// # Examples
 ///
 /// ```rust
@@ -47,11 +64,40 @@
 /// let span = span!(Color::Green; "test {content}");
 /// let span = span!(Modifier::BOLD; "test {content}");
 ///
+/// // a foreground color on a background color
+/// let span = span!(red on black; "ERROR");
+/// let span = span!(white on Rgb(30, 30, 46); "test {content}");
+///
+/// // indexed colors of the 256-color palette
+/// let span = span!(@208; "warning");
+/// let span = span!(@208 on @236; "test {content}");
+///
 /// // with format specifiers
 /// let span = span!(style; "test {:4}", 123);
 /// let span = span!(style; "test {:04}", 123);
 /// ```
 ///
+/// # Width
+///
+/// With a leading `width = cells;`, the span is padded with spaces or cut to exactly that many
+/// cells, measured in display cells rather than bytes, so wide characters like CJK count as two.
+/// The width may be followed by `left`, `center` or `right` to align the content within the
+/// width, and `left` is the default. The rest of the arguments are the arguments of any other
+/// `span!`, including a style, and the padding has the style of the span. This lines up columns
+/// inside a [`Line`]:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::span;
+///
+/// let size = 1024;
+/// assert_eq!(span!(width = 8, right; "{}", size).content, "    1024");
+/// assert_eq!(span!(width = 6, center; "界").content, "  界  ");
+/// assert_eq!(span!(width = 4; "truncated").content, "trun");
+///
+/// let span = span!(width = 10; red on black; "ERROR");
+/// ```
+///
 /// # Note
 ///
 /// The first parameter must be a formatting specifier followed by a comma OR anything that can be
@@ -60,7 +106,7 @@
 /// For example, the following will fail to compile:
 ///
 /// ```compile_fail
-/// # use ratatui::prelude::*;
+/// # use ratatui_core::style::Modifier;
 /// # use ratatui_macros::span;
 /// let span = span!(Modifier::BOLD, "hello world");
 /// ```
@@ -76,11 +122,17 @@
 /// The following will fail to compile:
 ///
 /// ```compile_fail
-/// # use ratatui::prelude::*;
 /// # use ratatui_macros::span;
 /// let span = span!("hello", "world");
 /// ```
 ///
+/// Neither will a `char` literal:
+///
+/// ```compile_fail
+/// # use ratatui_macros::span;
+/// let span = span!('✓');
+/// ```
+///
 /// But this will work:
 ///
 /// ```rust
@@ -88,14 +140,49 @@
 /// let span = span!("hello {}", "world");
 /// ```
 ///
-/// [`Color`]: crate::style::Color
-/// [`Style`]: crate::style::Style
-/// [`Span`]: crate::text::Span
-/// [`Style`]: crate::style::Style
+/// [`Color`]: ratatui_core::style::Color
+/// [`Line`]: ratatui_core::text::Line
+/// [`Style`]: ratatui_core::style::Style
+/// [`Span`]: ratatui_core::text::Span
+/// [`const_span!`]: crate::const_span!
+/// [`line!`]: crate::line!
+/// [`row!`]: crate::row!
+/// [`text!`]: crate::text!
 #[macro_export]
 macro_rules! span {
+    (@alignment) => {
+        $crate::ratatui_core::layout::Alignment::Left
+    };
+    (@alignment $alignment:ident) => {
+        $crate::text!(@alignment $alignment)
+    };
+    (width = $width:expr $(, $alignment:ident)? ; $($rest:tt)*) => {
+        $crate::__pad_span(
+            $crate::span!($($rest)*),
+            $width,
+            $crate::span!(@alignment $($alignment)?),
+        )
+    };
+    ($fg:ident $(($($fg_args:tt)*))? on $bg:ident $(($($bg_args:tt)*))?; $($rest:tt)*) => {
+        $crate::span!(
+            $crate::__fg_on_bg!($fg $(($($fg_args)*))? on $bg $(($($bg_args)*))?);
+            $($rest)*
+        )
+    };
+    ($fg:ident $(($($fg_args:tt)*))? on @ $bg:literal; $($rest:tt)*) => {
+        $crate::span!($crate::__fg_on_bg!($fg $(($($fg_args)*))? on @ $bg); $($rest)*)
+    };
+    (@ $fg:literal on $bg:ident $(($($bg_args:tt)*))?; $($rest:tt)*) => {
+        $crate::span!($crate::__fg_on_bg!(@ $fg on $bg $(($($bg_args)*))?); $($rest)*)
+    };
+    (@ $fg:literal on @ $bg:literal; $($rest:tt)*) => {
+        $crate::span!($crate::__fg_on_bg!(@ $fg on @ $bg); $($rest)*)
+    };
+    (@ $index:literal; $($rest:tt)*) => {
+        $crate::span!($crate::__color!(@ $index); $($rest)*)
+    };
     ($string:literal) => {
-        $crate::ratatui_core::text::Span::raw(format!($string))
+        $crate::ratatui_core::text::Span::raw($crate::__format_literal!($string))
     };
     ($string:literal, $($arg:tt)*) => {
         $crate::ratatui_core::text::Span::raw(format!($string, $($arg)*))
@@ -107,7 +194,7 @@ macro_rules! span {
         compile_error!("first parameter must be a formatting specifier followed by a comma OR a `Style` followed by a semicolon")
     };
     ($style:expr; $string:literal) => {
-        $crate::ratatui_core::text::Span::styled(format!($string), $style)
+        $crate::ratatui_core::text::Span::styled($crate::__format_literal!($string), $style)
     };
     ($style:expr; $string:literal, $($arg:tt)*) => {
         $crate::ratatui_core::text::Span::styled(format!($string, $($arg)*), $style)
@@ -117,8 +204,157 @@ macro_rules! span {
     };
 }
 
+/// A macro for creating a styled [`Span`] using formatting syntax.
+///
+/// `styled!` is similar to [`span!`] with a style, but the style and the format string are
+/// separated by a comma. The style is anything that can be converted into a [`Style`]:
+///
+/// - a [`Color`], which sets the foreground color
+/// - a [`Modifier`]
+/// - a `(fg, bg)` pair of [`Color`]s, or any other tuple accepted by `Style::from`
+/// - a `fg on bg` pair of color names, e.g. `white on Rgb(30, 30, 46)`, like in [`span!`]
+/// - a full [`Style`] expression
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Modifier, Style, Stylize};
+/// use ratatui_macros::styled;
+///
+/// let error = "file not found";
+/// let span = styled!(Color::Red, "err: {}", error);
+/// let span = styled!(Modifier::BOLD, "hello {error}");
+/// let span = styled!((Color::White, Color::Blue), "status");
+/// let span = styled!(Style::new().bold().on_blue(), "{:>8}", 42);
+/// let span = styled!(Color::Green, error);
+/// let span = styled!(white on Rgb(30, 30, 46), "{}", error);
+/// ```
+///
+/// [`Color`]: ratatui_core::style::Color
+/// [`Modifier`]: ratatui_core::style::Modifier
+/// [`Style`]: ratatui_core::style::Style
+/// [`Span`]: ratatui_core::text::Span
+#[macro_export]
+macro_rules! styled {
+    (@checked $style:expr, $string:literal $(,)?) => {
+        $crate::ratatui_core::text::Span::styled($crate::__format_literal!($string), $style)
+    };
+    (@checked $style:expr, $string:literal, $($arg:tt)*) => {
+        $crate::ratatui_core::text::Span::styled(format!($string, $($arg)*), $style)
+    };
+    (@checked $style:expr, $expr:expr $(,)?) => {
+        $crate::ratatui_core::text::Span::styled(format!("{}", $expr), $style)
+    };
+    ($fg:ident $(($($fg_args:tt)*))? on $bg:ident $(($($bg_args:tt)*))?, $($rest:tt)*) => {
+        $crate::styled!(
+            $crate::__fg_on_bg!($fg $(($($fg_args)*))? on $bg $(($($bg_args)*))?),
+            $($rest)*
+        )
+    };
+    ($($tokens:tt)*) => {
+        $crate::__check!(check_styled [$crate::styled!] [@checked] $($tokens)*)
+    };
+}
+
+/// A macro for creating a [`Span`] in a constant or static item.
+///
+/// The argument is a string literal, which is borrowed by the span as it is, so unlike [`span!`] it
+/// is not a format string. Other literals, e.g. a `char` or a number, are converted into a string
+/// literal with [`concat!`]. Like [`span!`], a style followed by a semicolon may come first, either
+/// as `fg on bg` color names or as a [`Style`] expression that can be evaluated in a constant, e.g.
+/// `Style::new().fg(Color::Red)` or [`style_from_str!`]. The methods of `Stylize` like `.bold()`
+/// are not `const`.
+///
+/// A [`Line`] stores its spans in a `Vec`, which cannot be created in a constant, so the spans of
+/// a line that never changes can be kept in a constant array instead, and the line is created
+/// from the array when it is rendered.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::{Color, Modifier, Style}, text::{Line, Span}};
+/// use ratatui_macros::const_span;
+///
+/// const TITLE: Span<'static> = const_span!(Style::new().add_modifier(Modifier::BOLD); "ratatui");
+/// static FOOTER: [Span<'static>; 3] = [
+///     const_span!(black on cyan; " q "),
+///     const_span!(" quit "),
+///     const_span!(Style::new().fg(Color::DarkGray); "{no formatting}"),
+/// ];
+///
+/// let footer = Line::from(FOOTER.to_vec());
+/// assert_eq!(footer.spans[2].content, "{no formatting}");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`Style`]: ratatui_core::style::Style
+/// [`Span`]: ratatui_core::text::Span
+/// [`style_from_str!`]: crate::style_from_str!
+#[macro_export]
+macro_rules! const_span {
+    ($fg:ident $(($($fg_args:tt)*))? on $bg:ident $(($($bg_args:tt)*))?; $string:literal $(,)?) => {
+        $crate::const_span!(
+            $crate::__fg_on_bg!($fg $(($($fg_args)*))? on $bg $(($($bg_args)*))?);
+            $string
+        )
+    };
+    ($string:literal $(,)?) => {
+        $crate::const_span!($crate::ratatui_core::style::Style::new(); $string)
+    };
+    ($style:expr; $string:literal $(,)?) => {
+        $crate::ratatui_core::text::Span {
+            style: $style,
+            content: ::std::borrow::Cow::Borrowed(::core::concat!($string)),
+        }
+    };
+}
+
+/// Formats a string literal without arguments into a [`Cow`], borrowing the literal instead of
+/// allocating a `String` when it does not capture any variables (e.g. `"hello"` but not
+/// `"hello {name}"`).
+///
+/// [`Cow`]: std::borrow::Cow
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_literal {
+    ($string:literal) => {
+        match format_args!($string).as_str() {
+            ::core::option::Option::Some(string) => ::std::borrow::Cow::Borrowed(string),
+            ::core::option::Option::None => ::std::borrow::Cow::Owned(format!($string)),
+        }
+    };
+}
+
+/// Pads the span with spaces or cuts it to exactly `width` cells, with the content aligned within
+/// the width. See [`span!`].
+///
+/// [`span!`]: crate::span!
+#[doc(hidden)]
+pub fn __pad_span(mut span: Span<'_>, width: u16, alignment: Alignment) -> Span<'_> {
+    let width = usize::from(width);
+    let mut content_width = span.width();
+    if content_width > width {
+        span.content = truncate_str(&span.content, width).into();
+        content_width = span.width();
+    }
+    if content_width == width {
+        return span;
+    }
+    let padding = width - content_width;
+    let left = match alignment {
+        Alignment::Left => 0,
+        Alignment::Center => padding / 2,
+        Alignment::Right => padding,
+    };
+    let right = padding - left;
+    span.content = format!("{:left$}{}{:right$}", "", span.content, "").into();
+    span
+}
+
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use ratatui_core::{
         style::{Color, Modifier, Style, Stylize},
         text::Span,
@@ -234,6 +470,13 @@ mod tests {
         let span = span!(Modifier::BOLD; "test {content}");
         assert_eq!(span, Span::styled("test content", Style::new().bold()));
 
+        // a full style expression combining colors and modifiers
+        let span = span!(Style::new().bold().red(); "user: {}", content);
+        assert_eq!(
+            span,
+            Span::styled("user: content", Style::new().bold().fg(Color::Red))
+        );
+
         // directly pass a number expression
         let span = span!(STYLE; number);
         assert_eq!(span, Span::styled("123", STYLE));
@@ -242,4 +485,143 @@ mod tests {
         let span = span!(STYLE; test);
         assert_eq!(span, Span::styled("test", STYLE));
     }
+
+    #[test]
+    fn styled_styles() {
+        let error = "file not found";
+
+        let span = styled!(Color::Red, "err: {}", error);
+        assert_eq!(
+            span,
+            Span::styled("err: file not found", Style::new().red())
+        );
+
+        let span = styled!(Modifier::BOLD, "err: {error}");
+        assert_eq!(
+            span,
+            Span::styled("err: file not found", Style::new().bold())
+        );
+
+        let span = styled!((Color::White, Color::Blue), "status");
+        assert_eq!(span, Span::styled("status", Style::new().white().on_blue()));
+
+        let span = styled!(Style::new().bold().on_blue(), "{:>4}", 42);
+        assert_eq!(span, Span::styled("  42", Style::new().bold().on_blue()));
+    }
+
+    #[test]
+    fn styled_expression() {
+        let number = 123;
+        let span = styled!(Color::Green, number);
+        assert_eq!(span, Span::styled("123", Style::new().green()));
+    }
+
+    #[test]
+    fn fg_on_bg() {
+        let span = span!(red on black; "ERROR");
+        assert_eq!(span, Span::styled("ERROR", Style::new().red().on_black()));
+
+        let code = 404;
+        let span = span!(LightYellow on Indexed(236); "{code}");
+        assert_eq!(
+            span,
+            Span::styled("404", Style::new().light_yellow().bg(Color::Indexed(236)))
+        );
+
+        let msg = "ready";
+        let span = styled!(white on Rgb(30, 30, 46), "{}", msg);
+        assert_eq!(
+            span,
+            Span::styled("ready", Style::new().white().bg(Color::Rgb(30, 30, 46)))
+        );
+
+        let accent = Color::Cyan;
+        let span = styled!(accent on dark_gray, msg);
+        assert_eq!(
+            span,
+            Span::styled("ready", Style::new().cyan().on_dark_gray())
+        );
+    }
+
+    #[test]
+    fn indexed_colors() {
+        assert_eq!(
+            span!(@208; "warning"),
+            Span::raw("warning").fg(Color::Indexed(208))
+        );
+        assert_eq!(
+            span!(@208 on @236; "{}", 1),
+            Span::raw("1")
+                .fg(Color::Indexed(208))
+                .bg(Color::Indexed(236))
+        );
+        assert_eq!(
+            span!(@15 on black; "x"),
+            Span::raw("x").fg(Color::Indexed(15)).on_black()
+        );
+        assert_eq!(
+            span!(white on @236; "x"),
+            Span::raw("x").white().bg(Color::Indexed(236))
+        );
+    }
+
+    #[test]
+    fn literal_is_borrowed() {
+        let name = "world";
+        assert!(matches!(span!("hello").content, Cow::Borrowed("hello")));
+        assert!(matches!(
+            span!("{{hello}}").content,
+            Cow::Borrowed("{hello}")
+        ));
+        assert!(matches!(
+            span!(Color::Red; "hello").content,
+            Cow::Borrowed("hello")
+        ));
+        assert!(matches!(
+            styled!(Color::Red, "hello").content,
+            Cow::Borrowed("hello")
+        ));
+        assert!(matches!(span!("hello {name}").content, Cow::Owned(_)));
+        assert_eq!(span!("hello {name}"), Span::raw("hello world"));
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(span!(width = 5; "ab"), Span::raw("ab   "));
+        assert_eq!(span!(width = 5, left; "ab"), Span::raw("ab   "));
+        assert_eq!(span!(width = 5, right; "{}", 42), Span::raw("   42"));
+        assert_eq!(span!(width = 5, center; "ab"), Span::raw(" ab  "));
+        assert_eq!(span!(width = 3; "abcdef"), Span::raw("abc"));
+        assert_eq!(
+            span!(width = 4, right; Color::Red; "{}", 7),
+            Span::raw("   7").red()
+        );
+        assert_eq!(
+            span!(width = 4; red on black; "x"),
+            Span::raw("x   ").red().on_black()
+        );
+    }
+
+    #[test]
+    fn width_wide_characters() {
+        assert_eq!(span!(width = 5, right; "你好"), Span::raw(" 你好"));
+        // the wide character does not fit in the last cell, which is padded
+        assert_eq!(span!(width = 3; "你好"), Span::raw("你 "));
+    }
+
+    #[test]
+    fn const_span() {
+        const PLAIN: Span<'static> = const_span!("hello {name}");
+        const BOLD: Span<'static> = const_span!(Style::new().add_modifier(Modifier::BOLD); "bold");
+        static FG_ON_BG: Span<'static> = const_span!(white on Rgb(30, 30, 46); "status");
+        assert_eq!(PLAIN, Span::raw("hello {name}"));
+        assert!(matches!(PLAIN.content, Cow::Borrowed(_)));
+        assert_eq!(BOLD, Span::raw("bold").bold());
+        const CHECK: Span<'static> = const_span!(Style::new().fg(Color::Green); '✓');
+        assert_eq!(CHECK, Span::raw("✓").green());
+        assert_eq!(
+            FG_ON_BG,
+            Span::raw("status").white().bg(Color::Rgb(30, 30, 46))
+        );
+    }
 }
@@ -0,0 +1,92 @@
+/// A macro for creating a [`Sparkline`] using vec! syntax.
+///
+/// The elements are the values of the sparkline. A single expression that is not a literal is
+/// used as the data directly, so slices, vectors and iterators of values can be passed as well.
+///
+/// The data can optionally be followed by a `;` and a list of `key: value` settings where each key
+/// is the name of a builder method on [`Sparkline`], e.g. `max: 10`, `style: Style::new().cyan()`
+/// or `block: block!("Traffic")`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::sparkline;
+///
+/// let sparkline = sparkline![3, 7, 2, 9, 4];
+/// let sparkline = sparkline![3, 7, 2, 9, 4; max: 10, style: Style::new().cyan()];
+///
+/// let data = vec![3, 7, 2, 9, 4];
+/// let sparkline = sparkline![&data; max: 10];
+/// ```
+///
+/// [`Sparkline`]: ratatui_widgets::sparkline::Sparkline
+#[macro_export]
+macro_rules! sparkline {
+    () => {
+        $crate::ratatui_widgets::sparkline::Sparkline::default()
+    };
+    ($value:literal $(; $($key:ident : $setting:expr),* $(,)?)?) => {
+        $crate::ratatui_widgets::sparkline::Sparkline::default()
+            .data([$value])
+            $($(.$key($setting))*)?
+    };
+    ($data:expr $(; $($key:ident : $setting:expr),* $(,)?)?) => {
+        $crate::ratatui_widgets::sparkline::Sparkline::default()
+            .data($data)
+            $($(.$key($setting))*)?
+    };
+    ($($value:expr),+ $(,)? $(; $($key:ident : $setting:expr),* $(,)?)?) => {
+        $crate::ratatui_widgets::sparkline::Sparkline::default()
+            .data([$($value),+])
+            $($(.$key($setting))*)?
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_widgets::sparkline::Sparkline;
+
+    #[test]
+    fn sparkline_empty() {
+        assert_eq!(sparkline![], Sparkline::default());
+    }
+
+    #[test]
+    fn sparkline_literal_values() {
+        assert_eq!(sparkline![3], Sparkline::default().data([3]));
+        assert_eq!(
+            sparkline![3, 7, 2, 9, 4],
+            Sparkline::default().data([3, 7, 2, 9, 4])
+        );
+    }
+
+    #[test]
+    fn sparkline_data_expression() {
+        let data = vec![3, 7, 2];
+        assert_eq!(sparkline![&data], Sparkline::default().data(&data));
+        assert_eq!(
+            sparkline![data.iter().map(|v| v * 2)],
+            Sparkline::default().data([6, 14, 4])
+        );
+    }
+
+    #[test]
+    fn sparkline_settings() {
+        let sparkline = sparkline![3, 7, 2, 9, 4; max: 10, style: Style::new().cyan()];
+        assert_eq!(
+            sparkline,
+            Sparkline::default()
+                .data([3, 7, 2, 9, 4])
+                .max(10)
+                .style(Style::new().cyan())
+        );
+
+        let data = [1, 2];
+        assert_eq!(
+            sparkline![&data; max: 5],
+            Sparkline::default().data(data).max(5)
+        );
+    }
+}
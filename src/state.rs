@@ -0,0 +1,201 @@
+/// A macro for creating a [`ListState`] with an initial selection and offset.
+///
+/// The arguments are `key: value` pairs separated by commas: `selected: index` selects the item at
+/// the index and `offset: index` sets the index of the first item that is shown. Without
+/// arguments, the state is [`ListState::default`], which selects nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::list_state;
+///
+/// let state = list_state!(selected: 0);
+/// assert_eq!(state.selected(), Some(0));
+///
+/// let state = list_state!(selected: 12, offset: 10);
+/// assert_eq!((state.selected(), state.offset()), (Some(12), 10));
+/// ```
+///
+/// [`ListState`]: ratatui_widgets::list::ListState
+/// [`ListState::default`]: ratatui_widgets::list::ListState::default
+#[macro_export]
+macro_rules! list_state {
+    (@settings $state:expr ; $(,)?) => {
+        $state
+    };
+    (@settings $state:expr ; selected : $index:expr $(, $($rest:tt)*)?) => {
+        $crate::list_state!(@settings
+            $state.with_selected(::core::option::Option::Some($index)) ;
+            $($($rest)*)?
+        )
+    };
+    (@settings $state:expr ; offset : $offset:expr $(, $($rest:tt)*)?) => {
+        $crate::list_state!(@settings $state.with_offset($offset) ; $($($rest)*)?)
+    };
+    (@settings $state:expr ; $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(concat!(
+            "unknown list state setting `",
+            stringify!($key),
+            "`, expected `selected` or `offset`"
+        ))
+    };
+    ($($settings:tt)*) => {
+        $crate::list_state!(@settings
+            $crate::ratatui_widgets::list::ListState::default() ;
+            $($settings)*
+        )
+    };
+}
+
+/// A macro for creating a [`TableState`] with an initial selection and offset.
+///
+/// Like [`list_state!`], the arguments are `key: value` pairs separated by commas: `selected:
+/// index` selects the row at the index, `column: index` selects the column at the index and
+/// `offset: index` sets the index of the first row that is shown.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::table_state;
+///
+/// let state = table_state!(selected: 3, offset: 10);
+/// assert_eq!((state.selected(), state.offset()), (Some(3), 10));
+///
+/// let state = table_state!(selected: 1, column: 2);
+/// assert_eq!(state.selected_cell(), Some((1, 2)));
+/// ```
+///
+/// [`TableState`]: ratatui_widgets::table::TableState
+/// [`list_state!`]: crate::list_state!
+#[macro_export]
+macro_rules! table_state {
+    (@settings $state:expr ; $(,)?) => {
+        $state
+    };
+    (@settings $state:expr ; selected : $index:expr $(, $($rest:tt)*)?) => {
+        $crate::table_state!(@settings
+            $state.with_selected(::core::option::Option::Some($index)) ;
+            $($($rest)*)?
+        )
+    };
+    (@settings $state:expr ; column : $index:expr $(, $($rest:tt)*)?) => {
+        $crate::table_state!(@settings
+            $state.with_selected_column(::core::option::Option::Some($index)) ;
+            $($($rest)*)?
+        )
+    };
+    (@settings $state:expr ; offset : $offset:expr $(, $($rest:tt)*)?) => {
+        $crate::table_state!(@settings $state.with_offset($offset) ; $($($rest)*)?)
+    };
+    (@settings $state:expr ; $key:ident $($rest:tt)*) => {
+        ::core::compile_error!(concat!(
+            "unknown table state setting `",
+            stringify!($key),
+            "`, expected `selected`, `column` or `offset`"
+        ))
+    };
+    ($($settings:tt)*) => {
+        $crate::table_state!(@settings
+            $crate::ratatui_widgets::table::TableState::default() ;
+            $($settings)*
+        )
+    };
+}
+
+/// A macro for moving the selection of a list or table state by a number of items, clamped to the
+/// number of items.
+///
+/// The arguments are the state, e.g. a [`ListState`] or a [`TableState`], the number of items to
+/// move the selection by, which is negative to move it up, and the number of items. The new
+/// selection stays between the first and the last item, and when nothing is selected the first
+/// item is selected. When there are no items, the selection is cleared. The macro returns the new
+/// selection.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::{list_state, select_clamped};
+///
+/// let items = ["a", "b", "c"];
+/// let mut state = list_state!(selected: 1);
+/// assert_eq!(select_clamped!(state, 1, items.len()), Some(2));
+/// assert_eq!(select_clamped!(state, 1, items.len()), Some(2));
+/// assert_eq!(select_clamped!(state, -10, items.len()), Some(0));
+/// ```
+///
+/// [`ListState`]: ratatui_widgets::list::ListState
+/// [`TableState`]: ratatui_widgets::table::TableState
+#[macro_export]
+macro_rules! select_clamped {
+    ($state:expr, $delta:expr, $len:expr $(,)?) => {{
+        let selected = $crate::__clamp_selection($state.selected(), $delta, $len);
+        $state.select(selected);
+        selected
+    }};
+}
+
+/// Returns the selection moved by `delta` items and clamped to `len` items. See
+/// [`select_clamped!`].
+///
+/// [`select_clamped!`]: crate::select_clamped!
+#[doc(hidden)]
+pub fn __clamp_selection(selected: Option<usize>, delta: isize, len: usize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let Some(selected) = selected else {
+        return Some(0);
+    };
+    let selected = selected.saturating_add_signed(delta);
+    Some(selected.min(last))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_widgets::{list::ListState, table::TableState};
+
+    use super::__clamp_selection;
+
+    #[test]
+    fn list_state_settings() {
+        assert_eq!(list_state!(), ListState::default());
+        assert_eq!(
+            list_state!(offset: 2, selected: 4,),
+            ListState::default().with_offset(2).with_selected(Some(4))
+        );
+    }
+
+    #[test]
+    fn table_state_settings() {
+        assert_eq!(table_state!(), TableState::default());
+        assert_eq!(
+            table_state!(selected: 3, column: 1, offset: 10),
+            TableState::default()
+                .with_selected(3)
+                .with_selected_column(1)
+                .with_offset(10)
+        );
+    }
+
+    #[test]
+    fn clamp_selection() {
+        assert_eq!(__clamp_selection(Some(1), 1, 3), Some(2));
+        assert_eq!(__clamp_selection(Some(2), 5, 3), Some(2));
+        assert_eq!(__clamp_selection(Some(1), -5, 3), Some(0));
+        assert_eq!(__clamp_selection(Some(7), 0, 3), Some(2));
+        assert_eq!(__clamp_selection(None, 1, 3), Some(0));
+        assert_eq!(__clamp_selection(Some(1), 1, 0), None);
+    }
+
+    #[test]
+    fn select_clamped_states() {
+        let mut list = list_state!(selected: 0);
+        assert_eq!(select_clamped!(list, -1, 4), Some(0));
+        assert_eq!(select_clamped!(list, 3, 4), Some(3));
+        assert_eq!(list.selected(), Some(3));
+
+        let mut table = table_state!();
+        let rows = ["a", "b"];
+        assert_eq!(select_clamped!(&mut table, 1, rows.len()), Some(0));
+        assert_eq!(select_clamped!(&mut table, 1, rows.len()), Some(1));
+        assert_eq!(table.selected(), Some(1));
+    }
+}
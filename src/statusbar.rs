@@ -0,0 +1,201 @@
+use ratatui_core::{
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::truncate::truncate_str;
+
+/// A macro for creating a status bar [`Line`] of an exact width from left, center and right
+/// sections.
+///
+/// The first argument is the width of the line in cells, followed by a `;` and the sections as
+/// `left: line`, `center: line` and `right: line`, where each section is anything that can be
+/// converted into a [`Line`] and all of them are optional. The left section is aligned to the left
+/// edge, the right section to the right edge and the center section is centered in the line, or as
+/// close to the center as the other sections allow. The gaps are filled with spaces.
+///
+/// When the sections do not fit, the center section is truncated first, then the right section
+/// and then the left section. The widths are measured in cells, so wide characters like CJK and
+/// emoji take two cells and are never split. The style of each section is applied to its spans.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{line, statusbar};
+///
+/// let (mode, filename, position) = ("NORMAL", "main.rs", "12:4");
+/// let line = statusbar!(24; left: line![mode.bold()], center: filename, right: position);
+/// assert_eq!(line.to_string(), "NORMAL  main.rs     12:4");
+///
+/// // the center section is truncated first
+/// let line = statusbar!(12; left: mode, center: filename, right: position);
+/// assert_eq!(line.to_string(), "NORMALma12:4");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! statusbar {
+    ($width:expr; $($section:ident : $line:expr),* $(,)?) => {
+        $crate::__StatusBar::default()
+            $(.$section($line))*
+            .line($width)
+    };
+}
+
+/// The sections of a status bar. See [`statusbar!`].
+///
+/// [`statusbar!`]: crate::statusbar!
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct __StatusBar<'a> {
+    left: Line<'a>,
+    center: Line<'a>,
+    right: Line<'a>,
+}
+
+impl<'a> __StatusBar<'a> {
+    pub fn left(mut self, line: impl Into<Line<'a>>) -> Self {
+        self.left = line.into();
+        self
+    }
+
+    pub fn center(mut self, line: impl Into<Line<'a>>) -> Self {
+        self.center = line.into();
+        self
+    }
+
+    pub fn right(mut self, line: impl Into<Line<'a>>) -> Self {
+        self.right = line.into();
+        self
+    }
+
+    /// Lays out the sections in a line of exactly `width` cells.
+    pub fn line(self, width: u16) -> Line<'a> {
+        let width = usize::from(width);
+        let left = truncate_line(self.left, width);
+        let left_width = left.width();
+        let right = truncate_line(self.right, width - left_width);
+        let right_width = right.width();
+        let center = truncate_line(self.center, width - left_width - right_width);
+        let center_width = center.width();
+
+        // the centered position, moved to the right of the left section and to the left of the
+        // right section. Without a center section, all of the padding is put after the left
+        // section.
+        let center_start = if center_width == 0 {
+            left_width
+        } else {
+            ((width - center_width) / 2)
+                .max(left_width)
+                .min(width - right_width - center_width)
+        };
+        let mut spans = left.spans;
+        push_padding(&mut spans, center_start - left_width);
+        spans.extend(center.spans);
+        push_padding(
+            &mut spans,
+            width - right_width - center_start - center_width,
+        );
+        spans.extend(right.spans);
+        Line::from(spans)
+    }
+}
+
+fn push_padding(spans: &mut Vec<Span<'_>>, width: usize) {
+    if width > 0 {
+        spans.push(Span::raw(" ".repeat(width)));
+    }
+}
+
+/// Truncates the spans of the line to at most `width` cells without splitting a grapheme, and
+/// applies the style of the line to its spans.
+pub(crate) fn truncate_line(line: Line<'_>, width: usize) -> Line<'_> {
+    let Line { spans, style, .. } = line;
+    let mut remaining = width;
+    let mut truncated = Vec::with_capacity(spans.len());
+    for span in spans {
+        let style = style.patch(span.style);
+        let span_width = span.width();
+        if span_width <= remaining {
+            remaining -= span_width;
+            truncated.push(Span::styled(span.content, style));
+            continue;
+        }
+        let content = truncate_str(&span.content, remaining);
+        if !content.is_empty() {
+            truncated.push(Span::styled(content, style));
+        }
+        break;
+    }
+    Line::from(truncated).style(Style::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::{Line, Span},
+    };
+
+    use crate::line;
+
+    #[test]
+    fn statusbar_sections() {
+        let line = statusbar!(20; left: "NORMAL", center: "a.rs", right: "1:1");
+        assert_eq!(line.to_string(), "NORMAL  a.rs     1:1");
+        assert_eq!(line.width(), 20);
+
+        assert_eq!(statusbar!(6; right: "ab").to_string(), "    ab");
+        assert_eq!(statusbar!(6; center: "ab").to_string(), "  ab  ");
+        assert_eq!(statusbar!(4;).to_string(), "    ");
+    }
+
+    #[test]
+    fn statusbar_moves_center() {
+        // the center section would overlap the left section, so it moves to the right
+        let line = statusbar!(12; left: "abcdef", center: "xyz", right: "1");
+        assert_eq!(line.to_string(), "abcdefxyz  1");
+    }
+
+    #[test]
+    fn statusbar_truncates() {
+        assert_eq!(
+            statusbar!(8; left: "abc", center: "center", right: "xyz").to_string(),
+            "abccexyz"
+        );
+        assert_eq!(
+            statusbar!(5; left: "abc", center: "center", right: "xyz").to_string(),
+            "abcxy"
+        );
+        assert_eq!(statusbar!(2; left: "abc", right: "xyz").to_string(), "ab");
+    }
+
+    #[test]
+    fn statusbar_wide_characters() {
+        // a wide character that does not fit is left out, and the gap is padded
+        let line = statusbar!(6; left: "你好", right: "世界");
+        assert_eq!(line.to_string(), "你好世");
+        assert_eq!(line.width(), 6);
+        let line = statusbar!(5; left: "你好世界");
+        assert_eq!(line.to_string(), "你好 ");
+    }
+
+    #[test]
+    fn statusbar_styles() {
+        let line = statusbar!(
+            8;
+            left: line!["a".bold(), "b"].red(),
+            right: Span::raw("xyz").on_blue(),
+        );
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::styled("a", Style::new().red().bold()),
+                Span::styled("b", Style::new().red()),
+                Span::raw("   "),
+                Span::raw("xyz").on_blue(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,570 @@
+use ratatui_core::style::Color;
+
+/// A macro for creating a [`Style`] from colors and modifier keywords.
+///
+/// The arguments are a comma separated list of:
+///
+/// - `fg: color` and `bg: color` to set the foreground and background colors. The color is either
+///   the name of a [`Color`] variant, e.g. `fg: Red` or `bg: Rgb(30, 41, 59)`, an indexed color of
+///   the 256-color palette written `@index`, e.g. `fg: @208`, or an expression, e.g.
+///   `fg: Color::Indexed(208)` or `fg: accent`
+/// - modifier keywords, which are the lowercase names of the [`Modifier`] flags: `bold`, `dim`,
+///   `italic`, `underlined`, `slow_blink`, `rapid_blink`, `reversed`, `hidden` and `crossed_out`
+/// - any other `key: value` calls the builder method of the same name on [`Style`], e.g.
+///   `remove_modifier: Modifier::DIM`
+///
+/// The expansion only uses `const` methods, so the macro can be used to define constants. The index
+/// of an `@index` color is checked at compile time, so an index over 255 is a compile error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style};
+/// use ratatui_macros::style;
+///
+/// let style = style!();
+/// let style = style!(fg: Red, bg: Black, bold, italic);
+/// let style = style!(fg: Rgb(122, 162, 247), underlined);
+/// let style = style!(fg: @208, bg: @236);
+///
+/// let accent = Color::Indexed(208);
+/// let style = style!(fg: accent, reversed);
+///
+/// const ERROR: Style = style!(fg: LightRed, bold);
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::style;
+/// let style = style!(fg: @256);
+/// ```
+///
+/// [`Style`]: ratatui_core::style::Style
+/// [`Color`]: ratatui_core::style::Color
+/// [`Modifier`]: ratatui_core::style::Modifier
+#[macro_export]
+macro_rules! style {
+    (@style $style:expr ; $(,)?) => {
+        $style
+    };
+    (@style $style:expr ; fg: @ $index:literal $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.fg($crate::__color!(@ $index)) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; bg: @ $index:literal $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.bg($crate::__color!(@ $index)) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; fg: $color:ident $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.fg($crate::__color!($color)) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; fg: $color:ident ($($args:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.fg($crate::__color!($color($($args)*))) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; bg: $color:ident $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.bg($crate::__color!($color)) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; bg: $color:ident ($($args:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.bg($crate::__color!($color($($args)*))) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@style $style.$key($value) ; $($($rest)*)?)
+    };
+    (@style $style:expr ; $modifier:ident $(, $($rest:tt)*)?) => {
+        $crate::style!(@style
+            $style.add_modifier($crate::__modifier!($modifier)) ;
+            $($($rest)*)?
+        )
+    };
+    ($($rest:tt)*) => {
+        $crate::style!(@style $crate::ratatui_core::style::Style::new() ; $($rest)*)
+    };
+}
+
+/// A macro for combining [`Modifier`] flags.
+///
+/// The flags are either the names of [`Modifier`] flags separated by `|`, e.g.
+/// `modifiers!(BOLD | ITALIC)`, or the lowercase modifier keywords accepted by [`style!`] separated
+/// by `,`, e.g. `modifiers!(bold, italic)`. The expansion is a constant expression, so the result
+/// can be used to define constants and passed to `const` methods such as [`Style::add_modifier`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Modifier, Style};
+/// use ratatui_macros::modifiers;
+///
+/// const EMPHASIS: Modifier = modifiers!(BOLD | ITALIC | UNDERLINED);
+/// assert_eq!(EMPHASIS, Modifier::BOLD | Modifier::ITALIC | Modifier::UNDERLINED);
+///
+/// let style = Style::new().add_modifier(modifiers!(bold, italic));
+/// ```
+///
+/// [`Modifier`]: ratatui_core::style::Modifier
+/// [`Style::add_modifier`]: ratatui_core::style::Style::add_modifier
+#[macro_export]
+macro_rules! modifiers {
+    () => {
+        $crate::ratatui_core::style::Modifier::empty()
+    };
+    ($($modifier:ident)|+) => {
+        $crate::ratatui_core::style::Modifier::empty()
+            $(.union($crate::__modifier!($modifier)))+
+    };
+    ($($modifier:ident),+ $(,)?) => {
+        $crate::ratatui_core::style::Modifier::empty()
+            $(.union($crate::__modifier!($modifier)))+
+    };
+}
+
+/// A macro for computing the [`Style`] of a row of a striped list or table from its index.
+///
+/// The first argument is the index of the row, and the other two arguments are the base style and
+/// the style of the stripe, which are anything that can be converted into a [`Style`]. Rows with
+/// an even index get the base style, and rows with an odd index get the base style patched with
+/// the stripe, the same as the `stripe` of [`rows!`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// use ratatui_macros::zebra;
+/// use ratatui_widgets::list::{List, ListItem};
+///
+/// let base = Style::new().white();
+/// let stripe = Style::new().bg(Color::Rgb(30, 30, 30));
+/// assert_eq!(zebra!(0, base, stripe), base);
+/// assert_eq!(zebra!(1, base, stripe), base.bg(Color::Rgb(30, 30, 30)));
+///
+/// let items = ["one", "two", "three"].into_iter().enumerate();
+/// let list = List::new(items.map(|(i, item)| ListItem::new(item).style(zebra!(i, base, stripe))));
+/// ```
+///
+/// [`Style`]: ratatui_core::style::Style
+/// [`rows!`]: crate::rows!
+#[macro_export]
+macro_rules! zebra {
+    ($index:expr, $base:expr, $stripe:expr $(,)?) => {{
+        let base: $crate::ratatui_core::style::Style = ::core::convert::Into::into($base);
+        if ($index) % 2 != 0 {
+            base.patch($stripe)
+        } else {
+            base
+        }
+    }};
+}
+
+/// A macro for computing the [`Style`] of an item from whether it is selected.
+///
+/// The first argument is a `bool` that is `true` for the selected item, and the other two
+/// arguments are the base style and the highlight style, which are anything that can be converted
+/// into a [`Style`]. The selected item gets the base style patched with the highlight, and the
+/// other items get the base style. This composes with [`zebra!`], so a selected striped row keeps
+/// the parts of its stripe that the highlight does not set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// use ratatui_macros::{selected, zebra};
+/// use ratatui_widgets::table::{Row, Table};
+///
+/// let highlight = Style::new().bold().reversed();
+/// assert_eq!(selected!(true, Color::Gray, highlight), highlight.fg(Color::Gray));
+/// assert_eq!(selected!(false, Color::Gray, highlight), Style::new().gray());
+///
+/// let current = Some(1);
+/// let rows = ["a", "b", "c"].into_iter().enumerate().map(|(i, name)| {
+///     let striped = zebra!(i, Style::new(), Color::DarkGray);
+///     Row::new([name]).style(selected!(current == Some(i), striped, highlight))
+/// });
+/// let table = Table::new(rows, [5]);
+/// ```
+///
+/// [`Style`]: ratatui_core::style::Style
+/// [`zebra!`]: crate::zebra!
+#[macro_export]
+macro_rules! selected {
+    ($selected:expr, $base:expr, $highlight:expr $(,)?) => {{
+        let base: $crate::ratatui_core::style::Style = ::core::convert::Into::into($base);
+        if $selected {
+            base.patch($highlight)
+        } else {
+            base
+        }
+    }};
+}
+
+/// Expands a color name (e.g. `Red`, `Rgb(30, 41, 59)` or `Indexed(208)`) or an indexed color
+/// (e.g. `@208`) to the matching [`Color`] variant. Any other identifier or call is passed through
+/// unchanged.
+///
+/// [`Color`]: ratatui_core::style::Color
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __color {
+    (Reset) => {
+        $crate::ratatui_core::style::Color::Reset
+    };
+    (Black) => {
+        $crate::ratatui_core::style::Color::Black
+    };
+    (Red) => {
+        $crate::ratatui_core::style::Color::Red
+    };
+    (Green) => {
+        $crate::ratatui_core::style::Color::Green
+    };
+    (Yellow) => {
+        $crate::ratatui_core::style::Color::Yellow
+    };
+    (Blue) => {
+        $crate::ratatui_core::style::Color::Blue
+    };
+    (Magenta) => {
+        $crate::ratatui_core::style::Color::Magenta
+    };
+    (Cyan) => {
+        $crate::ratatui_core::style::Color::Cyan
+    };
+    (Gray) => {
+        $crate::ratatui_core::style::Color::Gray
+    };
+    (DarkGray) => {
+        $crate::ratatui_core::style::Color::DarkGray
+    };
+    (LightRed) => {
+        $crate::ratatui_core::style::Color::LightRed
+    };
+    (LightGreen) => {
+        $crate::ratatui_core::style::Color::LightGreen
+    };
+    (LightYellow) => {
+        $crate::ratatui_core::style::Color::LightYellow
+    };
+    (LightBlue) => {
+        $crate::ratatui_core::style::Color::LightBlue
+    };
+    (LightMagenta) => {
+        $crate::ratatui_core::style::Color::LightMagenta
+    };
+    (LightCyan) => {
+        $crate::ratatui_core::style::Color::LightCyan
+    };
+    (White) => {
+        $crate::ratatui_core::style::Color::White
+    };
+    (Rgb($r:expr, $g:expr, $b:expr $(,)?)) => {
+        $crate::ratatui_core::style::Color::Rgb($r, $g, $b)
+    };
+    (Indexed($index:expr $(,)?)) => {
+        $crate::ratatui_core::style::Color::Indexed($index)
+    };
+    (@ $index:literal) => {{
+        const COLOR: $crate::ratatui_core::style::Color = $crate::__indexed_color($index);
+        COLOR
+    }};
+    ($color:ident) => {
+        $color
+    };
+    ($function:ident ($($args:tt)*)) => {
+        $function($($args)*)
+    };
+}
+
+/// Expands a `fg on bg` pair of colors, e.g. `white on Rgb(30, 30, 46)`, to a [`Style`] with the
+/// foreground and background colors. The colors are the names accepted by [`__color!`] or their
+/// lowercase forms, e.g. `red` or `light_blue`, or indexed colors, e.g. `@208`.
+///
+/// [`Style`]: ratatui_core::style::Style
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fg_on_bg {
+    (@ $fg:literal on $($bg:tt)+) => {
+        $crate::ratatui_core::style::Style::new()
+            .fg($crate::__color!(@ $fg))
+            .bg($crate::__color_name!($($bg)+))
+    };
+    ($fg:ident $(($($fg_args:tt)*))? on $($bg:tt)+) => {
+        $crate::ratatui_core::style::Style::new()
+            .fg($crate::__color_name!($fg $(($($fg_args)*))?))
+            .bg($crate::__color_name!($($bg)+))
+    };
+}
+
+/// Expands a lowercase color name (e.g. `red` or `dark_gray`) to the matching [`Color`] variant.
+/// Any other color is passed to [`__color!`].
+///
+/// [`Color`]: ratatui_core::style::Color
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __color_name {
+    (reset) => {
+        $crate::ratatui_core::style::Color::Reset
+    };
+    (black) => {
+        $crate::ratatui_core::style::Color::Black
+    };
+    (red) => {
+        $crate::ratatui_core::style::Color::Red
+    };
+    (green) => {
+        $crate::ratatui_core::style::Color::Green
+    };
+    (yellow) => {
+        $crate::ratatui_core::style::Color::Yellow
+    };
+    (blue) => {
+        $crate::ratatui_core::style::Color::Blue
+    };
+    (magenta) => {
+        $crate::ratatui_core::style::Color::Magenta
+    };
+    (cyan) => {
+        $crate::ratatui_core::style::Color::Cyan
+    };
+    (gray) => {
+        $crate::ratatui_core::style::Color::Gray
+    };
+    (dark_gray) => {
+        $crate::ratatui_core::style::Color::DarkGray
+    };
+    (light_red) => {
+        $crate::ratatui_core::style::Color::LightRed
+    };
+    (light_green) => {
+        $crate::ratatui_core::style::Color::LightGreen
+    };
+    (light_yellow) => {
+        $crate::ratatui_core::style::Color::LightYellow
+    };
+    (light_blue) => {
+        $crate::ratatui_core::style::Color::LightBlue
+    };
+    (light_magenta) => {
+        $crate::ratatui_core::style::Color::LightMagenta
+    };
+    (light_cyan) => {
+        $crate::ratatui_core::style::Color::LightCyan
+    };
+    (white) => {
+        $crate::ratatui_core::style::Color::White
+    };
+    ($($color:tt)*) => {
+        $crate::__color!($($color)*)
+    };
+}
+
+/// Expands a lowercase modifier keyword (e.g. `bold`) to the matching [`Modifier`] flag. Any other
+/// identifier is the name of a [`Modifier`] flag, e.g. `BOLD`.
+///
+/// [`Modifier`]: ratatui_core::style::Modifier
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __modifier {
+    (bold) => {
+        $crate::ratatui_core::style::Modifier::BOLD
+    };
+    (dim) => {
+        $crate::ratatui_core::style::Modifier::DIM
+    };
+    (italic) => {
+        $crate::ratatui_core::style::Modifier::ITALIC
+    };
+    (underlined) => {
+        $crate::ratatui_core::style::Modifier::UNDERLINED
+    };
+    (slow_blink) => {
+        $crate::ratatui_core::style::Modifier::SLOW_BLINK
+    };
+    (rapid_blink) => {
+        $crate::ratatui_core::style::Modifier::RAPID_BLINK
+    };
+    (reversed) => {
+        $crate::ratatui_core::style::Modifier::REVERSED
+    };
+    (hidden) => {
+        $crate::ratatui_core::style::Modifier::HIDDEN
+    };
+    (crossed_out) => {
+        $crate::ratatui_core::style::Modifier::CROSSED_OUT
+    };
+    ($flag:ident) => {
+        $crate::ratatui_core::style::Modifier::$flag
+    };
+}
+
+/// Returns the indexed color, panicking if the index is over 255. This is evaluated in a constant
+/// by [`__color!`], so an invalid index is a compile error.
+///
+/// [`__color!`]: crate::__color!
+#[doc(hidden)]
+pub const fn __indexed_color(index: u32) -> Color {
+    if index > 255 {
+        panic!("an indexed color is at most `@255`");
+    }
+    Color::Indexed(index as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier, Style};
+
+    #[test]
+    fn style_empty() {
+        assert_eq!(style!(), Style::new());
+    }
+
+    #[test]
+    fn style_colors() {
+        assert_eq!(style!(fg: Red), Style::new().fg(Color::Red));
+        assert_eq!(
+            style!(fg: LightCyan, bg: Black),
+            Style::new().fg(Color::LightCyan).bg(Color::Black)
+        );
+        assert_eq!(
+            style!(fg: Rgb(30, 41, 59), bg: Indexed(208)),
+            Style::new()
+                .fg(Color::Rgb(30, 41, 59))
+                .bg(Color::Indexed(208))
+        );
+    }
+
+    #[test]
+    fn style_indexed_colors() {
+        assert_eq!(
+            style!(fg: @208, bg: @236, bold),
+            Style::new()
+                .fg(Color::Indexed(208))
+                .bg(Color::Indexed(236))
+                .add_modifier(Modifier::BOLD)
+        );
+        const MUTED: Style = style!(fg: @0, bg: @255);
+        assert_eq!(
+            MUTED,
+            Style::new().fg(Color::Indexed(0)).bg(Color::Indexed(255))
+        );
+    }
+
+    #[test]
+    fn style_color_expressions() {
+        let accent = Color::Indexed(208);
+        assert_eq!(style!(fg: accent), Style::new().fg(accent));
+        assert_eq!(
+            style!(bg: Color::from_u32(0x1e293b)),
+            Style::new().bg(Color::Rgb(0x1e, 0x29, 0x3b))
+        );
+    }
+
+    #[test]
+    fn fg_on_bg() {
+        assert_eq!(
+            __fg_on_bg!(red on black),
+            Style::new().fg(Color::Red).bg(Color::Black)
+        );
+        assert_eq!(
+            __fg_on_bg!(light_blue on Rgb(30, 30, 46)),
+            Style::new().fg(Color::LightBlue).bg(Color::Rgb(30, 30, 46))
+        );
+        let accent = Color::Indexed(208);
+        assert_eq!(
+            __fg_on_bg!(accent on DarkGray),
+            Style::new().fg(accent).bg(Color::DarkGray)
+        );
+        assert_eq!(
+            __fg_on_bg!(@208 on @236),
+            Style::new().fg(Color::Indexed(208)).bg(Color::Indexed(236))
+        );
+    }
+
+    #[test]
+    fn style_modifiers() {
+        assert_eq!(
+            style!(bold, italic),
+            Style::new().add_modifier(Modifier::BOLD | Modifier::ITALIC)
+        );
+        assert_eq!(
+            style!(fg: Red, bg: Black, bold, italic, crossed_out,),
+            Style::new()
+                .fg(Color::Red)
+                .bg(Color::Black)
+                .add_modifier(Modifier::BOLD | Modifier::ITALIC | Modifier::CROSSED_OUT)
+        );
+    }
+
+    #[test]
+    fn style_builder_settings() {
+        assert_eq!(
+            style!(dim, remove_modifier: Modifier::DIM),
+            Style::new()
+                .add_modifier(Modifier::DIM)
+                .remove_modifier(Modifier::DIM)
+        );
+    }
+
+    #[test]
+    fn modifiers_flags() {
+        assert_eq!(modifiers!(), Modifier::empty());
+        assert_eq!(modifiers!(BOLD), Modifier::BOLD);
+        assert_eq!(
+            modifiers!(BOLD | ITALIC | UNDERLINED),
+            Modifier::BOLD | Modifier::ITALIC | Modifier::UNDERLINED
+        );
+    }
+
+    #[test]
+    fn modifiers_keywords() {
+        assert_eq!(modifiers!(bold), Modifier::BOLD);
+        assert_eq!(
+            modifiers!(bold, crossed_out,),
+            Modifier::BOLD | Modifier::CROSSED_OUT
+        );
+    }
+
+    #[test]
+    fn modifiers_in_style() {
+        const EMPHASIS: Modifier = modifiers!(bold, italic);
+        assert_eq!(
+            style!(fg: Red, add_modifier: EMPHASIS),
+            style!(fg: Red, bold, italic)
+        );
+    }
+
+    #[test]
+    fn style_const() {
+        const ERROR: Style = style!(fg: LightRed, bold);
+        assert_eq!(
+            ERROR,
+            Style::new()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn zebra() {
+        let base = Style::new().fg(Color::White);
+        let stripe = Style::new().bg(Color::DarkGray);
+        assert_eq!(zebra!(0, base, stripe), base);
+        assert_eq!(zebra!(3_u16, base, stripe), base.bg(Color::DarkGray));
+        assert_eq!(
+            zebra!(-1_i32, Color::Red, Color::Blue),
+            Style::new().fg(Color::Blue)
+        );
+    }
+
+    #[test]
+    fn selected() {
+        let highlight = Style::new().add_modifier(Modifier::REVERSED);
+        assert_eq!(
+            selected!(false, Color::Red, highlight),
+            Style::new().fg(Color::Red)
+        );
+        assert_eq!(
+            selected!(1 + 1 == 2, Color::Red, highlight),
+            highlight.fg(Color::Red)
+        );
+        let striped = zebra!(1, Style::new(), Style::new().bg(Color::DarkGray));
+        assert_eq!(
+            selected!(true, striped, Color::Yellow),
+            Style::new().fg(Color::Yellow).bg(Color::DarkGray)
+        );
+    }
+}
@@ -0,0 +1,636 @@
+use std::{error::Error, fmt};
+
+use ratatui_core::style::{Color, Modifier, Style};
+
+/// A macro for creating a [`Style`] from a string literal in the style mini-language of
+/// [`parse_style`], e.g. `"bold italic red on blue"`.
+///
+/// The string is parsed in a constant, so an invalid style is a compile error and the macro can be
+/// used to define constants. Styles that are only known at runtime, e.g. from a configuration file,
+/// are parsed with [`parse_style`], which returns an error instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// use ratatui_macros::style_from_str;
+///
+/// const ERROR: Style = style_from_str!("bold light_red");
+/// assert_eq!(ERROR, Style::new().bold().light_red());
+///
+/// let style = style_from_str!("fg=#ff8800 underlined on black");
+/// assert_eq!(style, Style::new().fg(Color::Rgb(255, 136, 0)).underlined().on_black());
+/// ```
+///
+/// An invalid style does not compile:
+///
+/// ```compile_fail
+/// use ratatui_macros::style_from_str;
+///
+/// let style = style_from_str!("blod red");
+/// ```
+///
+/// [`Style`]: ratatui_core::style::Style
+/// [`parse_style`]: crate::parse_style
+#[macro_export]
+macro_rules! style_from_str {
+    ($spec:literal $(,)?) => {{
+        const STYLE: $crate::ratatui_core::style::Style = match $crate::__parse_style($spec) {
+            Ok(style) => style,
+            Err(error) => panic!("{}", error.message),
+        };
+        STYLE
+    }};
+}
+
+/// Parses a [`Style`] from a string of words separated by whitespace or `,`, e.g.
+/// `"bold red on blue"`.
+///
+/// The words are matched case insensitively, and `_` and `-` in them are ignored:
+///
+/// - a color sets the foreground color, and a color after `on` sets the background color
+/// - `fg=color` and `bg=color`, or `fg: color` and `bg: color` like in [`style!`], also set the
+///   foreground and background colors
+/// - a modifier, i.e. `bold`, `dim`, `italic`, `underlined` (or `underline`), `slow_blink`,
+///   `rapid_blink`, `reversed`, `hidden` or `crossed_out`, adds the modifier
+///
+/// A color is the name of a [`Color`] variant, e.g. `red`, `light_blue` or `LightBlue`, a hex
+/// color like `#ff8800` or `#f80`, an index between 0 and 255, e.g. `208` or `@208`, or
+/// `rgb(r, g, b)` or `indexed(n)`. So the arguments of [`style!`] and the colors of [`span!`], e.g.
+/// `"fg: Red, bold"` or `"@208 on @236"`, are also valid styles, except for expressions. Setting
+/// the same color twice is an error. [`style_from_str!`] parses string literals at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Color, Style, Stylize};
+/// use ratatui_macros::parse_style;
+///
+/// let style = parse_style("bold italic red on blue")?;
+/// assert_eq!(style, Style::new().bold().italic().red().on_blue());
+///
+/// let style = parse_style("fg=#ff8800 bg=236 Crossed-Out")?;
+/// assert_eq!(
+///     style,
+///     Style::new().fg(Color::Rgb(255, 136, 0)).bg(Color::Indexed(236)).crossed_out()
+/// );
+///
+/// let style = parse_style("fg: Rgb(255, 136, 0), bg: @236, bold")?;
+/// assert_eq!(style, Style::new().fg(Color::Rgb(255, 136, 0)).bg(Color::Indexed(236)).bold());
+///
+/// let error = parse_style("blod red").unwrap_err();
+/// assert_eq!(error.to_string(), "unknown color or modifier `blod`");
+/// # Ok::<(), ratatui_macros::ParseStyleError>(())
+/// ```
+///
+/// [`Color`]: ratatui_core::style::Color
+/// [`Style`]: ratatui_core::style::Style
+/// [`span!`]: crate::span!
+/// [`style!`]: crate::style!
+/// [`style_from_str!`]: crate::style_from_str!
+pub fn parse_style(spec: &str) -> Result<Style, ParseStyleError> {
+    __parse_style(spec).map_err(|error| ParseStyleError {
+        message: error.message,
+        word: spec[error.start..error.end].to_owned(),
+    })
+}
+
+/// Parses a [`Color`] from a string in the color syntax of [`parse_style`], e.g. `"light_blue"`,
+/// `"#ff8800"` or `"208"`.
+///
+/// Leading and trailing whitespace is ignored. This is useful for a single color that is loaded at
+/// runtime, e.g. the accent color of a theme in a configuration file.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Color;
+/// use ratatui_macros::parse_color;
+///
+/// assert_eq!(parse_color("Light-Blue")?, Color::LightBlue);
+/// assert_eq!(parse_color(" #f80 ")?, Color::Rgb(255, 136, 0));
+/// assert_eq!(parse_color("208")?, Color::Indexed(208));
+///
+/// let error = parse_color("bold").unwrap_err();
+/// assert_eq!(error.to_string(), "unknown color `bold`");
+/// # Ok::<(), ratatui_macros::ParseStyleError>(())
+/// ```
+///
+/// [`Color`]: ratatui_core::style::Color
+/// [`parse_style`]: crate::parse_style
+pub fn parse_color(spec: &str) -> Result<Color, ParseStyleError> {
+    let word = spec.trim();
+    parse_color_word(word.as_bytes(), 0, word.len()).ok_or_else(|| ParseStyleError {
+        message: "unknown color",
+        word: word.to_owned(),
+    })
+}
+
+/// The error returned by [`parse_style`] and [`parse_color`] for an invalid style or color.
+///
+/// [`parse_style`]: crate::parse_style
+/// [`parse_color`]: crate::parse_color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStyleError {
+    message: &'static str,
+    word: String,
+}
+
+impl ParseStyleError {
+    /// Returns the word of the style that is invalid.
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.message, self.word)
+    }
+}
+
+impl Error for ParseStyleError {}
+
+/// The reason why a style is invalid and the byte range of the word that is invalid.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __StyleError {
+    pub message: &'static str,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl __StyleError {
+    const fn new(message: &'static str, start: usize, end: usize) -> Self {
+        Self {
+            message,
+            start,
+            end,
+        }
+    }
+}
+
+pub(crate) const COLORS: [(&str, Color); 19] = [
+    ("reset", Color::Reset),
+    ("black", Color::Black),
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("blue", Color::Blue),
+    ("magenta", Color::Magenta),
+    ("cyan", Color::Cyan),
+    ("gray", Color::Gray),
+    ("grey", Color::Gray),
+    ("darkgray", Color::DarkGray),
+    ("darkgrey", Color::DarkGray),
+    ("lightred", Color::LightRed),
+    ("lightgreen", Color::LightGreen),
+    ("lightyellow", Color::LightYellow),
+    ("lightblue", Color::LightBlue),
+    ("lightmagenta", Color::LightMagenta),
+    ("lightcyan", Color::LightCyan),
+    ("white", Color::White),
+];
+
+pub(crate) const MODIFIERS: [(&str, Modifier); 9] = [
+    ("bold", Modifier::BOLD),
+    ("dim", Modifier::DIM),
+    ("italic", Modifier::ITALIC),
+    ("underlined", Modifier::UNDERLINED),
+    ("slowblink", Modifier::SLOW_BLINK),
+    ("rapidblink", Modifier::RAPID_BLINK),
+    ("reversed", Modifier::REVERSED),
+    ("hidden", Modifier::HIDDEN),
+    ("crossedout", Modifier::CROSSED_OUT),
+];
+
+/// Parses a style like [`parse_style`], but in a constant for [`style_from_str!`].
+///
+/// [`parse_style`]: crate::parse_style
+/// [`style_from_str!`]: crate::style_from_str!
+#[doc(hidden)]
+pub const fn __parse_style(spec: &str) -> Result<Style, __StyleError> {
+    let bytes = spec.as_bytes();
+    let mut style = Style::new();
+    // the range of the `on`, `fg:` or `bg:` word that is waiting for a color, and whether the
+    // color is the foreground color
+    let mut pending: Option<(usize, usize, bool)> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        if is_separator(bytes[i]) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        // the `,` in the arguments of `rgb(r, g, b)` does not end the word
+        let mut depth = 0;
+        while i < bytes.len() && (depth > 0 || !is_separator(bytes[i])) {
+            if bytes[i] == b'(' {
+                depth += 1;
+            } else if bytes[i] == b')' && depth > 0 {
+                depth -= 1;
+            }
+            i += 1;
+        }
+        let end = i;
+
+        if let Some((pending_start, pending_end, is_fg)) = pending {
+            pending = None;
+            let Some(color) = parse_color_word(bytes, start, end) else {
+                let message = if is_fg {
+                    "expected a foreground color after `fg:`, found"
+                } else if word_is(bytes, pending_start, pending_end, "on") {
+                    "expected a background color after `on`, found"
+                } else {
+                    "expected a background color after `bg:`, found"
+                };
+                return Err(__StyleError::new(message, start, end));
+            };
+            style = match set_color(style, color, is_fg, start, end) {
+                Ok(style) => style,
+                Err(error) => return Err(error),
+            };
+        } else if word_is(bytes, start, end, "on") {
+            pending = Some((start, end, false));
+        } else if end - start >= 3 && (bytes[start + 2] == b'=' || bytes[start + 2] == b':') {
+            let is_fg = word_is(bytes, start, start + 2, "fg");
+            if !is_fg && !word_is(bytes, start, start + 2, "bg") {
+                return Err(__StyleError::new("unknown color or modifier", start, end));
+            }
+            // `fg: red` is written like the `fg: Red` of `style!`, with the color in the next word
+            if end - start == 3 && bytes[start + 2] == b':' {
+                pending = Some((start, end, is_fg));
+                continue;
+            }
+            let Some(color) = parse_color_word(bytes, start + 3, end) else {
+                let message = if bytes[start + 2] == b'=' {
+                    "expected a color after `=` in"
+                } else {
+                    "expected a color after `:` in"
+                };
+                return Err(__StyleError::new(message, start, end));
+            };
+            style = match set_color(style, color, is_fg, start, end) {
+                Ok(style) => style,
+                Err(error) => return Err(error),
+            };
+        } else if let Some(modifier) = parse_modifier(bytes, start, end) {
+            style = style.add_modifier(modifier);
+        } else if let Some(color) = parse_color_word(bytes, start, end) {
+            style = match set_color(style, color, true, start, end) {
+                Ok(style) => style,
+                Err(error) => return Err(error),
+            };
+        } else {
+            return Err(__StyleError::new("unknown color or modifier", start, end));
+        }
+    }
+    if let Some((start, end, is_fg)) = pending {
+        let message = if is_fg {
+            "expected a foreground color after"
+        } else {
+            "expected a background color after"
+        };
+        return Err(__StyleError::new(message, start, end));
+    }
+    Ok(style)
+}
+
+/// Returns whether the byte separates the words of a style, i.e. whitespace or a `,`.
+const fn is_separator(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || byte == b','
+}
+
+/// Sets the foreground or background color, or returns an error if it is already set.
+const fn set_color(
+    style: Style,
+    color: Color,
+    is_fg: bool,
+    start: usize,
+    end: usize,
+) -> Result<Style, __StyleError> {
+    if is_fg && style.fg.is_some() {
+        Err(__StyleError::new(
+            "the foreground color is set twice by",
+            start,
+            end,
+        ))
+    } else if !is_fg && style.bg.is_some() {
+        Err(__StyleError::new(
+            "the background color is set twice by",
+            start,
+            end,
+        ))
+    } else if is_fg {
+        Ok(style.fg(color))
+    } else {
+        Ok(style.bg(color))
+    }
+}
+
+/// Returns whether the word equals the lowercase name, ignoring case and `_` and `-` in the word.
+const fn word_is(bytes: &[u8], start: usize, end: usize, name: &str) -> bool {
+    let name = name.as_bytes();
+    let mut i = start;
+    let mut j = 0;
+    while i < end {
+        let byte = bytes[i];
+        i += 1;
+        if byte == b'_' || byte == b'-' {
+            continue;
+        }
+        if j == name.len() || byte.to_ascii_lowercase() != name[j] {
+            return false;
+        }
+        j += 1;
+    }
+    j == name.len()
+}
+
+const fn parse_modifier(bytes: &[u8], start: usize, end: usize) -> Option<Modifier> {
+    // `underline` is accepted as well, but `MODIFIERS` only has the names that describe a style
+    if word_is(bytes, start, end, "underline") {
+        return Some(Modifier::UNDERLINED);
+    }
+    let mut i = 0;
+    while i < MODIFIERS.len() {
+        if word_is(bytes, start, end, MODIFIERS[i].0) {
+            return Some(MODIFIERS[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn parse_color_word(bytes: &[u8], start: usize, end: usize) -> Option<Color> {
+    if start == end {
+        return None;
+    }
+    if bytes[start] == b'#' {
+        return parse_hex(bytes, start + 1, end);
+    }
+    if bytes[end - 1] == b')' {
+        return parse_call(bytes, start, end);
+    }
+    // an index is written either `208` or `@208`, like the indexed colors of `style!`
+    if bytes[start] == b'@' && start + 1 < end {
+        return parse_index(bytes, start + 1, end);
+    }
+    if bytes[start].is_ascii_digit() {
+        return parse_index(bytes, start, end);
+    }
+    let mut i = 0;
+    while i < COLORS.len() {
+        if word_is(bytes, start, end, COLORS[i].0) {
+            return Some(COLORS[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Parses a color written like a [`Color`] variant, i.e. `rgb(r, g, b)` or `indexed(n)`.
+///
+/// [`Color`]: ratatui_core::style::Color
+const fn parse_call(bytes: &[u8], start: usize, end: usize) -> Option<Color> {
+    let mut open = start;
+    while open < end && bytes[open] != b'(' {
+        open += 1;
+    }
+    if open == end {
+        return None;
+    }
+    // the arguments are separated by `,`, each surrounded by optional whitespace
+    let mut args = [0u8; 3];
+    let mut count = 0;
+    let mut i = open + 1;
+    while i < end - 1 {
+        if count == args.len() {
+            return None;
+        }
+        while i < end - 1 && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let arg_start = i;
+        while i < end - 1 && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let Some(Color::Indexed(arg)) = parse_index(bytes, arg_start, i) else {
+            return None;
+        };
+        if arg_start == i {
+            return None;
+        }
+        args[count] = arg;
+        count += 1;
+        while i < end - 1 && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i < end - 1 {
+            if bytes[i] != b',' {
+                return None;
+            }
+            i += 1;
+        }
+    }
+    if count == 3 && word_is(bytes, start, open, "rgb") {
+        Some(Color::Rgb(args[0], args[1], args[2]))
+    } else if count == 1 && word_is(bytes, start, open, "indexed") {
+        Some(Color::Indexed(args[0]))
+    } else {
+        None
+    }
+}
+
+/// Parses the digits of an indexed color between 0 and 255.
+const fn parse_index(bytes: &[u8], start: usize, end: usize) -> Option<Color> {
+    let mut index: u16 = 0;
+    let mut i = start;
+    while i < end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        index = index * 10 + (bytes[i] - b'0') as u16;
+        if index > 255 {
+            return None;
+        }
+        i += 1;
+    }
+    Some(Color::Indexed(index as u8))
+}
+
+/// Parses the digits of a `#rrggbb` or `#rgb` color.
+const fn parse_hex(bytes: &[u8], start: usize, end: usize) -> Option<Color> {
+    let len = end - start;
+    if len != 3 && len != 6 {
+        return None;
+    }
+    let mut digits = [0u8; 6];
+    let mut i = 0;
+    while i < len {
+        digits[i] = match bytes[start + i] {
+            byte @ b'0'..=b'9' => byte - b'0',
+            byte @ b'a'..=b'f' => byte - b'a' + 10,
+            byte @ b'A'..=b'F' => byte - b'A' + 10,
+            _ => return None,
+        };
+        i += 1;
+    }
+    Some(if len == 3 {
+        Color::Rgb(digits[0] * 0x11, digits[1] * 0x11, digits[2] * 0x11)
+    } else {
+        Color::Rgb(
+            digits[0] << 4 | digits[1],
+            digits[2] << 4 | digits[3],
+            digits[4] << 4 | digits[5],
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Style, Stylize};
+
+    use super::{parse_color, parse_style};
+
+    #[test]
+    fn parse_style_words() {
+        assert_eq!(parse_style(""), Ok(Style::new()));
+        assert_eq!(
+            parse_style("  bold\tItalic  dark_gray  on  LIGHT-BLUE "),
+            Ok(Style::new().bold().italic().dark_gray().on_light_blue())
+        );
+        assert_eq!(
+            parse_style("on black slow_blink rapidblink reversed hidden crossed_out dim"),
+            Ok(Style::new()
+                .on_black()
+                .slow_blink()
+                .rapid_blink()
+                .reversed()
+                .hidden()
+                .crossed_out()
+                .dim())
+        );
+        assert_eq!(
+            parse_style("grey underlined"),
+            Ok(Style::new().gray().underlined())
+        );
+        assert_eq!(
+            parse_style("reset on reset"),
+            Ok(Style::new().fg(Color::Reset).bg(Color::Reset))
+        );
+    }
+
+    #[test]
+    fn parse_style_colors() {
+        assert_eq!(
+            parse_style("fg=#FF8800 bg=#123"),
+            Ok(Style::new()
+                .fg(Color::Rgb(255, 136, 0))
+                .bg(Color::Rgb(0x11, 0x22, 0x33)))
+        );
+        assert_eq!(
+            parse_style("208 on 0"),
+            Ok(Style::new().fg(Color::Indexed(208)).bg(Color::Indexed(0)))
+        );
+        assert_eq!(
+            parse_style("@208 on @236"),
+            Ok(Style::new().fg(Color::Indexed(208)).bg(Color::Indexed(236)))
+        );
+        assert_eq!(parse_style("FG=red"), Ok(Style::new().red()));
+        assert_eq!(
+            parse_style("rgb( 1,2 , 3 ) on Indexed(4)"),
+            Ok(Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Indexed(4)))
+        );
+        assert_eq!(
+            parse_style("fg=#ff8800 underline"),
+            Ok(Style::new().fg(Color::Rgb(255, 136, 0)).underlined())
+        );
+    }
+
+    #[test]
+    fn parse_style_macro_syntax() {
+        assert_eq!(parse_style("fg: red, bold"), Ok(Style::new().red().bold()));
+        assert_eq!(
+            parse_style("fg: LightRed, bg: Rgb(30, 41, 59), underlined, italic,"),
+            Ok(Style::new()
+                .light_red()
+                .bg(Color::Rgb(30, 41, 59))
+                .underlined()
+                .italic())
+        );
+        assert_eq!(
+            parse_style("bg:@236,fg:#f80"),
+            Ok(Style::new()
+                .fg(Color::Rgb(255, 136, 0))
+                .bg(Color::Indexed(236)))
+        );
+    }
+
+    #[test]
+    fn parse_style_errors() {
+        let error = |spec| parse_style(spec).unwrap_err().to_string();
+        assert_eq!(error("bold blod"), "unknown color or modifier `blod`");
+        assert_eq!(error("xx=red"), "unknown color or modifier `xx=red`");
+        assert_eq!(error("red on"), "expected a background color after `on`");
+        assert_eq!(
+            error("on bold"),
+            "expected a background color after `on`, found `bold`"
+        );
+        assert_eq!(
+            error("fg=#12345"),
+            "expected a color after `=` in `fg=#12345`"
+        );
+        assert_eq!(error("256"), "unknown color or modifier `256`");
+        assert_eq!(error("@256"), "unknown color or modifier `@256`");
+        assert_eq!(
+            error("on @"),
+            "expected a background color after `on`, found `@`"
+        );
+        assert_eq!(
+            error("red blue"),
+            "the foreground color is set twice by `blue`"
+        );
+        assert_eq!(
+            error("on red bg=blue"),
+            "the background color is set twice by `bg=blue`"
+        );
+        assert_eq!(parse_style("red blue").unwrap_err().word(), "blue");
+        assert_eq!(
+            error("fg: bold"),
+            "expected a foreground color after `fg:`, found `bold`"
+        );
+        assert_eq!(
+            error("bg: red, bg: blue"),
+            "the background color is set twice by `blue`"
+        );
+        assert_eq!(
+            error("bold, bg:"),
+            "expected a background color after `bg:`"
+        );
+        assert_eq!(error("fg:blod"), "expected a color after `:` in `fg:blod`");
+        assert_eq!(error("rgb(1, 2)"), "unknown color or modifier `rgb(1, 2)`");
+        assert_eq!(
+            error("rgb(1, 2, 256)"),
+            "unknown color or modifier `rgb(1, 2, 256)`"
+        );
+    }
+
+    #[test]
+    fn parse_color_words() {
+        assert_eq!(parse_color("DarkGrey"), Ok(Color::DarkGray));
+        assert_eq!(parse_color("\t#123\n"), Ok(Color::Rgb(0x11, 0x22, 0x33)));
+        assert_eq!(parse_color("0"), Ok(Color::Indexed(0)));
+        let error = |spec| parse_color(spec).unwrap_err().to_string();
+        assert_eq!(error(""), "unknown color ``");
+        assert_eq!(error("red blue"), "unknown color `red blue`");
+        assert_eq!(error("on red"), "unknown color `on red`");
+        assert_eq!(error("256"), "unknown color `256`");
+    }
+
+    #[test]
+    fn style_from_str_const() {
+        const STYLE: Style = style_from_str!("bold red on #000");
+        assert_eq!(STYLE, Style::new().bold().red().bg(Color::Rgb(0, 0, 0)));
+    }
+}
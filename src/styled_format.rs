@@ -0,0 +1,87 @@
+/// A macro for creating a [`Line`] from a format string whose placeholders have styles, which are
+/// parsed at compile time.
+///
+/// The arguments are like the arguments of [`format!`], but each placeholder may set the style of
+/// the text that it formats before its format spec, e.g. `{user:bold.cyan}` or `{n:yellow:>5}`. A
+/// style is a list of words separated by `.`:
+///
+/// - a modifier: `bold`, `dim`, `italic`, `underlined`, `strike`, `blink`, `reversed` and `hidden`
+/// - a foreground color, e.g. `red`, `light_blue` or `#1e293b`
+/// - a background color, e.g. `on_black` or `on_#1e293b`
+///
+/// A placeholder without a style, e.g. `{}`, `{n:>5}` or `{n:x}`, and the text between the
+/// placeholders are unstyled. Unknown style names are compile errors, and the arguments are checked
+/// by [`format!`].
+///
+/// This macro requires the `proc-macros` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{style::Stylize, text::{Line, Span}};
+/// use ratatui_macros::styled_format;
+///
+/// let (user, branch) = ("ferris", "main");
+/// let line = styled_format!("{user:bold.cyan} pushed {:yellow} commits to {branch:italic}", 3);
+/// assert_eq!(
+///     line,
+///     Line::from(vec![
+///         Span::raw("ferris").bold().cyan(),
+///         Span::raw(" pushed "),
+///         Span::raw("3").yellow(),
+///         Span::raw(" commits to "),
+///         Span::raw("main").italic(),
+///     ])
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::styled_format;
+/// let line = styled_format!("{:blod}", "typo");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! styled_format {
+    ($($tokens:tt)*) => {
+        $crate::ratatui_macros_proc::styled_format!($crate, $($tokens)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Style, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn styled_format_placeholders() {
+        let user = "ferris";
+        let line = styled_format!("{user:bold.cyan} pushed {n:yellow:>3} commits", n = 5);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("ferris").bold().cyan(),
+                Span::raw(" pushed "),
+                Span::raw("  5").yellow(),
+                Span::raw(" commits"),
+            ])
+        );
+    }
+
+    #[test]
+    fn styled_format_specs_and_colors() {
+        let line = styled_format!("{:x} {{{:?}}} {:white.on_#1e293b}", 255, "a", 1);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("ff {\"a\"} "),
+                Span::styled(
+                    "1",
+                    Style::new().fg(Color::White).bg(Color::Rgb(30, 41, 59))
+                ),
+            ])
+        );
+    }
+}
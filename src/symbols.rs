@@ -0,0 +1,186 @@
+/// A macro for picking a set of symbols by the name of a kind and a set, e.g.
+/// `symbols!(border: rounded)`.
+///
+/// The macro expands to the corresponding constant of [`ratatui_core::symbols`]. The kinds and
+/// the names of their sets are:
+///
+/// - `border`: a border [`Set`] for [`Block::border_set`], one of `plain`, `rounded`, `double`,
+///   `thick`, `quadrant_outside`, `quadrant_inside`, `one_eighth_wide`, `one_eighth_tall`,
+///   `proportional_wide`, `proportional_tall`, `full` and `empty`
+/// - `line`: a line [`line::Set`], one of `normal`, `rounded`, `double` and `thick`
+/// - `marker`: a [`Marker`] for [`canvas!`] and [`dataset!`], one of `dot`, `block`, `bar`,
+///   `braille` and `half_block`
+/// - `bar`: a bar [`bar::Set`] for [`sparkline!`], one of `three_levels` and `nine_levels`
+/// - `block`: a block [`block::Set`], one of `three_levels` and `nine_levels`
+/// - `scrollbar`: a scrollbar [`scrollbar::Set`], one of `vertical`, `horizontal`,
+///   `double_vertical` and `double_horizontal`
+///
+/// An unknown kind or name is a compile error that lists the names that are accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::symbols::{self, Marker};
+/// use ratatui_macros::symbols;
+/// use ratatui_widgets::block::Block;
+///
+/// assert_eq!(symbols!(border: rounded), symbols::border::ROUNDED);
+/// assert_eq!(symbols!(line: thick), symbols::line::THICK);
+/// assert_eq!(symbols!(marker: braille), Marker::Braille);
+///
+/// let block = Block::bordered().border_set(symbols!(border: double));
+/// ```
+///
+/// ```compile_fail
+/// # use ratatui_macros::symbols;
+/// let set = symbols!(border: curvy);
+/// ```
+///
+/// [`ratatui_core::symbols`]: ratatui_core::symbols
+/// [`Set`]: ratatui_core::symbols::border::Set
+/// [`line::Set`]: ratatui_core::symbols::line::Set
+/// [`bar::Set`]: ratatui_core::symbols::bar::Set
+/// [`block::Set`]: ratatui_core::symbols::block::Set
+/// [`scrollbar::Set`]: ratatui_core::symbols::scrollbar::Set
+/// [`Marker`]: ratatui_core::symbols::Marker
+/// [`Block::border_set`]: ratatui_widgets::block::Block::border_set
+/// [`canvas!`]: crate::canvas!
+/// [`dataset!`]: crate::dataset!
+/// [`sparkline!`]: crate::sparkline!
+#[macro_export]
+macro_rules! symbols {
+    (@border plain) => { $crate::ratatui_core::symbols::border::PLAIN };
+    (@border rounded) => { $crate::ratatui_core::symbols::border::ROUNDED };
+    (@border double) => { $crate::ratatui_core::symbols::border::DOUBLE };
+    (@border thick) => { $crate::ratatui_core::symbols::border::THICK };
+    (@border quadrant_outside) => { $crate::ratatui_core::symbols::border::QUADRANT_OUTSIDE };
+    (@border quadrant_inside) => { $crate::ratatui_core::symbols::border::QUADRANT_INSIDE };
+    (@border one_eighth_wide) => { $crate::ratatui_core::symbols::border::ONE_EIGHTH_WIDE };
+    (@border one_eighth_tall) => { $crate::ratatui_core::symbols::border::ONE_EIGHTH_TALL };
+    (@border proportional_wide) => { $crate::ratatui_core::symbols::border::PROPORTIONAL_WIDE };
+    (@border proportional_tall) => { $crate::ratatui_core::symbols::border::PROPORTIONAL_TALL };
+    (@border full) => { $crate::ratatui_core::symbols::border::FULL };
+    (@border empty) => { $crate::ratatui_core::symbols::border::EMPTY };
+    (@border $name:ident) => {
+        compile_error!(concat!(
+            "unknown border set `",
+            stringify!($name),
+            "`, expected one of `plain`, `rounded`, `double`, `thick`, `quadrant_outside`, ",
+            "`quadrant_inside`, `one_eighth_wide`, `one_eighth_tall`, `proportional_wide`, ",
+            "`proportional_tall`, `full` or `empty`"
+        ))
+    };
+    (@line normal) => { $crate::ratatui_core::symbols::line::NORMAL };
+    (@line rounded) => { $crate::ratatui_core::symbols::line::ROUNDED };
+    (@line double) => { $crate::ratatui_core::symbols::line::DOUBLE };
+    (@line thick) => { $crate::ratatui_core::symbols::line::THICK };
+    (@line $name:ident) => {
+        compile_error!(concat!(
+            "unknown line set `",
+            stringify!($name),
+            "`, expected one of `normal`, `rounded`, `double` or `thick`"
+        ))
+    };
+    (@marker dot) => { $crate::ratatui_core::symbols::Marker::Dot };
+    (@marker block) => { $crate::ratatui_core::symbols::Marker::Block };
+    (@marker bar) => { $crate::ratatui_core::symbols::Marker::Bar };
+    (@marker braille) => { $crate::ratatui_core::symbols::Marker::Braille };
+    (@marker half_block) => { $crate::ratatui_core::symbols::Marker::HalfBlock };
+    (@marker $name:ident) => {
+        compile_error!(concat!(
+            "unknown marker `",
+            stringify!($name),
+            "`, expected one of `dot`, `block`, `bar`, `braille` or `half_block`"
+        ))
+    };
+    (@bar three_levels) => { $crate::ratatui_core::symbols::bar::THREE_LEVELS };
+    (@bar nine_levels) => { $crate::ratatui_core::symbols::bar::NINE_LEVELS };
+    (@bar $name:ident) => {
+        compile_error!(concat!(
+            "unknown bar set `",
+            stringify!($name),
+            "`, expected `three_levels` or `nine_levels`"
+        ))
+    };
+    (@block three_levels) => { $crate::ratatui_core::symbols::block::THREE_LEVELS };
+    (@block nine_levels) => { $crate::ratatui_core::symbols::block::NINE_LEVELS };
+    (@block $name:ident) => {
+        compile_error!(concat!(
+            "unknown block set `",
+            stringify!($name),
+            "`, expected `three_levels` or `nine_levels`"
+        ))
+    };
+    (@scrollbar vertical) => { $crate::ratatui_core::symbols::scrollbar::VERTICAL };
+    (@scrollbar horizontal) => { $crate::ratatui_core::symbols::scrollbar::HORIZONTAL };
+    (@scrollbar double_vertical) => { $crate::ratatui_core::symbols::scrollbar::DOUBLE_VERTICAL };
+    (@scrollbar double_horizontal) => {
+        $crate::ratatui_core::symbols::scrollbar::DOUBLE_HORIZONTAL
+    };
+    (@scrollbar $name:ident) => {
+        compile_error!(concat!(
+            "unknown scrollbar set `",
+            stringify!($name),
+            "`, expected one of `vertical`, `horizontal`, `double_vertical` or `double_horizontal`"
+        ))
+    };
+    (border: $name:ident $(,)?) => { $crate::symbols!(@border $name) };
+    (line: $name:ident $(,)?) => { $crate::symbols!(@line $name) };
+    (marker: $name:ident $(,)?) => { $crate::symbols!(@marker $name) };
+    (bar: $name:ident $(,)?) => { $crate::symbols!(@bar $name) };
+    (block: $name:ident $(,)?) => { $crate::symbols!(@block $name) };
+    (scrollbar: $name:ident $(,)?) => { $crate::symbols!(@scrollbar $name) };
+    ($kind:ident : $name:ident $(,)?) => {
+        compile_error!(concat!(
+            "unknown kind of symbols `",
+            stringify!($kind),
+            "`, expected one of `border`, `line`, `marker`, `bar`, `block` or `scrollbar`"
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::symbols::{self, Marker};
+    use ratatui_widgets::{block::Block, chart::Dataset, sparkline::Sparkline};
+
+    use crate::{dataset, sparkline};
+
+    #[test]
+    fn symbols_sets() {
+        assert_eq!(symbols!(border: plain), symbols::border::PLAIN);
+        assert_eq!(
+            symbols!(border: proportional_tall,),
+            symbols::border::PROPORTIONAL_TALL
+        );
+        assert_eq!(symbols!(line: rounded), symbols::line::ROUNDED);
+        assert_eq!(symbols!(marker: half_block), Marker::HalfBlock);
+        assert_eq!(symbols!(bar: three_levels), symbols::bar::THREE_LEVELS);
+        assert_eq!(symbols!(block: nine_levels), symbols::block::NINE_LEVELS);
+        assert_eq!(
+            symbols!(scrollbar: double_horizontal),
+            symbols::scrollbar::DOUBLE_HORIZONTAL
+        );
+    }
+
+    #[test]
+    fn symbols_in_macros() {
+        assert_eq!(
+            Block::bordered().border_set(symbols!(border: thick)),
+            Block::bordered().border_set(symbols::border::THICK)
+        );
+        assert_eq!(
+            dataset!("cpu"; &[(0.0, 1.0)], marker: symbols!(marker: braille)),
+            Dataset::default()
+                .name("cpu")
+                .data(&[(0.0, 1.0)])
+                .marker(Marker::Braille)
+        );
+        assert_eq!(
+            sparkline![1, 2; bar_set: symbols!(bar: three_levels)],
+            Sparkline::default()
+                .data([1, 2])
+                .bar_set(symbols::bar::THREE_LEVELS)
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use std::{
+    cell::{Cell as StdCell, RefCell},
+    fmt::Display,
+};
+
+use ratatui_core::{
+    layout::Constraint,
+    style::Style,
+    text::{Line, Span, Text},
+};
+use ratatui_widgets::table::Row;
+
+/// A macro for creating a [`Table`] from a list of `key: value` settings.
+///
+/// Each key is the name of a builder method on [`Table`] and the value is passed to it, so any
+/// setting that [`Table`] supports can be used. The most common ones are `header`, `footer`,
+/// `widths`, `rows`, `block`, `style`, `column_spacing`, `row_highlight_style` and
+/// `highlight_symbol`. Settings are applied in the order they are written.
+///
+/// # Examples
+///
+/// * Create a [`Table`] with a header, column widths and rows:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{constraints, row, table};
+///
+/// let table = table! {
+///     header: row!["Key", "Value"].bold(),
+///     widths: constraints![==30%, *=1],
+///     rows: [
+///         row!["name", "ratatui-macros"],
+///         row!["license", "MIT"],
+///     ],
+/// };
+/// ```
+///
+/// * Create an empty [`Table`]:
+///
+/// ```rust
+/// # use ratatui_macros::table;
+/// let table = table! {};
+/// ```
+///
+/// * Rows can be any iterator of [`Row`]s:
+///
+/// ```rust
+/// use ratatui_macros::{constraints, row, table};
+///
+/// let items = [("apple", 3), ("pear", 5)];
+/// let table = table! {
+///     widths: constraints![*=1; 2],
+///     rows: items.iter().map(|(name, count)| row![*name, count.to_string()]),
+///     column_spacing: 2,
+/// };
+/// ```
+///
+/// * Set `widths: auto` to give each column the width of its widest cell. This measures the cells
+///   of the `header`, the `footer` and the `rows` while they are created, so they must be written
+///   as [`row!`]s or [`sorted!`]s, e.g. `rows: [row![...], ...]`, optionally followed by builder
+///   methods. A cell is as wide as the widest line of its [`Display`] output, which covers
+///   strings, spans, lines, texts and numbers; other cells, e.g. a [`Cell`], are not measured. Use
+///   [`column_widths`] for rows that are created at runtime, e.g. from an iterator.
+///
+/// ```rust
+/// # use ratatui_core::{layout::Constraint, style::Stylize};
+/// use ratatui_macros::{row, table};
+///
+/// let table = table! {
+///     header: row!["Name", "Size"].bold(),
+///     widths: auto,
+///     rows: [
+///         row!["Cargo.toml", 1024],
+///         row!["README.md", "12 KiB"],
+///     ],
+/// };
+/// # let expected = ratatui_widgets::table::Table::default()
+/// #     .header(row!["Name", "Size"].bold())
+/// #     .rows([row!["Cargo.toml", 1024], row!["README.md", "12 KiB"]])
+/// #     .widths([Constraint::Length(10), Constraint::Length(6)]);
+/// # assert_eq!(table, expected);
+/// ```
+///
+/// [`Table`]: ratatui_widgets::table::Table
+/// [`Row`]: ratatui_widgets::table::Row
+/// [`Cell`]: ratatui_widgets::table::Cell
+/// [`Display`]: std::fmt::Display
+/// [`row!`]: crate::row!
+/// [`sorted!`]: crate::sorted!
+/// [`column_widths`]: crate::column_widths
+#[macro_export]
+macro_rules! table {
+    () => {
+        $crate::ratatui_widgets::table::Table::default()
+    };
+    // Looks for `widths: auto` in the settings
+    (@scan [$($settings:tt)*] widths: auto $(, $($rest:tt)*)?) => {
+        $crate::table!(@auto widths [] $($settings)* widths: auto $(, $($rest)*)?)
+    };
+    (@scan [$($settings:tt)*] $head:tt $($rest:tt)*) => {
+        $crate::table!(@scan [$($settings)* $head] $($rest)*)
+    };
+    (@scan [$($key:ident : $value:expr),+ $(,)?]) => {
+        $crate::ratatui_widgets::table::Table::default()
+            $(.$key($value))+
+    };
+    // Measures the cells of the rows, and sets the widths after all other settings
+    (@auto $widths:ident [$($settings:tt)*] $(,)?) => {{
+        let $widths = $crate::__ColumnWidths::default();
+        $crate::ratatui_widgets::table::Table::default()
+            $($settings)*
+            .widths($widths.constraints())
+    }};
+    (@auto $widths:ident [$($settings:tt)*] widths: auto $(, $($rest:tt)*)?) => {
+        $crate::table!(@auto $widths [$($settings)*] $($($rest)*)?)
+    };
+    (@auto $widths:ident [$($settings:tt)*]
+        $key:ident : row![$($cells:tt)*] $(.$method:ident($($args:tt)*))* $(, $($rest:tt)*)?
+    ) => {
+        $crate::table!(@auto $widths [$($settings)*
+            .$key({
+                $widths.start_row();
+                $crate::row!(@measure $widths ; $($cells)*) $(.$method($($args)*))*
+            })
+        ] $($($rest)*)?)
+    };
+    (@auto $widths:ident [$($settings:tt)*]
+        $key:ident : sorted!($($sorted:tt)*) $(.$method:ident($($args:tt)*))* $(, $($rest:tt)*)?
+    ) => {
+        $crate::table!(@auto $widths [$($settings)*
+            .$key({
+                $widths.start_row();
+                $crate::sorted!(@measure $widths ; $($sorted)*) $(.$method($($args)*))*
+            })
+        ] $($($rest)*)?)
+    };
+    (@auto $widths:ident [$($settings:tt)*]
+        rows: [$(row![$($cells:tt)*] $(.$method:ident($($args:tt)*))*),* $(,)?]
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::table!(@auto $widths [$($settings)*
+            .rows([$({
+                $widths.start_row();
+                $crate::row!(@measure $widths ; $($cells)*) $(.$method($($args)*))*
+            }),*])
+        ] $($($rest)*)?)
+    };
+    (@auto $widths:ident [$($settings:tt)*] rows: $rows:expr $(, $($rest:tt)*)?) => {
+        compile_error!(concat!(
+            "`widths: auto` can only measure rows that are written as `[row![...], ...]`, ",
+            "use `widths: column_widths(...)` for `rows: ",
+            stringify!($rows),
+            "`"
+        ))
+    };
+    (@auto $widths:ident [$($settings:tt)*] $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::table!(@auto $widths [$($settings)* .$key($value)] $($($rest)*)?)
+    };
+    ($($settings:tt)+) => {
+        $crate::table!(@scan [] $($settings)+)
+    };
+}
+
+/// A macro for creating the header [`Row`] of a sortable table, with a sort indicator on the
+/// column that the rows are sorted by.
+///
+/// The settings `col: column` and `dir: direction` are followed by a `;` and the labels of the
+/// columns, which are anything that can be converted into a [`Line`]. The column is the index of
+/// the sorted column, counting from zero, or an `Option<usize>` where `None` shows no indicator.
+/// The direction is `Asc` or `Desc`, or an expression of type [`SortDirection`], e.g. the sort
+/// order of the application. The label of the sorted column is followed by ` ▲` when ascending
+/// or ` ▼` when descending, styled with `style: style` after the direction, which is yellow by
+/// default.
+///
+/// With `widths: auto` in [`table!`], every label is measured as if it had an indicator, so the
+/// widths of the columns do not change when the table is sorted by another column.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::{row, sorted, table, SortDirection};
+///
+/// let header = sorted!(col: 2, dir: Desc; "Name", "Size", "Modified");
+///
+/// let (column, direction) = (Some(0), SortDirection::Asc);
+/// let table = table! {
+///     header: sorted!(col: column, dir: direction, style: Style::new().cyan(); "Name", "Size")
+///         .bold(),
+///     widths: auto,
+///     rows: [row!["Cargo.toml", 1024], row!["README.md", 2048]],
+/// };
+/// ```
+///
+/// [`Row`]: ratatui_widgets::table::Row
+/// [`Line`]: ratatui_core::text::Line
+/// [`SortDirection`]: crate::SortDirection
+/// [`table!`]: crate::table!
+#[macro_export]
+macro_rules! sorted {
+    (@measure $widths:ident ;
+        col: $column:expr, dir: $direction:expr $(, style: $style:expr)? $(,)? ;
+        $($label:expr),* $(,)?
+    ) => {
+        $crate::sorted!(@header ::core::option::Option::Some(&$widths) ;
+            $column, $direction, [$($style)?] ; $($label),*
+        )
+    };
+    (@header $widths:expr ;
+        $column:expr, $direction:expr, [$($style:expr)?] ; $($label:expr),*
+    ) => {
+        $crate::__sorted_header(
+            ::std::vec![$($crate::ratatui_core::text::Line::from($label)),*],
+            $column,
+            {
+                #[allow(unused_imports)]
+                use $crate::SortDirection::{Asc, Desc};
+                $direction
+            },
+            $crate::sorted!(@style $($style)?),
+            $widths,
+        )
+    };
+    (@style) => {
+        $crate::ratatui_core::style::Style::new().fg($crate::ratatui_core::style::Color::Yellow)
+    };
+    (@style $style:expr) => {
+        $crate::ratatui_core::style::Style::from($style)
+    };
+    (
+        col: $column:expr, dir: $direction:expr $(, style: $style:expr)? $(,)? ;
+        $($label:expr),* $(,)?
+    ) => {
+        $crate::sorted!(@header ::core::option::Option::None ;
+            $column, $direction, [$($style)?] ; $($label),*
+        )
+    };
+}
+
+/// The direction in which the rows of a table are sorted, which selects the indicator that
+/// [`sorted!`] shows on the sorted column.
+///
+/// [`sorted!`]: crate::sorted!
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// From the smallest to the largest value, shown as `▲`
+    #[default]
+    Asc,
+    /// From the largest to the smallest value, shown as `▼`
+    Desc,
+}
+
+impl SortDirection {
+    /// Returns the other direction, e.g. for clicking the header of the sorted column again.
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Asc => Self::Desc,
+            Self::Desc => Self::Asc,
+        }
+    }
+
+    const fn indicator(self) -> &'static str {
+        match self {
+            Self::Asc => " ▲",
+            Self::Desc => " ▼",
+        }
+    }
+}
+
+/// Appends the sort indicator to the label of the sorted column, and measures every label with
+/// room for an indicator when there are `widths`. See [`sorted!`].
+///
+/// [`sorted!`]: crate::sorted!
+#[doc(hidden)]
+pub fn __sorted_header<'a>(
+    mut labels: Vec<Line<'a>>,
+    column: impl Into<Option<usize>>,
+    direction: SortDirection,
+    style: Style,
+    widths: Option<&__ColumnWidths>,
+) -> Row<'a> {
+    let indicator = Span::styled(direction.indicator(), style);
+    if let Some(widths) = widths {
+        for label in &labels {
+            widths.measure(label.width() + indicator.width());
+        }
+    }
+    if let Some(label) = column.into().and_then(|column| labels.get_mut(column)) {
+        label.spans.push(indicator);
+    }
+    Row::new(labels)
+}
+
+/// The widths of the columns of a [`table!`] with `widths: auto`, which grow while the cells of
+/// its rows are measured.
+///
+/// [`table!`]: crate::table!
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct __ColumnWidths {
+    widths: RefCell<Vec<u16>>,
+    column: StdCell<usize>,
+}
+
+impl __ColumnWidths {
+    /// Starts measuring the cells of the next row from the first column.
+    pub fn start_row(&self) {
+        self.column.set(0);
+    }
+
+    /// Measures the cell in the next column of the current row.
+    pub fn measure(&self, width: usize) {
+        let column = self.column.get();
+        let width = u16::try_from(width).unwrap_or(u16::MAX);
+        let mut widths = self.widths.borrow_mut();
+        if column == widths.len() {
+            widths.push(width);
+        } else {
+            widths[column] = widths[column].max(width);
+        }
+        self.column.set(column + 1);
+    }
+
+    /// Returns a [`Constraint::Length`] with the width of each column.
+    pub fn constraints(&self) -> Vec<Constraint> {
+        let widths = self.widths.borrow();
+        widths.iter().copied().map(Constraint::Length).collect()
+    }
+}
+
+/// Returns a [`Constraint::Length`] for each column with the width of its widest cell, for the
+/// widths of a [`Table`] whose rows are created at runtime.
+///
+/// Each item of `rows` is a row, which is an iterator of cells that implement [`Display`], e.g.
+/// the same values that are passed to [`row!`]. A cell is as wide as the widest line of its
+/// [`Display`] output, and each column is as wide as its widest cell in any row, so rows may have
+/// different numbers of cells. The header can be included by chaining it before the rows.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::layout::Constraint;
+/// use ratatui_macros::{column_widths, row, table};
+///
+/// let files = [("Cargo.toml", 1024), ("src/lib.rs", 123456)];
+/// let widths = column_widths(
+///     [["Name".to_string(), "Size".to_string()]]
+///         .into_iter()
+///         .chain(files.iter().map(|(name, size)| [name.to_string(), size.to_string()])),
+/// );
+/// assert_eq!(widths, [Constraint::Length(10), Constraint::Length(6)]);
+///
+/// let table = table! {
+///     header: row!["Name", "Size"],
+///     rows: files.iter().map(|(name, size)| row![*name, size]),
+///     widths: widths,
+/// };
+/// ```
+///
+/// [`Constraint::Length`]: ratatui_core::layout::Constraint::Length
+/// [`Table`]: ratatui_widgets::table::Table
+/// [`Display`]: std::fmt::Display
+/// [`row!`]: crate::row!
+pub fn column_widths<R>(rows: R) -> Vec<Constraint>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: Display,
+{
+    let widths = __ColumnWidths::default();
+    for row in rows {
+        widths.start_row();
+        for cell in row {
+            widths.measure(Text::raw(cell.to_string()).width());
+        }
+    }
+    widths.constraints()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        layout::Constraint,
+        style::{Color, Style, Stylize},
+        text::{Line, Span},
+    };
+    use ratatui_widgets::table::{Row, Table};
+
+    use super::SortDirection;
+    use crate::{constraints, row};
+
+    #[test]
+    fn table_empty() {
+        let table = table! {};
+        assert_eq!(table, Table::default());
+    }
+
+    #[test]
+    fn table_header_widths_rows() {
+        let table = table! {
+            header: row!["Key", "Value"],
+            widths: constraints![==30%, *=1],
+            rows: [row!["a", "1"], row!["b", "2"]],
+        };
+        assert_eq!(
+            table,
+            Table::new(
+                [Row::new(["a", "1"]), Row::new(["b", "2"])],
+                [Constraint::Percentage(30), Constraint::Fill(1)],
+            )
+            .header(Row::new(["Key", "Value"]))
+        );
+    }
+
+    #[test]
+    fn table_other_settings() {
+        let table = table! {
+            rows: [row!["a"]],
+            widths: [Constraint::Length(1)],
+            style: Style::new().red(),
+            column_spacing: 3,
+            highlight_symbol: ">> ",
+        };
+        assert_eq!(
+            table,
+            Table::new([Row::new(["a"])], [Constraint::Length(1)])
+                .style(Style::new().red())
+                .column_spacing(3)
+                .highlight_symbol(">> ")
+        );
+    }
+
+    #[test]
+    fn table_auto_widths() {
+        let table = table! {
+            header: row!["Name", "Size"].bold(),
+            widths: auto,
+            rows: [
+                row!["a", 10].height(1),
+                row!["ccc", crate::text!["1", "22222"], "extra"],
+                row!["dd" => Style::new().red(), ..[1, 2]],
+            ],
+            footer: row!["x"; 4],
+            column_spacing: 1,
+        };
+        let expected = Table::default()
+            .header(row!["Name", "Size"].bold())
+            .rows([
+                row!["a", 10].height(1),
+                row!["ccc", crate::text!["1", "22222"], "extra"],
+                row!["dd" => Style::new().red(), ..[1, 2]],
+            ])
+            .footer(row!["x"; 4])
+            .column_spacing(1)
+            .widths([
+                Constraint::Length(4),
+                Constraint::Length(5),
+                Constraint::Length(5),
+                Constraint::Length(1),
+            ]);
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn column_widths_of_rows() {
+        let rows = [vec!["a", "bbb"], vec!["界界", "c", "dd"], vec![]];
+        assert_eq!(
+            super::column_widths(rows),
+            [
+                Constraint::Length(4),
+                Constraint::Length(3),
+                Constraint::Length(2)
+            ]
+        );
+        assert_eq!(super::column_widths(Vec::<[u8; 0]>::new()), []);
+    }
+
+    #[test]
+    fn sorted_header() {
+        let yellow = Style::new().fg(Color::Yellow);
+        assert_eq!(
+            sorted!(col: 1, dir: Desc; "Name", "Size", "Modified"),
+            Row::new([
+                Line::from("Name"),
+                Line::from(vec![Span::raw("Size"), Span::styled(" ▼", yellow)]),
+                Line::from("Modified"),
+            ])
+        );
+        let direction = SortDirection::Desc.reversed();
+        assert_eq!(
+            sorted!(col: Some(0), dir: direction, style: Color::Cyan,; "a".bold(), "b",),
+            Row::new([
+                Line::from(vec![Span::raw("a").bold(), Span::raw(" ▲").cyan()]),
+                Line::from("b"),
+            ])
+        );
+        assert_eq!(sorted!(col: None, dir: Asc; "a", "b"), Row::new(["a", "b"]));
+        assert_eq!(sorted!(col: 5, dir: Asc; "a"), Row::new(["a"]));
+    }
+
+    #[test]
+    fn table_auto_widths_sorted() {
+        let table = table! {
+            header: sorted!(col: 0, dir: Asc; "Name", "Size").bold(),
+            widths: auto,
+            rows: [row!["Cargo.toml", 1024], row!["a", 123456]],
+        };
+        let expected = Table::default()
+            .header(sorted!(col: 0, dir: Asc; "Name", "Size").bold())
+            .rows([row!["Cargo.toml", 1024], row!["a", 123456]])
+            .widths([Constraint::Length(10), Constraint::Length(6)]);
+        assert_eq!(table, expected);
+
+        let table = table! {
+            widths: auto,
+            header: sorted!(col: 1, dir: Desc; "Name", "Size"),
+            rows: [row!["ab", 1]],
+        };
+        assert_eq!(
+            table,
+            Table::default()
+                .header(sorted!(col: 1, dir: Desc; "Name", "Size"))
+                .rows([row!["ab", 1]])
+                .widths([Constraint::Length(6), Constraint::Length(6)])
+        );
+    }
+}
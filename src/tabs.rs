@@ -0,0 +1,86 @@
+/// A macro for creating a [`Tabs`] widget using vec! syntax.
+///
+/// Each element is a tab title and can be anything that can be converted into a [`Line`], such as
+/// string literals, [`Span`]s or the result of the [`line!`] macro.
+///
+/// The titles can optionally be preceded by a list of `key: value` directives separated from the
+/// titles by a `;`. Each key is the name of a builder method on [`Tabs`], e.g. `select: 1`,
+/// `divider: "|"`, `highlight_style: Style::new().bold()` or `block: block!("Title")`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{line, tabs};
+///
+/// let tabs = tabs!["Home", "Logs".yellow(), line!["Help ", "(F1)".dim()]];
+/// let tabs = tabs![select: 1, divider: "|"; "Home", "Logs", "Help"];
+/// ```
+///
+/// [`Tabs`]: ratatui_widgets::tabs::Tabs
+/// [`Line`]: ratatui_core::text::Line
+/// [`Span`]: ratatui_core::text::Span
+#[macro_export]
+macro_rules! tabs {
+    (@directives [$($settings:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::tabs!(@directives [$($settings)* .$key($value)] $($rest)*)
+    };
+    (@directives [$($settings:tt)*] $key:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::tabs!(@directives [$($settings)* .$key($value)] ; $($rest)*)
+    };
+    (@directives [$($settings:tt)*] ; $($titles:tt)*) => {
+        $crate::tabs![$($titles)*] $($settings)*
+    };
+    () => {
+        $crate::ratatui_widgets::tabs::Tabs::default()
+    };
+    ($key:ident : $($rest:tt)*) => {
+        $crate::tabs!(@directives [] $key : $($rest)*)
+    };
+    ($($title:expr),+ $(,)?) => {{
+        $crate::ratatui_widgets::tabs::Tabs::new(vec![
+        $(
+            $crate::ratatui_core::text::Line::from($title),
+        )+
+        ])
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Style, Stylize},
+        text::Line,
+    };
+    use ratatui_widgets::tabs::Tabs;
+
+    #[test]
+    fn tabs_empty() {
+        assert_eq!(tabs![], Tabs::default());
+    }
+
+    #[test]
+    fn tabs_titles() {
+        let tabs = tabs!["Home", "Logs".yellow(), crate::line!["Help ", "(F1)".dim()]];
+        assert_eq!(
+            tabs,
+            Tabs::new([
+                Line::from("Home"),
+                Line::from("Logs".yellow()),
+                Line::from(vec!["Help ".into(), "(F1)".dim()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn tabs_directives() {
+        let tabs = tabs![select: 1, divider: "|", highlight_style: Style::new().bold(); "a", "b"];
+        assert_eq!(
+            tabs,
+            Tabs::new(["a", "b"])
+                .select(1)
+                .divider("|")
+                .highlight_style(Style::new().bold())
+        );
+    }
+}
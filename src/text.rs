@@ -1,6 +1,11 @@
+use std::borrow::Cow;
+
+use ratatui_core::text::{Line, Span, Text};
+
 /// A macro for creating a [`Text`] using vec! syntax.
 ///
-/// `text!` is similar to the [`vec!`] macro, but it returns a [`Text`] instead of a `Vec`.
+/// `text!` is similar to the [`vec!`] macro, but it returns a [`Text`] instead of a `Vec`. Like in
+/// [`line!`], an element may also be any value that implements [`Display`], e.g. a number.
 ///
 /// # Examples
 ///
@@ -14,11 +19,17 @@
 /// let text = text!["hello".red(), "world".red().bold()];
 /// ```
 ///
-/// * Create a [`text`] from a given [`Line`] repeated some amount of times:
+/// * Create a [`Text`] from a given [`Line`] repeated some amount of times. Like [`line!`], the
+///   expression is evaluated and converted once, and the count may be a runtime expression, so a
+///   repeated [`line!`] fills a block of any size:
 ///
 /// ```rust
-/// # use ratatui_macros::text;
+/// # use ratatui_macros::{line, text};
 /// let text = text!["hello"; 2];
+///
+/// let (width, height) = (4, 2);
+/// let text = text![line!["─"; width]; height];
+/// assert_eq!(text.to_string(), "────\n────");
 /// ```
 ///
 /// * Use [`line!`] or [`span!`] macro inside [`text!`] macro.
@@ -30,29 +41,346 @@
 /// let text = text![line!["hello", "world"], span!(Modifier::BOLD; "goodbye {}", "world")];
 /// ```
 ///
+/// * Format a [`Line`] in place by wrapping a format string and its arguments in braces:
+///
+/// ```rust
+/// # use ratatui_macros::text;
+/// let count = 3;
+/// let text = text!["Summary", {"{} items", count}, {"{count} selected"}];
+/// ```
+///
+///   A block that only contains a string literal is also a format string, so a literal that
+///   contains braces must be written without the block, e.g. `text!["{}"]` instead of
+///   `text![{ "{}" }]`.
+///
+/// * Split strings that contain newlines into one [`Line`] per `\n`, which also works for formatted
+///   lines, e.g. to embed help text or ASCII art:
+///
+/// ```rust
+/// # use ratatui_macros::text;
+/// let version = "0.1.0";
+/// let text = text![{r#"usage: app [options]
+///   -h  show this help
+///   -V  show the version ({})"#, version}];
+/// assert_eq!(text.lines.len(), 3);
+/// ```
+///
+/// * Include a [`Line`] only when a condition holds with `if condition => line`:
+///
+/// ```rust
+/// # use ratatui_macros::text;
+/// let error = Some("file not found");
+/// let text = text!["Status", if error.is_some() => "Something went wrong"];
+/// ```
+///
+/// * Include an [`Option`] of a [`Line`] only when it is `Some` by prefixing it with `?`:
+///
+/// ```rust
+/// # use ratatui_macros::text;
+/// let error = Some("file not found");
+/// let text = text!["Status", ?error];
+/// ```
+///
+/// * Insert every [`Line`] of an iterator by prefixing it with `..`:
+///
+/// ```rust
+/// # use ratatui_macros::text;
+/// let items = ["apples", "pears"];
+/// let text = text!["Shopping list", ..items.iter().map(|item| format!("- {item}"))];
+///
+/// let log: Vec<String> = vec!["started".into(), "listening on :8080".into()];
+/// let text = text![..&log];
+/// let text = text!["Log", ..log.iter(), "(end)"];
+/// ```
+///
+/// * Mix strings, [`Span`]s, [`Line`]s and [`Text`]s. A [`Span`] becomes a line of its own, and
+///   the lines of a nested [`Text`] are added to the outer one:
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// # use ratatui_macros::{line, text};
+/// let header = text!["Title", "=====".dim()];
+/// let text = text![header, line!["key: ", "value".bold()], "footer".italic()];
+/// assert_eq!(text.lines.len(), 4);
+/// ```
+///
+/// * Apply directives to the whole [`Text`] by listing them before the lines, followed by a `;`.
+///   `left`, `center` and `right` set the alignment, and any `key: value` calls the builder method
+///   of the same name on [`Text`], e.g. `style: Style::new().dim()`:
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// # use ratatui_macros::text;
+/// let text = text![center; "hello", "world"];
+/// let text = text![style: Style::new().dim(), center; "hello", "world"];
+/// ```
+///
+/// * Patch a base style under all the lines and spans with `patch: style`, which calls
+///   [`Text::patch_style`], e.g. to dim the content of a panel that is not focused:
+///
+/// ```rust
+/// # use ratatui_core::style::{Modifier, Stylize};
+/// # use ratatui_macros::text;
+/// let lines = vec!["first".to_string(), "second".to_string()];
+/// let text = text![patch: Modifier::DIM; "Log".bold(), ..lines];
+/// ```
+///
+/// [`Display`]: std::fmt::Display
 /// [`Text`]: crate::text::Text
+/// [`Text::patch_style`]: ratatui_core::text::Text::patch_style
 /// [`Line`]: crate::text::Line
 /// [`Span`]: crate::text::Span
 #[macro_export]
 macro_rules! text {
+    (@directives [$($directives:tt)*] patch : $value:expr , $($rest:tt)*) => {
+        $crate::text!(@directives [$($directives)* .patch_style($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] patch : $value:expr ; $($rest:tt)*) => {
+        $crate::text!(@directives [$($directives)* .patch_style($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::text!(@directives [$($directives)* .$key($value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $key:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::text!(@directives [$($directives)* .$key($value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $alignment:ident , $($rest:tt)*) => {
+        $crate::text!(@directives [
+            $($directives)* .alignment($crate::text!(@alignment $alignment))
+        ] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $alignment:ident ; $($rest:tt)*) => {
+        $crate::text!(@directives [
+            $($directives)* .alignment($crate::text!(@alignment $alignment))
+        ] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $($lines:tt)*) => {
+        $crate::text![$($lines)*] $($directives)*
+    };
+    (@alignment left) => {
+        $crate::ratatui_core::layout::Alignment::Left
+    };
+    (@alignment center) => {
+        $crate::ratatui_core::layout::Alignment::Center
+    };
+    (@alignment right) => {
+        $crate::ratatui_core::layout::Alignment::Right
+    };
     () => {
-        ratatui_core::text::Text::default()
+        $crate::ratatui_core::text::Text::default()
     };
-    ($line:expr; $n:expr) => {
-        ratatui_core::text::Text::from(vec![$line.into(); $n])
-    };
-    ($($line:expr),+ $(,)?) => {{
-        ratatui_core::text::Text::from(vec![
-        $(
-            $line.into(),
-        )+
-        ])
+    ($key:ident : $($rest:tt)*) => {
+        $crate::text!(@directives [] $key : $($rest)*)
+    };
+    (left ; $($rest:tt)*) => {
+        $crate::text!(@directives [] left ; $($rest)*)
+    };
+    (left , $key:ident : $($rest:tt)*) => {
+        $crate::text!(@directives [] left , $key : $($rest)*)
+    };
+    (center ; $($rest:tt)*) => {
+        $crate::text!(@directives [] center ; $($rest)*)
+    };
+    (center , $key:ident : $($rest:tt)*) => {
+        $crate::text!(@directives [] center , $key : $($rest)*)
+    };
+    (right ; $($rest:tt)*) => {
+        $crate::text!(@directives [] right ; $($rest)*)
+    };
+    (right , $key:ident : $($rest:tt)*) => {
+        $crate::text!(@directives [] right , $key : $($rest)*)
+    };
+    // Adds each line in one step, for a list of plain lines that `text!` matches as a whole
+    (@elements $text:ident $($head:tt $(. $method:ident ($($args:tt)*))*),+) => {{
+        let mut $text = $crate::ratatui_core::text::Text::default();
+        $($crate::text!(@element $text $head $(. $method($($args)*))*);)+
+        $text
     }};
+    (@element $text:ident { $format:literal $(, $($args:tt)*)? }) => {
+        $crate::__TextElement::push_to(format!($format $(, $($args)*)?), &mut $text)
+    };
+    (@element $text:ident $line:expr) => {
+        $crate::__text_element!($line, &mut $text)
+    };
+    (@lines $text:ident [$($push:tt)*] $(,)?) => {{
+        let mut $text = $crate::ratatui_core::text::Text::default();
+        $($push)*
+        $text
+    }};
+    (@lines $text:ident [$($push:tt)*] if $($rest:tt)*) => {
+        $crate::text!(@if $text [$($push)*] [] $($rest)*)
+    };
+    // the condition is collected up to `=>`, as it cannot be parsed as an `expr` before a block
+    (@if $text:ident [$($push:tt)*] [$($condition:tt)+]
+        => { $format:literal $(, $($args:tt)*)? } $(, $($rest:tt)*)?
+    ) => {
+        $crate::text!(@lines $text [$($push)*
+            if $($condition)+ {
+                $crate::__TextElement::push_to(format!($format $(, $($args)*)?), &mut $text);
+            }
+        ] $($($rest)*)?)
+    };
+    (@if $text:ident [$($push:tt)*] [$($condition:tt)+] => $line:expr $(, $($rest:tt)*)?) => {
+        $crate::text!(@lines $text [$($push)*
+            if $($condition)+ {
+                $crate::__text_element!($line, &mut $text);
+            }
+        ] $($($rest)*)?)
+    };
+    // without `=>`, the element is an `if` expression, e.g. `if flag { "x" } else { "y" }`
+    (@if $text:ident [] [$($line:tt)+] ; $n:expr) => {
+        $crate::text![{ if $($line)+ }; $n]
+    };
+    (@if $text:ident [$($push:tt)*] [$($line:tt)+] $(, $($rest:tt)*)?) => {
+        $crate::text!(@lines $text [$($push)*
+            $crate::__text_element!(if $($line)+, &mut $text);
+        ] $($($rest)*)?)
+    };
+    (@if $text:ident [$($push:tt)*] [$($condition:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::text!(@if $text [$($push)*] [$($condition)* $next] $($rest)*)
+    };
+    (@lines $text:ident [$($push:tt)*] ? $line:expr $(, $($rest:tt)*)?) => {
+        $crate::text!(@lines $text [$($push)*
+            if let ::core::option::Option::Some(line) = $line {
+                $crate::__text_element!(line, &mut $text);
+            }
+        ] $($($rest)*)?)
+    };
+    (@lines $text:ident [$($push:tt)*] .. $lines:expr $(, $($rest:tt)*)?) => {
+        $crate::text!(@lines $text [$($push)*
+            for line in $lines {
+                $crate::__text_element!(line, &mut $text);
+            }
+        ] $($($rest)*)?)
+    };
+    (@lines $text:ident [$($push:tt)*]
+        { $format:literal $(, $($args:tt)*)? } $(, $($rest:tt)*)?
+    ) => {
+        $crate::text!(@lines $text [$($push)*
+            $crate::__TextElement::push_to(format!($format $(, $($args)*)?), &mut $text);
+        ] $($($rest)*)?)
+    };
+    (@lines $text:ident [$($push:tt)*] $line:expr $(, $($rest:tt)*)?) => {
+        $crate::text!(@lines $text [$($push)*
+            $crate::__text_element!($line, &mut $text);
+        ] $($($rest)*)?)
+    };
+    ({ $format:literal $(, $($args:tt)*)? } ; $n:expr) => {
+        $crate::ratatui_core::text::Text::from($crate::__repeat::<
+            $crate::ratatui_core::text::Line,
+        >(format!($format $(, $($args)*)?), $n))
+    };
+    // Like `line!`, a list of lines that are each a single token tree followed by method calls is
+    // matched as a whole, which keeps long texts within the recursion limit
+    ($($head:tt $(. $method:ident ($($args:tt)*))*),+ $(,)?) => {
+        $crate::text!(@elements text $($head $(. $method($($args)*))*),+)
+    };
+    ({ $format:literal $($args:tt)* } $($rest:tt)*) => {
+        $crate::text!(@lines text [] { $format $($args)* } $($rest)*)
+    };
+    (if $($rest:tt)*) => {
+        $crate::text!(@lines text [] if $($rest)*)
+    };
+    ($line:expr; $n:expr) => {
+        $crate::ratatui_core::text::Text::from(
+            $crate::__repeat::<$crate::ratatui_core::text::Line>($line, $n),
+        )
+    };
+    ($($lines:tt)+) => {
+        $crate::text!(@lines text [] $($lines)+)
+    };
+}
+
+/// An element of the [`text!`] macro that is added to a [`Text`] as one or more lines.
+///
+/// Strings are split into one line per `\n`, [`Span`]s become a single line, and the lines of a
+/// nested [`Text`] are added with the style and alignment of that text applied to them.
+#[doc(hidden)]
+pub trait __TextElement<'a> {
+    fn push_to(self, text: &mut Text<'a>);
+}
+
+impl<'a> __TextElement<'a> for &'a str {
+    fn push_to(self, text: &mut Text<'a>) {
+        text.extend(self.split('\n').map(strip_carriage_return));
+    }
+}
+
+impl<'a> __TextElement<'a> for &'a String {
+    fn push_to(self, text: &mut Text<'a>) {
+        self.as_str().push_to(text);
+    }
+}
+
+impl<'a> __TextElement<'a> for String {
+    fn push_to(self, text: &mut Text<'a>) {
+        if self.contains('\n') {
+            text.extend(
+                self.split('\n')
+                    .map(|line| strip_carriage_return(line).to_string()),
+            );
+        } else {
+            text.push_line(self);
+        }
+    }
+}
+
+impl<'a> __TextElement<'a> for Cow<'a, str> {
+    fn push_to(self, text: &mut Text<'a>) {
+        match self {
+            Cow::Borrowed(line) => line.push_to(text),
+            Cow::Owned(line) => line.push_to(text),
+        }
+    }
+}
+
+impl<'a> __TextElement<'a> for Span<'a> {
+    fn push_to(self, text: &mut Text<'a>) {
+        text.push_line(self);
+    }
+}
+
+impl<'a> __TextElement<'a> for Vec<Span<'a>> {
+    fn push_to(self, text: &mut Text<'a>) {
+        text.push_line(self);
+    }
+}
+
+impl<'a> __TextElement<'a> for Line<'a> {
+    fn push_to(self, text: &mut Text<'a>) {
+        text.push_line(self);
+    }
+}
+
+impl<'a> __TextElement<'a> for Text<'a> {
+    fn push_to(self, text: &mut Text<'a>) {
+        let Text {
+            alignment,
+            style,
+            lines,
+        } = self;
+        text.extend(lines.into_iter().map(|mut line| {
+            line.style = style.patch(line.style);
+            line.alignment = line.alignment.or(alignment);
+            line
+        }));
+    }
+}
+
+/// Removes the `\r` of a `\r\n` line ending.
+fn strip_carriage_return(line: &str) -> &str {
+    line.strip_suffix('\r').unwrap_or(line)
 }
 
 #[cfg(test)]
 mod tests {
-    use ratatui_core::text::Text;
+    use std::borrow::Cow;
+
+    use ratatui_core::{
+        layout::Alignment,
+        style::{Style, Stylize},
+        text::{Line, Span, Text},
+    };
 
     #[test]
     fn text() {
@@ -68,4 +396,286 @@ mod tests {
         let text = text!["hello"; 2];
         assert_eq!(text, Text::from(vec!["hello".into(), "hello".into()]));
     }
+
+    #[test]
+    fn text_repeated_line() {
+        let (width, height) = (3, 2);
+        let text = text![crate::line!["─".dim(); width]; height];
+        let line = Line::from(vec!["─".dim(); 3]);
+        assert_eq!(text, Text::from(vec![line.clone(), line.clone()]));
+
+        let text = text![center; crate::line!["·"; width]; height - 1];
+        assert_eq!(
+            text,
+            Text::from(vec![Line::from(vec!["·".into(); 3])]).centered()
+        );
+    }
+
+    #[test]
+    fn text_empty() {
+        let text = text![];
+        assert_eq!(text, Text::default());
+    }
+
+    #[test]
+    fn text_mixed_lines() {
+        let text = text![
+            "first line",
+            crate::line!["styled ", "second".bold()],
+            "third",
+        ];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("first line"),
+                Line::from(vec!["styled ".into(), "second".bold()]),
+                Line::from("third"),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_alignment() {
+        let text = text![center; "hello", "world"];
+        assert_eq!(
+            text,
+            Text::from(vec!["hello".into(), "world".into()]).centered()
+        );
+
+        assert_eq!(
+            text![right; "hello"; 2],
+            Text::from(vec!["hello".into(); 2]).right_aligned()
+        );
+    }
+
+    #[test]
+    fn text_alignment_variables() {
+        let left = "left";
+        let right = "right";
+        assert_eq!(
+            text![left, right],
+            Text::from(vec!["left".into(), "right".into()])
+        );
+        assert_eq!(text![left], Text::from("left"));
+    }
+
+    #[test]
+    fn text_directives() {
+        let text = text![style: Style::new().dim(), center; "a", "b"];
+        assert_eq!(
+            text,
+            Text::from(vec!["a".into(), "b".into()])
+                .style(Style::new().dim())
+                .alignment(Alignment::Center)
+        );
+
+        let text = text![left, style: Style::new().red(); "a"];
+        assert_eq!(
+            text,
+            Text::from("a").left_aligned().style(Style::new().red())
+        );
+
+        let lines = vec!["b".to_string()];
+        let text = text![center, patch: Style::new().dim(); "a".bold(), ..lines];
+        assert_eq!(
+            text,
+            Text::from(vec![Line::from("a".bold()), Line::from("b")])
+                .centered()
+                .patch_style(Style::new().dim())
+        );
+    }
+
+    #[test]
+    fn text_format_lines() {
+        let count = 3;
+        let text = text!["Summary", {"{} items", count}, {"{count} selected"}];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("Summary"),
+                Line::from("3 items"),
+                Line::from("3 selected"),
+            ])
+        );
+
+        let text = text![{"{}", count}; 2];
+        assert_eq!(text, Text::from(vec![Line::from("3"), Line::from("3")]));
+    }
+
+    #[test]
+    fn text_many_lines() {
+        let text = text![
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-",
+            "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-", "-"
+        ];
+        assert_eq!(text, Text::from(vec![Line::from("-"); 150]));
+    }
+
+    #[test]
+    fn text_multiline_strings() {
+        let text = text!["one\ntwo", String::from("three\r\nfour"), ""];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("one"),
+                Line::from("two"),
+                Line::from("three"),
+                Line::from("four"),
+                Line::from(""),
+            ])
+        );
+
+        let name = "ratatui";
+        let text = text![{r#"hello
+{name}, with {} lines"#, 2}, "\nend\n"];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("hello"),
+                Line::from("ratatui, with 2 lines"),
+                Line::from(""),
+                Line::from("end"),
+                Line::from(""),
+            ])
+        );
+
+        // spans and lines are never split
+        let text = text![Span::raw("a\nb"), Line::from("c\nd")];
+        assert_eq!(text.lines.len(), 2);
+    }
+
+    #[test]
+    fn text_conditional_lines() {
+        let (verbose, count) = (false, 3);
+        let text = text![
+            "Summary",
+            if verbose => "details",
+            if count > 0 => {"{count} items"},
+        ];
+        assert_eq!(
+            text,
+            Text::from(vec![Line::from("Summary"), Line::from("3 items")])
+        );
+
+        let text = text![center; if verbose => "details"];
+        assert_eq!(text, Text::default().alignment(Alignment::Center));
+    }
+
+    #[test]
+    fn text_if_expressions() {
+        let flag = true;
+        assert_eq!(text![if flag { "x" } else { "y" }], Text::from("x"));
+        assert_eq!(
+            text![if !flag { "x" } else { "y" }, if flag => "z"],
+            Text::from(vec![Line::from("y"), Line::from("z")])
+        );
+        assert_eq!(text![if flag { "-" } else { "=" }; 2], Text::from("-\n-"));
+    }
+
+    #[test]
+    fn text_optional_lines() {
+        let error = Some(Line::from("file not found").red());
+        let warning: Option<&str> = None;
+        let text = text!["Status", ?error, ?warning];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("Status"),
+                Line::from("file not found").red(),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_spread_lines() {
+        let items = ["apples", "pears"];
+        let text = text![
+            "Shopping list",
+            ..items.iter().map(|item| format!("- {item}")),
+            "",
+        ];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("Shopping list"),
+                Line::from("- apples"),
+                Line::from("- pears"),
+                Line::from(""),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_nested_text() {
+        let header = text!["Title", "=====".dim()];
+        let text = text![
+            header,
+            crate::line!["key: ", "value".bold()],
+            "footer".italic()
+        ];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("Title"),
+                Line::from("=====".dim()),
+                Line::from(vec![Span::raw("key: "), Span::raw("value").bold()]),
+                Line::from("footer".italic()),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_nested_text_style_and_alignment() {
+        let warning = Text::from(vec![Line::from("a"), Line::from("b").left_aligned()])
+            .yellow()
+            .centered();
+        let text = text!["header", warning];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("header"),
+                Line::from("a").yellow().centered(),
+                Line::from("b").yellow().left_aligned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn text_display_elements() {
+        let (count, ratio) = (3, 0.5);
+        let text = text!["Summary", count, ?Some(ratio)];
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("Summary"),
+                Line::from("3"),
+                Line::from("0.5")
+            ])
+        );
+    }
+
+    #[test]
+    fn text_from_iterator() {
+        let log = vec!["started".to_string(), "a\nb".to_string()];
+        let text = text![..&log];
+        assert_eq!(
+            text,
+            Text::from(vec![Line::from("started"), "a".into(), "b".into()])
+        );
+        assert!(text
+            .lines
+            .iter()
+            .all(|line| matches!(line.spans[0].content, Cow::Borrowed(_))));
+        let text = text!["Log", ..log.iter().take(1), "(end)"];
+        assert_eq!(text.to_string(), "Log\nstarted\n(end)");
+        let text = text![..log];
+        assert_eq!(text.lines.len(), 3);
+    }
 }
@@ -0,0 +1,167 @@
+/// A macro for defining a theme struct that maps semantic names to [`Style`]s.
+///
+/// The macro takes the name of the struct, optionally preceded by attributes and a visibility,
+/// followed by a block of `name: style` fields. Each field becomes a public [`Style`] field of the
+/// struct. The struct derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`, and has a
+/// `const fn new()` constructor and a [`Default`] implementation that both use the given styles.
+///
+/// The styles must be constant expressions, such as the ones created by [`style!`] or
+/// `Style::new().fg(Color::Red)`. Note that the methods of `Stylize` are not `const`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Modifier, Style, Stylize};
+/// use ratatui_macros::{style, theme};
+///
+/// theme! {
+///     /// The styles of the application
+///     pub Theme {
+///         error: style!(fg: Red, bold),
+///         hint: style!(fg: DarkGray, italic),
+///         /// The style of the selected item
+///         selected: Style::new().add_modifier(Modifier::REVERSED),
+///     }
+/// }
+///
+/// const THEME: Theme = Theme::new();
+/// assert_eq!(THEME.error, Style::new().red().bold());
+/// assert_eq!(Theme::default(), THEME);
+/// ```
+///
+/// [`Style`]: ratatui_core::style::Style
+/// [`style!`]: crate::style!
+#[macro_export]
+macro_rules! theme {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident {
+            $($(#[$field_meta:meta])* $field:ident : $style:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $crate::ratatui_core::style::Style,
+            )*
+        }
+
+        impl $name {
+            /// Creates the theme with its default styles.
+            pub const fn new() -> Self {
+                Self {
+                    $($field: $style,)*
+                }
+            }
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+/// A macro for loading a theme file at compile time into a struct defined with [`theme!`].
+///
+/// The first argument is the theme struct and the second argument is the path of the file,
+/// relative to the directory of the `Cargo.toml` of the crate. The file is a TOML file, or a JSON
+/// file if its name ends with `.json`, whose keys are names of the fields of the theme and whose
+/// values are style strings with the words of a [`markup!`] tag, e.g. `"bold red on black"`.
+///
+/// The expansion is a constant expression that sets the styles of the keys in the file and keeps
+/// the default styles of the other fields, so the theme cannot fail to load at runtime. A key that
+/// is not a field of the theme, a value that is not a valid style and a file that cannot be read
+/// are compile errors, and the crate is rebuilt when the file changes.
+///
+/// This macro requires the `include-theme` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "include-theme")]
+/// # {
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::{include_theme, style, theme};
+///
+/// theme! {
+///     pub Theme {
+///         error: style!(fg: Red),
+///         hint: style!(fg: DarkGray),
+///     }
+/// }
+///
+/// // tests/themes/default.toml:
+/// // error = "bold red"
+/// // hint = "italic dark-gray"
+/// static THEME: Theme = include_theme!(Theme, "tests/themes/default.toml");
+/// assert_eq!(THEME.error, Style::new().red().bold());
+/// # }
+/// ```
+///
+/// [`markup!`]: crate::markup!
+/// [`theme!`]: crate::theme!
+#[cfg(feature = "include-theme")]
+#[macro_export]
+macro_rules! include_theme {
+    ($theme:path, $file:literal $(,)?) => {
+        $crate::ratatui_macros_proc::include_theme!($crate, $theme, $file)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier, Style, Stylize};
+
+    theme! {
+        Theme {
+            error: crate::style!(fg: Red, bold),
+            hint: crate::style!(fg: DarkGray, italic),
+            selected: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    theme! {
+        Empty {}
+    }
+
+    #[test]
+    fn theme_fields() {
+        let theme = Theme::new();
+        assert_eq!(
+            theme.error,
+            Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(theme.hint, Style::new().dark_gray().italic());
+        assert_eq!(theme.selected, Style::new().reversed());
+    }
+
+    #[cfg(feature = "include-theme")]
+    #[test]
+    fn include_theme_files() {
+        static THEME: Theme = include_theme!(Theme, "tests/themes/default.toml");
+        assert_eq!(THEME.error, Style::new().red().bold());
+        assert_eq!(THEME.hint, Style::new().dark_gray().italic());
+        assert_eq!(THEME.selected, Style::new().reversed());
+
+        const JSON: Theme = include_theme!(self::Theme, "tests/themes/default.json");
+        assert_eq!(
+            JSON.selected,
+            Style::new()
+                .fg(Color::Rgb(0x1e, 0x29, 0x3b))
+                .on_white()
+                .reversed()
+        );
+        assert_eq!(JSON.error, Theme::new().error);
+    }
+
+    #[test]
+    fn theme_constructors() {
+        const THEME: Theme = Theme::new();
+        assert_eq!(Theme::default(), THEME);
+        assert_eq!(Empty::default(), Empty::new());
+    }
+}
@@ -0,0 +1,149 @@
+use ratatui_core::{style::Style, text::Span};
+
+/// A macro for creating a [`Span`] that shows the current frame of a spinner.
+///
+/// The first argument is a tick counter, which is any integer that is incremented on every frame
+/// or tick of the application, and the second argument is the frames of the spinner. The frame at
+/// `tick % frames.len()` is shown, so the spinner starts over after the last frame.
+///
+/// The frames are either one of the named frame sets below, or `frames: [...]` with a list of
+/// strings. They can be followed by `key: value` settings, where `style: style` sets the style of
+/// the span.
+///
+/// | Name     | Frames                        |
+/// |----------|-------------------------------|
+/// | `dots`   | `⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏`         |
+/// | `line`   | `- \ \| /`                    |
+/// | `circle` | `◐ ◓ ◑ ◒`                     |
+/// | `arc`    | `◜ ◠ ◝ ◞ ◡ ◟`                 |
+/// | `arrows` | `← ↖ ↑ ↗ → ↘ ↓ ↙`             |
+/// | `blocks` | `▁ ▂ ▃ ▄ ▅ ▆ ▇ █ ▇ ▆ ▅ ▄ ▃ ▂` |
+/// | `bounce` | `⠁ ⠂ ⠄ ⠂`                     |
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::{Style, Stylize};
+/// use ratatui_macros::throbber;
+///
+/// let span = throbber!(0, dots);
+/// assert_eq!(span.content, "⠋");
+///
+/// let tick = 5_u64;
+/// let span = throbber!(tick, frames: ["◐", "◓", "◑", "◒"], style: Style::new().cyan());
+/// assert_eq!(span, "◓".cyan());
+/// ```
+///
+/// [`Span`]: ratatui_core::text::Span
+#[macro_export]
+macro_rules! throbber {
+    (@frames dots) => {
+        ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]
+    };
+    (@frames line) => {
+        ["-", "\\", "|", "/"]
+    };
+    (@frames circle) => {
+        ["◐", "◓", "◑", "◒"]
+    };
+    (@frames arc) => {
+        ["◜", "◠", "◝", "◞", "◡", "◟"]
+    };
+    (@frames arrows) => {
+        ["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"]
+    };
+    (@frames blocks) => {
+        ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█", "▇", "▆", "▅", "▄", "▃", "▂"]
+    };
+    (@frames bounce) => {
+        ["⠁", "⠂", "⠄", "⠂"]
+    };
+    (@frames $name:ident) => {
+        compile_error!(concat!(
+            "unknown frame set `",
+            stringify!($name),
+            "`, expected `dots`, `line`, `circle`, `arc`, `arrows`, `blocks`, `bounce` or ",
+            "`frames: [...]`"
+        ))
+    };
+    (@settings $throbber:expr ; $(,)?) => {
+        $throbber.span()
+    };
+    (@settings $throbber:expr ; $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::throbber!(@settings $throbber.$key($value) ; $($($rest)*)?)
+    };
+    ($tick:expr, frames: $frames:expr $(, $($rest:tt)*)?) => {
+        $crate::throbber!(@settings
+            $crate::__Throbber::new(($tick) as usize, &$frames) ;
+            $($($rest)*)?
+        )
+    };
+    ($tick:expr, $name:ident $(, $($rest:tt)*)?) => {
+        $crate::throbber!(@settings
+            $crate::__Throbber::new(($tick) as usize, &$crate::throbber!(@frames $name)) ;
+            $($($rest)*)?
+        )
+    };
+}
+
+/// The frame and settings of a spinner. See [`throbber!`].
+///
+/// [`throbber!`]: crate::throbber!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Throbber<'a> {
+    frame: &'a str,
+    style: Style,
+}
+
+impl<'a> __Throbber<'a> {
+    /// Picks the frame for the tick, or an empty frame if there are no frames.
+    pub fn new(tick: usize, frames: &[&'a str]) -> Self {
+        let frame = match frames.len() {
+            0 => "",
+            len => frames[tick % len],
+        };
+        Self {
+            frame,
+            style: Style::new(),
+        }
+    }
+
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    pub fn span(self) -> Span<'a> {
+        Span::styled(self.frame, self.style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::Span,
+    };
+
+    #[test]
+    fn throbber_named() {
+        assert_eq!(throbber!(0, dots), Span::raw("⠋"));
+        assert_eq!(throbber!(11, dots), Span::raw("⠙"));
+        assert_eq!(throbber!(2_u64, line), Span::raw("|"));
+        assert_eq!(throbber!(3_i32, circle, style: Color::Cyan), "◒".cyan());
+    }
+
+    #[test]
+    fn throbber_frames() {
+        let tick = 7_u8;
+        assert_eq!(throbber!(tick, frames: ["a", "b", "c"]), Span::raw("b"));
+        let frames: &[&str] = &["x", "y"];
+        assert_eq!(
+            throbber!(tick, frames: frames, style: Color::Red,),
+            "y".red()
+        );
+        let empty: [&str; 0] = [];
+        assert_eq!(throbber!(1, frames: empty), Span::raw(""));
+    }
+}
@@ -0,0 +1,110 @@
+/// A macro for creating a block [`Title`] using formatting syntax, with alignment and position
+/// directives.
+///
+/// The content is either a format string and arguments, like [`span!`], or an expression that can
+/// be converted into a [`Line`]. It can be preceded by a comma separated list of directives that
+/// are separated from the content by a `;`:
+///
+/// - `left`, `center` or `right` sets the alignment of the title
+/// - `top` or `bottom` sets the position of the title
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::style::Stylize;
+/// use ratatui_macros::{block, line, title};
+///
+/// let count = 3;
+/// let title = title!("Files");
+/// let title = title!(center; "Files");
+/// let title = title!(right, bottom; " {} items ", count);
+/// let title = title!(left, bottom; line!["q".bold(), " quit"]);
+///
+/// let block = block!(title!(center; "Files")).title(title!(right, bottom; " {count} items "));
+/// ```
+///
+/// [`Title`]: ratatui_widgets::block::Title
+/// [`Line`]: ratatui_core::text::Line
+#[macro_export]
+macro_rules! title {
+    (@directive $title:ident left) => {
+        $title.alignment = Some($crate::ratatui_core::layout::Alignment::Left)
+    };
+    (@directive $title:ident center) => {
+        $title.alignment = Some($crate::ratatui_core::layout::Alignment::Center)
+    };
+    (@directive $title:ident right) => {
+        $title.alignment = Some($crate::ratatui_core::layout::Alignment::Right)
+    };
+    (@directive $title:ident top) => {
+        $title.position = Some($crate::ratatui_widgets::block::Position::Top)
+    };
+    (@directive $title:ident bottom) => {
+        $title.position = Some($crate::ratatui_widgets::block::Position::Bottom)
+    };
+    (@content $string:literal $(, $($arg:tt)*)?) => {
+        $crate::ratatui_core::text::Line::from(format!($string $(, $($arg)*)?))
+    };
+    (@content $content:expr) => {
+        $crate::ratatui_core::text::Line::from($content)
+    };
+    ($($directive:ident),+ ; $($content:tt)+) => {{
+        let mut title =
+            $crate::ratatui_widgets::block::Title::from($crate::title!(@content $($content)+));
+        $($crate::title!(@directive title $directive);)+
+        title
+    }};
+    ($($content:tt)+) => {
+        $crate::ratatui_widgets::block::Title::from($crate::title!(@content $($content)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        layout::Alignment,
+        style::Stylize,
+        text::{Line, Span},
+    };
+    use ratatui_widgets::block::{Position, Title};
+
+    #[test]
+    fn title_content() {
+        let count = 3;
+        assert_eq!(title!("Files"), Title::from("Files"));
+        assert_eq!(title!(" {} items ", count), Title::from(" 3 items "));
+        assert_eq!(title!(" {count} items "), Title::from(" 3 items "));
+
+        let line = Line::from(vec![Span::from("q").bold(), Span::from(" quit")]);
+        assert_eq!(title!(line.clone()), Title::from(line));
+    }
+
+    #[test]
+    fn title_directives() {
+        let count = 3;
+        assert_eq!(
+            title!(right, bottom; " {} items ", count),
+            Title {
+                content: Line::from(" 3 items "),
+                alignment: Some(Alignment::Right),
+                position: Some(Position::Bottom),
+            }
+        );
+        assert_eq!(
+            title!(center; "Files"),
+            Title {
+                content: Line::from("Files"),
+                alignment: Some(Alignment::Center),
+                position: None,
+            }
+        );
+        assert_eq!(
+            title!(top, left; crate::line!["q".bold(), " quit"]),
+            Title {
+                content: Line::from(vec![Span::from("q").bold(), Span::from(" quit")]),
+                alignment: Some(Alignment::Left),
+                position: Some(Position::Top),
+            }
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::Widget,
+};
+use ratatui_widgets::{
+    block::{Block, Padding},
+    borders::BorderType,
+    clear::Clear,
+    paragraph::Paragraph,
+};
+
+use crate::{__AnchorLength, __AnchorPosition, __anchor};
+
+/// A macro for creating a toast, a small notification [`Widget`] that is drawn over a corner or an
+/// edge of the area that it is rendered into.
+///
+/// The first argument is the severity, one of `info`, `success`, `warn` and `error`, which sets
+/// the color of the border and the title. It can be followed by `key: value` settings, and then a
+/// `;` and the message, which is either a format string and its arguments, like [`format!`], or
+/// anything that can be converted into a [`Text`]:
+///
+/// - `anchor: top_right` places the toast, using the anchors of [`anchor!`], where `top_right` is
+///   the default
+/// - `width: 40` sets the width of the toast in cells, which by default fits the message
+/// - `title: line` replaces the title, which by default is the severity, e.g. `Warning`
+///
+/// The height of the toast fits the lines of the message, and the toast is clamped to the area, so
+/// lines that are wider than the toast are cut off. The cells under the toast are cleared before
+/// it is drawn.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::{render_to_string, toast};
+///
+/// let percent = 93;
+/// let toast = toast!(warn, anchor: top_right, width: 26; "Disk almost full: {}%", percent);
+///
+/// let toast = toast!(error; "connection lost");
+/// assert_eq!(
+///     render_to_string!(24x4, toast),
+///     concat!(
+///         "     ╭ Error ──────────╮\n",
+///         "     │ connection lost │\n",
+///         "     ╰─────────────────╯\n",
+///         "                        ",
+///     )
+/// );
+/// ```
+///
+/// [`Widget`]: ratatui_core::widgets::Widget
+/// [`Text`]: ratatui_core::text::Text
+/// [`anchor!`]: crate::anchor!
+#[macro_export]
+macro_rules! toast {
+    (@level info) => {
+        $crate::__ToastLevel::Info
+    };
+    (@level success) => {
+        $crate::__ToastLevel::Success
+    };
+    (@level warn) => {
+        $crate::__ToastLevel::Warn
+    };
+    (@level error) => {
+        $crate::__ToastLevel::Error
+    };
+    (@level $level:ident) => {
+        compile_error!(concat!(
+            "unknown severity `",
+            stringify!($level),
+            "`, expected `info`, `success`, `warn` or `error`"
+        ))
+    };
+    (@settings $toast:expr ; anchor: $anchor:ident , $($rest:tt)*) => {
+        $crate::toast!(@settings $toast.anchor($crate::anchor!(@anchor $anchor)) ; $($rest)*)
+    };
+    (@settings $toast:expr ; anchor: $anchor:ident ; $($message:tt)+) => {
+        $crate::toast!(@message $toast.anchor($crate::anchor!(@anchor $anchor)) ; $($message)+)
+    };
+    (@settings $toast:expr ; $key:ident : $value:expr , $($rest:tt)*) => {
+        $crate::toast!(@settings $toast.$key($value) ; $($rest)*)
+    };
+    (@settings $toast:expr ; $key:ident : $value:expr ; $($message:tt)+) => {
+        $crate::toast!(@message $toast.$key($value) ; $($message)+)
+    };
+    (@message $toast:expr ; $format:literal $($args:tt)*) => {
+        $toast.message(::std::format!($format $($args)*))
+    };
+    (@message $toast:expr ; $message:expr) => {
+        $toast.message($message)
+    };
+    ($level:ident ; $($message:tt)+) => {
+        $crate::toast!(@message $crate::__Toast::new($crate::toast!(@level $level)) ; $($message)+)
+    };
+    ($level:ident , $($rest:tt)+) => {
+        $crate::toast!(@settings $crate::__Toast::new($crate::toast!(@level $level)) ; $($rest)+)
+    };
+}
+
+/// The severity of a [`toast!`].
+///
+/// [`toast!`]: crate::toast!
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum __ToastLevel {
+    Info,
+    Success,
+    Warn,
+    Error,
+}
+
+impl __ToastLevel {
+    /// Returns the default title of the severity and the color of its border and title.
+    const fn title(self) -> (&'static str, Color) {
+        match self {
+            Self::Info => ("Info", Color::Blue),
+            Self::Success => ("Success", Color::Green),
+            Self::Warn => ("Warning", Color::Yellow),
+            Self::Error => ("Error", Color::Red),
+        }
+    }
+}
+
+/// The message and settings of a toast. See [`toast!`].
+///
+/// [`toast!`]: crate::toast!
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct __Toast<'a> {
+    level: __ToastLevel,
+    message: Text<'a>,
+    anchor: (__AnchorPosition, __AnchorPosition),
+    width: Option<u16>,
+    title: Option<Line<'a>>,
+}
+
+impl<'a> __Toast<'a> {
+    pub fn new(level: __ToastLevel) -> Self {
+        Self {
+            level,
+            message: Text::default(),
+            anchor: (__AnchorPosition::End, __AnchorPosition::Start),
+            width: None,
+            title: None,
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<Text<'a>>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    pub const fn anchor(mut self, anchor: (__AnchorPosition, __AnchorPosition)) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Line<'a>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+impl Widget for __Toast<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (default_title, color) = self.level.title();
+        let mut title = self.title.unwrap_or_else(|| Line::from(default_title));
+        title.spans.insert(0, Span::raw(" "));
+        title.spans.push(Span::raw(" "));
+        let title = title.patch_style(Style::new().fg(color).add_modifier(Modifier::BOLD));
+        // the borders and the padding take two cells on each side
+        let width = self.width.unwrap_or_else(|| {
+            let content = self.message.width().max(title.width().saturating_sub(2));
+            u16::try_from(content).unwrap_or(u16::MAX).saturating_add(4)
+        });
+        let height = u16::try_from(self.message.height())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let area = __anchor(
+            area,
+            [__AnchorLength::Cells(width), __AnchorLength::Cells(height)],
+            self.anchor,
+        );
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(color))
+            .padding(Padding::horizontal(1))
+            .title(title);
+        Clear.render(area, buf);
+        Paragraph::new(self.message).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Color, Modifier},
+        text::Line,
+        widgets::Widget,
+    };
+
+    use crate::render_to_string;
+
+    #[test]
+    fn toast_default() {
+        let toast = toast!(info; "saved {} files", 3);
+        assert_eq!(
+            render_to_string!(20x3, toast),
+            concat!(
+                "   ╭ Info ─────────╮\n",
+                "   │ saved 3 files │\n",
+                "   ╰───────────────╯",
+            )
+        );
+    }
+
+    #[test]
+    fn toast_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        toast!(error; "boom").render(buffer.area, &mut buffer);
+        let border = (Color::Red, Modifier::empty());
+        assert_eq!(
+            (buffer[(0, 0)].fg, buffer[(0, 0)].modifier),
+            (Color::Reset, Modifier::empty())
+        );
+        assert_eq!((buffer[(1, 0)].fg, buffer[(1, 0)].modifier), border);
+        assert_eq!(
+            (buffer[(3, 0)].fg, buffer[(3, 0)].modifier),
+            (Color::Red, Modifier::BOLD)
+        );
+        assert_eq!(
+            (buffer[(3, 1)].fg, buffer[(3, 1)].modifier),
+            (Color::Reset, Modifier::empty())
+        );
+        assert_eq!((buffer[(9, 2)].fg, buffer[(9, 2)].modifier), border);
+    }
+
+    #[test]
+    fn toast_settings() {
+        let toast = toast!(
+            warn,
+            anchor: bottom_left,
+            width: 14,
+            title: Line::from("disk");
+            "{}% full\nclean up",
+            93,
+        );
+        assert_eq!(
+            render_to_string!(16x5, toast),
+            concat!(
+                "                \n",
+                "╭ disk ──────╮  \n",
+                "│ 93% full   │  \n",
+                "│ clean up   │  \n",
+                "╰────────────╯  ",
+            )
+        );
+        let toast = toast!(success, anchor: center; Line::from("done"));
+        assert_eq!(
+            render_to_string!(12x3, toast),
+            "╭ Success ╮ \n│ done    │ \n╰─────────╯ "
+        );
+    }
+
+    #[test]
+    fn toast_clamped() {
+        let toast = toast!(info, width: 40; "a message that is too wide");
+        assert_eq!(
+            render_to_string!(10x4, toast),
+            "╭ Info ──╮\n│ a mess │\n╰────────╯\n          "
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use ratatui_core::text::{Line, Span};
+use tracing::{
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+use crate::{__KeyValue, __LogLine};
+
+/// A macro for creating a [`Line`] from a [`tracing::Event`], with a styled level, a dimmed
+/// target, the message and the other fields of the event.
+///
+/// The level and target are styled like the lines of [`log_line!`], and each field other than the
+/// message is shown after it as `key=value`, with a dimmed key like [`kv!`]. The event can be
+/// preceded by `key: value` directives, separated from it by a `;`, where `timestamp: value` puts
+/// a dimmed timestamp before the level, like in [`log_line!`].
+///
+/// [`TracingLineLayer`] is a [`tracing_subscriber::Layer`] that collects the lines of all events,
+/// e.g. to show them in a log pane. This macro requires the `tracing` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::tracing_line;
+/// use tracing::{Event, Subscriber};
+/// use tracing_subscriber::{layer::Context, Layer};
+///
+/// struct Lines;
+///
+/// impl<S: Subscriber> Layer<S> for Lines {
+///     fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+///         let line = tracing_line!(timestamp: "12:00:01"; event);
+///         assert_eq!(line.to_string(), "12:00:01 WARN  app::net: retrying attempt=2");
+///     }
+/// }
+///
+/// # use tracing_subscriber::layer::SubscriberExt;
+/// let subscriber = tracing_subscriber::registry().with(Lines);
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::warn!(target: "app::net", attempt = 2, "retrying");
+/// });
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`tracing::Event`]: tracing::Event
+/// [`tracing_subscriber::Layer`]: tracing_subscriber::Layer
+/// [`TracingLineLayer`]: crate::TracingLineLayer
+/// [`kv!`]: crate::kv!
+/// [`log_line!`]: crate::log_line!
+#[macro_export]
+macro_rules! tracing_line {
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr , $($rest:tt)*) => {
+        $crate::tracing_line!(@directives [$($directives)* .$setting(&$value)] $($rest)*)
+    };
+    (@directives [$($directives:tt)*] $setting:ident : $value:expr ; $($rest:tt)*) => {
+        $crate::tracing_line!(@directives [$($directives)* .$setting(&$value)] ; $($rest)*)
+    };
+    (@directives [$($directives:tt)*] ; $event:expr $(,)?) => {
+        $crate::__tracing_line($crate::__LogLine::default() $($directives)*, $event)
+    };
+    ($setting:ident : $($rest:tt)*) => {
+        $crate::tracing_line!(@directives [] $setting : $($rest)*)
+    };
+    ($event:expr $(,)?) => {
+        $crate::__tracing_line($crate::__LogLine::default(), $event)
+    };
+}
+
+/// Converts a [`tracing::Event`] into a [`Line`] with a styled level, a dimmed target, the message
+/// and the other fields of the event.
+///
+/// The line is styled like the lines of [`tracing_line!`]. This function requires the `tracing`
+/// feature.
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`tracing::Event`]: tracing::Event
+/// [`tracing_line!`]: crate::tracing_line!
+pub fn tracing_event_to_line(event: &Event<'_>) -> Line<'static> {
+    __tracing_line(__LogLine::default(), event)
+}
+
+/// Converts an event into a line with the timestamp of the [`__LogLine`]. See [`tracing_line!`].
+///
+/// [`tracing_line!`]: crate::tracing_line!
+#[doc(hidden)]
+pub fn __tracing_line(log_line: __LogLine, event: &Event<'_>) -> Line<'static> {
+    let metadata = event.metadata();
+    let mut fields = Fields::default();
+    event.record(&mut fields);
+    let mut line = log_line
+        .target(&metadata.target())
+        .line(metadata.level(), fields.message);
+    for (key, value) in fields.fields {
+        line.push_span(Span::raw(" "));
+        line.extend(__KeyValue::default().separator("=").line(&key, &value));
+    }
+    line
+}
+
+/// The message and the other fields of an event.
+#[derive(Debug, Default)]
+struct Fields {
+    message: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_owned();
+        } else {
+            self.fields.push((field.name(), value.to_owned()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields.push((field.name(), format!("{value:?}")));
+        }
+    }
+}
+
+/// A [`tracing_subscriber::Layer`] that converts each event into a [`Line`] and keeps the most
+/// recent lines, e.g. to show the log of an application in a pane of its own interface.
+///
+/// The lines are styled like the lines of [`tracing_line!`]. The layer is cloned to register it
+/// with a subscriber, and the clones share their lines, so the lines are read from the clone that
+/// the application keeps. This type requires the `tracing` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::TracingLineLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let layer = TracingLineLayer::new(100);
+/// let subscriber = tracing_subscriber::registry().with(layer.clone());
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::info!(target: "app", files = 3, "loaded");
+/// });
+/// let lines = layer.lines();
+/// assert_eq!(lines[0].to_string(), "INFO  app: loaded files=3");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`tracing_subscriber::Layer`]: tracing_subscriber::Layer
+/// [`tracing_line!`]: crate::tracing_line!
+#[derive(Clone)]
+pub struct TracingLineLayer {
+    lines: Arc<Mutex<VecDeque<Line<'static>>>>,
+    capacity: usize,
+}
+
+impl TracingLineLayer {
+    /// Creates a layer that keeps the given number of the most recent lines.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Returns a copy of the lines, from the oldest to the most recent.
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+        lines.iter().cloned().collect()
+    }
+
+    /// Removes all lines.
+    pub fn clear(&self) {
+        let mut lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+        lines.clear();
+    }
+}
+
+impl fmt::Debug for TracingLineLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TracingLineLayer")
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Subscriber> Layer<S> for TracingLineLayer {
+    fn on_event(&self, event: &Event<'_>, _context: Context<'_, S>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let line = tracing_event_to_line(event);
+        let mut lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+        if lines.len() == self.capacity {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        style::Stylize,
+        text::{Line, Span},
+    };
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::TracingLineLayer;
+
+    fn collect(capacity: usize, events: impl FnOnce()) -> Vec<Line<'static>> {
+        let layer = TracingLineLayer::new(capacity);
+        let subscriber = tracing_subscriber::registry().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, events);
+        layer.lines()
+    }
+
+    #[test]
+    fn tracing_line_fields() {
+        let lines = collect(10, || {
+            let path = "a.txt";
+            tracing::error!(target: "app::io", path, code = 2, "read failed: {}", "denied");
+        });
+        assert_eq!(
+            lines,
+            [Line::from(vec![
+                Span::raw("ERROR").red().bold(),
+                Span::raw(" app::io:").dim(),
+                Span::raw(" read failed: denied"),
+                Span::raw(" "),
+                Span::raw("path=").dim(),
+                Span::raw("a.txt"),
+                Span::raw(" "),
+                Span::raw("code=").dim(),
+                Span::raw("2"),
+            ])]
+        );
+    }
+
+    #[test]
+    fn tracing_line_layer_capacity() {
+        let lines = collect(2, || {
+            tracing::debug!(target: "app", "one");
+            tracing::trace!(target: "app", "two");
+            tracing::info!(target: "app", "three");
+        });
+        let lines = lines.iter().map(Line::to_string).collect::<Vec<_>>();
+        assert_eq!(lines, ["TRACE app: two", "INFO  app: three"]);
+        assert!(collect(0, || tracing::info!("ignored")).is_empty());
+    }
+}
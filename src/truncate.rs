@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+
+use ratatui_core::{style::Style, text::Span};
+
+/// A macro for creating a [`Span`] that is truncated to a display width with an ellipsis.
+///
+/// The arguments are the content, which is anything that can be converted into a `Cow<str>`, and
+/// the maximum width in cells. Content that is wider is cut at the end and followed by `…`, so
+/// that the span including the ellipsis is at most as wide as the width. With a leading `left;`,
+/// the content is cut at the start instead, which keeps the end of e.g. a path visible, and
+/// `right;` is the default.
+///
+/// The width is measured in cells and the content is only cut between graphemes, so wide
+/// characters like CJK and emoji, and characters with combining marks, are never split. When a
+/// wide character does not fit next to the ellipsis, it is left out and the span is one cell
+/// narrower than the width. Content that fits is not copied.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_macros::truncate;
+///
+/// let path = "/home/ferris/projects/ratatui-macros/src/lib.rs";
+/// assert_eq!(truncate!(path, 16).content, "/home/ferris/pr…");
+/// assert_eq!(truncate!(left; path, 16).content, "…cros/src/lib.rs");
+/// assert_eq!(truncate!("short", 16).content, "short");
+/// assert_eq!(truncate!("你好世界", 6).content, "你好…");
+/// ```
+///
+/// [`Span`]: ratatui_core::text::Span
+#[macro_export]
+macro_rules! truncate {
+    (left; $content:expr, $width:expr $(,)?) => {
+        $crate::__truncate($content, $width, true)
+    };
+    (right; $content:expr, $width:expr $(,)?) => {
+        $crate::__truncate($content, $width, false)
+    };
+    ($content:expr, $width:expr $(,)?) => {
+        $crate::__truncate($content, $width, false)
+    };
+}
+
+/// The symbol that replaces the part of the content that is cut off.
+const ELLIPSIS: &str = "…";
+
+/// Truncates the content to the width with an ellipsis, at the start when `start` is true and at
+/// the end otherwise. See [`truncate!`].
+///
+/// [`truncate!`]: crate::truncate!
+#[doc(hidden)]
+pub fn __truncate<'a>(content: impl Into<Cow<'a, str>>, width: u16, start: bool) -> Span<'a> {
+    let content = content.into();
+    let width = usize::from(width);
+    if Span::raw(content.as_ref()).width() <= width {
+        return Span::raw(content);
+    }
+    if width == 0 {
+        return Span::raw("");
+    }
+    let available = width - Span::raw(ELLIPSIS).width();
+    let truncated = if start {
+        format!("{ELLIPSIS}{}", truncate_str_start(&content, available))
+    } else {
+        format!("{}{ELLIPSIS}", truncate_str(&content, available))
+    };
+    Span::raw(truncated)
+}
+
+/// Returns the longest prefix of the string that is at most `width` cells wide and does not split
+/// a grapheme. Control characters are left out.
+pub(crate) fn truncate_str(string: &str, width: usize) -> String {
+    let mut truncated = String::new();
+    let mut remaining = width;
+    for grapheme in Span::raw(string).styled_graphemes(Style::new()) {
+        let grapheme_width = Span::raw(grapheme.symbol).width();
+        if grapheme_width > remaining {
+            break;
+        }
+        remaining -= grapheme_width;
+        truncated.push_str(grapheme.symbol);
+    }
+    truncated
+}
+
+/// Returns the longest suffix of the string that is at most `width` cells wide and does not split
+/// a grapheme. Control characters are left out.
+fn truncate_str_start(string: &str, width: usize) -> String {
+    let span = Span::raw(string);
+    let graphemes: Vec<_> = span.styled_graphemes(Style::new()).collect();
+    let mut remaining = width;
+    let mut start = graphemes.len();
+    for (i, grapheme) in graphemes.iter().enumerate().rev() {
+        let grapheme_width = Span::raw(grapheme.symbol).width();
+        if grapheme_width > remaining {
+            break;
+        }
+        remaining -= grapheme_width;
+        start = i;
+    }
+    graphemes[start..]
+        .iter()
+        .map(|grapheme| grapheme.symbol)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    #[test]
+    fn truncate_end() {
+        assert_eq!(truncate!("abcdef", 4).content, "abc…");
+        assert_eq!(truncate!(right; "abcdef", 4).content, "abc…");
+        assert_eq!(truncate!(String::from("abcdef"), 6).content, "abcdef");
+        assert_eq!(truncate!("abcdef", 1).content, "…");
+        assert_eq!(truncate!("abcdef", 0).content, "");
+        assert_eq!(truncate!("", 0).content, "");
+    }
+
+    #[test]
+    fn truncate_start() {
+        assert_eq!(truncate!(left; "abcdef", 4).content, "…def");
+        assert_eq!(truncate!(left; "abcdef", 1).content, "…");
+        assert_eq!(truncate!(left; "abc", 3,).content, "abc");
+    }
+
+    #[test]
+    fn truncate_borrows_content_that_fits() {
+        assert!(matches!(truncate!("abc", 3).content, Cow::Borrowed("abc")));
+    }
+
+    #[test]
+    fn truncate_wide_characters() {
+        // a wide character that does not fit next to the ellipsis is left out
+        assert_eq!(truncate!("你好世界", 5).content, "你好…");
+        assert_eq!(truncate!("你好世界", 4).content, "你…");
+        assert_eq!(truncate!(left; "你好世界", 4).content, "…界");
+        assert_eq!(truncate!("a👍b", 3).content, "a…");
+        assert_eq!(truncate!("a👍b", 4).content, "a👍b");
+    }
+
+    #[test]
+    fn truncate_graphemes() {
+        // the combining acute accent belongs to the `e`, and the family emoji is one grapheme
+        assert_eq!(
+            truncate!("e\u{301}e\u{301}e\u{301}", 2).content,
+            "e\u{301}…"
+        );
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(
+            truncate!(left; format!("ab{family}"), 3).content,
+            format!("…{family}")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::marker::PhantomData;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A macro for creating a [`Widget`] from a closure that renders it.
+///
+/// The argument is a closure that receives the area to render into and the [`Buffer`], e.g.
+/// `|area, buf| { ... }`. The closure may capture variables, also with `move`, and the widget is
+/// rendered by calling it once, so the closure may consume the values it captures, e.g. by moving
+/// them into another widget that it renders.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+/// use ratatui_macros::widget;
+///
+/// let greeting = widget!(|area, buf| {
+///     buf.set_string(area.x, area.y, "hi", Style::default());
+/// });
+///
+/// let name = String::from("ferris");
+/// let hello = widget!(move |area, buf| {
+///     buf.set_string(area.x, area.y, format!("hello {name}"), Style::default());
+/// });
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+/// hello.render(buffer.area, &mut buffer);
+/// assert_eq!(buffer, Buffer::with_lines(["hello ferris"]));
+/// ```
+///
+/// [`Widget`]: ratatui_core::widgets::Widget
+/// [`Buffer`]: ratatui_core::buffer::Buffer
+#[macro_export]
+macro_rules! widget {
+    ($render:expr $(,)?) => {
+        $crate::__Widget::new($render)
+    };
+}
+
+/// A macro for creating a [`StatefulWidget`] from a closure that renders it.
+///
+/// Like [`widget!`], but the closure also receives the state of the widget, e.g.
+/// `|area, buf, state: &mut ListState| { ... }`. The type of the state is the
+/// [`StatefulWidget::State`] of the widget, so it is usually written on the closure argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::{buffer::Buffer, layout::Rect, style::Style, widgets::StatefulWidget};
+/// use ratatui_macros::stateful_widget;
+///
+/// let counter = stateful_widget!(|area, buf, count: &mut u32| {
+///     *count += 1;
+///     buf.set_string(area.x, area.y, format!("renders: {count}"), Style::default());
+/// });
+///
+/// let mut count = 0;
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+/// counter.render(buffer.area, &mut buffer, &mut count);
+/// assert_eq!(count, 1);
+/// assert_eq!(buffer, Buffer::with_lines(["renders: 1"]));
+/// ```
+///
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+/// [`StatefulWidget::State`]: ratatui_core::widgets::StatefulWidget::State
+/// [`widget!`]: crate::widget!
+#[macro_export]
+macro_rules! stateful_widget {
+    ($render:expr $(,)?) => {
+        $crate::__StatefulWidget::new($render)
+    };
+}
+
+/// A [`Widget`] that is rendered by a closure. See [`widget!`].
+///
+/// [`widget!`]: crate::widget!
+#[doc(hidden)]
+pub struct __Widget<F>(F);
+
+impl<F: FnOnce(Rect, &mut Buffer)> __Widget<F> {
+    /// Creates the widget. The bound on `F` lets the compiler infer the types of the arguments of
+    /// the closure.
+    pub const fn new(render: F) -> Self {
+        Self(render)
+    }
+}
+
+impl<F: FnOnce(Rect, &mut Buffer)> Widget for __Widget<F> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (self.0)(area, buf);
+    }
+}
+
+/// A [`StatefulWidget`] that is rendered by a closure. See [`stateful_widget!`].
+///
+/// [`stateful_widget!`]: crate::stateful_widget!
+#[doc(hidden)]
+pub struct __StatefulWidget<F, S>(F, PhantomData<fn(&mut S)>);
+
+impl<F: FnOnce(Rect, &mut Buffer, &mut S), S> __StatefulWidget<F, S> {
+    /// Creates the widget. The bound on `F` lets the compiler infer the types of the arguments of
+    /// the closure.
+    pub const fn new(render: F) -> Self {
+        Self(render, PhantomData)
+    }
+}
+
+impl<F: FnOnce(Rect, &mut Buffer, &mut S), S> StatefulWidget for __StatefulWidget<F, S> {
+    type State = S;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut S) {
+        (self.0)(area, buf, state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        text::Line,
+        widgets::{StatefulWidget, Widget},
+    };
+    use ratatui_widgets::list::{List, ListState};
+
+    #[test]
+    fn widget_closure() {
+        let widget = widget!(|area, buf| {
+            buf.set_string(area.x, area.y, "hi", Style::new().bold());
+        });
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        widget.render(Rect::new(1, 0, 2, 1), &mut buffer);
+        let mut expected = Buffer::with_lines([" hi"]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn widget_consumes_captures() {
+        let text = String::from("ab");
+        let widget =
+            widget!(move |area: Rect, buf: &mut Buffer| Line::from(text).render(area, buf));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["ab"]));
+    }
+
+    #[test]
+    fn stateful_widget_closure() {
+        let widget = stateful_widget!(|area, buf, state: &mut ListState| {
+            let list = List::new(["a", "b", "c"]).highlight_symbol(">");
+            StatefulWidget::render(list, area, buf, state);
+        });
+        let mut state = ListState::default().with_selected(Some(2));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 2));
+        widget.render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([" b", ">c"]));
+        assert_eq!(state.offset(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use ratatui_core::text::{Line, Span, Text};
+
+/// A macro for measuring the display width of a string, [`Span`], [`Line`] or [`Text`] in cells.
+///
+/// The width is unicode-aware, so wide characters like `界` count as two cells and combining
+/// characters as none, the same as the widths that Ratatui uses when it renders the text. A string
+/// or [`Text`] with several lines is as wide as its widest line.
+///
+/// With the `proc-macros` feature, the width of a string literal is measured at compile time, so
+/// `display_width!("...")` can be used in a constant. Measure the plain text of a string that
+/// contains ANSI escape sequences, e.g. with [`ansi_strip!`], since the sequences take up no cells.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{Line, Span};
+/// use ratatui_macros::display_width;
+///
+/// assert_eq!(display_width!("hello"), 5);
+/// assert_eq!(display_width!("界a"), 3);
+///
+/// let name = String::from("ferris");
+/// assert_eq!(display_width!(name), 6);
+/// assert_eq!(display_width!(Line::from(vec![Span::raw("a"), Span::raw("界")])), 3);
+/// ```
+///
+/// ```rust
+/// # #[cfg(feature = "proc-macros")]
+/// # {
+/// use ratatui_macros::display_width;
+///
+/// const LABEL_WIDTH: usize = display_width!("名前: ");
+/// assert_eq!(LABEL_WIDTH, 6);
+/// # }
+/// ```
+///
+/// [`Span`]: ratatui_core::text::Span
+/// [`Line`]: ratatui_core::text::Line
+/// [`Text`]: ratatui_core::text::Text
+/// [`ansi_strip!`]: crate::ansi_strip!
+#[macro_export]
+macro_rules! display_width {
+    ($text:literal $(,)?) => {
+        $crate::__literal_width!($text)
+    };
+    ($text:expr $(,)?) => {
+        $crate::__DisplayWidthOf::__display_width(&$text)
+    };
+}
+
+/// Measures a string literal for [`display_width!`], at compile time with the `proc-macros`
+/// feature.
+///
+/// [`display_width!`]: crate::display_width!
+#[cfg(feature = "proc-macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __literal_width {
+    ($text:literal) => {
+        $crate::ratatui_macros_proc::literal_width!($text)
+    };
+}
+
+/// Measures a string literal for [`display_width!`], at compile time with the `proc-macros`
+/// feature.
+///
+/// [`display_width!`]: crate::display_width!
+#[cfg(not(feature = "proc-macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __literal_width {
+    ($text:literal) => {
+        $crate::__DisplayWidthOf::__display_width($text)
+    };
+}
+
+/// The values that [`display_width!`] measures.
+///
+/// [`display_width!`]: crate::display_width!
+#[doc(hidden)]
+pub trait __DisplayWidthOf {
+    fn __display_width(&self) -> usize;
+}
+
+impl __DisplayWidthOf for str {
+    fn __display_width(&self) -> usize {
+        Text::raw(self).width()
+    }
+}
+
+impl __DisplayWidthOf for String {
+    fn __display_width(&self) -> usize {
+        self.as_str().__display_width()
+    }
+}
+
+impl __DisplayWidthOf for Span<'_> {
+    fn __display_width(&self) -> usize {
+        self.width()
+    }
+}
+
+impl __DisplayWidthOf for Line<'_> {
+    fn __display_width(&self) -> usize {
+        self.width()
+    }
+}
+
+impl __DisplayWidthOf for Text<'_> {
+    fn __display_width(&self) -> usize {
+        self.width()
+    }
+}
+
+impl<T: __DisplayWidthOf + ?Sized> __DisplayWidthOf for &T {
+    fn __display_width(&self) -> usize {
+        (**self).__display_width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::{Line, Span, Text};
+
+    #[test]
+    fn display_width_literal() {
+        assert_eq!(display_width!(""), 0);
+        assert_eq!(display_width!("hello"), 5);
+        assert_eq!(display_width!("界a\u{301}"), 3);
+        assert_eq!(display_width!("ab\nabcd"), 4);
+    }
+
+    #[cfg(feature = "proc-macros")]
+    #[test]
+    fn display_width_const() {
+        const WIDTH: usize = display_width!("名前: ");
+        assert_eq!(WIDTH, 6);
+    }
+
+    #[test]
+    fn display_width_values() {
+        let name = String::from("ferris");
+        assert_eq!(display_width!(name), 6);
+        assert_eq!(display_width!(&name[..3]), 3);
+        assert_eq!(display_width!(Span::raw("界")), 2);
+        assert_eq!(
+            display_width!(Line::from(vec![Span::raw("a"), Span::raw("界")])),
+            3
+        );
+        assert_eq!(display_width!(Text::from("a\nabc")), 3);
+    }
+}
@@ -0,0 +1,196 @@
+use ratatui_core::text::{Line, Text};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A macro for creating a [`Text`] from a string that is word-wrapped to a width.
+///
+/// The first argument is the width in columns, and it is followed by a format string and its
+/// arguments, like [`format!`], or by an expression that implements `AsRef<str>`. The string is
+/// wrapped with [`wrap_text`], which measures the display width of each character, so wide
+/// characters like CJK and emoji take two columns. This is useful where the wrapping of a
+/// `Paragraph` is not available, e.g. in the items of a list or the cells of a table.
+///
+/// This macro requires the `wrap` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::text::Text;
+/// use ratatui_macros::wrap;
+///
+/// let text = wrap!(12, "the {} brown fox jumps", "quick");
+/// assert_eq!(text, Text::from(vec!["the quick".into(), "brown fox".into(), "jumps".into()]));
+///
+/// let message = String::from("你好世界");
+/// let text = wrap!(5, message);
+/// assert_eq!(text, Text::from(vec!["你好".into(), "世界".into()]));
+/// ```
+///
+/// [`Text`]: ratatui_core::text::Text
+/// [`wrap_text`]: crate::wrap_text
+#[macro_export]
+macro_rules! wrap {
+    ($width:expr, $format:literal $($args:tt)*) => {
+        $crate::wrap_text(&::std::format!($format $($args)*), $width)
+    };
+    ($width:expr, $input:expr $(,)?) => {
+        $crate::wrap_text(::core::convert::AsRef::<str>::as_ref(&$input), $width)
+    };
+}
+
+/// Word-wraps a string into a [`Text`] whose lines are at most `width` columns wide.
+///
+/// Each line of the string is wrapped on its own. Words are separated by whitespace, which is
+/// collapsed to a single space inside a line and removed at the ends of the lines. A word that is
+/// wider than `width` is broken between its graphemes, and a single grapheme that is wider than
+/// `width` gets a line of its own. A `width` of zero is treated as one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui_core::text::Text;
+/// use ratatui_macros::wrap_text;
+///
+/// let text = wrap_text("a fox and a caterpillar", 6);
+/// assert_eq!(
+///     text,
+///     Text::from(vec!["a fox".into(), "and a".into(), "caterp".into(), "illar".into()])
+/// );
+/// ```
+pub fn wrap_text(input: &str, width: u16) -> Text<'static> {
+    let width = usize::from(width.max(1));
+    let mut lines = Vec::new();
+    for line in input.lines() {
+        let mut wrapper = Wrapper {
+            width,
+            lines: &mut lines,
+            current: String::new(),
+            current_width: 0,
+        };
+        for word in line.split_whitespace() {
+            wrapper.push_word(word);
+        }
+        wrapper.finish();
+    }
+    Text::from(lines)
+}
+
+/// Collects the words of one line of the input into lines of at most `width` columns.
+struct Wrapper<'a> {
+    width: usize,
+    lines: &'a mut Vec<Line<'static>>,
+    current: String,
+    current_width: usize,
+}
+
+impl Wrapper<'_> {
+    fn push_word(&mut self, word: &str) {
+        let word_width = word.width();
+        let separator = usize::from(self.current_width > 0);
+        if self.current_width + separator + word_width <= self.width {
+            if separator > 0 {
+                self.current.push(' ');
+            }
+            self.current.push_str(word);
+            self.current_width += separator + word_width;
+        } else if word_width <= self.width {
+            self.flush();
+            self.current.push_str(word);
+            self.current_width = word_width;
+        } else {
+            self.push_long_word(word);
+        }
+    }
+
+    /// Breaks a word that is wider than a line between its graphemes, starting on the current line
+    /// if there is room for a separator and at least one grapheme.
+    fn push_long_word(&mut self, word: &str) {
+        if self.current_width > 0 {
+            if self.current_width + 1 < self.width {
+                self.current.push(' ');
+                self.current_width += 1;
+            } else {
+                self.flush();
+            }
+        }
+        for grapheme in word.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            if self.current_width > 0 && self.current_width + grapheme_width > self.width {
+                self.flush();
+            }
+            self.current.push_str(grapheme);
+            self.current_width += grapheme_width;
+        }
+    }
+
+    fn flush(&mut self) {
+        let line = std::mem::take(&mut self.current);
+        self.lines.push(Line::from(line.trim_end().to_string()));
+        self.current_width = 0;
+    }
+
+    /// Pushes the last line, which is also pushed when it is empty to keep empty input lines.
+    fn finish(mut self) {
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::{Line, Text};
+
+    use super::wrap_text;
+
+    fn lines(text: &Text) -> Vec<String> {
+        text.lines.iter().map(Line::to_string).collect()
+    }
+
+    #[test]
+    fn wrap_words() {
+        let text = wrap!(10, "the quick  brown   fox jumps over");
+        assert_eq!(lines(&text), ["the quick", "brown fox", "jumps over"]);
+
+        let text = wrap!(9, "{} {}", "hello", 42);
+        assert_eq!(lines(&text), ["hello 42"]);
+
+        let detail = String::from("a b");
+        assert_eq!(lines(&wrap!(1, detail)), ["a", "b"]);
+    }
+
+    #[test]
+    fn wrap_keeps_lines() {
+        let text = wrap_text("one two\n\nthree", 5);
+        assert_eq!(lines(&text), ["one", "two", "", "three"]);
+        assert_eq!(
+            lines(&wrap_text("one two\n\nthree", 3)),
+            ["one", "two", "", "thr", "ee"]
+        );
+        assert_eq!(wrap_text("", 10), Text::default());
+    }
+
+    #[test]
+    fn wrap_long_words() {
+        assert_eq!(lines(&wrap_text("abcdefgh", 3)), ["abc", "def", "gh"]);
+        assert_eq!(lines(&wrap_text("ab cdefgh", 4)), ["ab c", "defg", "h"]);
+        assert_eq!(lines(&wrap_text("abc defg", 4)), ["abc", "defg"]);
+        assert_eq!(lines(&wrap_text("abc", 0)), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn wrap_wide_characters() {
+        // CJK characters are two columns wide
+        assert_eq!(lines(&wrap_text("你好世界", 4)), ["你好", "世界"]);
+        assert_eq!(lines(&wrap_text("你好世界", 5)), ["你好", "世界"]);
+        assert_eq!(lines(&wrap_text("a 你好", 4)), ["a", "你好"]);
+        assert_eq!(lines(&wrap_text("a 你好世界", 5)), ["a 你", "好世", "界"]);
+
+        // emoji are two columns wide, and a grapheme is never broken
+        assert_eq!(lines(&wrap_text("👍👍👍", 5)), ["👍👍", "👍"]);
+        assert_eq!(lines(&wrap_text("é👍", 1)), ["é", "👍"]);
+        // a family emoji is one grapheme that is two columns wide
+        assert_eq!(
+            lines(&wrap_text("ok 👨\u{200d}👩\u{200d}👧 fam", 4)),
+            ["ok", "👨\u{200d}👩\u{200d}👧", "fam"]
+        );
+    }
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "derive")]
+
+use ratatui_core::{
+    layout::Alignment,
+    style::{Color, Style, Stylize},
+    text::{Line, Span, Text},
+};
+use ratatui_macros::{ToLine, ToRow, ToText};
+use ratatui_widgets::table::{Cell, Row};
+
+#[derive(ToLine)]
+struct Entry {
+    #[line(style = "bold")]
+    name: String,
+    #[line(fg = "gray", format = "({})")]
+    count: usize,
+    #[line(skip)]
+    #[allow(dead_code)]
+    id: u64,
+}
+
+#[test]
+fn derive_to_line() {
+    let entry = Entry {
+        name: "src".to_string(),
+        count: 3,
+        id: 7,
+    };
+    assert_eq!(
+        Line::from(&entry),
+        Line::from(vec![
+            Span::raw("src").bold(),
+            Span::raw(" "),
+            Span::raw("(3)").fg(Color::Gray),
+        ])
+    );
+}
+
+#[derive(ToLine)]
+#[line(separator = " | ", style = "italic")]
+struct Pair<T: std::fmt::Display>(T, #[line(style = "red on black")] &'static str);
+
+#[test]
+fn derive_to_line_tuple_struct() {
+    assert_eq!(
+        Line::from(&Pair(1.5, "b")),
+        Line::from(vec![
+            Span::raw("1.5"),
+            Span::raw(" | "),
+            Span::styled("b", Style::new().red().on_black()),
+        ])
+        .italic()
+    );
+}
+
+#[derive(ToText)]
+#[text(fg = "#1e293b")]
+struct Commit {
+    #[text(style = "yellow", format = "commit {}")]
+    hash: &'static str,
+    #[text(format = "Author: {}")]
+    author: &'static str,
+}
+
+#[test]
+fn derive_to_text() {
+    let commit = Commit {
+        hash: "4b397bf",
+        author: "Ferris",
+    };
+    assert_eq!(
+        Text::from(&commit),
+        Text::from(vec![
+            Line::from(Span::raw("commit 4b397bf").yellow()),
+            Line::from(Span::raw("Author: Ferris")),
+        ])
+        .fg(Color::Rgb(0x1e, 0x29, 0x3b))
+    );
+}
+
+#[derive(ToRow)]
+#[row(header_style = "bold", bg = "black")]
+struct Process {
+    #[row(header = "PID", align = "right")]
+    pid: u32,
+    #[row(style = "cyan")]
+    name: &'static str,
+    #[row(header = "CPU", format = "{:.1}%", align = "right")]
+    cpu: f32,
+}
+
+#[test]
+fn derive_to_row() {
+    let process = Process {
+        pid: 42,
+        name: "cargo",
+        cpu: 12.34,
+    };
+    assert_eq!(
+        Row::from(&process),
+        Row::new([
+            Cell::from(Text::from("42").alignment(Alignment::Right)),
+            Cell::from(Text::from("cargo")).cyan(),
+            Cell::from(Text::from("12.3%").alignment(Alignment::Right)),
+        ])
+        .on_black()
+    );
+    assert_eq!(
+        Process::header(),
+        Row::new([
+            Cell::from(Text::from("PID").alignment(Alignment::Right)),
+            Cell::from(Text::from("name")),
+            Cell::from(Text::from("CPU").alignment(Alignment::Right)),
+        ])
+        .bold()
+    );
+}
+
+mod framework {
+    pub use ratatui_macros;
+}
+
+#[derive(ToLine)]
+#[line(crate = "framework::ratatui_macros", fg = "red")]
+struct Reexported {
+    name: &'static str,
+}
+
+#[test]
+fn derive_crate_path() {
+    assert_eq!(
+        Line::from(&Reexported { name: "src" }),
+        Line::from(vec![Span::raw("src")]).red()
+    );
+}
+
+#[cfg(feature = "crossterm")]
+mod key_chord {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui_macros::KeyChord;
+
+    #[derive(Debug, PartialEq, KeyChord)]
+    enum Action {
+        #[chord("ctrl-q", "esc")]
+        Quit,
+        #[chord("shift-ctrl-p")]
+        Palette,
+        #[chord("G")]
+        Bottom,
+    }
+
+    #[test]
+    fn derive_key_chord() {
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        assert_eq!(
+            Action::from_key_event(&key(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            Action::from_key_event(&key(KeyCode::Esc, KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            Action::from_key_event(&key(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            Some(Action::Bottom)
+        );
+        assert_eq!(
+            Action::from_key_event(&key(KeyCode::Char('q'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            Action::Quit.key_chords(),
+            [
+                KeyChord::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
+                KeyChord::new(KeyCode::Esc, KeyModifiers::NONE),
+            ]
+        );
+        assert_eq!(Action::Quit.to_string(), "ctrl-q");
+        assert_eq!(Action::Palette.to_string(), "ctrl-shift-p");
+    }
+}
@@ -1,5 +1,8 @@
-use ratatui_core::layout::{Constraint, Rect};
-use ratatui_macros::{constraints, horizontal, vertical};
+use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
+use ratatui_macros::{
+    anchor, areas, cached_layout, centered_rect, clamp_rect, constraints, ensure_min, grid,
+    horizontal, inset, layout, offset, rect, split, vertical,
+};
 
 #[test]
 fn layout_constraints_macro() {
@@ -95,10 +98,343 @@ fn layout_constraints_macro() {
     let [a, b] = constraints![ == 1/2; 2 ];
     assert_eq!(a, Constraint::Ratio(1, 2));
     assert_eq!(b, Constraint::Ratio(1, 2));
+
+    let [a, b] = constraints![ *=1; 2 ];
+    assert_eq!(a, Constraint::Fill(1));
+    assert_eq!(b, Constraint::Fill(1));
+
+    assert_eq!(
+        constraints![==50%, >=3, <=10, ==1/3, *=1],
+        [
+            Constraint::Percentage(50),
+            Constraint::Min(3),
+            Constraint::Max(10),
+            Constraint::Ratio(1, 3),
+            Constraint::Fill(1),
+        ]
+    );
+}
+
+#[test]
+fn layout_repetition_macro() {
+    let rect = Rect::new(0, 0, 10, 5);
+
+    let rows: [Rect; 5] = vertical![==1; 5].areas(rect);
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(*row, Rect::new(0, i as u16, 10, 1));
+    }
+
+    let [left, right] = horizontal![*=1; 2].areas(rect);
+    assert_eq!(left, Rect::new(0, 0, 5, 5));
+    assert_eq!(right, Rect::new(5, 0, 5, 5));
+
+    let [header, body, footer] = vertical![==3, *=1, ==1].areas(Rect::new(0, 0, 10, 10));
+    assert_eq!(header, Rect::new(0, 0, 10, 3));
+    assert_eq!(body, Rect::new(0, 3, 10, 6));
+    assert_eq!(footer, Rect::new(0, 9, 10, 1));
+}
+
+#[test]
+fn layout_fill_and_ratio_macro() {
+    let rect = Rect::new(0, 0, 12, 12);
+
+    let [a, b, c] = horizontal![*=1, *=2, *=3].areas(rect);
+    assert_eq!((a.width, b.width, c.width), (2, 4, 6));
+
+    let [a, b, c] = vertical![==1/4, *=2, *=1].areas(rect);
+    assert_eq!((a.height, b.height, c.height), (3, 6, 3));
+
+    let weight = 3;
+    assert_eq!(
+        horizontal![==1/3, *=weight],
+        Layout::horizontal([Constraint::Ratio(1, 3), Constraint::Fill(3)])
+    );
+}
+
+#[test]
+fn layout_directives_macro() {
+    assert_eq!(
+        vertical![margin: 1, spacing: 1; ==3, *=1],
+        Layout::vertical([Constraint::Length(3), Constraint::Fill(1)])
+            .margin(1)
+            .spacing(1)
+    );
+    assert_eq!(
+        horizontal![horizontal_margin: 2, vertical_margin: 1, flex: Flex::Center; ==5; 2],
+        Layout::horizontal([Constraint::Length(5); 2])
+            .horizontal_margin(2)
+            .vertical_margin(1)
+            .flex(Flex::Center)
+    );
+
+    let [header, body] = vertical![margin: 1, spacing: 1; ==3, *=1].areas(Rect::new(0, 0, 10, 10));
+    assert_eq!(header, Rect::new(1, 1, 8, 3));
+    assert_eq!(body, Rect::new(1, 5, 8, 4));
+}
+
+#[test]
+fn areas_macro() {
+    let area = Rect::new(0, 0, 10, 10);
+    let [top, middle, bottom] = areas!(area; ==3, *=1, ==1);
+    assert_eq!(top, Rect::new(0, 0, 10, 3));
+    assert_eq!(middle, Rect::new(0, 3, 10, 6));
+    assert_eq!(bottom, Rect::new(0, 9, 10, 1));
+
+    let [left, right] = areas!(area, horizontal; ==30%, *=1);
+    assert_eq!(left, Rect::new(0, 0, 3, 10));
+    assert_eq!(right, Rect::new(3, 0, 7, 10));
+
+    let rows: [Rect; 5] = areas!(area, vertical; ==2; 5);
+    assert_eq!(rows[4], Rect::new(0, 8, 10, 2));
+}
+
+#[test]
+fn split_macro() {
+    let area = Rect::new(0, 0, 10, 10);
+    let chunks = split!(area; header: ==3, body: *=1, footer: ==1,);
+    assert_eq!(chunks.header, Rect::new(0, 0, 10, 3));
+    assert_eq!(chunks.body, Rect::new(0, 3, 10, 6));
+    assert_eq!(chunks.footer, Rect::new(0, 9, 10, 1));
+
+    let width = 3;
+    let columns = split!(area, horizontal; left: ==width, right: *=1);
+    assert_eq!(columns.left, Rect::new(0, 0, 3, 10));
+    assert_eq!(columns.right, Rect::new(3, 0, 7, 10));
+    let copy = columns;
+    assert_eq!(copy, columns);
+}
+
+#[test]
+fn cached_layout_macro() {
+    fn layout() -> &'static Layout {
+        cached_layout![horizontal; spacing: 1; ==30%, *=1]
+    }
+    assert_eq!(*layout(), horizontal![spacing: 1; ==30%, *=1]);
+    assert!(std::ptr::eq(layout(), layout()));
+
+    let [header, body] = cached_layout![vertical; ==1, *=1].areas(Rect::new(0, 0, 10, 10));
+    assert_eq!(header, Rect::new(0, 0, 10, 1));
+    assert_eq!(body, Rect::new(0, 1, 10, 9));
+}
+
+#[test]
+fn layout_tree_macro() {
+    let area = Rect::new(0, 0, 100, 20);
+    layout!(area => vertical {
+        header: ==3,
+        body: horizontal {
+            sidebar: ==30,
+            main: *=1,
+        },
+        footer: ==1,
+    });
+    assert_eq!(header, Rect::new(0, 0, 100, 3));
+    assert_eq!(body, Rect::new(0, 3, 100, 16));
+    assert_eq!(sidebar, Rect::new(0, 3, 30, 16));
+    assert_eq!(main, Rect::new(30, 3, 70, 16));
+    assert_eq!(footer, Rect::new(0, 19, 100, 1));
+
+    let width = 10;
+    layout!(Rect::new(0, 0, 40, 10) => horizontal {
+        left: ==width,
+        vertical { top: ==1/2, bottom: *=1 },
+        right: vertical { status: ==1 },
+    });
+    assert_eq!(left, Rect::new(0, 0, 10, 10));
+    assert_eq!(top, Rect::new(10, 0, 15, 5));
+    assert_eq!(bottom, Rect::new(10, 5, 15, 5));
+    assert_eq!(right, Rect::new(25, 0, 15, 10));
+    assert_eq!(status, Rect::new(25, 0, 15, 1));
+
+    layout!(area => vertical { ==2 horizontal { a: ==1/4, b: *=1 }, c: *=1 });
+    assert_eq!(a, Rect::new(0, 0, 25, 2));
+    assert_eq!(b, Rect::new(25, 0, 75, 2));
+    assert_eq!(c, Rect::new(0, 2, 100, 18));
+}
+
+#[test]
+fn layout_flex_macro() {
+    for (flex, layout) in [
+        (Flex::Start, horizontal![flex: Start; ==10, ==10, ==10]),
+        (Flex::End, horizontal![flex: End; ==10, ==10, ==10]),
+        (Flex::Center, horizontal![flex: Center; ==10, ==10, ==10]),
+        (
+            Flex::SpaceAround,
+            horizontal![flex: SpaceAround; ==10, ==10, ==10],
+        ),
+        (
+            Flex::SpaceBetween,
+            horizontal![flex: SpaceBetween; ==10, ==10, ==10],
+        ),
+    ] {
+        assert_eq!(
+            layout,
+            Layout::horizontal([Constraint::Length(10); 3]).flex(flex)
+        );
+    }
+
+    let [a, b, c] = horizontal![flex: SpaceBetween; ==10, ==10, ==10].areas(Rect::new(0, 0, 50, 1));
+    assert_eq!((a.x, b.x, c.x), (0, 20, 40));
+
+    let flex = Flex::End;
+    assert_eq!(
+        vertical![margin: 1, flex: Center, spacing: 2; ==1; 2],
+        Layout::vertical([Constraint::Length(1); 2])
+            .margin(1)
+            .flex(Flex::Center)
+            .spacing(2)
+    );
+    assert_eq!(
+        vertical![flex: (flex); ==1],
+        Layout::vertical([Constraint::Length(1)]).flex(Flex::End)
+    );
+}
+
+#[test]
+fn rect_macro() {
+    assert_eq!(rect!(1, 2, 30, 10), Rect::new(1, 2, 30, 10));
+    assert_eq!(rect!(30, 10), Rect::new(0, 0, 30, 10));
+
+    let width = 30;
+    assert_eq!(rect!(width, width / 3,), Rect::new(0, 0, 30, 10));
+
+    const AREA: Rect = rect!(0, 0, 100, 100);
+    assert_eq!(AREA, Rect::new(0, 0, 100, 100));
+}
+
+#[test]
+fn rect_macro_clamp() {
+    let area = Rect::new(0, 0, 100, 100);
+    assert_eq!(
+        rect!(80, 80, 30, 30, clamp: area),
+        Rect::new(70, 70, 30, 30)
+    );
+    assert_eq!(rect!(120, 50, clamp: area), Rect::new(0, 0, 100, 50));
+}
+
+#[test]
+fn centered_rect_macro() {
+    let area = Rect::new(0, 0, 100, 50);
+    assert_eq!(centered_rect!(area, 60%, 20%), Rect::new(20, 20, 60, 10));
+    assert_eq!(centered_rect!(area, ==40, ==10), Rect::new(30, 20, 40, 10));
+    assert_eq!(centered_rect!(area, ==50%, 20%), Rect::new(25, 20, 50, 10));
+    assert_eq!(centered_rect!(area, 50%, ==6,), Rect::new(25, 22, 50, 6));
+
+    let width = 40;
+    assert_eq!(
+        centered_rect!(Rect::new(10, 10, 100, 50), ==width, ==1/5),
+        Rect::new(40, 30, 40, 10)
+    );
+}
+
+#[test]
+fn grid_macro() {
+    let area = Rect::new(2, 1, 12, 4);
+    let cells = grid!(area, rows: [==1; 3], cols: [*=1; 3],);
+    assert_eq!(cells.len(), 3);
+    assert_eq!(
+        cells[0],
+        [
+            Rect::new(2, 1, 4, 1),
+            Rect::new(6, 1, 4, 1),
+            Rect::new(10, 1, 4, 1)
+        ]
+    );
+    assert_eq!(cells[2][1], Rect::new(6, 3, 4, 1));
+
+    let [[top], [bottom]] = grid!(area, rows: [==50%, *=1], cols: [==6]);
+    assert_eq!(
+        (top, bottom),
+        (Rect::new(2, 1, 6, 2), Rect::new(2, 3, 6, 2))
+    );
 }
 
 #[test]
+fn anchor_macro() {
+    let area = Rect::new(10, 5, 40, 20);
+    assert_eq!(anchor!(area, 10x4, top_left), Rect::new(10, 5, 10, 4));
+    assert_eq!(anchor!(area, 10x4, top_right), Rect::new(40, 5, 10, 4));
+    assert_eq!(anchor!(area, 10x4, center_right,), Rect::new(40, 13, 10, 4));
+    assert_eq!(anchor!(area, 10x4, bottom_left), Rect::new(10, 21, 10, 4));
+    assert_eq!(anchor!(area, 10x4, bottom_center), Rect::new(25, 21, 10, 4));
+    assert_eq!(anchor!(area, 25%x50%, center), Rect::new(25, 10, 10, 10));
+    assert_eq!(
+        anchor!(area, 10x50%, bottom_right),
+        Rect::new(40, 15, 10, 10)
+    );
+    assert_eq!(anchor!(area, 50 % x3, top_center), Rect::new(20, 5, 20, 3));
+    assert_eq!(
+        anchor!(area, 150 % x100, bottom_right),
+        Rect::new(10, 5, 40, 20)
+    );
+    assert_eq!(
+        anchor!(area, (u16::MAX, 2), center_left),
+        Rect::new(10, 14, 40, 2)
+    );
+}
+
+#[test]
+fn inset_macro() {
+    let area = Rect::new(2, 2, 10, 6);
+    assert_eq!(inset!(area, 1), Rect::new(3, 3, 8, 4));
+    assert_eq!(inset!(area, x: 2, y: 1,), Rect::new(4, 3, 6, 4));
+    assert_eq!(inset!(area, x: 5), Rect::new(7, 2, 0, 6));
+    assert_eq!(inset!(area, y: 4), Rect::ZERO);
+}
+
+#[test]
+fn offset_macro() {
+    let area = Rect::new(5, 5, 10, 2);
+    let (dx, dy): (u16, i32) = (3, -2);
+    assert_eq!(offset!(area, dx, dy), Rect::new(8, 3, 10, 2));
+    assert_eq!(offset!(area, -6, -6), Rect::new(0, 0, 10, 2));
+    assert_eq!(offset!(area, i32::MAX, 0).right(), u16::MAX);
+    assert_eq!(
+        offset!(area, 20, 0, clamp: Rect::new(0, 0, 20, 10)),
+        Rect::new(10, 5, 10, 2)
+    );
+}
+
+#[test]
+fn clamp_rect_macro() {
+    let parent = Rect::new(5, 5, 10, 10);
+    assert_eq!(
+        clamp_rect!(Rect::new(6, 6, 2, 2), parent),
+        Rect::new(6, 6, 2, 2)
+    );
+    assert_eq!(
+        clamp_rect!(Rect::new(0, 0, 8, 20), parent),
+        Rect::new(5, 5, 3, 10)
+    );
+    assert_eq!(
+        clamp_rect!(Rect::new(0, 0, 100, 100), parent, Rect::new(10, 0, 20, 8),),
+        Rect::new(10, 5, 5, 3)
+    );
+    assert!(clamp_rect!(Rect::new(20, 20, 5, 5), parent).is_empty());
+}
+
+#[test]
+fn ensure_min_macro() {
+    let area = Rect::new(2, 3, 20, 5);
+    assert_eq!(ensure_min!(area, 20x5), Some(area));
+    assert_eq!(ensure_min!(area, 21x5), None);
+    assert_eq!(ensure_min!(area, 1x6,), None);
+    assert_eq!(ensure_min!(Rect::ZERO, 0x0), Some(Rect::ZERO));
+    let width = 10;
+    assert_eq!(ensure_min!(area, (width, 5)), Some(area));
+    assert_eq!(ensure_min!(area, (width * 3, 5)), None);
+}
+
+#[test]
+#[cfg(not(feature = "proc-macros"))]
 fn fails() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/fails.rs");
 }
+
+#[test]
+#[cfg(feature = "proc-macros")]
+fn fails_checked() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/checked.rs");
+}
@@ -0,0 +1,24 @@
+use ratatui_macros::{constraints, line, styled};
+
+fn main() {
+    let _ = constraints![==1, , ==2];
+    let _ = constraints![50%];
+    let _ = constraints![==];
+    let _ = constraints![==1 + , ==2];
+    let _ = constraints![==1, ==2; 3];
+    let _ = constraints![==1;];
+    let _ = constraints![==60%, ==50%];
+
+    let modified = true;
+    let _ = line!["a", if modified " [+]"];
+    let _ = line!["a" "b"];
+    let _ = line!["a", {1, 2}];
+    let _ = line!["a", , "b"];
+    let _ = line!["a", "b"; 2];
+
+    let _ = styled!();
+    let _ = styled!(Color::Red; "error");
+    let _ = styled!(Color::Red);
+    let _ = styled!(Color::Red,);
+    let _ = styled!(Color::Red, 1, 2);
+}